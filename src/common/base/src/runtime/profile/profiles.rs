@@ -44,6 +44,8 @@ pub enum ProfileStatisticsName {
     SpillReadBytes,
     SpillReadTime,
     RuntimeFilterPruneParts,
+    AdaptiveJoinBroadcast,
+    AdaptiveJoinShuffle,
     MemoryUsage,
 }
 
@@ -236,6 +238,20 @@ pub fn get_statistics_desc() -> Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>
                 unit: StatisticsUnit::Count,
                 plain_statistics: true,
             }),
+            (ProfileStatisticsName::AdaptiveJoinBroadcast, ProfileDesc {
+                display_name: "adaptive join kept broadcast",
+                desc: "The number of nodes on which the adaptive join kept broadcasting the build side",
+                index: ProfileStatisticsName::AdaptiveJoinBroadcast as usize,
+                unit: StatisticsUnit::Count,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::AdaptiveJoinShuffle, ProfileDesc {
+                display_name: "adaptive join switched to shuffle",
+                desc: "The number of nodes on which the adaptive join switched to shuffling both sides",
+                index: ProfileStatisticsName::AdaptiveJoinShuffle as usize,
+                unit: StatisticsUnit::Count,
+                plain_statistics: true,
+            }),
             (ProfileStatisticsName::MemoryUsage, ProfileDesc {
                 display_name: "memory usage",
                 desc: "The real time memory usage",
@@ -173,6 +173,9 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// CheckConstraintViolated is used when the written rows don't satisfy
+    /// the CHECK constraints of the table.
+    CheckConstraintViolated(1304),
 
     // License related errors starts here
 
@@ -342,6 +345,20 @@ build_exceptions! {
     IllegalStream(2733),
     StreamVersionMismatched(2734),
 
+    // Sequence error codes.
+    UnknownSequence(2740),
+    SequenceAlreadyExists(2741),
+    SequenceError(2742),
+
+    // Row access policy error codes.
+    UnknownRowAccessPolicy(2750),
+    RowAccessPolicyAlreadyExists(2751),
+    RowAccessPolicyInUse(2752),
+
+    // Plan baseline error codes.
+    UnknownPlanBaseline(2760),
+    PlanBaselineAlreadyExists(2761),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
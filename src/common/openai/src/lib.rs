@@ -14,8 +14,17 @@
 
 mod completion;
 mod embedding;
+mod onnx;
+mod provider;
 
 #[allow(clippy::module_inception)]
 mod openai;
 
+pub use onnx::OnnxEmbedding;
 pub use openai::OpenAI;
+pub use provider::embedding_in_batches;
+pub use provider::onnx_model_path;
+pub use provider::AIConnection;
+pub use provider::EmbeddingProvider;
+pub use provider::AI_PROVIDER_ONNX;
+pub use provider::AI_PROVIDER_OPENAI;
@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use parking_lot::Mutex;
+
+use crate::EmbeddingProvider;
+
+/// Inputs longer than this are truncated, it's the limit of the common BERT-like models.
+#[cfg(feature = "onnx")]
+const MAX_INPUT_TOKENS: usize = 512;
+
+/// An in-process embedding model, exported to ONNX with its huggingface tokenizer:
+///
+/// <model_path>/model.onnx
+/// <model_path>/tokenizer.json
+///
+/// The embedding is the mean pooling of the last hidden state, normalized to unit length.
+pub struct OnnxEmbedding {
+    #[cfg(feature = "onnx")]
+    session: ort::Session,
+    #[cfg(feature = "onnx")]
+    tokenizer: tokenizers::Tokenizer,
+    #[cfg(feature = "onnx")]
+    with_token_type_ids: bool,
+}
+
+impl OnnxEmbedding {
+    /// Loading a model is expensive, so each model is loaded once and shared by all the queries.
+    pub fn load(model_path: &Path) -> Result<Arc<OnnxEmbedding>> {
+        static MODELS: OnceLock<Mutex<HashMap<PathBuf, Arc<OnnxEmbedding>>>> = OnceLock::new();
+
+        let mut models = MODELS.get_or_init(Default::default).lock();
+        if let Some(model) = models.get(model_path) {
+            return Ok(model.clone());
+        }
+        let model = Arc::new(Self::try_create(model_path)?);
+        models.insert(model_path.to_path_buf(), model.clone());
+        Ok(model)
+    }
+
+    #[cfg(feature = "onnx")]
+    fn try_create(model_path: &Path) -> Result<OnnxEmbedding> {
+        let to_error = |e: &dyn std::fmt::Display| {
+            ErrorCode::InvalidConfig(format!(
+                "failed to load onnx model {}: {e}",
+                model_path.display()
+            ))
+        };
+
+        let session = ort::Session::builder()
+            .and_then(|builder| builder.commit_from_file(model_path.join("model.onnx")))
+            .map_err(|e| to_error(&e))?;
+        let with_token_type_ids = session
+            .inputs
+            .iter()
+            .any(|input| input.name == "token_type_ids");
+
+        let mut tokenizer = tokenizers::Tokenizer::from_file(model_path.join("tokenizer.json"))
+            .map_err(|e| to_error(&e))?;
+        tokenizer
+            .with_truncation(Some(tokenizers::TruncationParams {
+                max_length: MAX_INPUT_TOKENS,
+                ..Default::default()
+            }))
+            .map_err(|e| to_error(&e))?;
+
+        Ok(OnnxEmbedding {
+            session,
+            tokenizer,
+            with_token_type_ids,
+        })
+    }
+
+    #[cfg(not(feature = "onnx"))]
+    fn try_create(_model_path: &Path) -> Result<OnnxEmbedding> {
+        Err(ErrorCode::Unimplemented(
+            "the onnx provider is not enabled in this build, it requires the `onnx` feature",
+        ))
+    }
+}
+
+#[cfg(feature = "onnx")]
+impl EmbeddingProvider for OnnxEmbedding {
+    fn embedding(&self, input: &[String]) -> Result<Vec<Vec<f32>>> {
+        use ndarray::Array2;
+        use ndarray::Axis;
+
+        let to_error =
+            |e: &dyn std::fmt::Display| ErrorCode::Internal(format!("onnx embedding error: {e}"));
+
+        let encodings = self
+            .tokenizer
+            .encode_batch(input.to_vec(), true)
+            .map_err(|e| to_error(&e))?;
+        let rows = encodings.len();
+        let tokens = encodings.iter().map(|e| e.len()).max().unwrap_or(0);
+
+        // Pad all the inputs to the longest one, the padding is masked out.
+        let mut input_ids = Array2::<i64>::zeros((rows, tokens));
+        let mut attention_mask = Array2::<i64>::zeros((rows, tokens));
+        let mut token_type_ids = Array2::<i64>::zeros((rows, tokens));
+        for (row, encoding) in encodings.iter().enumerate() {
+            for (token, id) in encoding.get_ids().iter().enumerate() {
+                input_ids[[row, token]] = *id as i64;
+                attention_mask[[row, token]] = encoding.get_attention_mask()[token] as i64;
+                token_type_ids[[row, token]] = encoding.get_type_ids()[token] as i64;
+            }
+        }
+
+        let inputs = if self.with_token_type_ids {
+            ort::inputs![
+                "input_ids" => input_ids.view(),
+                "attention_mask" => attention_mask.view(),
+                "token_type_ids" => token_type_ids.view(),
+            ]
+        } else {
+            ort::inputs![
+                "input_ids" => input_ids.view(),
+                "attention_mask" => attention_mask.view(),
+            ]
+        }
+        .map_err(|e| to_error(&e))?;
+        let outputs = self.session.run(inputs).map_err(|e| to_error(&e))?;
+        // [rows, tokens, dimensions]
+        let hidden = outputs[0]
+            .try_extract_tensor::<f32>()
+            .map_err(|e| to_error(&e))?;
+
+        let mut embeddings = Vec::with_capacity(rows);
+        for (row, states) in hidden.axis_iter(Axis(0)).enumerate() {
+            let mut embedding = vec![0_f32; states.shape()[1]];
+            let mut count = 0_f32;
+            for (token, state) in states.axis_iter(Axis(0)).enumerate() {
+                if attention_mask[[row, token]] == 0 {
+                    continue;
+                }
+                count += 1.0;
+                for (v, s) in embedding.iter_mut().zip(state.iter()) {
+                    *v += *s;
+                }
+            }
+            let count = count.max(1.0);
+            embedding.iter_mut().for_each(|v| *v /= count);
+
+            let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+            if norm > 0.0 {
+                embedding.iter_mut().for_each(|v| *v /= norm);
+            }
+            embeddings.push(embedding);
+        }
+        Ok(embeddings)
+    }
+}
+
+#[cfg(not(feature = "onnx"))]
+impl EmbeddingProvider for OnnxEmbedding {
+    fn embedding(&self, _input: &[String]) -> Result<Vec<Vec<f32>>> {
+        unreachable!("the onnx provider is not enabled in this build")
+    }
+}
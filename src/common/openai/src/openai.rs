@@ -15,6 +15,13 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use url::Url;
+
+const OPENAI_API_BASE: &str = "https://api.openai.com/v1/";
+const OPENAI_API_HOST: &str = "api.openai.com";
+
 pub struct OpenAI {
     pub(crate) api_key: String,
     pub(crate) api_version: String,
@@ -47,7 +54,7 @@ impl OpenAI {
         // openai: the api_base always same.
         // azure: chat and embedding are not same, need config them.
         let api_base = if api_base.is_empty() {
-            "https://api.openai.com/v1/".to_string()
+            OPENAI_API_BASE.to_string()
         } else {
             api_base
         };
@@ -72,4 +79,27 @@ impl OpenAI {
             api_version,
         }
     }
+
+    /// Only the hosted OpenAI and Azure endpoints require an api key,
+    /// OpenAI-compatible servers (e.g. a local llama.cpp server) usually don't.
+    pub fn require_api_key(&self) -> bool {
+        // Azure is the only one configured with an api version.
+        if !self.api_version.is_empty() {
+            return true;
+        }
+        match Url::parse(&self.api_base) {
+            Ok(url) => url.host_str() == Some(OPENAI_API_HOST),
+            Err(_) => true,
+        }
+    }
+
+    pub fn check_api_key(&self) -> Result<()> {
+        if self.api_key.is_empty() && self.require_api_key() {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "openai_api_key is empty, it's required by {}",
+                self.api_base
+            )));
+        }
+        Ok(())
+    }
 }
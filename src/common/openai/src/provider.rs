@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use url::Url;
+
+use crate::OpenAI;
+
+pub const AI_PROVIDER_OPENAI: &str = "openai";
+pub const AI_PROVIDER_ONNX: &str = "onnx";
+
+/// An AI provider defined by a connection and selected by the `ai_connection` setting, e.g.
+///
+/// CREATE CONNECTION llama STORAGE_TYPE = 'openai' ENDPOINT_URL = 'http://127.0.0.1:8080/v1/'
+/// CREATE CONNECTION bge STORAGE_TYPE = 'onnx' MODEL = 'bge-small-en'
+///
+/// The api key of the config is never sent to the endpoint of a connection,
+/// a connection which needs an api key must carry its own.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AIConnection {
+    OpenAI {
+        endpoint_url: String,
+        api_key: String,
+        api_version: String,
+        embedding_model: String,
+        completion_model: String,
+    },
+    Onnx {
+        model: String,
+    },
+}
+
+impl AIConnection {
+    pub fn is_ai_provider(storage_type: &str) -> bool {
+        let storage_type = storage_type.to_lowercase();
+        storage_type == AI_PROVIDER_OPENAI || storage_type == AI_PROVIDER_ONNX
+    }
+
+    pub fn try_create(storage_type: &str, params: &BTreeMap<String, String>) -> Result<Self> {
+        let mut params = params
+            .iter()
+            .map(|(k, v)| (k.to_lowercase(), v.clone()))
+            .collect::<BTreeMap<_, _>>();
+
+        let connection = match storage_type.to_lowercase().as_str() {
+            AI_PROVIDER_OPENAI => {
+                let endpoint_url = params.remove("endpoint_url").ok_or_else(|| {
+                    ErrorCode::BadArguments("endpoint_url is required by an openai connection")
+                })?;
+                Url::parse(&endpoint_url).map_err(|e| {
+                    ErrorCode::BadArguments(format!("invalid endpoint_url '{endpoint_url}': {e}"))
+                })?;
+                AIConnection::OpenAI {
+                    endpoint_url,
+                    api_key: params.remove("api_key").unwrap_or_default(),
+                    api_version: params.remove("api_version").unwrap_or_default(),
+                    embedding_model: params.remove("embedding_model").unwrap_or_default(),
+                    completion_model: params.remove("completion_model").unwrap_or_default(),
+                }
+            }
+            AI_PROVIDER_ONNX => {
+                let model = params.remove("model").ok_or_else(|| {
+                    ErrorCode::BadArguments("model is required by an onnx connection")
+                })?;
+                check_onnx_model_name(&model)?;
+                AIConnection::Onnx { model }
+            }
+            other => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "unknown ai provider '{other}', expect '{AI_PROVIDER_OPENAI}' or '{AI_PROVIDER_ONNX}'"
+                )));
+            }
+        };
+
+        if let Some(key) = params.keys().next() {
+            return Err(ErrorCode::BadArguments(format!(
+                "unknown option '{key}' for {storage_type} connection"
+            )));
+        }
+        Ok(connection)
+    }
+}
+
+/// The ONNX models are loaded from `<models_dir>/<model>/`. The model must be a plain
+/// directory name, so a connection can't load files outside of the configured directory.
+pub fn onnx_model_path(models_dir: &str, model: &str) -> Result<PathBuf> {
+    check_onnx_model_name(model)?;
+    if models_dir.is_empty() {
+        return Err(ErrorCode::InvalidConfig(
+            "ai_onnx_models_dir is empty, it's required by the onnx provider",
+        ));
+    }
+    Ok(Path::new(models_dir).join(model))
+}
+
+fn check_onnx_model_name(model: &str) -> Result<()> {
+    let valid = !model.is_empty()
+        && !model.starts_with('.')
+        && model
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err(ErrorCode::BadArguments(format!(
+            "invalid onnx model name '{model}'"
+        )));
+    }
+    Ok(())
+}
+
+pub trait EmbeddingProvider {
+    fn embedding(&self, input: &[String]) -> Result<Vec<Vec<f32>>>;
+}
+
+impl EmbeddingProvider for OpenAI {
+    fn embedding(&self, input: &[String]) -> Result<Vec<Vec<f32>>> {
+        self.check_api_key()?;
+        let (embeddings, _) = self.embedding_request(input)?;
+        Ok(embeddings)
+    }
+}
+
+/// Sends the inputs to the provider in batches of at most `batch_size` inputs,
+/// returns the embedding of each input, or the error of its batch.
+pub fn embedding_in_batches(
+    provider: &dyn EmbeddingProvider,
+    input: &[&str],
+    batch_size: usize,
+) -> Vec<Result<Vec<f32>>> {
+    let mut results = Vec::with_capacity(input.len());
+    for batch in input.chunks(batch_size.max(1)) {
+        let batch = batch.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        match provider.embedding(&batch) {
+            Ok(embeddings) if embeddings.len() == batch.len() => {
+                results.extend(embeddings.into_iter().map(Ok));
+            }
+            Ok(embeddings) => {
+                let err = ErrorCode::Internal(format!(
+                    "embedding request error: expect {} embeddings, but got {}",
+                    batch.len(),
+                    embeddings.len()
+                ));
+                results.extend(batch.iter().map(|_| Err(err.clone())));
+            }
+            Err(err) => {
+                results.extend(batch.iter().map(|_| Err(err.clone())));
+            }
+        }
+    }
+    results
+}
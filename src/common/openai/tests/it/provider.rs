@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_openai::embedding_in_batches;
+use databend_common_openai::onnx_model_path;
+use databend_common_openai::AIConnection;
+use databend_common_openai::EmbeddingProvider;
+use databend_common_openai::OpenAI;
+
+/// Embeds each input as `[len]` and records the size of each request.
+#[derive(Default)]
+struct MockProvider {
+    requests: RefCell<Vec<usize>>,
+    fail_on: Option<&'static str>,
+}
+
+impl EmbeddingProvider for MockProvider {
+    fn embedding(&self, input: &[String]) -> Result<Vec<Vec<f32>>> {
+        self.requests.borrow_mut().push(input.len());
+        if let Some(fail_on) = self.fail_on {
+            if input.iter().any(|s| s == fail_on) {
+                return Err(ErrorCode::Internal("mock error"));
+            }
+        }
+        Ok(input.iter().map(|s| vec![s.len() as f32]).collect())
+    }
+}
+
+#[test]
+fn test_embedding_in_batches() -> Result<()> {
+    let input = ["a", "bb", "ccc", "dddd", "eeeee"];
+
+    let provider = MockProvider::default();
+    let results = embedding_in_batches(&provider, &input, 2);
+    assert_eq!(*provider.requests.borrow(), vec![2, 2, 1]);
+    let results = results.into_iter().collect::<Result<Vec<_>>>()?;
+    assert_eq!(results, vec![
+        vec![1.0],
+        vec![2.0],
+        vec![3.0],
+        vec![4.0],
+        vec![5.0]
+    ]);
+
+    let provider = MockProvider::default();
+    embedding_in_batches(&provider, &input, 100);
+    assert_eq!(*provider.requests.borrow(), vec![5]);
+
+    // A zero batch size still makes progress.
+    let provider = MockProvider::default();
+    embedding_in_batches(&provider, &input, 0);
+    assert_eq!(*provider.requests.borrow(), vec![1, 1, 1, 1, 1]);
+
+    // Only the rows of the failed batch get the error.
+    let provider = MockProvider {
+        fail_on: Some("ccc"),
+        ..Default::default()
+    };
+    let results = embedding_in_batches(&provider, &input, 2);
+    let failed = results.iter().map(|r| r.is_err()).collect::<Vec<_>>();
+    assert_eq!(failed, vec![false, false, true, true, false]);
+
+    Ok(())
+}
+
+#[test]
+fn test_ai_connection() -> Result<()> {
+    let params = |kvs: &[(&str, &str)]| {
+        kvs.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    assert!(AIConnection::is_ai_provider("OpenAI"));
+    assert!(AIConnection::is_ai_provider("onnx"));
+    assert!(!AIConnection::is_ai_provider("s3"));
+
+    let connection = AIConnection::try_create(
+        "openai",
+        &params(&[
+            ("ENDPOINT_URL", "http://127.0.0.1:8080/v1/"),
+            ("embedding_model", "nomic-embed-text"),
+        ]),
+    )?;
+    assert_eq!(connection, AIConnection::OpenAI {
+        endpoint_url: "http://127.0.0.1:8080/v1/".to_string(),
+        api_key: "".to_string(),
+        api_version: "".to_string(),
+        embedding_model: "nomic-embed-text".to_string(),
+        completion_model: "".to_string(),
+    });
+
+    let connection = AIConnection::try_create("onnx", &params(&[("model", "bge-small-en")]))?;
+    assert_eq!(connection, AIConnection::Onnx {
+        model: "bge-small-en".to_string()
+    });
+
+    assert!(AIConnection::try_create("openai", &params(&[])).is_err());
+    assert!(AIConnection::try_create("openai", &params(&[("endpoint_url", "not a url")])).is_err());
+    assert!(
+        AIConnection::try_create(
+            "openai",
+            &params(&[("endpoint_url", "http://127.0.0.1/"), ("arg", "x")])
+        )
+        .is_err()
+    );
+    assert!(AIConnection::try_create("onnx", &params(&[("model", "../models")])).is_err());
+    assert!(AIConnection::try_create("s3", &params(&[])).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_onnx_model_path() -> Result<()> {
+    let path = onnx_model_path("/var/lib/models", "bge-small-en")?;
+    assert_eq!(path.to_str(), Some("/var/lib/models/bge-small-en"));
+
+    assert!(onnx_model_path("", "bge-small-en").is_err());
+    assert!(onnx_model_path("/var/lib/models", "").is_err());
+    assert!(onnx_model_path("/var/lib/models", "..").is_err());
+    assert!(onnx_model_path("/var/lib/models", "a/../../b").is_err());
+    assert!(onnx_model_path("/var/lib/models", "/etc").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_require_api_key() {
+    let openai = |api_base: &str, api_version: &str| {
+        OpenAI::create(
+            api_base.to_string(),
+            "".to_string(),
+            api_version.to_string(),
+            "".to_string(),
+            "".to_string(),
+        )
+    };
+
+    assert!(openai("", "").require_api_key());
+    assert!(openai("https://api.openai.com/v1/", "").require_api_key());
+    assert!(openai("https://api.openai.com/v1", "").require_api_key());
+    assert!(openai("https://my.openai.azure.com/", "2023-05-15").require_api_key());
+    assert!(!openai("http://127.0.0.1:8080/v1/", "").require_api_key());
+    assert!(!openai("http://127.0.0.1:8080/?u=https://api.openai.com/v1/", "").require_api_key());
+
+    assert!(
+        openai("https://api.openai.com/v1/", "")
+            .check_api_key()
+            .is_err()
+    );
+    assert!(
+        openai("http://127.0.0.1:8080/v1/", "")
+            .check_api_key()
+            .is_ok()
+    );
+}
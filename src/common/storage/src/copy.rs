@@ -24,6 +24,11 @@ use thiserror::Error;
 pub struct CopyStatus {
     /// Key is file path.
     pub files: DashMap<String, FileStatus>,
+    /// The number of rows skipped by `ON_ERROR`, key is the name of the check constraint
+    /// they violate. The constraints are checked on the blocks written to the table, which
+    /// may mix the rows of several files, so the rows are not counted per file.
+    #[serde(default)]
+    pub check_violations: DashMap<String, usize>,
 }
 
 impl CopyStatus {
@@ -38,10 +43,26 @@ impl CopyStatus {
         };
     }
 
+    /// Adds the rows violating a check constraint, returns the number of the violating
+    /// rows of all the constraints so far.
+    pub fn add_check_violations(&self, constraint: &str, num_rows: usize) -> usize {
+        *self
+            .check_violations
+            .entry(constraint.to_string())
+            .or_default() += num_rows;
+        self.check_violations
+            .iter()
+            .map(|entry| *entry.value())
+            .sum()
+    }
+
     pub fn merge(&self, other: CopyStatus) {
         for (k, v) in other.files.into_iter() {
             self.add_chunk(&k, v);
         }
+        for (k, v) in other.check_violations.into_iter() {
+            self.add_check_violations(&k, v);
+        }
     }
 }
 
@@ -29,10 +29,14 @@ mod id_generator;
 pub mod kv_app_error;
 pub mod kv_pb_api;
 pub mod reply;
+mod row_access_policy_api;
+mod row_access_policy_api_impl;
 mod schema_api;
 mod schema_api_impl;
 mod schema_api_keys;
 mod schema_api_test_suite;
+mod sequence_api;
+mod sequence_api_impl;
 mod share_api;
 mod share_api_impl;
 mod share_api_keys;
@@ -45,9 +49,11 @@ pub use background_api::BackgroundApi;
 pub use background_api_test_suite::BackgroundApiTestSuite;
 pub use data_mask_api::DatamaskApi;
 pub use id_generator::IdGenerator;
+pub use row_access_policy_api::RowAccessPolicyApi;
 pub use schema_api::SchemaApi;
 pub(crate) use schema_api_impl::get_db_or_err;
 pub use schema_api_test_suite::SchemaApiTestSuite;
+pub use sequence_api::SequenceApi;
 pub use share_api::ShareApi;
 pub use share_api_test_suite::ShareApiTestSuite;
 pub use util::assert_table_exist;
@@ -0,0 +1,258 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use databend_common_meta_app::app_error::AppError;
+use databend_common_meta_app::app_error::RowAccessPolicyAlreadyExists;
+use databend_common_meta_app::app_error::RowAccessPolicyInUse;
+use databend_common_meta_app::app_error::UnknownRowAccessPolicy;
+use databend_common_meta_app::row_access_policy::AttachRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::AttachRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::DetachRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::DetachRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_types::ConditionResult::Eq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::TxnRequest;
+use log::debug;
+use minitrace::func_name;
+
+use crate::get_pb_value;
+use crate::kv_app_error::KVAppError;
+use crate::row_access_policy_api::RowAccessPolicyApi;
+use crate::send_txn;
+use crate::serialize_struct;
+use crate::txn_backoff::txn_backoff;
+use crate::txn_cond_seq;
+use crate::txn_op_del;
+use crate::txn_op_put;
+
+/// RowAccessPolicyApi is implemented upon kvapi::KVApi.
+/// Thus every type that impl kvapi::KVApi impls RowAccessPolicyApi.
+///
+/// The ids of the tables a policy is attached to are kept in the policy, which can not
+/// be dropped until it is detached from all of them. The ids of dropped tables are
+/// removed by the caller before dropping the policy, see `DropRowAccessPolicyInterpreter`.
+#[tonic::async_trait]
+impl<KV: kvapi::KVApi<Error = MetaError>> RowAccessPolicyApi for KV {
+    async fn create_row_access_policy(
+        &self,
+        req: CreateRowAccessPolicyReq,
+    ) -> Result<CreateRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let mut meta: RowAccessPolicyMeta = req.clone().into();
+            let (seq, old): (_, Option<RowAccessPolicyMeta>) = get_pb_value(self, name_key).await?;
+            debug!(seq = seq, name_key :? =(name_key); "create_row_access_policy");
+
+            if seq > 0 {
+                match req.create_option {
+                    CreateOption::Create => {
+                        return Err(KVAppError::AppError(
+                            AppError::RowAccessPolicyAlreadyExists(
+                                RowAccessPolicyAlreadyExists::new(
+                                    &name_key.name,
+                                    format!("create row access policy: {}", name_key),
+                                ),
+                            ),
+                        ));
+                    }
+                    CreateOption::CreateIfNotExists => return Ok(CreateRowAccessPolicyReply {}),
+                    // the replaced policy is still attached to the same tables.
+                    CreateOption::CreateOrReplace => {
+                        if let Some(old) = old {
+                            meta.table_ids = old.table_ids;
+                        }
+                    }
+                }
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(name_key, Eq, seq)],
+                if_then: vec![txn_op_put(name_key, serialize_struct(&meta)?)],
+                else_then: vec![],
+            };
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(name_key :? =(name_key), succ = succ; "create_row_access_policy");
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(CreateRowAccessPolicyReply {})
+    }
+
+    async fn drop_row_access_policy(
+        &self,
+        req: DropRowAccessPolicyReq,
+    ) -> Result<DropRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, policy): (_, Option<RowAccessPolicyMeta>) =
+                get_pb_value(self, name_key).await?;
+            let Some(policy) = policy.filter(|_| seq > 0) else {
+                if req.if_exists {
+                    return Ok(DropRowAccessPolicyReply {});
+                }
+                return Err(KVAppError::AppError(AppError::UnknownRowAccessPolicy(
+                    UnknownRowAccessPolicy::new(
+                        &name_key.name,
+                        format!("drop row access policy: {}", name_key),
+                    ),
+                )));
+            };
+            if !policy.table_ids.is_empty() {
+                return Err(KVAppError::AppError(AppError::RowAccessPolicyInUse(
+                    RowAccessPolicyInUse::new(
+                        &name_key.name,
+                        format!(
+                            "drop row access policy: {}, attached to tables {:?}",
+                            name_key, policy.table_ids
+                        ),
+                    ),
+                )));
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(name_key, Eq, seq)],
+                if_then: vec![txn_op_del(name_key)],
+                else_then: vec![],
+            };
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(name_key :? =(name_key), succ = succ; "drop_row_access_policy");
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(DropRowAccessPolicyReply {})
+    }
+
+    async fn get_row_access_policy(
+        &self,
+        req: GetRowAccessPolicyReq,
+    ) -> Result<GetRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+        let (seq, policy): (_, Option<RowAccessPolicyMeta>) = get_pb_value(self, name_key).await?;
+        match policy {
+            Some(policy) if seq > 0 => Ok(GetRowAccessPolicyReply { policy }),
+            _ => Err(KVAppError::AppError(AppError::UnknownRowAccessPolicy(
+                UnknownRowAccessPolicy::new(
+                    &name_key.name,
+                    format!("get row access policy: {}", name_key),
+                ),
+            ))),
+        }
+    }
+
+    async fn attach_row_access_policy(
+        &self,
+        req: AttachRowAccessPolicyReq,
+    ) -> Result<AttachRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let found = update_table_ids(self, &req.name, |ids| {
+            ids.insert(req.table_id);
+        })
+        .await?;
+        if !found {
+            return Err(KVAppError::AppError(AppError::UnknownRowAccessPolicy(
+                UnknownRowAccessPolicy::new(
+                    &req.name.name,
+                    format!("attach row access policy: {}", req.name),
+                ),
+            )));
+        }
+        Ok(AttachRowAccessPolicyReply {})
+    }
+
+    async fn detach_row_access_policy(
+        &self,
+        req: DetachRowAccessPolicyReq,
+    ) -> Result<DetachRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        // detaching a dropped policy is a no-op.
+        update_table_ids(self, &req.name, |ids| {
+            ids.remove(&req.table_id);
+        })
+        .await?;
+        Ok(DetachRowAccessPolicyReply {})
+    }
+}
+
+/// Updates the ids of the tables the policy is attached to, returns false if the policy
+/// does not exist.
+async fn update_table_ids(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &RowAccessPolicyNameIdent,
+    f: impl Fn(&mut BTreeSet<u64>),
+) -> Result<bool, KVAppError> {
+    let mut trials = txn_backoff(None, func_name!());
+    loop {
+        trials.next().unwrap()?.await;
+
+        let (seq, policy): (_, Option<RowAccessPolicyMeta>) =
+            get_pb_value(kv_api, name_key).await?;
+        let Some(mut policy) = policy.filter(|_| seq > 0) else {
+            return Ok(false);
+        };
+        let old_ids = policy.table_ids.clone();
+        f(&mut policy.table_ids);
+        if policy.table_ids == old_ids {
+            return Ok(true);
+        }
+
+        let txn_req = TxnRequest {
+            condition: vec![txn_cond_seq(name_key, Eq, seq)],
+            if_then: vec![txn_op_put(name_key, serialize_struct(&policy)?)],
+            else_then: vec![],
+        };
+        let (succ, _responses) = send_txn(kv_api, txn_req).await?;
+
+        debug!(name_key :? =(name_key), succ = succ; "update row access policy table ids");
+
+        if succ {
+            return Ok(true);
+        }
+    }
+}
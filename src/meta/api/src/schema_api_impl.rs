@@ -58,6 +58,7 @@ use databend_common_meta_app::app_error::WrongShare;
 use databend_common_meta_app::app_error::WrongShareObject;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdList;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdListKey;
+use databend_common_meta_app::schema::identity_sequence_prefix;
 use databend_common_meta_app::schema::CatalogId;
 use databend_common_meta_app::schema::CatalogIdToName;
 use databend_common_meta_app::schema::CatalogInfo;
@@ -140,11 +141,13 @@ use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SetLVTReply;
 use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::TableClonesKey;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
@@ -5297,6 +5300,7 @@ async fn gc_dropped_table_by_id(
         }
         gc_dropped_table_data(kv_api, table_id, &mut condition, &mut if_then).await?;
         gc_dropped_table_index(kv_api, &tenant, table_id, &mut if_then).await?;
+        gc_dropped_table_sequences(kv_api, &tenant, table_id, &mut if_then).await?;
 
         let txn_req = TxnRequest {
             condition,
@@ -5344,12 +5348,33 @@ async fn gc_dropped_table_data(
     }
     // remove table meta
     if_then.push(txn_op_del(&tbid));
+    // remove the ids of the clones, the table is only vacuumed once all of them are vacuumed
+    if_then.push(txn_op_del(&TableClonesKey::new(table_id)));
 
     remove_table_copied_files(kv_api, table_id, condition, if_then).await?;
 
     Ok(())
 }
 
+/// Removes the sequences of the `IDENTITY` columns of the table.
+async fn gc_dropped_table_sequences(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &str,
+    table_id: u64,
+    if_then: &mut Vec<TxnOp>,
+) -> Result<(), KVAppError> {
+    let prefix_key = kvapi::KeyBuilder::new_prefixed(SequenceIdent::PREFIX)
+        .push_str(tenant)
+        .push_str(&identity_sequence_prefix(table_id))
+        .done();
+
+    let sequences = kv_api.prefix_list_kv(&prefix_key).await?;
+    for (key, _) in sequences {
+        if_then.push(TxnOp::delete(key));
+    }
+    Ok(())
+}
+
 async fn gc_dropped_table_index(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     tenant: &str,
@@ -44,6 +44,7 @@ use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReq;
@@ -60,6 +61,7 @@ use databend_common_meta_app::schema::DeleteLockRevReq;
 use databend_common_meta_app::schema::DropCatalogReq;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropSequenceReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableIndexReq;
 use databend_common_meta_app::schema::DropVirtualColumnReq;
@@ -70,6 +72,8 @@ use databend_common_meta_app::schema::GetCatalogReq;
 use databend_common_meta_app::schema::GetDatabaseReq;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::GetLVTReq;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::GetSequenceReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::GetTableReq;
 use databend_common_meta_app::schema::IcebergCatalogOption;
@@ -89,6 +93,7 @@ use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockKey;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
@@ -139,6 +144,7 @@ use crate::testing::get_kv_data;
 use crate::testing::get_kv_u64_data;
 use crate::DatamaskApi;
 use crate::SchemaApi;
+use crate::SequenceApi;
 use crate::ShareApi;
 use crate::DEFAULT_MGET_SIZE;
 
@@ -269,7 +275,7 @@ impl SchemaApiTestSuite {
     pub async fn test_single_node<B, MT>(b: B) -> anyhow::Result<()>
     where
         B: kvapi::ApiBuilder<MT>,
-        MT: ShareApi + kvapi::AsKVApi<Error = MetaError> + SchemaApi + DatamaskApi,
+        MT: ShareApi + kvapi::AsKVApi<Error = MetaError> + SchemaApi + DatamaskApi + SequenceApi,
     {
         let suite = SchemaApiTestSuite {};
 
@@ -346,6 +352,7 @@ impl SchemaApiTestSuite {
 
         suite.get_table_name_by_id(&b.build().await).await?;
         suite.get_db_name_by_id(&b.build().await).await?;
+        suite.sequence_create_next_drop(&b.build().await).await?;
 
         Ok(())
     }
@@ -6185,6 +6192,96 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn sequence_create_next_drop<MT: SequenceApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let ident = SequenceIdent::new("tenant1", "seq1");
+
+        info!("--- create sequence");
+        {
+            let req = CreateSequenceReq {
+                create_option: CreateOption::Create,
+                ident: ident.clone(),
+                start: 10,
+                step: 2,
+                comment: Some("seq1".to_string()),
+                create_on: Utc::now(),
+            };
+            mt.create_sequence(req.clone()).await?;
+
+            let res = mt.create_sequence(req.clone()).await;
+            let err = res.unwrap_err();
+            let err = ErrorCode::from(err);
+            assert_eq!(ErrorCode::SequenceAlreadyExists("").code(), err.code());
+
+            let req = CreateSequenceReq {
+                create_option: CreateOption::CreateIfNotExists,
+                start: 100,
+                ..req
+            };
+            mt.create_sequence(req).await?;
+
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert_eq!(10, res.meta.start);
+            assert_eq!(2, res.meta.step);
+            assert_eq!(10, res.meta.current);
+        }
+
+        info!("--- allocate ranges");
+        {
+            let req = GetSequenceNextValueReq {
+                ident: ident.clone(),
+                count: 3,
+            };
+            let res = mt.get_sequence_next_value(req.clone()).await?;
+            assert_eq!((10, 2, 16), (res.start, res.step, res.end));
+
+            let res = mt.get_sequence_next_value(req).await?;
+            assert_eq!((16, 2, 22), (res.start, res.step, res.end));
+
+            let req = GetSequenceNextValueReq {
+                ident: ident.clone(),
+                count: u64::MAX,
+            };
+            let err = ErrorCode::from(mt.get_sequence_next_value(req).await.unwrap_err());
+            assert_eq!(ErrorCode::SequenceError("").code(), err.code());
+
+            let res = mt
+                .get_sequence(GetSequenceReq {
+                    ident: ident.clone(),
+                })
+                .await?;
+            assert_eq!(22, res.meta.current);
+        }
+
+        info!("--- drop sequence");
+        {
+            let req = DropSequenceReq {
+                if_exists: false,
+                ident: ident.clone(),
+            };
+            mt.drop_sequence(req.clone()).await?;
+
+            let err = ErrorCode::from(mt.drop_sequence(req).await.unwrap_err());
+            assert_eq!(ErrorCode::UnknownSequence("").code(), err.code());
+
+            mt.drop_sequence(DropSequenceReq {
+                if_exists: true,
+                ident: ident.clone(),
+            })
+            .await?;
+
+            let res = mt.get_sequence(GetSequenceReq { ident }).await;
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::UnknownSequence("").code(), err.code());
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn virtual_column_create_list_drop<MT>(&self, mt: &MT) -> anyhow::Result<()>
     where MT: SchemaApi + kvapi::AsKVApi<Error = MetaError> {
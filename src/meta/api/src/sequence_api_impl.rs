@@ -0,0 +1,219 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_meta_app::app_error::AppError;
+use databend_common_meta_app::app_error::OutOfSequenceRange;
+use databend_common_meta_app::app_error::SequenceAlreadyExists;
+use databend_common_meta_app::app_error::UnknownSequence;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReply;
+use databend_common_meta_app::schema::CreateSequenceReq;
+use databend_common_meta_app::schema::DropSequenceReply;
+use databend_common_meta_app::schema::DropSequenceReq;
+use databend_common_meta_app::schema::GetSequenceNextValueReply;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::GetSequenceReply;
+use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::SequenceMeta;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_types::ConditionResult::Eq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::TxnRequest;
+use log::debug;
+use minitrace::func_name;
+
+use crate::get_pb_value;
+use crate::kv_app_error::KVAppError;
+use crate::send_txn;
+use crate::sequence_api::SequenceApi;
+use crate::serialize_struct;
+use crate::txn_backoff::txn_backoff;
+use crate::txn_cond_seq;
+use crate::txn_op_del;
+use crate::txn_op_put;
+
+/// SequenceApi is implemented upon kvapi::KVApi.
+/// Thus every type that impl kvapi::KVApi impls SequenceApi.
+#[tonic::async_trait]
+impl<KV: kvapi::KVApi<Error = MetaError>> SequenceApi for KV {
+    async fn create_sequence(
+        &self,
+        req: CreateSequenceReq,
+    ) -> Result<CreateSequenceReply, KVAppError> {
+        debug!(req :? =(&req); "SequenceApi: {}", func_name!());
+
+        let ident = &req.ident;
+        let meta: SequenceMeta = req.clone().into();
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, _): (_, Option<SequenceMeta>) = get_pb_value(self, ident).await?;
+            debug!(seq = seq, ident :? =(ident); "create_sequence");
+
+            if seq > 0 {
+                match req.create_option {
+                    CreateOption::Create => {
+                        return Err(KVAppError::AppError(AppError::SequenceAlreadyExists(
+                            SequenceAlreadyExists::new(
+                                &ident.name,
+                                format!("create sequence: {}", ident),
+                            ),
+                        )));
+                    }
+                    CreateOption::CreateIfNotExists => return Ok(CreateSequenceReply {}),
+                    CreateOption::CreateOrReplace => {}
+                }
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(ident, Eq, seq)],
+                if_then: vec![txn_op_put(ident, serialize_struct(&meta)?)],
+                else_then: vec![],
+            };
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(ident :? =(ident), succ = succ; "create_sequence");
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(CreateSequenceReply {})
+    }
+
+    async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply, KVAppError> {
+        debug!(req :? =(&req); "SequenceApi: {}", func_name!());
+
+        let (_seq, meta) =
+            get_sequence_or_err(self, &req.ident, format!("get_sequence: {}", req.ident)).await?;
+
+        Ok(GetSequenceReply { meta })
+    }
+
+    async fn get_sequence_next_value(
+        &self,
+        req: GetSequenceNextValueReq,
+    ) -> Result<GetSequenceNextValueReply, KVAppError> {
+        debug!(req :? =(&req); "SequenceApi: {}", func_name!());
+
+        let ident = &req.ident;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, mut meta) =
+                get_sequence_or_err(self, ident, format!("get_sequence_next_value: {}", ident))
+                    .await?;
+
+            // Allocate the range `[start, end)` at once, the `current` is moved to `end`.
+            let start = meta.current;
+            let end = req
+                .count
+                .checked_mul(meta.step)
+                .and_then(|n| start.checked_add(n))
+                .ok_or_else(|| {
+                    KVAppError::AppError(AppError::OutOfSequenceRange(OutOfSequenceRange::new(
+                        &ident.name,
+                        format!("get_sequence_next_value: count {}", req.count),
+                    )))
+                })?;
+            meta.current = end;
+            meta.update_on = Utc::now();
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(ident, Eq, seq)],
+                if_then: vec![txn_op_put(ident, serialize_struct(&meta)?)],
+                else_then: vec![],
+            };
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                ident :? =(ident),
+                start = start,
+                end = end,
+                succ = succ;
+                "get_sequence_next_value"
+            );
+
+            if succ {
+                return Ok(GetSequenceNextValueReply {
+                    start,
+                    step: meta.step,
+                    end,
+                });
+            }
+        }
+    }
+
+    async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply, KVAppError> {
+        debug!(req :? =(&req); "SequenceApi: {}", func_name!());
+
+        let ident = &req.ident;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, _): (_, Option<SequenceMeta>) = get_pb_value(self, ident).await?;
+            if seq == 0 {
+                if req.if_exists {
+                    return Ok(DropSequenceReply {});
+                }
+                return Err(KVAppError::AppError(AppError::UnknownSequence(
+                    UnknownSequence::new(&ident.name, format!("drop sequence: {}", ident)),
+                )));
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(ident, Eq, seq)],
+                if_then: vec![txn_op_del(ident)],
+                else_then: vec![],
+            };
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(ident :? =(ident), succ = succ; "drop_sequence");
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(DropSequenceReply {})
+    }
+}
+
+/// Returns (seq, sequence_meta)
+async fn get_sequence_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    ident: &SequenceIdent,
+    msg: impl std::fmt::Display,
+) -> Result<(u64, SequenceMeta), KVAppError> {
+    let (seq, meta): (_, Option<SequenceMeta>) = get_pb_value(kv_api, ident).await?;
+    match meta {
+        Some(meta) if seq > 0 => Ok((seq, meta)),
+        _ => {
+            debug!(seq = seq, ident :? =(ident); "sequence does not exist");
+
+            Err(KVAppError::AppError(AppError::UnknownSequence(
+                UnknownSequence::new(&ident.name, format!("{}: {}", msg, ident)),
+            )))
+        }
+    }
+}
@@ -74,6 +74,102 @@ impl DatamaskAlreadyExists {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("SequenceAlreadyExists: `{name}` while `{context}`")]
+pub struct SequenceAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl SequenceAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UnknownSequence: `{name}` while `{context}`")]
+pub struct UnknownSequence {
+    name: String,
+    context: String,
+}
+
+impl UnknownSequence {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("RowAccessPolicyAlreadyExists: `{name}` while `{context}`")]
+pub struct RowAccessPolicyAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl RowAccessPolicyAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UnknownRowAccessPolicy: `{name}` while `{context}`")]
+pub struct UnknownRowAccessPolicy {
+    name: String,
+    context: String,
+}
+
+impl UnknownRowAccessPolicy {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("RowAccessPolicyInUse: `{name}` while `{context}`")]
+pub struct RowAccessPolicyInUse {
+    name: String,
+    context: String,
+}
+
+impl RowAccessPolicyInUse {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("OutOfSequenceRange: `{name}` while `{context}`")]
+pub struct OutOfSequenceRange {
+    name: String,
+    context: String,
+}
+
+impl OutOfSequenceRange {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("BackgroundJobAlreadyExists: `{name}` while `{context}`")]
 pub struct BackgroundJobAlreadyExists {
@@ -1020,6 +1116,24 @@ pub enum AppError {
     #[error(transparent)]
     UnknownDatamask(#[from] UnknownDatamask),
 
+    #[error(transparent)]
+    SequenceAlreadyExists(#[from] SequenceAlreadyExists),
+
+    #[error(transparent)]
+    UnknownSequence(#[from] UnknownSequence),
+
+    #[error(transparent)]
+    OutOfSequenceRange(#[from] OutOfSequenceRange),
+
+    #[error(transparent)]
+    RowAccessPolicyAlreadyExists(#[from] RowAccessPolicyAlreadyExists),
+
+    #[error(transparent)]
+    UnknownRowAccessPolicy(#[from] UnknownRowAccessPolicy),
+
+    #[error(transparent)]
+    RowAccessPolicyInUse(#[from] RowAccessPolicyInUse),
+
     #[error(transparent)]
     BackgroundJobAlreadyExists(#[from] BackgroundJobAlreadyExists),
 
@@ -1344,6 +1458,45 @@ impl AppErrorMessage for UnknownDatamask {
     }
 }
 
+impl AppErrorMessage for SequenceAlreadyExists {
+    fn message(&self) -> String {
+        format!("Sequence '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownSequence {
+    fn message(&self) -> String {
+        format!("Sequence '{}' does not exists", self.name)
+    }
+}
+
+impl AppErrorMessage for RowAccessPolicyAlreadyExists {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownRowAccessPolicy {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' does not exists", self.name)
+    }
+}
+
+impl AppErrorMessage for RowAccessPolicyInUse {
+    fn message(&self) -> String {
+        format!(
+            "Row access policy '{}' is attached to tables, detach it first",
+            self.name
+        )
+    }
+}
+
+impl AppErrorMessage for OutOfSequenceRange {
+    fn message(&self) -> String {
+        format!("Sequence '{}' is out of range", self.name)
+    }
+}
+
 impl AppErrorMessage for UnmatchColumnDataType {
     fn message(&self) -> String {
         format!(
@@ -1456,6 +1609,16 @@ impl From<AppError> for ErrorCode {
             AppError::GetIndexWithDropTIme(err) => ErrorCode::GetIndexWithDropTime(err.message()),
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDatamask(err) => ErrorCode::UnknownDatamask(err.message()),
+            AppError::SequenceAlreadyExists(err) => ErrorCode::SequenceAlreadyExists(err.message()),
+            AppError::UnknownSequence(err) => ErrorCode::UnknownSequence(err.message()),
+            AppError::OutOfSequenceRange(err) => ErrorCode::SequenceError(err.message()),
+            AppError::RowAccessPolicyAlreadyExists(err) => {
+                ErrorCode::RowAccessPolicyAlreadyExists(err.message())
+            }
+            AppError::UnknownRowAccessPolicy(err) => {
+                ErrorCode::UnknownRowAccessPolicy(err.message())
+            }
+            AppError::RowAccessPolicyInUse(err) => ErrorCode::RowAccessPolicyInUse(err.message()),
 
             AppError::BackgroundJobAlreadyExists(err) => {
                 ErrorCode::BackgroundJobAlreadyExists(err.message())
@@ -21,6 +21,8 @@ mod network_policy_ident;
 mod ownership_info;
 mod password_policy;
 mod password_policy_ident;
+mod plan_baseline;
+mod plan_baseline_ident;
 mod principal_identity;
 mod role_ident;
 mod role_info;
@@ -45,6 +47,12 @@ pub use network_policy_ident::NetworkPolicyIdent;
 pub use ownership_info::OwnershipInfo;
 pub use password_policy::PasswordPolicy;
 pub use password_policy_ident::PasswordPolicyIdent;
+pub use plan_baseline::JoinOutline;
+pub use plan_baseline::JoinOutlineDistribution;
+pub use plan_baseline::PlanBaseline;
+pub use plan_baseline::PlanBaselineUsage;
+pub use plan_baseline_ident::PlanBaselineIdent;
+pub use plan_baseline_ident::PlanBaselineUsageIdent;
 pub use principal_identity::PrincipalIdentity;
 pub use role_ident::RoleIdent;
 pub use role_info::RoleInfo;
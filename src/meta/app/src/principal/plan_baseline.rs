@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// The plan captured for a statement by `CREATE PLAN BASELINE`, the statements
+/// with the same fingerprint are planned in the same shape.
+///
+/// Only the join order, the join distribution and the build side of each group
+/// of reorderable joins are pinned, the rest of the plan (pushdowns, aggregates,
+/// the join algorithm of the other joins) is still optimized for each statement.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PlanBaseline {
+    /// See [`PlanBaseline::fingerprint`].
+    pub fingerprint: String,
+    /// The normalized statement, the literals are rendered as `?`.
+    pub query: String,
+    pub catalog: String,
+    pub database: String,
+    /// The join trees of the plan, one for each group of reorderable joins.
+    pub outline: Vec<JoinOutline>,
+    pub created_on: DateTime<Utc>,
+}
+
+/// How often a plan baseline is used, summed over the nodes of the cluster. It is kept apart
+/// from the baseline, so that counting the uses doesn't change the version of the baseline.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlanBaselineUsage {
+    pub uses: u64,
+    pub last_used_on: Option<DateTime<Utc>>,
+}
+
+impl PlanBaselineUsage {
+    pub fn merge(&mut self, other: &PlanBaselineUsage) {
+        self.uses += other.uses;
+        self.last_used_on = self.last_used_on.max(other.last_used_on);
+    }
+}
+
+/// The shape of a join tree, the tables are referred by their names or aliases in the statement.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum JoinOutline {
+    /// A subtree without reorderable joins, e.g. a table scan or an aggregated subquery.
+    Relation { tables: Vec<String> },
+    Join {
+        probe: Box<JoinOutline>,
+        build: Box<JoinOutline>,
+        distribution: Option<JoinOutlineDistribution>,
+        hash_join: bool,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinOutlineDistribution {
+    Broadcast,
+    Shuffle,
+}
+
+impl PlanBaseline {
+    /// The fingerprint of a normalized statement, the same statement text may
+    /// refer to different tables in different databases.
+    pub fn fingerprint(catalog: &str, database: &str, query: &str) -> String {
+        let mut hasher = Sha256::new();
+        for part in [catalog, database, query] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        hex::encode(hasher.finalize())
+    }
+}
+
+impl Display for JoinOutline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinOutline::Relation { tables } if tables.len() == 1 => write!(f, "{}", tables[0]),
+            JoinOutline::Relation { tables } => write!(f, "{{{}}}", tables.join(", ")),
+            JoinOutline::Join {
+                probe,
+                build,
+                distribution,
+                hash_join,
+            } => {
+                write!(f, "({probe} JOIN {build}")?;
+                let mut hints = Vec::new();
+                match distribution {
+                    Some(JoinOutlineDistribution::Broadcast) => hints.push("BROADCAST"),
+                    Some(JoinOutlineDistribution::Shuffle) => hints.push("SHUFFLE"),
+                    None => {}
+                }
+                if *hash_join {
+                    hints.push("HASH_JOIN");
+                }
+                if !hints.is_empty() {
+                    write!(f, " [{}]", hints.join(", "))?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tenant_key::TIdent;
+
+/// Define the meta-service key for a plan baseline, the name is the statement fingerprint.
+pub type PlanBaselineIdent = TIdent<kvapi_impl::Resource>;
+
+/// Define the meta-service key for the usage of a plan baseline, the name is the statement
+/// fingerprint.
+pub type PlanBaselineUsageIdent = TIdent<kvapi_impl::UsageResource>;
+
+mod kvapi_impl {
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::principal::PlanBaseline;
+    use crate::principal::PlanBaselineUsage;
+    use crate::tenant_key::TenantResource;
+
+    pub struct Resource;
+    impl TenantResource for Resource {
+        const PREFIX: &'static str = "__fd_plan_baselines";
+        type ValueType = PlanBaseline;
+    }
+
+    impl kvapi::Value for PlanBaseline {
+        fn dependency_keys(&self) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+
+    pub struct UsageResource;
+    impl TenantResource for UsageResource {
+        const PREFIX: &'static str = "__fd_plan_baseline_usages";
+        type ValueType = PlanBaselineUsage;
+    }
+
+    impl kvapi::Value for PlanBaselineUsage {
+        fn dependency_keys(&self) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use crate::principal::plan_baseline_ident::PlanBaselineIdent;
+    use crate::principal::plan_baseline_ident::PlanBaselineUsageIdent;
+    use crate::tenant::Tenant;
+
+    #[test]
+    fn test_plan_baseline_ident() {
+        let tenant = Tenant::new("tenant1");
+        let ident = PlanBaselineIdent::new(tenant.clone(), "3f2a");
+        assert_eq!("__fd_plan_baselines/tenant1/3f2a", ident.to_string_key());
+
+        let got = PlanBaselineIdent::from_str_key(&ident.to_string_key()).unwrap();
+        assert_eq!(ident, got);
+
+        let ident = PlanBaselineUsageIdent::new(tenant, "3f2a");
+        assert_eq!(
+            "__fd_plan_baseline_usages/tenant1/3f2a",
+            ident.to_string_key()
+        );
+    }
+}
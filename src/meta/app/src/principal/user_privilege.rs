@@ -75,6 +75,8 @@ pub enum UserPrivilegeType {
     Write = 1 << 19,
     // Privilege to Create database
     CreateDatabase = 1 << 20,
+    // Privilege to Add/Drop the row access policy of tables.
+    ApplyRowAccessPolicy = 1 << 21,
     // Discard Privilege Type
     Set = 1 << 4,
 }
@@ -101,6 +103,7 @@ const ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
         | Read
         | Write
         | CreateDatabase
+        | ApplyRowAccessPolicy
     }
 );
 
@@ -128,6 +131,7 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::Read => "Read",
             UserPrivilegeType::Write => "Write",
             UserPrivilegeType::CreateDatabase => "CREATE DATABASE",
+            UserPrivilegeType::ApplyRowAccessPolicy => "APPLY ROW ACCESS POLICY",
         })
     }
 }
@@ -160,7 +164,7 @@ impl UserPrivilegeSet {
         let database_privs = Self::available_privileges_on_database(false);
         let stage_privs_without_ownership = Self::available_privileges_on_stage(false);
         let udf_privs_without_ownership = Self::available_privileges_on_udf(false);
-        let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | CreateDatabase | Grant | CreateDataMask });
+        let privs = make_bitflags!(UserPrivilegeType::{ Usage | Super | CreateUser | DropUser | CreateRole | DropRole | CreateDatabase | Grant | CreateDataMask | ApplyRowAccessPolicy });
         (database_privs.privileges
             | privs
             | stage_privs_without_ownership.privileges
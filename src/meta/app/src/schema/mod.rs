@@ -21,6 +21,7 @@ mod index;
 mod least_visible_time;
 mod lock;
 mod ownership;
+mod sequence;
 mod table;
 mod virtual_column;
 pub use catalog::*;
@@ -63,6 +64,19 @@ pub use lock::LockMeta;
 pub use lock::LockType;
 pub use lock::TableLockKey;
 pub use ownership::Ownership;
+pub use sequence::identity_sequence_name;
+pub use sequence::identity_sequence_prefix;
+pub use sequence::CreateSequenceReply;
+pub use sequence::CreateSequenceReq;
+pub use sequence::DropSequenceReply;
+pub use sequence::DropSequenceReq;
+pub use sequence::GetSequenceNextValueReply;
+pub use sequence::GetSequenceNextValueReq;
+pub use sequence::GetSequenceReply;
+pub use sequence::GetSequenceReq;
+pub use sequence::SequenceIdent;
+pub use sequence::SequenceMeta;
+pub use sequence::IDENTITY_SEQUENCE_PREFIX;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -91,6 +105,8 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::TableClonesKey;
+pub use table::TableConstraint;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileNameIdent;
 pub use table::TableId;
@@ -103,6 +119,7 @@ pub use table::TableInfo;
 pub use table::TableInfoFilter;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::schema::CreateOption;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct SequenceIdent {
+    pub tenant: String,
+    pub name: String,
+}
+
+impl SequenceIdent {
+    pub fn new(tenant: impl ToString, name: impl ToString) -> Self {
+        SequenceIdent {
+            tenant: tenant.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Display for SequenceIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}'/'{}'", self.tenant, self.name)
+    }
+}
+
+/// The prefix of the names of the sequences of `IDENTITY` columns, the names are reserved.
+pub const IDENTITY_SEQUENCE_PREFIX: &str = "_identity_";
+
+/// The sequence of an `IDENTITY` column is named after the ids of the table and the column,
+/// so that it never collides with other sequences and is removed along with the table.
+pub fn identity_sequence_name(table_id: u64, column_id: u32) -> String {
+    format!("{}{}_{}", IDENTITY_SEQUENCE_PREFIX, table_id, column_id)
+}
+
+/// The prefix of the names of the sequences of the `IDENTITY` columns of a table.
+pub fn identity_sequence_prefix(table_id: u64) -> String {
+    format!("{}{}_", IDENTITY_SEQUENCE_PREFIX, table_id)
+}
+
+/// The meta of a sequence.
+///
+/// `current` is the next value to be allocated, values are allocated in ranges,
+/// so the values are unique and increasing, but may have gaps.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SequenceMeta {
+    pub start: u64,
+    pub step: u64,
+    pub current: u64,
+    pub comment: Option<String>,
+    pub create_on: DateTime<Utc>,
+    pub update_on: DateTime<Utc>,
+}
+
+impl From<CreateSequenceReq> for SequenceMeta {
+    fn from(req: CreateSequenceReq) -> Self {
+        SequenceMeta {
+            start: req.start,
+            step: req.step,
+            current: req.start,
+            comment: req.comment,
+            create_on: req.create_on,
+            update_on: req.create_on,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReq {
+    pub create_option: CreateOption,
+    pub ident: SequenceIdent,
+    pub start: u64,
+    pub step: u64,
+    pub comment: Option<String>,
+    pub create_on: DateTime<Utc>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateSequenceReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReq {
+    pub ident: SequenceIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceReply {
+    pub meta: SequenceMeta,
+}
+
+/// Allocates `count` values of the sequence in one transaction.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValueReq {
+    pub ident: SequenceIdent,
+    pub count: u64,
+}
+
+/// The allocated values are `start, start + step, ..., end - step`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetSequenceNextValueReply {
+    pub start: u64,
+    pub step: u64,
+    pub end: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReq {
+    pub if_exists: bool,
+    pub ident: SequenceIdent,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropSequenceReply {}
+
+mod kvapi_key_impl {
+    use databend_common_meta_kvapi::kvapi;
+
+    use super::SequenceIdent;
+    use crate::schema::SequenceMeta;
+    use crate::tenant::Tenant;
+
+    /// __fd_sequence/<tenant>/<name> -> SequenceMeta
+    impl kvapi::Key for SequenceIdent {
+        const PREFIX: &'static str = "__fd_sequence";
+
+        type ValueType = SequenceMeta;
+
+        /// It belongs to a tenant
+        fn parent(&self) -> Option<String> {
+            Some(Tenant::new(&self.tenant).to_string_key())
+        }
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let name = p.next_str()?;
+            p.done()?;
+
+            Ok(SequenceIdent { tenant, name })
+        }
+    }
+
+    impl kvapi::Value for SequenceMeta {
+        fn dependency_keys(&self) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
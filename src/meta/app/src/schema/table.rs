@@ -23,6 +23,7 @@ use std::sync::Arc;
 
 use chrono::DateTime;
 use chrono::Utc;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::TableField;
@@ -153,6 +154,25 @@ impl Display for TableIdListKey {
     }
 }
 
+/// The key of the ids of the zero-copy clones of a table, kept apart from the table meta so
+/// that registering a clone does not change the version of its source.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct TableClonesKey {
+    pub table_id: u64,
+}
+
+impl TableClonesKey {
+    pub fn new(table_id: u64) -> Self {
+        TableClonesKey { table_id }
+    }
+}
+
+impl Display for TableClonesKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "TableClones{{{}}}", self.table_id)
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 pub enum DatabaseType {
     #[default]
@@ -250,6 +270,9 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub indexes: BTreeMap<String, TableIndex>,
+    // Constraints of the table, keyed by the constraint name.
+    pub constraints: BTreeMap<String, TableConstraint>,
+    pub row_access_policy: Option<TableRowAccessPolicy>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -258,6 +281,58 @@ pub struct TableIndex {
     pub column_ids: Vec<u32>,
 }
 
+/// The row access policy attached to a table, `column_ids` are the
+/// columns passed to the policy arguments in order.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub column_ids: Vec<u32>,
+}
+
+/// A table constraint.
+///
+/// Only `Check` is enforced when rows are written, the key constraints are informational:
+/// they are not validated, but the optimizer and the clients may rely on them.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TableConstraint {
+    Check {
+        expr: String,
+    },
+    PrimaryKey {
+        column_ids: Vec<u32>,
+    },
+    Unique {
+        column_ids: Vec<u32>,
+    },
+    ForeignKey {
+        column_ids: Vec<u32>,
+        ref_database: String,
+        ref_table: String,
+        ref_columns: Vec<String>,
+    },
+}
+
+impl TableConstraint {
+    pub fn constraint_type(&self) -> &'static str {
+        match self {
+            TableConstraint::Check { .. } => "CHECK",
+            TableConstraint::PrimaryKey { .. } => "PRIMARY KEY",
+            TableConstraint::Unique { .. } => "UNIQUE",
+            TableConstraint::ForeignKey { .. } => "FOREIGN KEY",
+        }
+    }
+
+    /// The ids of the constrained columns, empty for `Check`.
+    pub fn column_ids(&self) -> &[u32] {
+        match self {
+            TableConstraint::Check { .. } => &[],
+            TableConstraint::PrimaryKey { column_ids }
+            | TableConstraint::Unique { column_ids }
+            | TableConstraint::ForeignKey { column_ids, .. } => column_ids,
+        }
+    }
+}
+
 impl TableMeta {
     pub fn add_column(
         &mut self,
@@ -277,6 +352,24 @@ impl TableMeta {
     pub fn drop_column(&mut self, column: &str) -> Result<()> {
         self.fill_field_comments();
 
+        let column_id = self.schema.field_with_name(column)?.column_id();
+        if let Some(name) = self.constraints.iter().find_map(|(name, constraint)| {
+            constraint.column_ids().contains(&column_id).then_some(name)
+        }) {
+            return Err(ErrorCode::BadArguments(format!(
+                "column `{}` is referenced by constraint `{}`",
+                column, name
+            )));
+        }
+        if let Some(row_access_policy) = &self.row_access_policy {
+            if row_access_policy.column_ids.contains(&column_id) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "column `{}` is referenced by row access policy `{}`",
+                    column, row_access_policy.policy
+                )));
+            }
+        }
+
         let mut new_schema = self.schema.as_ref().to_owned();
         let index = new_schema.drop_column(column)?;
         self.field_comments.remove(index);
@@ -371,6 +464,8 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             indexes: BTreeMap::new(),
+            constraints: BTreeMap::new(),
+            row_access_policy: None,
         }
     }
 }
@@ -968,6 +1063,7 @@ mod kvapi_key_impl {
     use crate::schema::DatabaseId;
     use crate::schema::LeastVisibleTime;
     use crate::schema::LeastVisibleTimeKey;
+    use crate::schema::TableClonesKey;
     use crate::schema::TableCopiedFileInfo;
     use crate::schema::TableCopiedFileNameIdent;
     use crate::schema::TableId;
@@ -1167,6 +1263,32 @@ mod kvapi_key_impl {
         }
     }
 
+    /// "__fd_table_clones/<table_id> -> TableIdList"
+    impl kvapi::Key for TableClonesKey {
+        const PREFIX: &'static str = "__fd_table_clones";
+
+        type ValueType = TableIdList;
+
+        fn parent(&self) -> Option<String> {
+            Some(TableId::new(self.table_id).to_string_key())
+        }
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_u64(self.table_id)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let table_id = p.next_u64()?;
+            p.done()?;
+
+            Ok(TableClonesKey { table_id })
+        }
+    }
+
     impl kvapi::Value for TableId {
         fn dependency_keys(&self) -> impl IntoIterator<Item = String> {
             [self.to_string_key()]
@@ -1211,8 +1333,18 @@ mod tests {
     use databend_common_meta_kvapi::kvapi;
     use databend_common_meta_kvapi::kvapi::Key;
 
+    use crate::schema::TableClonesKey;
     use crate::schema::TableCopiedFileNameIdent;
 
+    #[test]
+    fn test_table_clones_key_conversion() -> Result<(), kvapi::KeyError> {
+        let name = TableClonesKey::new(3);
+        let key = name.to_string_key();
+        assert_eq!(key, "__fd_table_clones/3");
+        assert_eq!(TableClonesKey::from_str_key(&key)?, name);
+        Ok(())
+    }
+
     #[test]
     fn test_table_copied_file_name_ident_conversion() -> Result<(), kvapi::KeyError> {
         // test with a key has a file has multi path
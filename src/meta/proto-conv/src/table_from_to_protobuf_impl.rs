@@ -189,6 +189,10 @@ impl FromToProto for mt::TableMeta {
         for (name, index) in p.indexes {
             indexes.insert(name, mt::TableIndex::from_pb(index)?);
         }
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in p.constraints {
+            constraints.insert(name, mt::TableConstraint::from_pb(constraint)?);
+        }
 
         let v = Self {
             schema: Arc::new(ex::TableSchema::from_pb(schema)?),
@@ -224,6 +228,11 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            constraints,
+            row_access_policy: p
+                .row_access_policy
+                .map(mt::TableRowAccessPolicy::from_pb)
+                .transpose()?,
         };
         Ok(v)
     }
@@ -233,6 +242,10 @@ impl FromToProto for mt::TableMeta {
         for (name, index) in &self.indexes {
             indexes.insert(name.clone(), index.to_pb()?);
         }
+        let mut constraints = BTreeMap::new();
+        for (name, constraint) in &self.constraints {
+            constraints.insert(name.clone(), constraint.to_pb()?);
+        }
         let p = pb::TableMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -265,6 +278,12 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            constraints,
+            row_access_policy: self
+                .row_access_policy
+                .as_ref()
+                .map(|p| p.to_pb())
+                .transpose()?,
         };
         Ok(p)
     }
@@ -352,3 +371,94 @@ impl FromToProto for mt::TableIndex {
         Ok(p)
     }
 }
+
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            policy: p.policy,
+            column_ids: p.column_ids,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            policy: self.policy.clone(),
+            column_ids: self.column_ids.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableConstraint {
+    type PB = pb::TableConstraint;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableConstraint) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let constraint = p.constraint.ok_or_else(|| Incompatible {
+            reason: "TableConstraint.constraint can not be None".to_string(),
+        })?;
+        let v = match constraint {
+            pb::table_constraint::Constraint::Check(c) => Self::Check { expr: c.expr },
+            pb::table_constraint::Constraint::PrimaryKey(c) => Self::PrimaryKey {
+                column_ids: c.column_ids,
+            },
+            pb::table_constraint::Constraint::Unique(c) => Self::Unique {
+                column_ids: c.column_ids,
+            },
+            pb::table_constraint::Constraint::ForeignKey(c) => Self::ForeignKey {
+                column_ids: c.column_ids,
+                ref_database: c.ref_database,
+                ref_table: c.ref_table,
+                ref_columns: c.ref_columns,
+            },
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableConstraint, Incompatible> {
+        let constraint = match self {
+            Self::Check { expr } => {
+                pb::table_constraint::Constraint::Check(pb::CheckConstraint { expr: expr.clone() })
+            }
+            Self::PrimaryKey { column_ids } => {
+                pb::table_constraint::Constraint::PrimaryKey(pb::KeyConstraint {
+                    column_ids: column_ids.clone(),
+                })
+            }
+            Self::Unique { column_ids } => {
+                pb::table_constraint::Constraint::Unique(pb::KeyConstraint {
+                    column_ids: column_ids.clone(),
+                })
+            }
+            Self::ForeignKey {
+                column_ids,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => pb::table_constraint::Constraint::ForeignKey(pb::ForeignKeyConstraint {
+                column_ids: column_ids.clone(),
+                ref_database: ref_database.clone(),
+                ref_table: ref_table.clone(),
+                ref_columns: ref_columns.clone(),
+            }),
+        };
+        let p = pb::TableConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            constraint: Some(constraint),
+        };
+        Ok(p)
+    }
+}
@@ -112,7 +112,11 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (80, "2024-02-01: Add: datatype.proto/DataType Geometry type"),
     (81, "2024-03-04: Add: udf.udf_script"),
     (82, "2024-03-08: Add: table.inverted_index"),
-    (83, "2024-03-14: Add: null_if in user.proto/NDJSONFileFormatParams")
+    (83, "2024-03-14: Add: null_if in user.proto/NDJSONFileFormatParams"),
+    (84, "2024-03-21: Add: sequence.proto/SequenceMeta"),
+    (85, "2024-03-26: Add: table.proto/TableMeta constraints"),
+    (86, "2024-03-28: Add: row_access_policy.proto/RowAccessPolicyMeta, table.proto/TableMeta row_access_policy"),
+    (87, "2024-03-29: Add: row_access_policy.proto/RowAccessPolicyMeta table_ids")
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
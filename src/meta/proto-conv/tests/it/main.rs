@@ -86,3 +86,7 @@ mod v079_udf_created_on;
 mod v081_udf_script;
 mod v082_table_index;
 mod v083_ndjson_format_params;
+mod v084_sequence_meta;
+mod v085_table_constraint;
+mod v086_row_access_policy;
+mod v087_row_access_policy_table_ids;
@@ -95,6 +95,8 @@ fn test_decode_v82_table_meta() -> anyhow::Result<()> {
             name: "idx1".to_string(),
             column_ids: vec![1, 2]
         }},
+        constraints: btreemap! {},
+        row_access_policy: None,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), table_meta_v82.as_slice(), 82, want())?;
@@ -0,0 +1,59 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use maplit::btreeset;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v87_row_access_policy_meta() -> anyhow::Result<()> {
+    let row_access_policy_meta_v87 = vec![
+        10, 9, 116, 101, 110, 97, 110, 116, 95, 105, 100, 18, 6, 83, 84, 82, 73, 78, 71, 26, 26,
+        116, 101, 110, 97, 110, 116, 95, 105, 100, 32, 61, 32, 99, 117, 114, 114, 101, 110, 116,
+        95, 117, 115, 101, 114, 40, 41, 34, 3, 114, 97, 112, 42, 23, 50, 48, 49, 52, 45, 49, 49,
+        45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 58, 2, 1, 2, 160, 6, 87,
+        168, 6, 24,
+    ];
+
+    let want = || RowAccessPolicyMeta {
+        args: vec![("tenant_id".to_string(), "STRING".to_string())],
+        body: "tenant_id = current_user()".to_string(),
+        comment: Some("rap".to_string()),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: None,
+        table_ids: btreeset! {1, 2},
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        row_access_policy_meta_v87.as_slice(),
+        87,
+        want(),
+    )
+}
@@ -885,6 +885,14 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     vec![from_node],
                 )
             }
+            CopyIntoLocationSource::Stream(stream) => {
+                self.visit_table_ref(&stream.catalog, &stream.database, &stream.table);
+                let from_node = self.children.pop().unwrap();
+                FormatTreeNode::with_children(
+                    AstFormatContext::with_children("Stream".to_string(), 1),
+                    vec![from_node],
+                )
+            }
             CopyIntoLocationSource::Query(query) => {
                 self.visit_query(query);
                 FormatTreeNode::with_children(
@@ -1214,6 +1222,21 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let options_node = FormatTreeNode::with_children(options_format_ctx, options_children);
             children.push(options_node);
         }
+        if let Some(clone_from) = &stmt.clone_from {
+            let mut clone_children = Vec::with_capacity(2);
+            self.visit_database_ref(&clone_from.catalog, &clone_from.database);
+            clone_children.push(self.children.pop().unwrap());
+            if let Some(travel_point) = &clone_from.travel_point {
+                self.visit_time_travel_point(travel_point);
+                clone_children.push(self.children.pop().unwrap());
+            }
+            let clone_format_ctx =
+                AstFormatContext::with_children("CloneDatabase".to_string(), clone_children.len());
+            children.push(FormatTreeNode::with_children(
+                clone_format_ctx,
+                clone_children,
+            ));
+        }
         let name = "CreateDatabase".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1238,6 +1261,28 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_backup_database(&mut self, stmt: &'ast BackupDatabaseStmt) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let database_child = self.children.pop().unwrap();
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        let location_child = FormatTreeNode::new(location_format_ctx);
+        let name = "BackupDatabase".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![database_child, location_child]);
+        self.children.push(node);
+    }
+
+    fn visit_restore_database(&mut self, stmt: &'ast RestoreDatabaseStmt) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let database_child = self.children.pop().unwrap();
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        let location_child = FormatTreeNode::new(location_format_ctx);
+        let name = "RestoreDatabase".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![database_child, location_child]);
+        self.children.push(node);
+    }
+
     fn visit_alter_database(&mut self, stmt: &'ast AlterDatabaseStmt) {
         self.visit_database_ref(&stmt.catalog, &stmt.database);
         let database_child = self.children.pop().unwrap();
@@ -1248,6 +1293,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterDatabaseAction::RevertTo { point } => {
+                self.visit_time_travel_point(point);
+                let point_node = self.children.pop().unwrap();
+                let action_name = "Action RevertTo".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![point_node])
+            }
         };
 
         let name = "AlterDatabase".to_string();
@@ -1417,12 +1469,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, constraints) => {
+                let mut children = Vec::with_capacity(columns.len() + constraints.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                for constraint in constraints.iter() {
+                    let name = format!("TableConstraint {}", constraint);
+                    let format_ctx = AstFormatContext::new(name);
+                    children.push(FormatTreeNode::new(format_ctx));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1440,6 +1497,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let mut children = Vec::with_capacity(2);
+                self.visit_table_ref(catalog, database, table);
+                children.push(self.children.pop().unwrap());
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1589,6 +1664,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let action_name = format!(
+                    "Action Add row access policy {} on ({})",
+                    policy,
+                    columns
+                        .iter()
+                        .map(|column| column.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy => {
+                let action_name = "Action DropRowAccessPolicy".to_string();
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1723,6 +1816,38 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = format!("CreateMaterializedView RefreshMode {}", stmt.refresh_mode);
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_materialized_view(&mut self, stmt: &'ast DropMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
         let mut children = Vec::new();
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
@@ -2821,6 +2946,86 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_sequence(&mut self, stmt: &'ast CreateSequenceStmt) {
+        let ctx = AstFormatContext::new(format!("SequenceName {}", stmt.sequence));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_sequence(&mut self, stmt: &'ast DropSequenceStmt) {
+        let ctx = AstFormatContext::new(format!("SequenceName {}", stmt.sequence));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_sequence(&mut self, stmt: &'ast DescSequenceStmt) {
+        let ctx = AstFormatContext::new(format!("SequenceName {}", stmt.sequence));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_plan_baseline(&mut self, stmt: &'ast CreatePlanBaselineStmt) {
+        self.visit_query(&stmt.query);
+        let child = self.children.pop().unwrap();
+
+        let name = "CreatePlanBaseline".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_plan_baseline(&mut self, stmt: &'ast DropPlanBaselineStmt) {
+        self.visit_query(&stmt.query);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropPlanBaseline".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_password_policies(&mut self, show_options: &'ast Option<ShowOptions>) {
         self.visit_show_options(show_options, "ShowPasswordPolicies".to_string());
     }
@@ -3098,6 +3303,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 since_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 let mut name = String::new();
                 name.push_str("TableIdentifier ");
@@ -3121,6 +3327,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     name.push_str(&unpivot.to_string());
                 }
 
+                if let Some(sample) = sample {
+                    name.push(' ');
+                    name.push_str(&sample.to_string());
+                }
+
                 let mut children = Vec::new();
                 if let Some(travel_point) = travel_point {
                     self.visit_time_travel_point(travel_point);
@@ -44,6 +44,11 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if stmt.temporary {
+            RcDoc::space().append(RcDoc::text("TEMPORARY"))
+        } else {
+            RcDoc::nil()
+        })
         .append(RcDoc::space().append(RcDoc::text("TABLE")))
         .append(match stmt.create_option {
             CreateOption::Create => RcDoc::nil(),
@@ -114,11 +119,16 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
+        CreateTableSource::Columns(columns, constraints) => RcDoc::space().append(parenthesized(
             interweave_comma(
                 columns
                     .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
+                    .map(|column| RcDoc::text(column.to_string()))
+                    .chain(
+                        constraints
+                            .into_iter()
+                            .map(|constraint| RcDoc::text(constraint.to_string())),
+                    ),
             )
             .group(),
         )),
@@ -140,6 +150,30 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(if let Some(travel_point) = travel_point {
+                RcDoc::text(format!(" AT{travel_point}"))
+            } else {
+                RcDoc::nil()
+            }),
     }
 }
 
@@ -233,6 +267,19 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text(format!("ADD ROW ACCESS POLICY {policy} ON ")))
+            .append(parenthesized(
+                interweave_comma(
+                    columns
+                        .into_iter()
+                        .map(|column| RcDoc::text(column.to_string())),
+                )
+                .group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy => {
+            RcDoc::line().append(RcDoc::text("DROP ROW ACCESS POLICY"))
+        }
     }
 }
 
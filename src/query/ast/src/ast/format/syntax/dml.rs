@@ -270,6 +270,7 @@ pub(crate) fn pretty_copy_into_location(copy_stmt: CopyIntoLocationStmt) -> RcDo
         .append(RcDoc::line().append(RcDoc::text("FROM ")))
         .append(match copy_stmt.src {
             CopyIntoLocationSource::Table(v) => RcDoc::text(format!("{v}")),
+            CopyIntoLocationSource::Stream(v) => RcDoc::text(format!("STREAM {v}")),
             CopyIntoLocationSource::Query(query) => RcDoc::text("(")
                 .append(pretty_query(*query))
                 .append(RcDoc::text(")")),
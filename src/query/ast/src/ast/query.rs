@@ -263,6 +263,24 @@ pub enum TimeTravelPoint {
     Timestamp(Box<Expr>),
 }
 
+/// `TABLESAMPLE {BLOCK | ROW} (probability) [SEED seed]`
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct SampleConfig {
+    #[drive(skip)]
+    pub level: SampleLevel,
+    /// The percentage of the blocks or rows to sample, from 0 to 100.
+    #[drive(skip)]
+    pub probability: f64,
+    #[drive(skip)]
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleLevel {
+    Block,
+    Row,
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct Pivot {
     pub aggregate: Expr,
@@ -292,6 +310,7 @@ pub enum TableReference {
         since_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<SampleConfig>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
@@ -448,6 +467,20 @@ impl Display for TableAlias {
     }
 }
 
+impl Display for SampleConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let level = match self.level {
+            SampleLevel::Block => "BLOCK",
+            SampleLevel::Row => "ROW",
+        };
+        write!(f, "TABLESAMPLE {level} ({})", self.probability)?;
+        if let Some(seed) = self.seed {
+            write!(f, " SEED {seed}")?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for Pivot {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "PIVOT({} FOR {} IN (", self.aggregate, self.value_column)?;
@@ -483,6 +516,7 @@ impl Display for TableReference {
                 since_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 write_dot_separated_list(
                     f,
@@ -508,6 +542,9 @@ impl Display for TableReference {
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
                 if let Some(pivot) = pivot {
                     write!(f, " {pivot}")?;
                 }
@@ -200,6 +200,9 @@ pub struct CopyIntoLocationStmt {
     pub max_file_size: usize,
     #[drive(skip)]
     pub detailed_output: bool,
+    /// Export the changes of a stream as change events of the format, e.g. `debezium`.
+    #[drive(skip)]
+    pub change_format: Option<String>,
 }
 
 impl Display for CopyIntoLocationStmt {
@@ -219,6 +222,9 @@ impl Display for CopyIntoLocationStmt {
         write!(f, " SINGLE = {}", self.single)?;
         write!(f, " MAX_FILE_SIZE = {}", self.max_file_size)?;
         write!(f, " DETAILED_OUTPUT = {}", self.detailed_output)?;
+        if let Some(change_format) = &self.change_format {
+            write!(f, " CHANGE_FORMAT = '{}'", change_format)?;
+        }
 
         Ok(())
     }
@@ -231,6 +237,7 @@ impl CopyIntoLocationStmt {
             CopyIntoLocationOption::Single(v) => self.single = v,
             CopyIntoLocationOption::MaxFileSize(v) => self.max_file_size = v,
             CopyIntoLocationOption::DetailedOutput(v) => self.detailed_output = v,
+            CopyIntoLocationOption::ChangeFormat(v) => self.change_format = Some(v),
         }
     }
 }
@@ -259,6 +266,8 @@ pub enum CopyIntoLocationSource {
     Query(Box<Query>),
     /// it will be rewrite as `(SELECT * FROM table)`
     Table(TableRef),
+    /// `STREAM <stream>`, the offset of the stream advances when the copy commits
+    Stream(TableRef),
 }
 
 impl Display for CopyIntoLocationSource {
@@ -270,6 +279,9 @@ impl Display for CopyIntoLocationSource {
             CopyIntoLocationSource::Table(table) => {
                 write!(f, "{}", table)
             }
+            CopyIntoLocationSource::Stream(stream) => {
+                write!(f, "STREAM {}", stream)
+            }
         }
     }
 }
@@ -490,4 +502,5 @@ pub enum CopyIntoLocationOption {
     MaxFileSize(usize),
     Single(bool),
     DetailedOutput(bool),
+    ChangeFormat(String),
 }
@@ -24,6 +24,7 @@ use crate::ast::statements::show::ShowLimit;
 use crate::ast::write_dot_separated_list;
 use crate::ast::DatabaseRef;
 use crate::ast::Identifier;
+use crate::ast::TimeTravelPoint;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct ShowDatabasesStmt {
@@ -66,7 +67,7 @@ impl Display for ShowCreateDatabaseStmt {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct CreateDatabaseStmt {
     #[drive(skip)]
     pub create_option: CreateOption,
@@ -75,6 +76,26 @@ pub struct CreateDatabaseStmt {
     pub options: Vec<SQLProperty>,
     #[drive(skip)]
     pub from_share: Option<ShareNameIdent>,
+    pub clone_from: Option<CloneDatabaseSource>,
+}
+
+/// Zero-copy clone of all the tables of a database, optionally at a historical point.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CloneDatabaseSource {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub travel_point: Option<TimeTravelPoint>,
+}
+
+impl Display for CloneDatabaseSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CLONE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        if let Some(travel_point) = &self.travel_point {
+            write!(f, " AT{}", travel_point)?;
+        }
+        Ok(())
+    }
 }
 
 impl Display for CreateDatabaseStmt {
@@ -100,6 +121,9 @@ impl Display for CreateDatabaseStmt {
                 from_share.tenant, from_share.share_name
             )?;
         }
+        if let Some(clone_from) = &self.clone_from {
+            write!(f, " {clone_from}")?;
+        }
 
         // TODO(leiysky): display rest information
         Ok(())
@@ -141,6 +165,40 @@ impl Display for UndropDatabaseStmt {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct BackupDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    #[drive(skip)]
+    pub location: String,
+}
+
+impl Display for BackupDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BACKUP DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " TO @{}", self.location)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct RestoreDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    #[drive(skip)]
+    pub location: String,
+}
+
+impl Display for RestoreDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RESTORE DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " FROM @{}", self.location)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct AlterDatabaseStmt {
     #[drive(skip)]
     pub if_exists: bool,
@@ -160,15 +218,19 @@ impl Display for AlterDatabaseStmt {
             AlterDatabaseAction::RenameDatabase { new_db } => {
                 write!(f, " RENAME TO {new_db}")?;
             }
+            AlterDatabaseAction::RevertTo { point } => {
+                write!(f, " FLASHBACK TO{point}")?;
+            }
         }
 
         Ok(())
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum AlterDatabaseAction {
     RenameDatabase { new_db: Identifier },
+    RevertTo { point: TimeTravelPoint },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
@@ -24,6 +24,7 @@ use crate::ast::Identifier;
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct Hint {
     pub hints_list: Vec<HintItem>,
+    pub join_hints: Vec<JoinHintItem>,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -32,6 +33,20 @@ pub struct HintItem {
     pub expr: Expr,
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum JoinHintItem {
+    /// Join the tables first, in the given order.
+    Leading(Vec<Identifier>),
+    /// Broadcast the table to the join with it.
+    Broadcast(Vec<Identifier>),
+    /// Shuffle both sides of the join with the table by the join keys.
+    Shuffle(Vec<Identifier>),
+    /// Use hash join for the join with the table.
+    HashJoin(Vec<Identifier>),
+    /// Never use merge join in the query.
+    NoMerge,
+}
+
 impl Display for Hint {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "/*+ ")?;
@@ -42,6 +57,29 @@ impl Display for Hint {
             write!(f, "{}", hint.expr)?;
             write!(f, ") ")?;
         }
+        for hint in &self.join_hints {
+            write!(f, "{} ", hint)?;
+        }
         write!(f, "*/")
     }
 }
+
+impl Display for JoinHintItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (name, tables) = match self {
+            JoinHintItem::Leading(tables) => ("LEADING", tables),
+            JoinHintItem::Broadcast(tables) => ("BROADCAST", tables),
+            JoinHintItem::Shuffle(tables) => ("SHUFFLE", tables),
+            JoinHintItem::HashJoin(tables) => ("HASH_JOIN", tables),
+            JoinHintItem::NoMerge => return write!(f, "NO_MERGE"),
+        };
+        write!(f, "{}(", name)?;
+        for (i, table) in tables.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", table)?;
+        }
+        write!(f, ")")
+    }
+}
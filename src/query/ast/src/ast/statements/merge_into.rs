@@ -280,6 +280,7 @@ impl MergeSource {
                 since_point: None,
                 pivot: None,
                 unpivot: None,
+                sample: None,
             },
         }
     }
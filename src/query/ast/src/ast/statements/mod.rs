@@ -31,9 +31,12 @@ mod network_policy;
 mod notification;
 mod password_policy;
 mod pipe;
+mod plan_baseline;
 mod presign;
 mod replace;
+mod row_access_policy;
 mod script;
+mod sequence;
 mod share;
 mod show;
 mod stage;
@@ -67,9 +70,12 @@ pub use network_policy::*;
 pub use notification::*;
 pub use password_policy::*;
 pub use pipe::*;
+pub use plan_baseline::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use script::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
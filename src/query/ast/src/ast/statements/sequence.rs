@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_meta_app::schema::CreateOption;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::Identifier;
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateSequenceStmt {
+    #[drive(skip)]
+    pub create_option: CreateOption,
+    pub sequence: Identifier,
+    #[drive(skip)]
+    pub start: Option<u64>,
+    #[drive(skip)]
+    pub increment: Option<u64>,
+    #[drive(skip)]
+    pub comment: Option<String>,
+}
+
+impl Display for CreateSequenceStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "SEQUENCE ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.sequence)?;
+        if let Some(start) = self.start {
+            write!(f, " START = {start}")?;
+        }
+        if let Some(increment) = self.increment {
+            write!(f, " INCREMENT = {increment}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DropSequenceStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    pub sequence: Identifier,
+}
+
+impl Display for DropSequenceStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP SEQUENCE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.sequence)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DescSequenceStmt {
+    pub sequence: Identifier,
+}
+
+impl Display for DescSequenceStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE SEQUENCE {}", self.sequence)
+    }
+}
@@ -122,6 +122,8 @@ pub enum Statement {
     CreateDatabase(CreateDatabaseStmt),
     DropDatabase(DropDatabaseStmt),
     UndropDatabase(UndropDatabaseStmt),
+    BackupDatabase(BackupDatabaseStmt),
+    RestoreDatabase(RestoreDatabaseStmt),
     AlterDatabase(AlterDatabaseStmt),
     UseDatabase {
         database: Identifier,
@@ -154,6 +156,9 @@ pub enum Statement {
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    DropMaterializedView(DropMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
 
     // Streams
     CreateStream(CreateStreamStmt),
@@ -295,6 +300,20 @@ pub enum Statement {
     AlterPasswordPolicy(AlterPasswordPolicyStmt),
     DropPasswordPolicy(DropPasswordPolicyStmt),
     DescPasswordPolicy(DescPasswordPolicyStmt),
+
+    // Sequence
+    CreateSequence(CreateSequenceStmt),
+    DropSequence(DropSequenceStmt),
+    DescSequence(DescSequenceStmt),
+
+    // Row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
+    // Plan baseline
+    CreatePlanBaseline(CreatePlanBaselineStmt),
+    DropPlanBaseline(DropPlanBaselineStmt),
     ShowPasswordPolicies {
         show_options: Option<ShowOptions>,
     },
@@ -525,6 +544,8 @@ impl Display for Statement {
             Statement::CreateDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UndropDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::BackupDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::RestoreDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::AlterDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
@@ -549,6 +570,9 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
             Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
             Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::ShowStreams(stmt) => write!(f, "{stmt}")?,
@@ -691,6 +715,14 @@ impl Display for Statement {
             Statement::AlterPasswordPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropPasswordPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DescSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreatePlanBaseline(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPlanBaseline(stmt) => write!(f, "{stmt}")?,
             Statement::ShowPasswordPolicies { show_options } => {
                 write!(f, "SHOW PASSWORD POLICIES")?;
                 if let Some(show_options) = show_options {
@@ -146,6 +146,8 @@ pub struct CreateTableStmt {
     pub as_query: Option<Box<Query>>,
     #[drive(skip)]
     pub transient: bool,
+    #[drive(skip)]
+    pub temporary: bool,
 }
 
 impl Display for CreateTableStmt {
@@ -157,6 +159,9 @@ impl Display for CreateTableStmt {
         if self.transient {
             write!(f, "TRANSIENT ")?;
         }
+        if self.temporary {
+            write!(f, "TEMPORARY ")?;
+        }
         write!(f, "TABLE ")?;
         if let CreateOption::CreateIfNotExists = self.create_option {
             write!(f, "IF NOT EXISTS ")?;
@@ -230,20 +235,31 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Vec<TableConstraintDefinition>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    /// Zero-copy clone of a fuse table, optionally at a historical point.
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -254,6 +270,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{}", travel_point)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -385,6 +414,11 @@ pub enum AlterTableAction {
         #[drive(skip)]
         set_options: BTreeMap<String, String>,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy,
 }
 
 impl Display for AlterTableAction {
@@ -439,6 +473,14 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropRowAccessPolicy => {
+                write!(f, "DROP ROW ACCESS POLICY")?;
+            }
         };
         Ok(())
     }
@@ -776,6 +818,13 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    /// `AUTOINCREMENT | IDENTITY [(<start>, <step>)]`, the column is filled by an implicit sequence.
+    Identity {
+        #[drive(skip)]
+        start: u64,
+        #[drive(skip)]
+        step: u64,
+    },
 }
 
 impl Display for ColumnExpr {
@@ -790,6 +839,9 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::Identity { start, step } => {
+                write!(f, " IDENTITY({start}, {step})")?;
+            }
         }
         Ok(())
     }
@@ -823,6 +875,66 @@ impl Display for ColumnDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct TableConstraintDefinition {
+    pub name: Option<Identifier>,
+    pub kind: TableConstraintKind,
+}
+
+impl Display for TableConstraintDefinition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {name} ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum TableConstraintKind {
+    Check(Box<Expr>),
+    PrimaryKey(Vec<Identifier>),
+    Unique(Vec<Identifier>),
+    ForeignKey {
+        columns: Vec<Identifier>,
+        ref_database: Option<Identifier>,
+        ref_table: Identifier,
+        ref_columns: Vec<Identifier>,
+    },
+}
+
+impl Display for TableConstraintKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableConstraintKind::Check(expr) => write!(f, "CHECK ({expr})"),
+            TableConstraintKind::PrimaryKey(columns) => {
+                write!(f, "PRIMARY KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraintKind::Unique(columns) => {
+                write!(f, "UNIQUE (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => {
+                write!(f, "FOREIGN KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") REFERENCES ")?;
+                write_dot_separated_list(f, ref_database.iter().chain(Some(ref_table)))?;
+                write!(f, " (")?;
+                write_comma_separated_list(f, ref_columns)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum ModifyColumnAction {
     // (column name id, masking policy name)
@@ -518,6 +518,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_undrop_database(&mut self, _stmt: &'ast UndropDatabaseStmt) {}
 
+    fn visit_backup_database(&mut self, _stmt: &'ast BackupDatabaseStmt) {}
+
+    fn visit_restore_database(&mut self, _stmt: &'ast RestoreDatabaseStmt) {}
+
     fn visit_alter_database(&mut self, _stmt: &'ast AlterDatabaseStmt) {}
 
     fn visit_use_database(&mut self, _database: &'ast Identifier) {}
@@ -572,6 +576,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &'ast DropMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
     fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
 
     fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
@@ -702,6 +712,22 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_password_policy(&mut self, _stmt: &'ast DescPasswordPolicyStmt) {}
 
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt) {}
+
+    fn visit_desc_sequence(&mut self, _stmt: &'ast DescSequenceStmt) {}
+
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
+    fn visit_create_plan_baseline(&mut self, _stmt: &'ast CreatePlanBaselineStmt) {}
+
+    fn visit_drop_plan_baseline(&mut self, _stmt: &'ast DropPlanBaselineStmt) {}
+
     fn visit_show_password_policies(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
     fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
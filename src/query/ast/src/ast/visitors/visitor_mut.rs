@@ -531,6 +531,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_undrop_database(&mut self, _stmt: &mut UndropDatabaseStmt) {}
 
+    fn visit_backup_database(&mut self, _stmt: &mut BackupDatabaseStmt) {}
+
+    fn visit_restore_database(&mut self, _stmt: &mut RestoreDatabaseStmt) {}
+
     fn visit_alter_database(&mut self, _stmt: &mut AlterDatabaseStmt) {}
 
     fn visit_use_database(&mut self, _database: &mut Identifier) {}
@@ -585,6 +589,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &mut DropMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
     fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
 
     fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
@@ -715,6 +725,22 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_password_policy(&mut self, _stmt: &mut DescPasswordPolicyStmt) {}
 
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt) {}
+
+    fn visit_desc_sequence(&mut self, _stmt: &mut DescSequenceStmt) {}
+
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
+    fn visit_create_plan_baseline(&mut self, _stmt: &mut CreatePlanBaselineStmt) {}
+
+    fn visit_drop_plan_baseline(&mut self, _stmt: &mut DropPlanBaselineStmt) {}
+
     fn visit_show_password_policies(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
     fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
@@ -85,6 +85,9 @@ pub fn copy_into_table(i: Input) -> IResult<Statement> {
 
 fn copy_into_location(i: Input) -> IResult<Statement> {
     let copy_into_location_source = alt((
+        map(rule! { STREAM ~ #table_ref }, |(_, stream)| {
+            CopyIntoLocationSource::Stream(stream)
+        }),
         map(table_ref, CopyIntoLocationSource::Table),
         map(rule! { "(" ~ #query ~ ")" }, |(_, query, _)| {
             CopyIntoLocationSource::Query(Box::new(query))
@@ -108,6 +111,7 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
                 single: Default::default(),
                 max_file_size: Default::default(),
                 detailed_output: false,
+                change_format: None,
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -198,6 +202,10 @@ fn copy_into_location_option(i: Input) -> IResult<CopyIntoLocationOption> {
             rule! { DETAILED_OUTPUT ~ "=" ~ #literal_bool },
             |(_, _, detailed_output)| CopyIntoLocationOption::DetailedOutput(detailed_output),
         ),
+        map(
+            rule! { CHANGE_FORMAT ~ "=" ~ #literal_string },
+            |(_, _, change_format)| CopyIntoLocationOption::ChangeFormat(change_format),
+        ),
         map(rule! { #file_format_clause }, |options| {
             CopyIntoLocationOption::FileFormat(options)
         }),
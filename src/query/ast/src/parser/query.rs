@@ -514,6 +514,30 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
     )(i)
 }
 
+pub fn table_sample(i: Input) -> IResult<SampleConfig> {
+    let level = alt((
+        value(SampleLevel::Block, rule! { BLOCK }),
+        value(SampleLevel::Row, rule! { ROW }),
+    ));
+    let probability = map_res(rule! { LiteralInteger | LiteralFloat }, |token| {
+        token
+            .text()
+            .parse::<f64>()
+            .map_err(|_| nom::Err::Failure(ErrorKind::Other("invalid sample probability")))
+    });
+
+    map(
+        rule! {
+            TABLESAMPLE ~ ^#level ~ ^"(" ~ ^#probability ~ ^")" ~ (SEED ~ ^#literal_u64)?
+        },
+        |(_, level, _, probability, _, seed)| SampleConfig {
+            level,
+            probability,
+            seed: seed.map(|(_, seed)| seed),
+        },
+    )(i)
+}
+
 pub fn alias_name(i: Input) -> IResult<Identifier> {
     let short_alias = map(
         rule! {
@@ -629,6 +653,7 @@ pub enum TableReferenceElement {
         since_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<SampleConfig>,
     },
     // `TABLE(expr)[ AS alias ]`
     TableFunction {
@@ -685,9 +710,17 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_table = map(
         rule! {
-            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)?  ~ (SINCE ~ ^#travel_point)? ~ #table_alias? ~ #pivot? ~ #unpivot?
+            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)?  ~ (SINCE ~ ^#travel_point)? ~ #table_alias? ~ #table_sample? ~ #pivot? ~ #unpivot?
         },
-        |((catalog, database, table), travel_point_opt, since_point_opt, alias, pivot, unpivot)| {
+        |(
+            (catalog, database, table),
+            travel_point_opt,
+            since_point_opt,
+            alias,
+            sample,
+            pivot,
+            unpivot,
+        )| {
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -697,6 +730,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 since_point: since_point_opt.map(|p| p.1),
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
+                sample,
             }
         },
     );
@@ -808,6 +842,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 since_point,
                 pivot,
                 unpivot,
+                sample,
             } => TableReference::Table {
                 span: transform_span(input.span.0),
                 catalog,
@@ -818,6 +853,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 since_point,
                 pivot,
                 unpivot,
+                sample,
             },
             TableReferenceElement::TableFunction {
                 lateral,
@@ -58,6 +58,7 @@ pub enum ShowGrantOption {
 pub enum CreateDatabaseOption {
     DatabaseEngine(DatabaseEngine),
     FromShare(ShareNameIdent),
+    Clone(CloneDatabaseSource),
 }
 
 pub fn statement_body(i: Input) -> IResult<Statement> {
@@ -476,6 +477,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         engine: Some(engine),
                         options: vec![],
                         from_share: None,
+                        clone_from: None,
                     })
                 }
                 Some(CreateDatabaseOption::FromShare(share_name)) => {
@@ -485,6 +487,17 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         engine: None,
                         options: vec![],
                         from_share: Some(share_name),
+                        clone_from: None,
+                    })
+                }
+                Some(CreateDatabaseOption::Clone(clone_from)) => {
+                    Statement::CreateDatabase(CreateDatabaseStmt {
+                        create_option,
+                        database,
+                        engine: None,
+                        options: vec![],
+                        from_share: None,
+                        clone_from: Some(clone_from),
                     })
                 }
                 None => Statement::CreateDatabase(CreateDatabaseStmt {
@@ -493,6 +506,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     engine: None,
                     options: vec![],
                     from_share: None,
+                    clone_from: None,
                 }),
             };
 
@@ -522,6 +536,32 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let backup_database = map(
+        rule! {
+            BACKUP ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ TO ~ #at_string
+        },
+        |(_, _, (catalog, database), _, location)| {
+            Statement::BackupDatabase(BackupDatabaseStmt {
+                catalog,
+                database,
+                location,
+            })
+        },
+    );
+
+    let restore_database = map(
+        rule! {
+            RESTORE ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ FROM ~ #at_string
+        },
+        |(_, _, (catalog, database), _, location)| {
+            Statement::RestoreDatabase(RestoreDatabaseStmt {
+                catalog,
+                database,
+                location,
+            })
+        },
+    );
+
     let alter_database = map(
         rule! {
             ALTER ~ DATABASE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_2 ~ #alter_database_action
@@ -661,7 +701,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let create_table = map_res(
         rule! {
-            CREATE ~ ( OR ~ ^REPLACE )? ~ TRANSIENT? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            CREATE ~ ( OR ~ ^REPLACE )? ~ ( TRANSIENT | TEMPORARY )? ~ TABLE ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #dot_separated_idents_1_to_3
             ~ #create_table_source?
             ~ ( #engine )?
@@ -673,7 +713,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(
             _,
             opt_or_replace,
-            opt_transient,
+            opt_table_type,
             _,
             opt_if_not_exists,
             (catalog, database, table),
@@ -699,7 +739,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
-                transient: opt_transient.is_some(),
+                transient: opt_table_type.map_or(false, |t| t.kind == TRANSIENT),
+                temporary: opt_table_type.map_or(false, |t| t.kind == TEMPORARY),
             }))
         },
     );
@@ -919,6 +960,66 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let create_materialized_view = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ MATERIALIZED ~ ^VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ ( REFRESH ~ "=" ~ #materialized_view_refresh_mode )?
+            ~ AS ~ #query
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, view),
+            opt_refresh_mode,
+            _,
+            query,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateMaterializedView(
+                CreateMaterializedViewStmt {
+                    create_option,
+                    catalog,
+                    database,
+                    view,
+                    refresh_mode: opt_refresh_mode
+                        .map(|(_, _, mode)| mode)
+                        .unwrap_or_default(),
+                    query: Box::new(query),
+                },
+            ))
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ ^VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ ^VIEW ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+
     let create_index = map_res(
         rule! {
             CREATE
@@ -1791,6 +1892,134 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         rule! { SHOW ~ NETWORK ~ ^POLICIES },
     );
 
+    let create_sequence = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( START ~ WITH? ~ "="? ~ ^#literal_u64 )?
+            ~ ( INCREMENT ~ BY? ~ "="? ~ ^#literal_u64 )?
+            ~ ( COMMENT ~ "="? ~ ^#literal_string )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            sequence,
+            opt_start,
+            opt_increment,
+            opt_comment,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateSequence(CreateSequenceStmt {
+                create_option,
+                sequence,
+                start: opt_start.map(|(_, _, _, start)| start),
+                increment: opt_increment.map(|(_, _, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            }))
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, sequence)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                sequence,
+            })
+        },
+    );
+    let create_row_access_policy = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ^AS ~ ^"(" ~ ^#comma_separated_list1(row_access_policy_arg) ~ ^")"
+            ~ ^RETURNS ~ ^BOOLEAN ~ ^"->" ~ ^#expr
+            ~ ( COMMENT ~ "="? ~ ^#literal_string )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            _,
+            _,
+            body,
+            opt_comment,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateRowAccessPolicy(
+                CreateRowAccessPolicyStmt {
+                    create_option,
+                    name,
+                    args,
+                    body,
+                    comment: opt_comment.map(|(_, _, comment)| comment),
+                },
+            ))
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt { name }),
+    );
+
+    let create_plan_baseline = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ PLAN ~ ^BASELINE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ ^FOR ~ ^#query
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, _, query)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreatePlanBaseline(CreatePlanBaselineStmt {
+                create_option,
+                query: Box::new(query),
+            }))
+        },
+    );
+    let drop_plan_baseline = map(
+        rule! {
+            DROP ~ PLAN ~ ^BASELINE ~ ( IF ~ ^EXISTS )? ~ ^FOR ~ ^#query
+        },
+        |(_, _, _, opt_if_exists, _, query)| {
+            Statement::DropPlanBaseline(DropPlanBaselineStmt {
+                if_exists: opt_if_exists.is_some(),
+                query: Box::new(query),
+            })
+        },
+    );
+
+    let describe_sequence = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ SEQUENCE ~ #ident
+        },
+        |(_, _, sequence)| Statement::DescSequence(DescSequenceStmt { sequence }),
+    );
+
     let create_password_policy = map_res(
         rule! {
             CREATE ~ ( OR ~ ^REPLACE )? ~ PASSWORD ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
@@ -2012,11 +2241,24 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #show_databases : "`SHOW [FULL] DATABASES [(FROM | IN) <catalog>] [<show_limit>]`"
             | #undrop_database : "`UNDROP DATABASE <database>`"
             | #show_create_database : "`SHOW CREATE DATABASE <database>`"
-            | #create_database : "`CREATE [OR REPLACE] DATABASE [IF NOT EXISTS] <database> [ENGINE = <engine>]`"
+            | #create_database : "`CREATE [OR REPLACE] DATABASE [IF NOT EXISTS] <database> [ENGINE = <engine> | CLONE <database> [AT (...)]]`"
             | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
+            | #backup_database : "`BACKUP DATABASE <database> TO @<stage>[/<path>]`"
+            | #restore_database : "`RESTORE DATABASE <database> FROM @<stage>[/<path>]`"
             | #use_database : "`USE <database>`"
         ),
+        // sequence / row access policy / plan baseline
+        rule!(
+            #create_sequence: "`CREATE [OR REPLACE] SEQUENCE [IF NOT EXISTS] <sequence> [START [WITH] [=] <u64_literal>] [INCREMENT [BY] [=] <u64_literal>] [COMMENT = '<string_literal>']`"
+            | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] <sequence>`"
+            | #describe_sequence: "`DESC SEQUENCE <sequence>`"
+            | #create_row_access_policy: "`CREATE [OR REPLACE] ROW ACCESS POLICY [IF NOT EXISTS] <name> AS (<arg> <type>, ...) RETURNS BOOLEAN -> <expr> [COMMENT = '<string_literal>']`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <name>`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY <name>`"
+            | #create_plan_baseline: "`CREATE [OR REPLACE] PLAN BASELINE [IF NOT EXISTS] FOR <query>`"
+            | #drop_plan_baseline: "`DROP PLAN BASELINE [IF EXISTS] FOR <query>`"
+        ),
         // network policy / password policy
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -2071,6 +2313,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #create_view : "`CREATE [OR REPLACE] VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_materialized_view : "`CREATE [OR REPLACE] MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> [REFRESH = {MANUAL | AUTO}] AS SELECT ...`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
             | #stream_table
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP <index_type> INDEX [IF EXISTS] <index>`"
@@ -2442,18 +2687,79 @@ pub fn set_var_hints(i: Input) -> IResult<HintItem> {
     )(i)
 }
 
+pub fn join_hint(i: Input) -> IResult<JoinHintItem> {
+    let leading = map(
+        rule! {
+            LEADING ~ ^"(" ~ #ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| JoinHintItem::Leading(tables),
+    );
+    let broadcast = map(
+        rule! {
+            BROADCAST ~ ^"(" ~ #ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| JoinHintItem::Broadcast(tables),
+    );
+    let shuffle = map(
+        rule! {
+            SHUFFLE ~ ^"(" ~ #ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| JoinHintItem::Shuffle(tables),
+    );
+    let hash_join = map(
+        rule! {
+            HASH_JOIN ~ ^"(" ~ #ident+ ~ ^")"
+        },
+        |(_, _, tables, _)| JoinHintItem::HashJoin(tables),
+    );
+    let no_merge = value(JoinHintItem::NoMerge, rule! { NO_MERGE });
+
+    rule!(
+        #leading
+        | #broadcast
+        | #shuffle
+        | #hash_join
+        | #no_merge
+    )(i)
+}
+
+enum HintOrJoinHint {
+    SetVar(HintItem),
+    Join(JoinHintItem),
+}
+
 pub fn hint(i: Input) -> IResult<Hint> {
+    let hint_item = alt((
+        map(set_var_hints, HintOrJoinHint::SetVar),
+        map(join_hint, HintOrJoinHint::Join),
+    ));
     let hint = map(
         rule! {
-            "/*+" ~ #set_var_hints+ ~ "*/"
+            "/*+" ~ #hint_item+ ~ "*/"
+        },
+        |(_, items, _)| {
+            let mut hints_list = vec![];
+            let mut join_hints = vec![];
+            for item in items {
+                match item {
+                    HintOrJoinHint::SetVar(item) => hints_list.push(item),
+                    HintOrJoinHint::Join(item) => join_hints.push(item),
+                }
+            }
+            Hint {
+                hints_list,
+                join_hints,
+            }
         },
-        |(_, hints_list, _)| Hint { hints_list },
     );
     let invalid_hint = map(
         rule! {
             "/*+" ~ (!"*/" ~ #any_token)* ~ "*/"
         },
-        |_| Hint { hints_list: vec![] },
+        |_| Hint {
+            hints_list: vec![],
+            join_hints: vec![],
+        },
     );
     rule!(#hint|#invalid_hint)(i)
 }
@@ -2478,6 +2784,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        Identity(u64, u64),
     }
 
     let nullable = alt((
@@ -2503,6 +2810,15 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, _, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
+        map(
+            rule! {
+                ( AUTOINCREMENT | IDENTITY ) ~ ( "(" ~ ^#literal_u64 ~ ^"," ~ ^#literal_u64 ~ ^")" )?
+            },
+            |(_, opt_args)| {
+                let (start, step) = opt_args.map_or((1, 1), |(_, start, _, step, _)| (start, step));
+                ColumnConstraint::Identity(start, step)
+            },
+        ),
     ));
 
     let comment = map(
@@ -2557,6 +2873,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::Identity(start, step) => {
+                def.expr = Some(ColumnExpr::Identity { start, step })
+            }
         }
     }
 
@@ -2663,6 +2982,10 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
         value(UserPrivilegeType::DropRole, rule! { DROP ~ ROLE }),
         value(UserPrivilegeType::Grant, rule! { GRANT }),
         value(UserPrivilegeType::CreateStage, rule! { CREATE ~ STAGE }),
+        value(
+            UserPrivilegeType::ApplyRowAccessPolicy,
+            rule! { APPLY ~ ROW ~ ACCESS ~ POLICY },
+        ),
         value(UserPrivilegeType::Set, rule! { SET }),
         value(UserPrivilegeType::Drop, rule! { DROP }),
         value(UserPrivilegeType::Create, rule! { CREATE }),
@@ -2873,12 +3196,154 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
     )(i)
 }
 
+pub fn table_constraint_def(i: Input) -> IResult<TableConstraintDefinition> {
+    let check = map(
+        rule! {
+            CHECK ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableConstraintKind::Check(Box::new(expr)),
+    );
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _)| TableConstraintKind::PrimaryKey(columns),
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, columns, _)| TableConstraintKind::Unique(columns),
+    );
+    let foreign_key = map(
+        rule! {
+            FOREIGN ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ^REFERENCES ~ ^#dot_separated_idents_1_to_2
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _, _, (ref_database, ref_table), _, ref_columns, _)| {
+            TableConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            }
+        },
+    );
+
+    map(
+        rule! {
+            ( CONSTRAINT ~ ^#ident )?
+            ~ ( #check | #primary_key | #unique | #foreign_key )
+            : "`[CONSTRAINT <name>] CHECK (<expr>) | PRIMARY KEY (<column>, ...) | UNIQUE (<column>, ...) | FOREIGN KEY (<column>, ...) REFERENCES <table> (<column>, ...)`"
+        },
+        |(opt_name, kind)| TableConstraintDefinition {
+            name: opt_name.map(|(_, name)| name),
+            kind,
+        },
+    )(i)
+}
+
+fn row_access_policy_arg(i: Input) -> IResult<RowAccessPolicyArg> {
+    map(rule! { #ident ~ #type_name }, |(name, data_type)| {
+        RowAccessPolicyArg { name, data_type }
+    })(i)
+}
+
+/// A column definition followed by the constraints on the column, e.g. `id INT PRIMARY KEY`.
+fn column_def_with_constraints(
+    i: Input,
+) -> IResult<(ColumnDefinition, Vec<TableConstraintDefinition>)> {
+    #[derive(Clone)]
+    enum InlineConstraint {
+        Check(Box<Expr>),
+        PrimaryKey,
+        Unique,
+        References(Option<Identifier>, Identifier, Identifier),
+    }
+
+    let inline_constraint = alt((
+        map(
+            rule! {
+                CHECK ~ ^"(" ~ ^#expr ~ ^")"
+            },
+            |(_, _, expr, _)| InlineConstraint::Check(Box::new(expr)),
+        ),
+        value(InlineConstraint::PrimaryKey, rule! { PRIMARY ~ ^KEY }),
+        value(InlineConstraint::Unique, rule! { UNIQUE }),
+        map(
+            rule! {
+                REFERENCES ~ ^#dot_separated_idents_1_to_2 ~ ^"(" ~ ^#ident ~ ^")"
+            },
+            |(_, (ref_database, ref_table), _, ref_column, _)| {
+                InlineConstraint::References(ref_database, ref_table, ref_column)
+            },
+        ),
+    ));
+
+    map(
+        rule! {
+            #column_def ~ ( ( CONSTRAINT ~ ^#ident )? ~ #inline_constraint )*
+        },
+        |(column, inline_constraints)| {
+            let constraints = inline_constraints
+                .into_iter()
+                .map(|(opt_name, constraint)| {
+                    let columns = vec![column.name.clone()];
+                    let kind = match constraint {
+                        InlineConstraint::Check(expr) => TableConstraintKind::Check(expr),
+                        InlineConstraint::PrimaryKey => TableConstraintKind::PrimaryKey(columns),
+                        InlineConstraint::Unique => TableConstraintKind::Unique(columns),
+                        InlineConstraint::References(ref_database, ref_table, ref_column) => {
+                            TableConstraintKind::ForeignKey {
+                                columns,
+                                ref_database,
+                                ref_table,
+                                ref_columns: vec![ref_column],
+                            }
+                        }
+                    };
+                    TableConstraintDefinition {
+                        name: opt_name.map(|(_, name)| name),
+                        kind,
+                    }
+                })
+                .collect();
+            (column, constraints)
+        },
+    )(i)
+}
+
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
+    enum Element {
+        Column(ColumnDefinition, Vec<TableConstraintDefinition>),
+        Constraint(TableConstraintDefinition),
+    }
+
+    let element = alt((
+        map(table_constraint_def, Element::Constraint),
+        map(column_def_with_constraints, |(column, constraints)| {
+            Element::Column(column, constraints)
+        }),
+    ));
     let columns = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            "(" ~ ^#comma_separated_list1(element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::with_capacity(elements.len());
+            let mut constraints = vec![];
+            for element in elements {
+                match element {
+                    Element::Column(column, column_constraints) => {
+                        columns.push(column);
+                        constraints.extend(column_constraints);
+                    }
+                    Element::Constraint(constraint) => constraints.push(constraint),
+                }
+            }
+            CreateTableSource::Columns(columns, constraints)
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
@@ -2891,9 +3356,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)?
+        },
+        |(_, (catalog, database, table), travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: travel_point.map(|(_, p)| p),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -2905,8 +3383,16 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
         |(_, _, new_db)| AlterDatabaseAction::RenameDatabase { new_db },
     );
 
+    let revert_database = map(
+        rule! {
+            FLASHBACK ~ TO ~ #travel_point
+        },
+        |(_, _, point)| AlterDatabaseAction::RevertTo { point },
+    );
+
     rule!(
         #rename_database
+        | #revert_database
     )(i)
 }
 
@@ -3099,11 +3585,27 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ ^#ident ~ ^ON ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = value(AlterTableAction::DropRowAccessPolicy, rule! {
+        DROP ~ ROW ~ ACCESS ~ POLICY
+    });
+
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
         | #rename_table
         | #rename_column
+        | #add_row_access_policy
+        | #drop_row_access_policy
         | #add_column
         | #drop_column
         | #modify_column
@@ -3592,9 +4094,23 @@ pub fn create_database_option(i: Input) -> IResult<CreateDatabaseOption> {
         },
     );
 
+    let clone_from = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_2 ~ (AT ~ ^#travel_point)?
+        },
+        |(_, (catalog, database), travel_point)| {
+            CreateDatabaseOption::Clone(CloneDatabaseSource {
+                catalog,
+                database,
+                travel_point: travel_point.map(|(_, p)| p),
+            })
+        },
+    );
+
     rule!(
         #create_db_engine
         | #share_from
+        | #clone_from
     )(i)
 }
 
@@ -3606,6 +4122,13 @@ pub fn catalog_type(i: Input) -> IResult<CatalogType> {
     ))(i)
 }
 
+pub fn materialized_view_refresh_mode(i: Input) -> IResult<MaterializedViewRefreshMode> {
+    alt((
+        value(MaterializedViewRefreshMode::Manual, rule! { MANUAL }),
+        value(MaterializedViewRefreshMode::Auto, rule! { AUTO }),
+    ))(i)
+}
+
 pub fn user_option(i: Input) -> IResult<UserOptionItem> {
     let tenant_setting = value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING });
     let no_tenant_setting = value(
@@ -3721,6 +4244,7 @@ pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
             since_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
@@ -3740,6 +4264,7 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             since_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
@@ -320,6 +320,8 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ACCOUNT", ignore(ascii_case))]
     ACCOUNT,
     #[token("ALL", ignore(ascii_case))]
@@ -336,10 +338,14 @@ pub enum TokenKind {
     ANY,
     #[token("APPEND_ONLY", ignore(ascii_case))]
     APPEND_ONLY,
+    #[token("APPLY", ignore(ascii_case))]
+    APPLY,
     #[token("ARGS", ignore(ascii_case))]
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -366,6 +372,8 @@ pub enum TokenKind {
     ASYNC,
     #[token("ATTACH", ignore(ascii_case))]
     ATTACH,
+    #[token("BASELINE", ignore(ascii_case))]
+    BASELINE,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
     #[token("BETWEEN", ignore(ascii_case))]
@@ -388,6 +396,8 @@ pub enum TokenKind {
     BINARY_FORMAT,
     #[token("BITMAP", ignore(ascii_case))]
     BITMAP,
+    #[token("BLOCK", ignore(ascii_case))]
+    BLOCK,
     #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
     BLOCKED_IP_LIST,
     #[token("BOOL", ignore(ascii_case))]
@@ -396,6 +406,8 @@ pub enum TokenKind {
     BOOLEAN,
     #[token("BOTH", ignore(ascii_case))]
     BOTH,
+    #[token("BROADCAST", ignore(ascii_case))]
+    BROADCAST,
     #[token("BY", ignore(ascii_case))]
     BY,
     #[token("BROTLI", ignore(ascii_case))]
@@ -414,6 +426,8 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -426,10 +440,16 @@ pub enum TokenKind {
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
     CONTENT_TYPE,
     #[token("CONTINUE", ignore(ascii_case))]
     CONTINUE,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
+    #[token("CHANGE_FORMAT", ignore(ascii_case))]
+    CHANGE_FORMAT,
     #[token("CHAR", ignore(ascii_case))]
     CHAR,
     #[token("COLUMN", ignore(ascii_case))]
@@ -588,6 +608,8 @@ pub enum TokenKind {
     FOR,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
+    #[token("FOREIGN", ignore(ascii_case))]
+    FOREIGN,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
     #[token("FOLLOWING", ignore(ascii_case))]
@@ -624,6 +646,8 @@ pub enum TokenKind {
     GROUP,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
+    #[token("HASH_JOIN", ignore(ascii_case))]
+    HASH_JOIN,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
     #[token("HISTORY", ignore(ascii_case))]
@@ -640,10 +664,14 @@ pub enum TokenKind {
     INTERSECT,
     #[token("IDENTIFIED", ignore(ascii_case))]
     IDENTIFIED,
+    #[token("IDENTITY", ignore(ascii_case))]
+    IDENTITY,
     #[token("IF", ignore(ascii_case))]
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
     #[token("INNER", ignore(ascii_case))]
@@ -698,6 +726,8 @@ pub enum TokenKind {
     LOOP,
     #[token("SECONDARY", ignore(ascii_case))]
     SECONDARY,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
     /// L2DISTANCE op, from https://github.com/pgvector/pgvector
@@ -719,6 +749,8 @@ pub enum TokenKind {
     LZO,
     #[token("MASKING", ignore(ascii_case))]
     MASKING,
+    #[token("MANUAL", ignore(ascii_case))]
+    MANUAL,
     #[token("MAP", ignore(ascii_case))]
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
@@ -755,6 +787,8 @@ pub enum TokenKind {
     NDJSON,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
+    #[token("NO_MERGE", ignore(ascii_case))]
+    NO_MERGE,
     #[token("NONE", ignore(ascii_case))]
     NONE,
     #[token("NOT", ignore(ascii_case))]
@@ -825,6 +859,8 @@ pub enum TokenKind {
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
+    #[token("PLAN", ignore(ascii_case))]
+    PLAN,
     #[token("POLICIES", ignore(ascii_case))]
     POLICIES,
     #[token("POLICY", ignore(ascii_case))]
@@ -893,6 +929,8 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("QUALIFY", ignore(ascii_case))]
@@ -905,6 +943,8 @@ pub enum TokenKind {
     REVOKE,
     #[token("RECURSIVE", ignore(ascii_case))]
     RECURSIVE,
+    #[token("REFERENCES", ignore(ascii_case))]
+    REFERENCES,
     #[token("RETURN", ignore(ascii_case))]
     RETURN,
     #[token("RETURNS", ignore(ascii_case))]
@@ -929,6 +969,8 @@ pub enum TokenKind {
     SCHEMAS,
     #[token("SECOND", ignore(ascii_case))]
     SECOND,
+    #[token("SEED", ignore(ascii_case))]
+    SEED,
     #[token("SELECT", ignore(ascii_case))]
     SELECT,
     #[token("PIVOT", ignore(ascii_case))]
@@ -947,12 +989,16 @@ pub enum TokenKind {
     STAGES,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SUMMARY", ignore(ascii_case))]
     SUMMARY,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
     SHA256_PASSWORD,
     #[token("SHOW", ignore(ascii_case))]
     SHOW,
+    #[token("SHUFFLE", ignore(ascii_case))]
+    SHUFFLE,
     #[token("SINCE", ignore(ascii_case))]
     SINCE,
     #[token("SIGNED", ignore(ascii_case))]
@@ -1015,6 +1061,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TABLESAMPLE", ignore(ascii_case))]
+    TABLESAMPLE,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("LONGTEXT", ignore(ascii_case))]
@@ -1067,6 +1115,8 @@ pub enum TokenKind {
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
     UNION,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UINT16", ignore(ascii_case))]
     UINT16,
     #[token("UINT32", ignore(ascii_case))]
@@ -1213,6 +1263,10 @@ pub enum TokenKind {
     UNTIL,
     #[token("BEGIN", ignore(ascii_case))]
     BEGIN,
+    #[token("BACKUP", ignore(ascii_case))]
+    BACKUP,
+    #[token("RESTORE", ignore(ascii_case))]
+    RESTORE,
     #[token("TRANSACTION", ignore(ascii_case))]
     TRANSACTION,
     #[token("COMMIT", ignore(ascii_case))]
@@ -1540,7 +1594,7 @@ impl TokenKind {
             | TokenKind::SEMI
             // | TokenKind::SYMMETRIC
             // | TokenKind::TABLE
-            // | TokenKind::TABLESAMPLE
+            | TokenKind::TABLESAMPLE
             | TokenKind::THEN
             | TokenKind::TRAILING
             | TokenKind::TRANSACTION
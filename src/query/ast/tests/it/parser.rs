@@ -23,6 +23,7 @@ use databend_common_ast::parser::query::*;
 use databend_common_ast::parser::quote::quote_ident;
 use databend_common_ast::parser::quote::unquote_ident;
 use databend_common_ast::parser::script::script_stmt;
+use databend_common_ast::parser::statement::hint;
 use databend_common_ast::parser::statement::insert_stmt;
 use databend_common_ast::parser::token::*;
 use databend_common_ast::parser::tokenize_sql;
@@ -760,6 +761,40 @@ fn test_raw_insert_stmt() {
     }
 }
 
+#[test]
+fn test_hint() {
+    let mut mint = Mint::new("tests/it/testdata");
+    let file = &mut mint.new_goldenfile("hint.txt").unwrap();
+    let cases = &[
+        r#"/*+ LEADING(t1 t2 t3) */"#,
+        r#"/*+ BROADCAST(t1) SHUFFLE(t2 t3) */"#,
+        r#"/*+ hash_join(t1) no_merge */"#,
+        r#"/*+ SET_VAR(timezone='Asia/Shanghai') LEADING(b a) */"#,
+        r#"/*+ LEADING() */"#,
+    ];
+
+    for case in cases {
+        run_parser(file, hint, case);
+    }
+}
+
+#[test]
+fn test_hint_round_trip() {
+    let cases = &[
+        r#"select /*+ LEADING(b a) BROADCAST(b) */ * from a join b on a.id = b.id"#,
+        r#"select /*+ SET_VAR(max_threads=1) SHUFFLE(b) HASH_JOIN(b) NO_MERGE */ * from a, b where a.id = b.id"#,
+    ];
+
+    for case in cases {
+        let tokens = tokenize_sql(case).unwrap();
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL).unwrap();
+        let sql = stmt.to_string();
+        let tokens = tokenize_sql(&sql).unwrap();
+        let (round_trip, _) = parse_sql(&tokens, Dialect::PostgreSQL).unwrap();
+        assert_eq!(round_trip.to_string(), sql);
+    }
+}
+
 #[test]
 fn test_query() {
     let mut mint = Mint::new("tests/it/testdata");
@@ -816,6 +851,8 @@ fn test_query() {
         r#"VALUES(1,'a'),(2,'b'),(null,'c') order by col0 limit 2"#,
         r#"select * from t left join lateral(select 1) on true, lateral(select 2)"#,
         r#"select * from t, lateral flatten(input => u.col) f"#,
+        r#"select * from t tablesample block (1)"#,
+        r#"select * from t tablesample row (0.1) seed 42"#,
     ];
 
     for case in cases {
@@ -35,6 +35,7 @@ use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::ListCatalogReq;
 use databend_common_meta_store::MetaStore;
 use databend_common_meta_store::MetaStoreProvider;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 
 use super::Catalog;
@@ -128,10 +129,15 @@ impl CatalogManager {
     ///
     /// There are some place that we don't have async context, so we provide
     /// `get_default_catalog` to allow users fetch default catalog without async.
-    pub fn get_default_catalog(&self, txn_mgr: TxnManagerRef) -> Result<Arc<dyn Catalog>> {
+    pub fn get_default_catalog(
+        &self,
+        txn_mgr: TxnManagerRef,
+        temp_tbl_mgr: TempTblMgrRef,
+    ) -> Result<Arc<dyn Catalog>> {
         Ok(Arc::new(SessionCatalog::create(
             self.default_catalog.clone(),
             txn_mgr,
+            temp_tbl_mgr,
         )))
     }
 
@@ -163,9 +169,10 @@ impl CatalogManager {
         tenant: &str,
         catalog_name: &str,
         txn_mgr: TxnManagerRef,
+        temp_tbl_mgr: TempTblMgrRef,
     ) -> Result<Arc<dyn Catalog>> {
         if catalog_name == CATALOG_DEFAULT {
-            return self.get_default_catalog(txn_mgr);
+            return self.get_default_catalog(txn_mgr, temp_tbl_mgr);
         }
 
         if let Some(ctl) = self.external_catalogs.get(catalog_name) {
@@ -236,8 +243,9 @@ impl CatalogManager {
         &self,
         tenant: &str,
         txn_mgr: TxnManagerRef,
+        temp_tbl_mgr: TempTblMgrRef,
     ) -> Result<Vec<Arc<dyn Catalog>>> {
-        let mut catalogs = vec![self.get_default_catalog(txn_mgr)?];
+        let mut catalogs = vec![self.get_default_catalog(txn_mgr, temp_tbl_mgr)?];
 
         // insert external catalogs.
         for ctl in self.external_catalogs.values() {
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CountTablesReply;
@@ -26,6 +27,7 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableIndexReply;
 use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReply;
@@ -86,6 +88,9 @@ use databend_common_meta_app::schema::UpsertTableOptionReply;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_app::schema::VirtualColumnMeta;
 use databend_common_meta_types::MetaId;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_txn::TempTblMgr;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 use databend_storages_common_txn::TxnState;
 
@@ -100,11 +105,79 @@ use crate::table_function::TableFunction;
 pub struct SessionCatalog {
     inner: Arc<dyn Catalog>,
     txn_mgr: TxnManagerRef,
+    temp_tbl_mgr: TempTblMgrRef,
 }
 
 impl SessionCatalog {
-    pub fn create(inner: Arc<dyn Catalog>, txn_mgr: TxnManagerRef) -> Self {
-        SessionCatalog { inner, txn_mgr }
+    pub fn create(
+        inner: Arc<dyn Catalog>,
+        txn_mgr: TxnManagerRef,
+        temp_tbl_mgr: TempTblMgrRef,
+    ) -> Self {
+        SessionCatalog {
+            inner,
+            txn_mgr,
+            temp_tbl_mgr,
+        }
+    }
+
+    /// Temporary tables are kept in the session, they are never written to the meta service.
+    async fn create_temp_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        // Make sure the database exists.
+        self.inner.get_database(req.tenant(), req.db_name()).await?;
+
+        let db_name = req.db_name().to_string();
+        let table_name = req.table_name().to_string();
+        if self
+            .temp_tbl_mgr
+            .lock()
+            .get_table(&db_name, &table_name)
+            .is_some()
+        {
+            match req.create_option {
+                CreateOption::Create => {
+                    return Err(ErrorCode::TableAlreadyExists(format!(
+                        "Temporary table '{}' already exists",
+                        table_name
+                    )));
+                }
+                CreateOption::CreateIfNotExists => {
+                    return Ok(CreateTableReply {
+                        table_id: 0,
+                        new_table: false,
+                        spec_vec: None,
+                    });
+                }
+                CreateOption::CreateOrReplace => {}
+            }
+        }
+
+        let table_id = TempTblMgr::next_table_id();
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", db_name, table_name),
+            name: table_name,
+            meta: req.table_meta,
+            tenant: req.name_ident.tenant,
+            ..Default::default()
+        };
+        self.temp_tbl_mgr.lock().add_table(&db_name, table_info);
+        Ok(CreateTableReply {
+            table_id,
+            new_table: true,
+            spec_vec: None,
+        })
+    }
+
+    /// Returns the temporary table, the version mutated by the active transaction is preferred.
+    fn temp_table_in_txn(&self, table_info: TableInfo) -> TableInfo {
+        let txn_mgr = self.txn_mgr.lock();
+        if txn_mgr.is_active() {
+            if let Some(t) = txn_mgr.get_table_from_buffer_by_id(table_info.ident.table_id) {
+                return t;
+            }
+        }
+        table_info
     }
 }
 
@@ -218,6 +291,11 @@ impl Catalog for SessionCatalog {
 
     // Get the table meta by meta id.
     async fn get_table_meta_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
+        let temp_table = self.temp_tbl_mgr.lock().get_table_by_id(table_id);
+        if let Some(t) = temp_table {
+            let t = self.temp_table_in_txn(t);
+            return Ok((t.ident, Arc::new(t.meta)));
+        }
         let state = self.txn_mgr.lock().state();
         match state {
             TxnState::Active => {
@@ -234,6 +312,9 @@ impl Catalog for SessionCatalog {
 
     // Get the table name by meta id.
     async fn get_table_name_by_id(&self, table_id: MetaId) -> Result<String> {
+        if let Some(t) = self.temp_tbl_mgr.lock().get_table_by_id(table_id) {
+            return Ok(t.name);
+        }
         let state = self.txn_mgr.lock().state();
         match state {
             TxnState::Active => {
@@ -276,6 +357,12 @@ impl Catalog for SessionCatalog {
         db_name: &str,
         table_name: &str,
     ) -> Result<Arc<dyn Table>> {
+        // Temporary tables shadow the tables with the same name.
+        let temp_table = self.temp_tbl_mgr.lock().get_table(db_name, table_name);
+        if let Some(table_info) = temp_table {
+            return self.get_table_by_info(&self.temp_table_in_txn(table_info));
+        }
+
         let state = self.txn_mgr.lock().state();
         match state {
             TxnState::Active => {
@@ -301,7 +388,17 @@ impl Catalog for SessionCatalog {
     }
 
     async fn list_tables(&self, tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
-        self.inner.list_tables(tenant, db_name).await
+        let temp_tables = self.temp_tbl_mgr.lock().list_tables(db_name);
+        let mut tables = Vec::with_capacity(temp_tables.len());
+        for table_info in temp_tables.iter() {
+            tables.push(self.get_table_by_info(&self.temp_table_in_txn(table_info.clone()))?);
+        }
+        for table in self.inner.list_tables(tenant, db_name).await? {
+            if !temp_tables.iter().any(|t| t.name == table.name()) {
+                tables.push(table);
+            }
+        }
+        Ok(tables)
     }
     async fn list_tables_history(
         &self,
@@ -323,10 +420,23 @@ impl Catalog for SessionCatalog {
     }
 
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        if req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) {
+            return self.create_temp_table(req).await;
+        }
         self.inner.create_table(req).await
     }
 
     async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply> {
+        if TempTblMgr::is_temp_table_id(req.tb_id) {
+            let dropped = self.temp_tbl_mgr.lock().drop_table_by_id(req.tb_id);
+            if dropped.is_none() && !req.if_exists {
+                return Err(ErrorCode::UnknownTable(format!(
+                    "Unknown temporary table '{}'",
+                    req.table_name
+                )));
+            }
+            return Ok(DropTableReply { spec_vec: None });
+        }
         self.inner.drop_table_by_id(req).await
     }
 
@@ -335,6 +445,29 @@ impl Catalog for SessionCatalog {
     }
 
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        let db_name = req.db_name();
+        let table_name = req.table_name();
+        let temp_table = self.temp_tbl_mgr.lock().get_table(db_name, table_name);
+        if let Some(table_info) = temp_table {
+            // Make sure the target database exists.
+            self.inner
+                .get_database(req.tenant(), &req.new_db_name)
+                .await?;
+            if !self.temp_tbl_mgr.lock().rename_table(
+                db_name,
+                table_name,
+                &req.new_db_name,
+                &req.new_table_name,
+            ) {
+                return Err(ErrorCode::TableAlreadyExists(format!(
+                    "Temporary table '{}' already exists",
+                    req.new_table_name
+                )));
+            }
+            return Ok(RenameTableReply {
+                table_id: table_info.ident.table_id,
+            });
+        }
         self.inner.rename_table(req).await
     }
 
@@ -344,6 +477,11 @@ impl Catalog for SessionCatalog {
         db_name: &str,
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply> {
+        if TempTblMgr::is_temp_table_id(req.table_id) {
+            return Err(ErrorCode::Unimplemented(
+                "Alter table options of temporary table is not supported",
+            ));
+        }
         self.inner.upsert_table_option(tenant, db_name, req).await
     }
 
@@ -352,6 +490,40 @@ impl Catalog for SessionCatalog {
         table_info: &TableInfo,
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply> {
+        // Temporary tables are private to the session, they are updated in place unless a
+        // transaction is active. The copied files of temporary tables are not recorded.
+        if TempTblMgr::is_temp_table_id(req.table_id) {
+            if self.txn_mgr.lock().is_active() {
+                let req = UpdateTableMetaReq {
+                    copied_files: None,
+                    ..req
+                };
+                self.txn_mgr.lock().update_table_meta(req, table_info);
+                return Ok(UpdateTableMetaReply {
+                    share_table_info: None,
+                });
+            }
+            if !self.temp_tbl_mgr.lock().update_table_meta(&req) {
+                return Err(ErrorCode::TableVersionMismatched(format!(
+                    "Table version mismatched of temporary table '{}'",
+                    table_info.name
+                )));
+            }
+            if !req.update_stream_meta.is_empty() {
+                self.inner
+                    .update_multi_table_meta(UpdateMultiTableMetaReq {
+                        update_table_metas: vec![],
+                        copied_files: vec![],
+                        update_stream_metas: req.update_stream_meta,
+                        deduplicated_labels: req.deduplicated_label.into_iter().collect(),
+                    })
+                    .await?;
+            }
+            return Ok(UpdateTableMetaReply {
+                share_table_info: None,
+            });
+        }
+
         let state = self.txn_mgr.lock().state();
         match state {
             TxnState::AutoCommit => self.inner.update_table_meta(table_info, req).await,
@@ -365,8 +537,45 @@ impl Catalog for SessionCatalog {
         }
     }
 
-    async fn update_multi_table_meta(&self, req: UpdateMultiTableMetaReq) -> Result<()> {
-        self.inner.update_multi_table_meta(req).await
+    async fn update_multi_table_meta(&self, mut req: UpdateMultiTableMetaReq) -> Result<()> {
+        // The temporary tables mutated by a transaction are committed to the session,
+        // after the other tables are committed to the meta service.
+        let (temp_table_metas, update_table_metas): (Vec<_>, Vec<_>) = req
+            .update_table_metas
+            .into_iter()
+            .partition(|r| TempTblMgr::is_temp_table_id(r.table_id));
+        req.update_table_metas = update_table_metas;
+        if temp_table_metas.is_empty() {
+            return self.inner.update_multi_table_meta(req).await;
+        }
+
+        req.copied_files
+            .retain(|(table_id, _)| !TempTblMgr::is_temp_table_id(*table_id));
+        {
+            let temp_tbl_mgr = self.temp_tbl_mgr.lock();
+            if let Some(r) = temp_table_metas
+                .iter()
+                .find(|r| !temp_tbl_mgr.check_table_meta(r))
+            {
+                return Err(ErrorCode::TableVersionMismatched(format!(
+                    "Table version mismatched of temporary table with id {}",
+                    r.table_id
+                )));
+            }
+        }
+        if !req.update_table_metas.is_empty()
+            || !req.copied_files.is_empty()
+            || !req.update_stream_metas.is_empty()
+            || !req.deduplicated_labels.is_empty()
+        {
+            self.inner.update_multi_table_meta(req).await?;
+        }
+
+        let mut temp_tbl_mgr = self.temp_tbl_mgr.lock();
+        for r in temp_table_metas.iter() {
+            temp_tbl_mgr.update_table_meta(r);
+        }
+        Ok(())
     }
 
     async fn set_table_column_mask_policy(
@@ -395,6 +604,11 @@ impl Catalog for SessionCatalog {
         req: GetTableCopiedFileReq,
     ) -> Result<GetTableCopiedFileReply> {
         let table_id = req.table_id;
+        if TempTblMgr::is_temp_table_id(table_id) {
+            return Ok(GetTableCopiedFileReply {
+                file_info: Default::default(),
+            });
+        }
         let mut reply = self
             .inner
             .get_table_copied_file_info(tenant, db_name, req)
@@ -410,6 +624,9 @@ impl Catalog for SessionCatalog {
         table_info: &TableInfo,
         req: TruncateTableReq,
     ) -> Result<TruncateTableReply> {
+        if TempTblMgr::is_temp_table_id(req.table_id) {
+            return Ok(TruncateTableReply {});
+        }
         self.inner.truncate_table(table_info, req).await
     }
 
@@ -14,6 +14,8 @@
 
 use std::path::Path;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::string::StringColumnBuilder;
@@ -35,6 +37,7 @@ use databend_common_expression::Value;
 use databend_common_expression::BASE_BLOCK_IDS_COLUMN_ID;
 use databend_common_expression::BASE_ROW_ID_COLUMN_ID;
 use databend_common_expression::BLOCK_NAME_COLUMN_ID;
+use databend_common_expression::BLOCK_TIMESTAMP_COLUMN_ID;
 use databend_common_expression::ROW_ID_COLUMN_ID;
 use databend_common_expression::SEGMENT_NAME_COLUMN_ID;
 use databend_common_expression::SNAPSHOT_NAME_COLUMN_ID;
@@ -95,6 +98,8 @@ pub struct InternalColumnMeta {
     pub block_location: String,
     pub segment_location: String,
     pub snapshot_location: Option<String>,
+    /// The time that the block was written, unknown for the blocks of old versions.
+    pub create_on: Option<DateTime<Utc>>,
     /// The row offsets in the block.
     pub offsets: Option<Vec<usize>>,
     pub base_block_ids: Option<Scalar>,
@@ -124,6 +129,7 @@ impl InternalColumnMeta {
 pub enum InternalColumnType {
     RowId,
     BlockName,
+    BlockTimestamp,
     SegmentName,
     SnapshotName,
 
@@ -154,6 +160,9 @@ impl InternalColumn {
         match &self.column_type {
             InternalColumnType::RowId => TableDataType::Number(NumberDataType::UInt64),
             InternalColumnType::BlockName => TableDataType::String,
+            InternalColumnType::BlockTimestamp => {
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp))
+            }
             InternalColumnType::SegmentName => TableDataType::String,
             InternalColumnType::SnapshotName => TableDataType::String,
             InternalColumnType::BaseRowId => TableDataType::String,
@@ -179,6 +188,7 @@ impl InternalColumn {
         match &self.column_type {
             InternalColumnType::RowId => ROW_ID_COLUMN_ID,
             InternalColumnType::BlockName => BLOCK_NAME_COLUMN_ID,
+            InternalColumnType::BlockTimestamp => BLOCK_TIMESTAMP_COLUMN_ID,
             InternalColumnType::SegmentName => SEGMENT_NAME_COLUMN_ID,
             InternalColumnType::SnapshotName => SNAPSHOT_NAME_COLUMN_ID,
             InternalColumnType::BaseRowId => BASE_ROW_ID_COLUMN_ID,
@@ -219,6 +229,14 @@ impl InternalColumn {
                     Value::Scalar(Scalar::String(builder.build_scalar())),
                 )
             }
+            InternalColumnType::BlockTimestamp => BlockEntry::new(
+                DataType::Nullable(Box::new(DataType::Timestamp)),
+                Value::Scalar(
+                    meta.create_on
+                        .map(|create_on| Scalar::Timestamp(create_on.timestamp_micros()))
+                        .unwrap_or(Scalar::Null),
+                ),
+            ),
             InternalColumnType::SegmentName => {
                 let mut builder =
                     StringColumnBuilder::with_capacity(1, meta.segment_location.len());
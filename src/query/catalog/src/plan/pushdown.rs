@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::F64;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
@@ -96,6 +97,41 @@ pub struct PushDownInfo {
     pub agg_index: Option<AggIndexInfo>,
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
+    /// Optional sample of the table, `TABLESAMPLE` in SQL.
+    pub sample: Option<TableSample>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableSampleLevel {
+    /// Samples whole blocks, the blocks not sampled are pruned before being read.
+    Block,
+    /// Samples every row independently.
+    Row,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableSample {
+    pub level: TableSampleLevel,
+    /// The probability of a block or a row to be sampled, from 0 to 1.
+    pub probability: F64,
+    /// Makes the sample repeatable, the blocks or rows are sampled randomly if `None`.
+    pub seed: Option<u64>,
+}
+
+impl TableSample {
+    pub fn block_sample(push_downs: &Option<PushDownInfo>) -> Option<&TableSample> {
+        push_downs
+            .as_ref()
+            .and_then(|p| p.sample.as_ref())
+            .filter(|s| s.level == TableSampleLevel::Block)
+    }
+
+    pub fn row_sample(push_downs: &Option<PushDownInfo>) -> Option<&TableSample> {
+        push_downs
+            .as_ref()
+            .and_then(|p| p.sample.as_ref())
+            .filter(|s| s.level == TableSampleLevel::Row)
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -34,6 +34,7 @@ use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::StorageMetrics;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::ChangeType;
@@ -139,6 +140,37 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// The version of the data of an external table, e.g. the snapshot id of an Iceberg table.
+    /// Streams on the table record it to read the changes made since then.
+    #[async_backtrace::framed]
+    async fn changes_version(&self) -> Result<String> {
+        Err(ErrorCode::UnsupportedEngineParams(format!(
+            "Stream is not supported for the '{}' engine.",
+            self.engine()
+        )))
+    }
+
+    /// Gather partitions of the data files that were added since `base_version`,
+    /// or the removed ones if the change type is `ChangeType::Delete`.
+    ///
+    /// The changes are tracked by data file, a rewrite of the data files such as a compaction
+    /// shows up as the deletion of the old rows and the insertion of the same rows.
+    #[async_backtrace::framed]
+    async fn read_changes_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base_version: &str,
+        change_type: ChangeType,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let (_, _, _, _) = (ctx, base_version, change_type, push_downs);
+
+        Err(ErrorCode::UnsupportedEngineParams(format!(
+            "Stream is not supported for the '{}' engine.",
+            self.engine()
+        )))
+    }
+
     /// Whether the table engine supports prewhere optimization.
     /// only Fuse Engine supports this.
     fn support_prewhere(&self) -> bool {
@@ -496,6 +528,11 @@ pub trait ColumnStatisticsProvider: Send {
 
     // returns the num rows of the table, if any.
     fn num_rows(&self) -> Option<u64>;
+
+    // returns the histogram of the given column collected by analyzing the table, if any.
+    fn histogram(&self, _column_id: ColumnId) -> Option<&ColumnHistogram> {
+        None
+    }
 }
 
 pub struct DummyColumnStatisticsProvider;
@@ -48,6 +48,7 @@ use databend_common_storage::StageFileInfo;
 use databend_common_storage::StorageMetrics;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 use parking_lot::RwLock;
 use xorf::BinaryFuse16;
@@ -262,4 +263,5 @@ pub trait TableContext: Send + Sync {
 
     fn has_bloom_runtime_filters(&self, id: usize) -> bool;
     fn txn_mgr(&self) -> TxnManagerRef;
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef;
 }
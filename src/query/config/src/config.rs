@@ -1643,6 +1643,18 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "gpt-3.5-turbo")]
     pub openai_api_completion_model: String,
 
+    /// The embedding provider: `openai` (or an OpenAI-compatible server) or `onnx` (in-process).
+    #[clap(long, value_name = "VALUE", default_value = "openai")]
+    pub ai_embedding_provider: String,
+
+    /// The directory of the ONNX models, each model is a sub directory with `model.onnx` and `tokenizer.json`.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub ai_onnx_models_dir: String,
+
+    /// The ONNX model used by the `onnx` embedding provider.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub ai_onnx_embedding_model: String,
+
     #[clap(long, value_name = "VALUE", default_value = "false")]
     pub enable_udf_server: bool,
 
@@ -1735,6 +1747,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             openai_api_completion_model: self.openai_api_completion_model,
             openai_api_embedding_model: self.openai_api_embedding_model,
             openai_api_version: self.openai_api_version,
+            ai_embedding_provider: self.ai_embedding_provider,
+            ai_onnx_models_dir: self.ai_onnx_models_dir,
+            ai_onnx_embedding_model: self.ai_onnx_embedding_model,
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
@@ -1832,6 +1847,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             openai_api_version: inner.openai_api_version,
             openai_api_completion_model: inner.openai_api_completion_model,
             openai_api_embedding_model: inner.openai_api_embedding_model,
+            ai_embedding_provider: inner.ai_embedding_provider,
+            ai_onnx_models_dir: inner.ai_onnx_models_dir,
+            ai_onnx_embedding_model: inner.ai_onnx_embedding_model,
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
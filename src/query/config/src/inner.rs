@@ -226,6 +226,11 @@ pub struct QueryConfig {
     pub openai_api_embedding_model: String,
     pub openai_api_completion_model: String,
 
+    /// `openai` or `onnx`
+    pub ai_embedding_provider: String,
+    pub ai_onnx_models_dir: String,
+    pub ai_onnx_embedding_model: String,
+
     pub enable_udf_server: bool,
     pub udf_server_allow_list: Vec<String>,
 
@@ -297,6 +302,9 @@ impl Default for QueryConfig {
             openai_api_version: "".to_string(),
             openai_api_completion_model: "gpt-3.5-turbo".to_string(),
             openai_api_embedding_model: "text-embedding-ada-002".to_string(),
+            ai_embedding_provider: "openai".to_string(),
+            ai_onnx_models_dir: "".to_string(),
+            ai_onnx_embedding_model: "".to_string(),
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
             cloud_control_grpc_server_address: None,
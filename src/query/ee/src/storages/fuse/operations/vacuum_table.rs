@@ -129,10 +129,23 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    let mut blocks = locations_referenced.block_location;
+    let mut blocks_index = locations_referenced.bloom_location;
+
+    // A clone only owns the files under its own prefix, and the files shared with the
+    // clones of the table are still referenced.
+    let shared_locations = fuse_table.get_shared_locations(ctx, &segments).await?;
+    shared_locations.retain_owned(&mut segments);
+    shared_locations.retain_owned(&mut blocks);
+    shared_locations.retain_owned(&mut blocks_index);
+    segments.extend(shared_locations.segments);
+    blocks.extend(shared_locations.blocks);
+    blocks_index.extend(shared_locations.blooms);
+
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
     }))
 }
 
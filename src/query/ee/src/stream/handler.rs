@@ -43,9 +43,14 @@ use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_VERSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_DATABASE;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_QUERY;
 
 pub struct RealStreamHandler {}
 
@@ -70,11 +75,20 @@ impl StreamHandler for RealStreamHandler {
                 plan.table_database, plan.table_name
             )));
         }
+        if table_info.options().contains_key(OPT_KEY_TEMP_PREFIX) {
+            return Err(ErrorCode::IllegalStream(format!(
+                "The table '{}.{}' is temporary, can't create stream",
+                plan.table_database, plan.table_name
+            )));
+        }
 
         let table_version = table_info.ident.seq;
         let table_id = table_info.ident.table_id;
         let schema = table_info.schema().clone();
-        if !table.change_tracking_enabled() {
+        // The changes of external tables, e.g. Iceberg and Delta tables, are tracked
+        // by their own snapshots, the others must be fuse tables.
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).ok();
+        if fuse_table.is_some() && !table.change_tracking_enabled() {
             // enable change tracking.
             let req = UpsertTableOptionReq {
                 table_id,
@@ -116,6 +130,10 @@ impl StreamHandler for RealStreamHandler {
                     )));
                 }
                 options = stream.get_table_info().options().clone();
+                // The view of the new stream is set below, which may differ from the given one.
+                for key in [OPT_KEY_VIEW_DATABASE, OPT_KEY_VIEW_NAME, OPT_KEY_VIEW_QUERY] {
+                    options.remove(key);
+                }
                 let stream_mode = if plan.append_only {
                     MODE_APPEND_ONLY
                 } else {
@@ -137,13 +155,23 @@ impl StreamHandler for RealStreamHandler {
                 );
                 options.insert(OPT_KEY_TABLE_ID.to_string(), table_id.to_string());
                 options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
-                let fuse_table = FuseTable::try_from_table(table.as_ref())?;
-                if let Some(snapshot_loc) = fuse_table.snapshot_loc().await? {
-                    options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
+                if let Some(fuse_table) = fuse_table {
+                    if let Some(snapshot_loc) = fuse_table.snapshot_loc().await? {
+                        options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
+                    }
+                } else {
+                    let source_version = table.changes_version().await?;
+                    options.insert(OPT_KEY_SOURCE_VERSION.to_string(), source_version);
                 }
             }
         }
 
+        if let Some(view) = &plan.view {
+            options.insert(OPT_KEY_VIEW_DATABASE.to_string(), view.database.clone());
+            options.insert(OPT_KEY_VIEW_NAME.to_string(), view.name.clone());
+            options.insert(OPT_KEY_VIEW_QUERY.to_string(), view.query.clone());
+        }
+
         let req = CreateTableReq {
             create_option: plan.create_option,
             name_ident: TableNameIdent {
@@ -103,6 +103,13 @@ pub struct FunctionContext {
     pub openai_api_version: String,
     pub openai_api_embedding_model: String,
     pub openai_api_completion_model: String,
+    pub openai_api_embedding_batch_size: u64,
+    /// `openai` (or an OpenAI-compatible server) or `onnx`.
+    pub ai_embedding_provider: String,
+    pub ai_onnx_embedding_model_path: String,
+    /// The provider (e.g. the connection of the `ai_connection` setting) can't be used,
+    /// the AI functions fail with this error instead of falling back to the config.
+    pub ai_provider_error: String,
 
     pub external_server_connect_timeout_secs: u64,
     pub external_server_request_timeout_secs: u64,
@@ -41,6 +41,7 @@ pub const ROW_ID_COLUMN_ID: u32 = u32::MAX;
 pub const BLOCK_NAME_COLUMN_ID: u32 = u32::MAX - 1;
 pub const SEGMENT_NAME_COLUMN_ID: u32 = u32::MAX - 2;
 pub const SNAPSHOT_NAME_COLUMN_ID: u32 = u32::MAX - 3;
+pub const BLOCK_TIMESTAMP_COLUMN_ID: u32 = u32::MAX - 4;
 // internal stream column id.
 pub const BASE_ROW_ID_COLUMN_ID: u32 = u32::MAX - 5;
 pub const BASE_BLOCK_IDS_COLUMN_ID: u32 = u32::MAX - 6;
@@ -50,12 +51,15 @@ pub const ROW_ID_COL_NAME: &str = "_row_id";
 pub const SNAPSHOT_NAME_COL_NAME: &str = "_snapshot_name";
 pub const SEGMENT_NAME_COL_NAME: &str = "_segment_name";
 pub const BLOCK_NAME_COL_NAME: &str = "_block_name";
+pub const BLOCK_TIMESTAMP_COL_NAME: &str = "_block_timestamp";
 // internal stream column name.
 pub const BASE_ROW_ID_COL_NAME: &str = "_base_row_id";
 pub const BASE_BLOCK_IDS_COL_NAME: &str = "_base_block_ids";
 pub const CHANGE_ACTION_COL_NAME: &str = "change$action";
 pub const CHANGE_IS_UPDATE_COL_NAME: &str = "change$is_update";
 pub const CHANGE_ROW_ID_COL_NAME: &str = "change$row_id";
+// the time that the change was written, it is not visible in `SELECT *` of a stream.
+pub const CHANGE_TIMESTAMP_COL_NAME: &str = "change$timestamp";
 
 pub const ROW_NUMBER_COL_NAME: &str = "_row_number";
 pub const PREDICATE_COLUMN_NAME: &str = "_predicate";
@@ -84,12 +88,14 @@ pub fn is_internal_column(column_name: &str) -> bool {
             | SNAPSHOT_NAME_COL_NAME
             | SEGMENT_NAME_COL_NAME
             | BLOCK_NAME_COL_NAME
+            | BLOCK_TIMESTAMP_COL_NAME
             | BASE_BLOCK_IDS_COL_NAME
             | ROW_NUMBER_COL_NAME
             | PREDICATE_COLUMN_NAME
             | CHANGE_ACTION_COL_NAME
             | CHANGE_IS_UPDATE_COL_NAME
             | CHANGE_ROW_ID_COL_NAME
+            | CHANGE_TIMESTAMP_COL_NAME
             // change$row_id might be expended
             // to the computation of the two following internal columns
             | ORIGIN_BLOCK_ROW_NUM_COL_NAME
@@ -0,0 +1,388 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::*;
+use databend_common_expression::types::*;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+const DEFAULT_K: u64 = 10;
+const MAX_K: u64 = 10000;
+/// The sketch keeps `k * CAPACITY_FACTOR` counters to make the top k more accurate.
+const CAPACITY_FACTOR: u64 = 3;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+struct Counter<K> {
+    key: K,
+    count: u64,
+    /// The upper bound of the over-estimation of `count`.
+    error: u64,
+}
+
+/// A mergeable Space-Saving sketch.
+///
+/// Instead of replacing the minimum counter for every new key, the sketch lets
+/// the counters grow up to twice its capacity and then evicts the smallest ones
+/// in a batch. `floor` is the largest count ever evicted, any key absent from the
+/// sketch could have been seen at most `floor` times, so it's used as the initial
+/// count (and error) of new keys, which keeps the Space-Saving guarantees:
+/// `count - error <= true count <= count`.
+///
+/// Merging follows "Mergeable Summaries" (Agarwal et al.): the counts of a key
+/// absent from one side are taken as the floor of that side.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SpaceSaving<K>
+where K: Hash + Eq
+{
+    capacity: u64,
+    floor: u64,
+    counters: Vec<Counter<K>>,
+    #[borsh(skip)]
+    index: HashMap<K, usize>,
+}
+
+impl<K> SpaceSaving<K>
+where K: Hash + Eq + Clone + Ord
+{
+    pub fn new(capacity: u64) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            floor: 0,
+            counters: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, key: K) {
+        match self.index.get(&key) {
+            Some(pos) => self.counters[*pos].count += 1,
+            None => {
+                self.index.insert(key.clone(), self.counters.len());
+                self.counters.push(Counter {
+                    key,
+                    count: self.floor + 1,
+                    error: self.floor,
+                });
+                if self.counters.len() as u64 >= self.capacity * 2 {
+                    self.compact();
+                }
+            }
+        }
+    }
+
+    pub fn merge(&mut self, rhs: &Self) {
+        if rhs.counters.is_empty() {
+            return;
+        }
+        // The index is not serialized, rebuild it if the state is deserialized.
+        if self.index.len() != self.counters.len() {
+            self.rebuild_index();
+        }
+
+        let mut matched = vec![false; self.counters.len()];
+        for counter in &rhs.counters {
+            match self.index.get(&counter.key) {
+                Some(pos) => {
+                    self.counters[*pos].count += counter.count;
+                    self.counters[*pos].error += counter.error;
+                    matched[*pos] = true;
+                }
+                None => {
+                    self.index.insert(counter.key.clone(), self.counters.len());
+                    self.counters.push(Counter {
+                        key: counter.key.clone(),
+                        count: counter.count + self.floor,
+                        error: counter.error + self.floor,
+                    });
+                }
+            }
+        }
+        // The keys only in the left side could have been seen `rhs.floor` times in the right side.
+        for (counter, matched) in self.counters.iter_mut().zip(matched) {
+            if !matched {
+                counter.count += rhs.floor;
+                counter.error += rhs.floor;
+            }
+        }
+        self.floor += rhs.floor;
+
+        if self.counters.len() as u64 > self.capacity {
+            self.compact();
+        }
+    }
+
+    /// Returns the `k` most frequent keys with their estimated counts, in descending order of the counts.
+    /// The ties are broken by the keys, so the result doesn't depend on the order of the rows.
+    pub fn top_k(&mut self, k: usize) -> Vec<(K, u64)> {
+        self.sort();
+        self.counters
+            .iter()
+            .take(k)
+            .map(|counter| (counter.key.clone(), counter.count))
+            .collect()
+    }
+
+    fn sort(&mut self) {
+        // Sort by the guaranteed count if the estimated counts are equal, then by the key.
+        self.counters.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.error.cmp(&b.error))
+                .then_with(|| a.key.cmp(&b.key))
+        });
+    }
+
+    fn compact(&mut self) {
+        self.sort();
+        if self.counters.len() as u64 > self.capacity {
+            let evicted = self.counters.drain(self.capacity as usize..);
+            let evicted_max = evicted.map(|counter| counter.count).max().unwrap_or(0);
+            self.floor = self.floor.max(evicted_max);
+        }
+        self.rebuild_index();
+    }
+
+    fn rebuild_index(&mut self) {
+        self.index.clear();
+        for (pos, counter) in self.counters.iter().enumerate() {
+            self.index.insert(counter.key.clone(), pos);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateApproxTopKFunction<T> {
+    display_name: String,
+    return_type: DataType,
+    k: u64,
+    _t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateApproxTopKFunction<T>
+where
+    T: ValueType + Send + Sync,
+    T::Scalar: Hash + Eq + Clone + Ord + BorshSerialize + BorshDeserialize + Send + Sync,
+{
+    fn name(&self) -> &str {
+        "AggregateApproxTopKFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| SpaceSaving::<T::Scalar>::new(self.k * CAPACITY_FACTOR));
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<SpaceSaving<T::Scalar>>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = T::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<SpaceSaving<T::Scalar>>();
+        match validity {
+            Some(bitmap) => {
+                for (value, valid) in T::iter_column(&column).zip(bitmap.iter()) {
+                    if valid {
+                        state.add(T::to_owned_scalar(value));
+                    }
+                }
+            }
+            None => {
+                for value in T::iter_column(&column) {
+                    state.add(T::to_owned_scalar(value));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = T::try_downcast_column(&columns[0]).unwrap();
+        for (value, place) in T::iter_column(&column).zip(places.iter()) {
+            let state = place.next(offset).get::<SpaceSaving<T::Scalar>>();
+            state.add(T::to_owned_scalar(value));
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = T::try_downcast_column(&columns[0]).unwrap();
+        let value = unsafe { T::index_column_unchecked(&column, row) };
+        let state = place.get::<SpaceSaving<T::Scalar>>();
+        state.add(T::to_owned_scalar(value));
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<SpaceSaving<T::Scalar>>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<SpaceSaving<T::Scalar>>();
+        let rhs: SpaceSaving<T::Scalar> = borsh_deserialize_state(reader)?;
+        state.merge(&rhs);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<SpaceSaving<T::Scalar>>();
+        let other = rhs.get::<SpaceSaving<T::Scalar>>();
+        state.merge(other);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<SpaceSaving<T::Scalar>>();
+        let top_k = state.top_k(self.k as usize);
+
+        let inner_type = self.return_type.as_array().unwrap();
+        let mut inner_builder = ColumnBuilder::with_capacity(inner_type, top_k.len());
+        for (key, count) in top_k {
+            let key = T::upcast_scalar(key);
+            inner_builder.push(ScalarRef::Tuple(vec![
+                key.as_ref(),
+                ScalarRef::Number(NumberScalar::UInt64(count)),
+            ]));
+        }
+        builder.push(ScalarRef::Array(inner_builder.build()));
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<SpaceSaving<T::Scalar>>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateApproxTopKFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateApproxTopKFunction<T>
+where
+    T: ValueType + Send + Sync,
+    T::Scalar: Hash + Eq + Clone + Ord + BorshSerialize + BorshDeserialize + Send + Sync,
+{
+    fn try_create(display_name: &str, k: u64, argument: DataType) -> Result<AggregateFunctionRef> {
+        let return_type = DataType::Array(Box::new(DataType::Tuple(vec![
+            argument,
+            DataType::Number(NumberDataType::UInt64),
+        ])));
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            return_type,
+            k,
+            _t: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_approx_top_k_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_variadic_arguments(display_name, arguments.len(), (1, 2))?;
+
+    let data_type = arguments[0].clone();
+    let k = get_k(&params, display_name)?;
+
+    with_number_mapped_type!(|NUM| match &data_type {
+        DataType::Number(NumberDataType::NUM) => {
+            AggregateApproxTopKFunction::<NumberType<NUM>>::try_create(display_name, k, data_type)
+        }
+        DataType::String => {
+            AggregateApproxTopKFunction::<StringType>::try_create(display_name, k, data_type)
+        }
+        DataType::Date => {
+            AggregateApproxTopKFunction::<DateType>::try_create(display_name, k, data_type)
+        }
+        DataType::Timestamp => {
+            AggregateApproxTopKFunction::<TimestampType>::try_create(display_name, k, data_type)
+        }
+        DataType::Boolean => {
+            AggregateApproxTopKFunction::<BooleanType>::try_create(display_name, k, data_type)
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, data_type
+        ))),
+    })
+}
+
+pub fn aggregate_approx_top_k_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_approx_top_k_function))
+}
+
+fn get_k(params: &[Scalar], display_name: &str) -> Result<u64> {
+    if params.len() != 1 {
+        return Ok(DEFAULT_K);
+    }
+    if let Scalar::Number(number) = params[0] {
+        if let Some(number) = number.integer_to_i128() {
+            if number > 0 && number <= MAX_K as i128 {
+                return Ok(number as u64);
+            }
+        }
+    }
+    Err(ErrorCode::BadDataValueType(format!(
+        "The argument k of aggregate function {} must be a positive int not greater than {}",
+        display_name, MAX_K
+    )))
+}
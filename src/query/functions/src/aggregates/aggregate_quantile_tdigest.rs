@@ -50,8 +50,8 @@ use crate::BUILTIN_FUNCTIONS;
 pub(crate) const MEDIAN: u8 = 0;
 pub(crate) const QUANTILE: u8 = 1;
 
-#[derive(BorshSerialize, BorshDeserialize)]
-pub(crate) struct QuantileTDigestState {
+#[derive(BorshSerialize, BorshDeserialize, Clone)]
+pub struct QuantileTDigestState {
     epsilon: u32,
     max_centroids: usize,
 
@@ -66,8 +66,14 @@ pub(crate) struct QuantileTDigestState {
     max: f64,
 }
 
+impl Default for QuantileTDigestState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl QuantileTDigestState {
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             epsilon: 100u32,
             max_centroids: 2048,
@@ -82,7 +88,32 @@ impl QuantileTDigestState {
         }
     }
 
-    pub(crate) fn add(&mut self, other: f64, weight: Option<u64>) {
+    /// Rebuilds a compressed state from its centroids, see [`Self::centroids`].
+    pub(crate) fn from_centroids(
+        epsilon: u32,
+        min: f64,
+        max: f64,
+        means: Vec<f64>,
+        weights: Vec<f64>,
+    ) -> Self {
+        Self {
+            epsilon,
+            total_weight: weights.iter().sum(),
+            weights,
+            means,
+            min,
+            max,
+            ..Self::new()
+        }
+    }
+
+    /// Compresses the pending values and returns `(epsilon, min, max, means, weights)`.
+    pub fn centroids(&mut self) -> (u32, f64, f64, &[f64], &[f64]) {
+        self.compress();
+        (self.epsilon, self.min, self.max, &self.means, &self.weights)
+    }
+
+    pub fn add(&mut self, other: f64, weight: Option<u64>) {
         if self.unmerged_weights.len() + self.weights.len() >= self.max_centroids - 1 {
             self.compress();
         }
@@ -92,7 +123,7 @@ impl QuantileTDigestState {
         self.unmerged_total_weight += 1f64;
     }
 
-    pub(crate) fn merge(&mut self, rhs: &mut Self) -> Result<()> {
+    pub fn merge(&mut self, rhs: &mut Self) -> Result<()> {
         if rhs.len() == 0 {
             return Ok(());
         }
@@ -101,7 +132,7 @@ impl QuantileTDigestState {
 
         self.unmerged_weights.extend_from_slice(&rhs.weights);
         self.unmerged_means.extend_from_slice(&rhs.means);
-        self.unmerged_total_weight = rhs.weights.iter().sum();
+        self.unmerged_total_weight += rhs.weights.iter().sum::<f64>();
         self.compress();
 
         Ok(())
@@ -130,7 +161,7 @@ impl QuantileTDigestState {
         Ok(())
     }
 
-    pub(crate) fn quantile(&mut self, level: f64) -> f64 {
+    pub fn quantile(&mut self, level: f64) -> f64 {
         self.compress();
         if self.weights.is_empty() {
             return 0f64;
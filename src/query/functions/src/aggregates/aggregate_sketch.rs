@@ -0,0 +1,691 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sketches which can be persisted as binary values and merged later, e.g.
+//!
+//! ```sql
+//! INSERT INTO daily SELECT day, hll_sketch(user_id) FROM events GROUP BY day;
+//! SELECT hll_estimate(hll_merge(sketch)) FROM daily;
+//! ```
+//!
+//! Unlike the opaque states of the `_state` combinator, the sketches have a stable layout,
+//! all integers and floats are little endian:
+//!
+//! ```text
+//! sketch  := magic "DBSK" | kind: u8 | version: u8 | payload
+//!
+//! hll v1     := p: u8 | encoding: u8 | registers
+//!   dense    := 2^p ranks, one u8 per register
+//!   sparse   := n: u32 | n * (index: u16, rank: u8), indexes in ascending order
+//!
+//! tdigest v1 := epsilon: u32 | min: f64 | max: f64 | n: u32 | n * (mean: f64, weight: f64)
+//! ```
+//!
+//! The hll hashes the canonical encoding of a value with xxhash64 (seed 0), so the registers
+//! don't depend on the platform or on the `Hash` impls of the rust types:
+//!
+//! ```text
+//! integer, date, timestamp, decimal := fixed width little endian
+//! float   := little endian bits, -0.0 as 0.0 and NaN as the quiet NaN
+//! boolean := u8
+//! string, binary, bitmap, variant, geometry := raw bytes
+//! array, map := n: u32 | n * element
+//! tuple   := fields as elements
+//! element := 0u8 for NULL, else 1u8 | len: u32 for the variable width values | value
+//! ```
+//!
+//! The register index is the top `p` bits of the hash and the rank is the number of leading
+//! zeros of the remaining bits plus one.
+//! The tdigest centroids are compressed and sorted by mean.
+
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::number::*;
+use databend_common_expression::types::*;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use num_traits::AsPrimitive;
+use twox_hash::XxHash64;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_quantile_tdigest::QuantileTDigestState;
+use super::AggregateFunctionRef;
+use super::AggregateUnaryFunction;
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::assert_unary_arguments;
+
+const SKETCH_MAGIC: &[u8; 4] = b"DBSK";
+const SKETCH_HEADER_SIZE: usize = 6;
+
+const SKETCH_KIND_HLL: u8 = 1;
+const SKETCH_KIND_TDIGEST: u8 = 2;
+
+/// Bump the version if the payload layout of a sketch changes,
+/// and keep decoding the old versions.
+const HLL_SKETCH_VERSION: u8 = 1;
+const TDIGEST_SKETCH_VERSION: u8 = 1;
+
+const HLL_ENCODING_DENSE: u8 = 0;
+const HLL_ENCODING_SPARSE: u8 = 1;
+
+/// The precision of the hll sketch, same as the default of `approx_count_distinct`.
+pub const HLL_SKETCH_P: usize = 14;
+
+const HLL_SKETCH_REGISTERS: usize = 1 << HLL_SKETCH_P;
+
+/// The largest rank of a register, 64 - p leading zeros plus one.
+const HLL_SKETCH_MAX_RANK: u8 = (64 - HLL_SKETCH_P + 1) as u8;
+
+/// A HyperLogLog with `2^HLL_SKETCH_P` registers, see the module doc for the layout.
+#[derive(BorshSerialize, BorshDeserialize, Clone, PartialEq, Debug)]
+pub struct HllSketch {
+    registers: Vec<u8>,
+}
+
+impl Default for HllSketch {
+    fn default() -> Self {
+        Self {
+            registers: vec![0; HLL_SKETCH_REGISTERS],
+        }
+    }
+}
+
+impl HllSketch {
+    pub fn add_scalar(&mut self, value: &ScalarRef) {
+        let mut hasher = XxHash64::with_seed(0);
+        write_canonical(&mut hasher, value);
+        self.add_hash(hasher.finish());
+    }
+
+    /// Adds a value by its xxhash64, see [`Self::add_scalar`].
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_SKETCH_P)) as usize;
+        // The sentinel bit bounds the rank when the remaining bits are all zero.
+        let rank = ((hash << HLL_SKETCH_P) | (1 << (HLL_SKETCH_P - 1))).leading_zeros() as u8 + 1;
+        if self.registers[index] < rank {
+            self.registers[index] = rank;
+        }
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for (lhs, rhs) in self.registers.iter_mut().zip(other.registers.iter()) {
+            if *lhs < *rhs {
+                *lhs = *rhs;
+            }
+        }
+    }
+
+    /// Estimates the number of distinct values, with the linear counting
+    /// correction for small cardinalities.
+    pub fn count(&self) -> usize {
+        let m = HLL_SKETCH_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let mut zeros = 0;
+        let mut sum = 0.0;
+        for rank in &self.registers {
+            if *rank == 0 {
+                zeros += 1;
+            }
+            sum += 1.0 / (1u64 << *rank) as f64;
+        }
+
+        let estimate = alpha * m * m / sum;
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as usize
+        } else {
+            estimate.round() as usize
+        }
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(HLL_SKETCH_P as u8);
+        let non_zeros = self.registers.iter().filter(|rank| **rank != 0).count();
+        // A sparse register takes 3 bytes.
+        if 4 + non_zeros * 3 < HLL_SKETCH_REGISTERS {
+            buf.push(HLL_ENCODING_SPARSE);
+            buf.extend_from_slice(&(non_zeros as u32).to_le_bytes());
+            for (index, rank) in self.registers.iter().enumerate() {
+                if *rank != 0 {
+                    buf.extend_from_slice(&(index as u16).to_le_bytes());
+                    buf.push(*rank);
+                }
+            }
+        } else {
+            buf.push(HLL_ENCODING_DENSE);
+            buf.extend_from_slice(&self.registers);
+        }
+    }
+
+    fn decode(mut payload: &[u8]) -> Result<Self> {
+        let p = read_u8(&mut payload)?;
+        if p as usize != HLL_SKETCH_P {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported hll sketch precision {p}, expected {HLL_SKETCH_P}"
+            )));
+        }
+
+        let mut sketch = Self::default();
+        match read_u8(&mut payload)? {
+            HLL_ENCODING_DENSE => {
+                let registers = read_bytes(&mut payload, HLL_SKETCH_REGISTERS)?;
+                sketch.registers.copy_from_slice(registers);
+            }
+            HLL_ENCODING_SPARSE => {
+                let n = read_u32(&mut payload)? as usize;
+                if n > HLL_SKETCH_REGISTERS {
+                    return Err(ErrorCode::BadBytes("invalid hll sketch registers"));
+                }
+                for _ in 0..n {
+                    let index =
+                        u16::from_le_bytes(read_bytes(&mut payload, 2)?.try_into().unwrap());
+                    let rank = read_u8(&mut payload)?;
+                    match sketch.registers.get_mut(index as usize) {
+                        Some(register) => *register = rank,
+                        None => return Err(ErrorCode::BadBytes("invalid hll sketch registers")),
+                    }
+                }
+            }
+            encoding => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unknown hll sketch encoding {encoding}"
+                )));
+            }
+        }
+
+        if sketch
+            .registers
+            .iter()
+            .any(|rank| *rank > HLL_SKETCH_MAX_RANK)
+        {
+            return Err(ErrorCode::BadBytes("invalid hll sketch registers"));
+        }
+        check_consumed("hll", payload)?;
+        Ok(sketch)
+    }
+}
+
+/// Writes the canonical encoding of a value, see the module doc.
+fn write_canonical(hasher: &mut XxHash64, value: &ScalarRef) {
+    match value {
+        ScalarRef::Null | ScalarRef::EmptyArray | ScalarRef::EmptyMap => {}
+        ScalarRef::Number(number) => match number {
+            NumberScalar::UInt8(v) => hasher.write(&v.to_le_bytes()),
+            NumberScalar::UInt16(v) => hasher.write(&v.to_le_bytes()),
+            NumberScalar::UInt32(v) => hasher.write(&v.to_le_bytes()),
+            NumberScalar::UInt64(v) => hasher.write(&v.to_le_bytes()),
+            NumberScalar::Int8(v) => hasher.write(&v.to_le_bytes()),
+            NumberScalar::Int16(v) => hasher.write(&v.to_le_bytes()),
+            NumberScalar::Int32(v) => hasher.write(&v.to_le_bytes()),
+            NumberScalar::Int64(v) => hasher.write(&v.to_le_bytes()),
+            NumberScalar::Float32(v) => {
+                let v = if v.0 == 0.0 {
+                    0.0
+                } else if v.0.is_nan() {
+                    f32::NAN
+                } else {
+                    v.0
+                };
+                hasher.write(&v.to_le_bytes())
+            }
+            NumberScalar::Float64(v) => {
+                let v = if v.0 == 0.0 {
+                    0.0
+                } else if v.0.is_nan() {
+                    f64::NAN
+                } else {
+                    v.0
+                };
+                hasher.write(&v.to_le_bytes())
+            }
+        },
+        ScalarRef::Decimal(DecimalScalar::Decimal128(v, _)) => hasher.write(&v.to_le_bytes()),
+        ScalarRef::Decimal(DecimalScalar::Decimal256(v, _)) => hasher.write(&v.to_le_bytes()),
+        ScalarRef::Timestamp(v) => hasher.write(&v.to_le_bytes()),
+        ScalarRef::Date(v) => hasher.write(&v.to_le_bytes()),
+        ScalarRef::Boolean(v) => hasher.write(&[*v as u8]),
+        ScalarRef::String(v) => hasher.write(v.as_bytes()),
+        ScalarRef::Binary(v)
+        | ScalarRef::Bitmap(v)
+        | ScalarRef::Variant(v)
+        | ScalarRef::Geometry(v) => hasher.write(v),
+        ScalarRef::Array(column) | ScalarRef::Map(column) => {
+            hasher.write(&(column.len() as u32).to_le_bytes());
+            for element in column.iter() {
+                write_canonical_element(hasher, &element);
+            }
+        }
+        ScalarRef::Tuple(fields) => {
+            for field in fields {
+                write_canonical_element(hasher, field);
+            }
+        }
+    }
+}
+
+fn write_canonical_element(hasher: &mut XxHash64, value: &ScalarRef) {
+    let len = match value {
+        ScalarRef::Null => {
+            hasher.write(&[0]);
+            return;
+        }
+        ScalarRef::String(v) => Some(v.len()),
+        ScalarRef::Binary(v)
+        | ScalarRef::Bitmap(v)
+        | ScalarRef::Variant(v)
+        | ScalarRef::Geometry(v) => Some(v.len()),
+        _ => None,
+    };
+    hasher.write(&[1]);
+    if let Some(len) = len {
+        hasher.write(&(len as u32).to_le_bytes());
+    }
+    write_canonical(hasher, value);
+}
+
+fn read_bytes<'a>(payload: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if payload.len() < len {
+        return Err(ErrorCode::BadBytes("unexpected end of sketch"));
+    }
+    let (bytes, rest) = payload.split_at(len);
+    *payload = rest;
+    Ok(bytes)
+}
+
+fn read_u8(payload: &mut &[u8]) -> Result<u8> {
+    Ok(read_bytes(payload, 1)?[0])
+}
+
+fn read_u32(payload: &mut &[u8]) -> Result<u32> {
+    Ok(u32::from_le_bytes(
+        read_bytes(payload, 4)?.try_into().unwrap(),
+    ))
+}
+
+fn read_f64(payload: &mut &[u8]) -> Result<f64> {
+    Ok(f64::from_le_bytes(
+        read_bytes(payload, 8)?.try_into().unwrap(),
+    ))
+}
+
+fn check_consumed(name: &str, payload: &[u8]) -> Result<()> {
+    if !payload.is_empty() {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid {name} sketch: {} trailing bytes",
+            payload.len()
+        )));
+    }
+    Ok(())
+}
+
+fn encode_header(kind: u8, version: u8, buf: &mut Vec<u8>) {
+    buf.extend_from_slice(SKETCH_MAGIC);
+    buf.push(kind);
+    buf.push(version);
+}
+
+/// Checks the header and returns the version and the payload.
+fn decode_header<'a>(
+    name: &str,
+    kind: u8,
+    max_version: u8,
+    data: &'a [u8],
+) -> Result<(u8, &'a [u8])> {
+    if data.len() < SKETCH_HEADER_SIZE || &data[0..4] != SKETCH_MAGIC || data[4] != kind {
+        return Err(ErrorCode::BadBytes(format!("invalid {name} sketch")));
+    }
+    let version = data[5];
+    if version == 0 || version > max_version {
+        return Err(ErrorCode::BadBytes(format!(
+            "unsupported {name} sketch version {version}, the latest version is {max_version}"
+        )));
+    }
+    Ok((version, &data[SKETCH_HEADER_SIZE..]))
+}
+
+pub fn encode_hll_sketch(sketch: &HllSketch, buf: &mut Vec<u8>) {
+    encode_header(SKETCH_KIND_HLL, HLL_SKETCH_VERSION, buf);
+    sketch.encode(buf);
+}
+
+pub fn decode_hll_sketch(data: &[u8]) -> Result<HllSketch> {
+    let (_version, payload) = decode_header("hll", SKETCH_KIND_HLL, HLL_SKETCH_VERSION, data)?;
+    HllSketch::decode(payload)
+}
+
+pub fn encode_tdigest_sketch(sketch: &mut QuantileTDigestState, buf: &mut Vec<u8>) {
+    encode_header(SKETCH_KIND_TDIGEST, TDIGEST_SKETCH_VERSION, buf);
+    let (epsilon, min, max, means, weights) = sketch.centroids();
+    buf.extend_from_slice(&epsilon.to_le_bytes());
+    buf.extend_from_slice(&min.to_le_bytes());
+    buf.extend_from_slice(&max.to_le_bytes());
+    buf.extend_from_slice(&(means.len() as u32).to_le_bytes());
+    for (mean, weight) in means.iter().zip(weights.iter()) {
+        buf.extend_from_slice(&mean.to_le_bytes());
+        buf.extend_from_slice(&weight.to_le_bytes());
+    }
+}
+
+pub fn decode_tdigest_sketch(data: &[u8]) -> Result<QuantileTDigestState> {
+    let (_version, mut payload) =
+        decode_header("tdigest", SKETCH_KIND_TDIGEST, TDIGEST_SKETCH_VERSION, data)?;
+    let epsilon = read_u32(&mut payload)?;
+    let min = read_f64(&mut payload)?;
+    let max = read_f64(&mut payload)?;
+    let n = read_u32(&mut payload)? as usize;
+    if epsilon == 0 || n.saturating_mul(16) != payload.len() {
+        return Err(ErrorCode::BadBytes("invalid tdigest sketch"));
+    }
+
+    let mut means = Vec::with_capacity(n);
+    let mut weights = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mean = read_f64(&mut payload)?;
+        let weight = read_f64(&mut payload)?;
+        if !mean.is_finite() || !weight.is_finite() || weight <= 0.0 {
+            return Err(ErrorCode::BadBytes("invalid tdigest sketch centroid"));
+        }
+        if means.last().is_some_and(|last| *last > mean) {
+            return Err(ErrorCode::BadBytes(
+                "tdigest sketch centroids are not sorted",
+            ));
+        }
+        means.push(mean);
+        weights.push(weight);
+    }
+    check_consumed("tdigest", payload)?;
+    Ok(QuantileTDigestState::from_centroids(
+        epsilon, min, max, means, weights,
+    ))
+}
+
+/// State of `hll_sketch(col)` and `hll_merge(sketch)`.
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct HllSketchState {
+    hll: HllSketch,
+}
+
+impl<T> UnaryState<T, BinaryType> for HllSketchState
+where T: ValueType + Send + Sync
+{
+    fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
+        let value = T::upcast_scalar(T::to_owned_scalar(other));
+        self.hll.add_scalar(&value.as_ref());
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.hll.merge(&rhs.hll);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        encode_hll_sketch(&self.hll, &mut builder.data);
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct HllMergeState {
+    hll: HllSketch,
+}
+
+impl UnaryState<BinaryType, BinaryType> for HllMergeState {
+    fn add(&mut self, other: &[u8]) -> Result<()> {
+        let rhs = decode_hll_sketch(other)?;
+        self.hll.merge(&rhs);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.hll.merge(&rhs.hll);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        encode_hll_sketch(&self.hll, &mut builder.data);
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+/// State of `tdigest_sketch(col)` and `tdigest_merge(sketch)`.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct TDigestSketchState {
+    tdigest: QuantileTDigestState,
+}
+
+impl Default for TDigestSketchState {
+    fn default() -> Self {
+        Self {
+            tdigest: QuantileTDigestState::new(),
+        }
+    }
+}
+
+impl<T> UnaryState<T, BinaryType> for TDigestSketchState
+where
+    T: ValueType + Send + Sync,
+    T::Scalar: AsPrimitive<f64>,
+{
+    fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
+        self.tdigest.add(T::to_owned_scalar(other).as_(), None);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        let mut rhs = rhs.tdigest.clone();
+        self.tdigest.merge(&mut rhs)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        encode_tdigest_sketch(&mut self.tdigest, &mut builder.data);
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct TDigestMergeState {
+    tdigest: QuantileTDigestState,
+}
+
+impl Default for TDigestMergeState {
+    fn default() -> Self {
+        Self {
+            tdigest: QuantileTDigestState::new(),
+        }
+    }
+}
+
+impl UnaryState<BinaryType, BinaryType> for TDigestMergeState {
+    fn add(&mut self, other: &[u8]) -> Result<()> {
+        let mut rhs = decode_tdigest_sketch(other)?;
+        self.tdigest.merge(&mut rhs)
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        let mut rhs = rhs.tdigest.clone();
+        self.tdigest.merge(&mut rhs)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        encode_tdigest_sketch(&mut self.tdigest, &mut builder.data);
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_hll_sketch_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let return_type = DataType::Binary;
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            AggregateUnaryFunction::<HllSketchState, NumberType<NUM_TYPE>, BinaryType>::try_create_unary(
+                display_name,
+                return_type,
+                params,
+                arguments[0].clone(),
+            )
+        }
+        DataType::String => {
+            AggregateUnaryFunction::<HllSketchState, StringType, BinaryType>::try_create_unary(
+                display_name,
+                return_type,
+                params,
+                arguments[0].clone(),
+            )
+        }
+        DataType::Date => {
+            AggregateUnaryFunction::<HllSketchState, DateType, BinaryType>::try_create_unary(
+                display_name,
+                return_type,
+                params,
+                arguments[0].clone(),
+            )
+        }
+        DataType::Timestamp => {
+            AggregateUnaryFunction::<HllSketchState, TimestampType, BinaryType>::try_create_unary(
+                display_name,
+                return_type,
+                params,
+                arguments[0].clone(),
+            )
+        }
+        _ => {
+            AggregateUnaryFunction::<HllSketchState, AnyType, BinaryType>::try_create_unary(
+                display_name,
+                return_type,
+                params,
+                arguments[0].clone(),
+            )
+        }
+    })
+}
+
+pub fn try_create_aggregate_hll_merge_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    if arguments[0] != DataType::Binary {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} expects a binary hll sketch, but got '{:?}'",
+            display_name, arguments[0]
+        )));
+    }
+
+    AggregateUnaryFunction::<HllMergeState, BinaryType, BinaryType>::try_create_unary(
+        display_name,
+        DataType::Binary,
+        params,
+        arguments[0].clone(),
+    )
+}
+
+pub fn try_create_aggregate_tdigest_sketch_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            let func = AggregateUnaryFunction::<
+                TDigestSketchState,
+                NumberType<NUM_TYPE>,
+                BinaryType,
+            >::try_create(
+                display_name, DataType::Binary, params, arguments[0].clone()
+            )
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} just support numeric type, but got '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+pub fn try_create_aggregate_tdigest_merge_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    if arguments[0] != DataType::Binary {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} expects a binary tdigest sketch, but got '{:?}'",
+            display_name, arguments[0]
+        )));
+    }
+
+    let func = AggregateUnaryFunction::<TDigestMergeState, BinaryType, BinaryType>::try_create(
+        display_name,
+        DataType::Binary,
+        params,
+        arguments[0].clone(),
+    )
+    .with_need_drop(true);
+    Ok(Arc::new(func))
+}
+
+pub fn aggregate_hll_sketch_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_hll_sketch_function))
+}
+
+pub fn aggregate_hll_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_hll_merge_function))
+}
+
+pub fn aggregate_tdigest_sketch_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_tdigest_sketch_function))
+}
+
+pub fn aggregate_tdigest_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_tdigest_merge_function))
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_function_desc;
+use super::aggregate_approx_top_k::aggregate_approx_top_k_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
@@ -41,6 +42,8 @@ use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
 use crate::aggregates::aggregate_histogram_function_desc;
+use crate::aggregates::aggregate_hll_merge_function_desc;
+use crate::aggregates::aggregate_hll_sketch_function_desc;
 use crate::aggregates::aggregate_kurtosis_function_desc;
 use crate::aggregates::aggregate_median_function_desc;
 use crate::aggregates::aggregate_median_tdigest_function_desc;
@@ -53,6 +56,8 @@ use crate::aggregates::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum_function_desc;
+use crate::aggregates::aggregate_tdigest_merge_function_desc;
+use crate::aggregates::aggregate_tdigest_sketch_function_desc;
 
 pub struct Aggregators;
 
@@ -98,6 +103,7 @@ impl Aggregators {
             "approx_count_distinct",
             aggregate_approx_count_distinct_function_desc(),
         );
+        factory.register("approx_top_k", aggregate_approx_top_k_function_desc());
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
@@ -137,6 +143,11 @@ impl Aggregators {
         );
 
         factory.register("histogram", aggregate_histogram_function_desc());
+
+        factory.register("hll_sketch", aggregate_hll_sketch_function_desc());
+        factory.register("hll_merge", aggregate_hll_merge_function_desc());
+        factory.register("tdigest_sketch", aggregate_tdigest_sketch_function_desc());
+        factory.register("tdigest_merge", aggregate_tdigest_merge_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
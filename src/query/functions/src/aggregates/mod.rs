@@ -18,6 +18,7 @@ mod aggregate_function_factory;
 
 mod adaptors;
 mod aggregate_approx_count_distinct;
+mod aggregate_approx_top_k;
 mod aggregate_arg_min_max;
 mod aggregate_array_agg;
 mod aggregate_array_moving;
@@ -38,6 +39,7 @@ mod aggregate_quantile_tdigest;
 mod aggregate_quantile_tdigest_weighted;
 mod aggregate_retention;
 mod aggregate_scalar_state;
+mod aggregate_sketch;
 mod aggregate_skewness;
 mod aggregate_stddev;
 mod aggregate_string_agg;
@@ -48,6 +50,7 @@ mod aggregator;
 mod aggregator_common;
 
 pub use adaptors::*;
+pub use aggregate_approx_top_k::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_array_agg::*;
 pub use aggregate_array_moving::*;
@@ -66,6 +69,7 @@ pub use aggregate_quantile_disc::*;
 pub use aggregate_quantile_tdigest::*;
 pub use aggregate_quantile_tdigest_weighted::*;
 pub use aggregate_retention::*;
+pub use aggregate_sketch::*;
 pub use aggregate_skewness::*;
 pub use aggregate_string_agg::*;
 pub use aggregate_sum::*;
@@ -0,0 +1,289 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::number::UInt32Type;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+/// IPv6 addresses are stored as 16 bytes binary in network byte order, so that they
+/// compare in the same order as the addresses. IPv4 addresses are stored as IPv4-mapped
+/// IPv6 addresses (`::ffff:a.b.c.d`) in this encoding.
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, BooleanType, _, _>(
+        "is_ipv4_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, BooleanType>(|val, output, _| {
+            output.push(val.parse::<Ipv4Addr>().is_ok());
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BooleanType, _, _>(
+        "is_ipv6_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, BooleanType>(|val, output, _| {
+            output.push(val.parse::<Ipv6Addr>().is_ok());
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "ipv6_string_to_num",
+        |_, _| FunctionDomain::MayThrow,
+        eval_ipv6_string_to_num,
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_ipv6_string_to_num",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_ipv6_string_to_num),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "ipv6_num_to_string",
+        |_, _| FunctionDomain::MayThrow,
+        eval_ipv6_num_to_string,
+    );
+
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_ipv6_num_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_ipv6_num_to_string),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "ip_in_cidr",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |addr, cidr, output, ctx| match (addr.parse::<IpAddr>(), Cidr::parse(cidr)) {
+                (Ok(addr), Ok(cidr)) => output.push(cidr.contains(addr)),
+                (Err(_), _) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("Failed to parse '{}' into a IP address", addr),
+                    );
+                    output.push(false);
+                }
+                (_, Err(err)) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    // The IPv4 addresses encoded by `inet_aton`. A constant CIDR is a contiguous range
+    // of the numbers, so the domain can be used to prune the blocks by min/max index.
+    registry.register_passthrough_nullable_2_arg::<UInt32Type, StringType, BooleanType, _, _>(
+        "ip_in_cidr",
+        |_, addr, cidr| {
+            let Some(max) = &cidr.max else {
+                return FunctionDomain::MayThrow;
+            };
+            if cidr.min != *max {
+                return FunctionDomain::MayThrow;
+            }
+            match Cidr::parse(max).and_then(|cidr| cidr.ipv4_range()) {
+                Ok((start, end)) => FunctionDomain::Domain(BooleanDomain {
+                    has_false: addr.min < start || addr.max > end,
+                    has_true: addr.max >= start && addr.min <= end,
+                }),
+                Err(_) => FunctionDomain::MayThrow,
+            }
+        },
+        vectorize_with_builder_2_arg::<UInt32Type, StringType, BooleanType>(
+            |addr, cidr, output, ctx| match Cidr::parse(cidr).and_then(|cidr| cidr.ipv4_range()) {
+                Ok((start, end)) => output.push(addr >= start && addr <= end),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, UInt32Type, _, _>(
+        "ipv4_cidr_range_start",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, UInt32Type>(|cidr, output, ctx| {
+            match Cidr::parse(cidr).and_then(|cidr| cidr.ipv4_range()) {
+                Ok((start, _)) => output.push(start),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, UInt32Type, _, _>(
+        "ipv4_cidr_range_end",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, UInt32Type>(|cidr, output, ctx| {
+            match Cidr::parse(cidr).and_then(|cidr| cidr.ipv4_range()) {
+                Ok((_, end)) => output.push(end),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "ipv6_cidr_range_start",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|cidr, output, ctx| {
+            match Cidr::parse(cidr) {
+                Ok(cidr) => output.put_slice(&cidr.ipv6_range().0.to_be_bytes()),
+                Err(err) => ctx.set_error(output.len(), err),
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "ipv6_cidr_range_end",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|cidr, output, ctx| {
+            match Cidr::parse(cidr) {
+                Ok(cidr) => output.put_slice(&cidr.ipv6_range().1.to_be_bytes()),
+                Err(err) => ctx.set_error(output.len(), err),
+            }
+            output.commit_row();
+        }),
+    );
+}
+
+fn eval_ipv6_string_to_num(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
+    vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+        match val.parse::<IpAddr>() {
+            Ok(addr) => output.put_slice(&to_ipv6(addr).octets()),
+            Err(_) => ctx.set_error(
+                output.len(),
+                format!("Failed to parse '{}' into a IP address", val),
+            ),
+        }
+        output.commit_row();
+    })(val, ctx)
+}
+
+fn eval_ipv6_num_to_string(val: ValueRef<BinaryType>, ctx: &mut EvalContext) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, ctx| {
+        match <[u8; 16]>::try_from(val) {
+            Ok(octets) => {
+                let addr = Ipv6Addr::from(octets);
+                // IPv4-mapped addresses are displayed in the IPv4 notation.
+                match addr.to_ipv4_mapped() {
+                    Some(addr) => output.put_str(&addr.to_string()),
+                    None => output.put_str(&addr.to_string()),
+                }
+            }
+            Err(_) => ctx.set_error(
+                output.len(),
+                format!(
+                    "IPv6 address must be 16 bytes binary, but got {} bytes",
+                    val.len()
+                ),
+            ),
+        }
+        output.commit_row();
+    })(val, ctx)
+}
+
+fn to_ipv6(addr: IpAddr) -> Ipv6Addr {
+    match addr {
+        IpAddr::V4(addr) => addr.to_ipv6_mapped(),
+        IpAddr::V6(addr) => addr,
+    }
+}
+
+/// A network in the CIDR notation, e.g. `10.0.0.0/8` or `2001:db8::/32`.
+/// An address without the prefix length is a network of the single address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cidr {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl Cidr {
+    fn parse(cidr: &str) -> Result<Cidr, String> {
+        let err = || format!("Failed to parse '{}' into a CIDR", cidr);
+        let (addr, prefix_len) = match cidr.split_once('/') {
+            Some((addr, prefix_len)) => {
+                let addr = addr.trim().parse::<IpAddr>().map_err(|_| err())?;
+                let prefix_len = prefix_len.trim().parse::<u8>().map_err(|_| err())?;
+                (addr, prefix_len)
+            }
+            None => {
+                let addr = cidr.trim().parse::<IpAddr>().map_err(|_| err())?;
+                (addr, Self::max_prefix_len(addr))
+            }
+        };
+        if prefix_len > Self::max_prefix_len(addr) {
+            return Err(err());
+        }
+        Ok(Cidr { addr, prefix_len })
+    }
+
+    fn max_prefix_len(addr: IpAddr) -> u8 {
+        match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+
+    /// The first and the last address of the network in the IPv6 encoding.
+    fn ipv6_range(&self) -> (u128, u128) {
+        let (addr, prefix_len) = match self.addr {
+            IpAddr::V4(addr) => (addr.to_ipv6_mapped(), self.prefix_len + 96),
+            IpAddr::V6(addr) => (addr, self.prefix_len),
+        };
+        let host_mask = u128::MAX.checked_shr(prefix_len as u32).unwrap_or(0);
+        let addr = u128::from(addr);
+        (addr & !host_mask, addr | host_mask)
+    }
+
+    fn ipv4_range(&self) -> Result<(u32, u32), String> {
+        match self.addr {
+            IpAddr::V4(addr) => {
+                let host_mask = u32::MAX.checked_shr(self.prefix_len as u32).unwrap_or(0);
+                let addr = u32::from(addr);
+                Ok((addr & !host_mask, addr | host_mask))
+            }
+            IpAddr::V6(_) => Err(format!(
+                "CIDR '{}/{}' is not an IPv4 network",
+                self.addr, self.prefix_len
+            )),
+        }
+    }
+
+    fn contains(&self, addr: IpAddr) -> bool {
+        let addr = u128::from(to_ipv6(addr));
+        let (start, end) = self.ipv6_range();
+        addr >= start && addr <= end
+    }
+}
@@ -28,12 +28,16 @@ mod geo;
 mod geo_h3;
 mod geometry;
 mod hash;
+mod ip;
 mod map;
 mod math;
 mod other;
+mod sketch;
 mod string;
 mod string_multi_args;
+mod string_similarity;
 mod tuple;
+mod url;
 mod variant;
 mod vector;
 
@@ -55,13 +59,17 @@ pub fn register(registry: &mut FunctionRegistry) {
     string::register(registry);
     binary::register(registry);
     string_multi_args::register(registry);
+    string_similarity::register(registry);
     tuple::register(registry);
     geo::register(registry);
     geo_h3::register(registry);
     hash::register(registry);
     other::register(registry);
+    ip::register(registry);
+    url::register(registry);
     decimal::register_to_decimal(registry);
     vector::register(registry);
     bitmap::register(registry);
     geometry::register(registry);
+    sketch::register(registry);
 }
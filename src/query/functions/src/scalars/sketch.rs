@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::F64;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+
+use crate::aggregates::decode_hll_sketch;
+use crate::aggregates::decode_tdigest_sketch;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // Estimates the number of distinct values of a sketch built by `hll_sketch` or `hll_merge`.
+    registry.register_passthrough_nullable_1_arg::<BinaryType, UInt64Type, _, _>(
+        "hll_estimate",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, UInt64Type>(|sketch, output, ctx| {
+            match decode_hll_sketch(sketch) {
+                Ok(hll) => output.push(hll.count() as u64),
+                Err(e) => {
+                    ctx.set_error(output.len(), e.message());
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    // Estimates the quantile of a sketch built by `tdigest_sketch` or `tdigest_merge`.
+    registry.register_passthrough_nullable_2_arg::<BinaryType, Float64Type, Float64Type, _, _>(
+        "tdigest_quantile",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BinaryType, Float64Type, Float64Type>(
+            |sketch, level, output, ctx| {
+                if !(0.0..=1.0).contains(&level.0) {
+                    ctx.set_error(
+                        output.len(),
+                        format!("level range between [0, 1], got: {:?}", level.0),
+                    );
+                    output.push(F64::from(0.0));
+                    return;
+                }
+                match decode_tdigest_sketch(sketch) {
+                    Ok(mut tdigest) => output.push(F64::from(tdigest.quantile(level.0))),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e.message());
+                        output.push(F64::from(0.0));
+                    }
+                }
+            },
+        ),
+    );
+}
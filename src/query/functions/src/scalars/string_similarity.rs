@@ -0,0 +1,427 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::types::F64;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+
+const DEFAULT_NGRAM_SIZE: u64 = 3;
+const MAX_NGRAM_SIZE: u64 = 64;
+const MAX_MINHASH_SIZE: u64 = 1024;
+/// The distance matrix of damerau_levenshtein is limited to 16M cells (64MB).
+const MAX_DAMERAU_LEVENSHTEIN_CELLS: usize = 16 * 1024 * 1024;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("levenshtein", &["edit_distance"]);
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, UInt64Type, _, _>(
+        "levenshtein",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, UInt64Type>(|a, b, output, _| {
+            output.push(similarity::levenshtein(a, b) as u64);
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, UInt64Type, _, _>(
+        "damerau_levenshtein",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, UInt64Type>(|a, b, output, ctx| {
+            match similarity::damerau_levenshtein(a, b, MAX_DAMERAU_LEVENSHTEIN_CELLS) {
+                Some(distance) => output.push(distance as u64),
+                None => {
+                    ctx.set_error(
+                        output.len(),
+                        format!(
+                            "the strings are too long for damerau_levenshtein, the product of their lengths must be less than {}",
+                            MAX_DAMERAU_LEVENSHTEIN_CELLS
+                        ),
+                    );
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "jaro_winkler_similarity",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, Float64Type>(|a, b, output, _| {
+            output.push(F64::from(similarity::jaro_winkler(a, b)));
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float64Type, _, _>(
+        "ngram_distance",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, Float64Type>(|a, b, output, _| {
+            let distance = similarity::ngram_distance(a, b, DEFAULT_NGRAM_SIZE as usize);
+            output.push(F64::from(distance));
+        }),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, UInt64Type, Float64Type, _, _>(
+        "ngram_distance",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, UInt64Type, Float64Type>(
+            |a, b, n, output, ctx| {
+                if n == 0 || n > MAX_NGRAM_SIZE {
+                    ctx.set_error(
+                        output.len(),
+                        format!("ngram size must be in [1, {}], but got {}", MAX_NGRAM_SIZE, n),
+                    );
+                    output.push(F64::from(0.0));
+                    return;
+                }
+                output.push(F64::from(similarity::ngram_distance(a, b, n as usize)));
+            },
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, UInt64Type, ArrayType<StringType>, _, _>(
+            "ngrams",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, UInt64Type, ArrayType<StringType>>(
+                |s, n, output, ctx| {
+                    if n == 0 || n > MAX_NGRAM_SIZE {
+                        ctx.set_error(
+                            output.len(),
+                            format!(
+                                "ngram size must be in [1, {}], but got {}",
+                                MAX_NGRAM_SIZE, n
+                            ),
+                        );
+                    } else {
+                        for ngram in similarity::ngrams(s, n as usize) {
+                            output.builder.put_str(ngram);
+                            output.builder.commit_row();
+                        }
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+
+    // minhash(str, num_hashes) computes the MinHash signature of the character trigrams of the string.
+    // The fraction of the equal positions of two signatures estimates the Jaccard similarity of the strings.
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, UInt64Type, ArrayType<UInt64Type>, _, _>(
+            "minhash",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, UInt64Type, ArrayType<UInt64Type>>(
+                |s, num_hashes, output, ctx| {
+                    if num_hashes == 0 || num_hashes > MAX_MINHASH_SIZE {
+                        ctx.set_error(
+                            output.len(),
+                            format!(
+                                "number of hashes must be in [1, {}], but got {}",
+                                MAX_MINHASH_SIZE, num_hashes
+                            ),
+                        );
+                    } else {
+                        let signature = similarity::minhash(
+                            s,
+                            num_hashes as usize,
+                            DEFAULT_NGRAM_SIZE as usize,
+                        );
+                        for hash in signature {
+                            output.builder.push(hash);
+                        }
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<ArrayType<UInt64Type>, ArrayType<UInt64Type>, Float64Type, _, _>(
+        "minhash_similarity",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<ArrayType<UInt64Type>, ArrayType<UInt64Type>, Float64Type>(
+            |a, b, output, _| {
+                output.push(F64::from(similarity::minhash_similarity(
+                    a.as_slice(),
+                    b.as_slice(),
+                )));
+            },
+        ),
+    );
+
+    // minhash_bands(signature, num_bands) hashes each band of a MinHash signature (LSH banding).
+    // Two strings sharing any band hash are candidates of a similarity join, so the join can be
+    // rewritten to an equi-join on `unnest(minhash_bands(...))` instead of a full cross join.
+    registry.register_passthrough_nullable_2_arg::<ArrayType<UInt64Type>, UInt64Type, ArrayType<UInt64Type>, _, _>(
+        "minhash_bands",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<UInt64Type>, UInt64Type, ArrayType<UInt64Type>>(
+            |signature, num_bands, output, ctx| {
+                if num_bands == 0 || num_bands as usize > signature.len() {
+                    ctx.set_error(
+                        output.len(),
+                        format!(
+                            "number of bands must be in [1, {}], but got {}",
+                            signature.len(),
+                            num_bands
+                        ),
+                    );
+                } else {
+                    for hash in similarity::minhash_bands(signature.as_slice(), num_bands as usize)
+                    {
+                        output.builder.push(hash);
+                    }
+                }
+                output.commit_row();
+            },
+        ),
+    );
+}
+
+pub(crate) mod similarity {
+    use std::collections::HashMap;
+
+    /// Levenshtein distance counted in characters.
+    pub fn levenshtein(a: &str, b: &str) -> usize {
+        if a == b {
+            return 0;
+        }
+        let b: Vec<char> = b.chars().collect();
+        let mut prev: Vec<usize> = (0..=b.len()).collect();
+        let mut curr = vec![0; b.len() + 1];
+        for (i, ca) in a.chars().enumerate() {
+            curr[0] = i + 1;
+            for (j, cb) in b.iter().enumerate() {
+                let cost = usize::from(ca != *cb);
+                curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+            }
+            std::mem::swap(&mut prev, &mut curr);
+        }
+        prev[b.len()]
+    }
+
+    /// Damerau-Levenshtein distance (with adjacent transpositions, unrestricted edits)
+    /// counted in characters.
+    /// The unrestricted distance needs the whole `(n + 2) * (m + 2)` matrix, as a transposition
+    /// may refer to any earlier row. Return None if the matrix has more than `max_cells` cells.
+    pub fn damerau_levenshtein(a: &str, b: &str, max_cells: usize) -> Option<usize> {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let (n, m) = (a.len(), b.len());
+        if n == 0 || m == 0 {
+            return Some(n.max(m));
+        }
+        if (n + 2).saturating_mul(m + 2) > max_cells {
+            return None;
+        }
+
+        let max_dist = (n + m) as u32;
+        let width = m + 2;
+        // The matrix has an extra row and column holding `max_dist` as the sentinel.
+        let mut d = vec![0_u32; (n + 2) * width];
+        d[0] = max_dist;
+        for i in 0..=n {
+            d[(i + 1) * width] = max_dist;
+            d[(i + 1) * width + 1] = i as u32;
+        }
+        for j in 0..=m {
+            d[j + 1] = max_dist;
+            d[width + j + 1] = j as u32;
+        }
+
+        // The last row where each character appears in `a`.
+        let mut last_row: HashMap<char, usize> = HashMap::new();
+        for i in 1..=n {
+            // The last column in this row where `a[i - 1]` matched.
+            let mut last_match_col = 0;
+            for j in 1..=m {
+                let i1 = *last_row.get(&b[j - 1]).unwrap_or(&0);
+                let j1 = last_match_col;
+                let cost = if a[i - 1] == b[j - 1] {
+                    last_match_col = j;
+                    0
+                } else {
+                    1
+                };
+                let substitution = d[i * width + j] + cost;
+                let insertion = d[(i + 1) * width + j] + 1;
+                let deletion = d[i * width + j + 1] + 1;
+                let transposition = d[i1 * width + j1] + ((i - i1 - 1) + 1 + (j - j1 - 1)) as u32;
+                d[(i + 1) * width + j + 1] =
+                    substitution.min(insertion).min(deletion).min(transposition);
+            }
+            last_row.insert(a[i - 1], i);
+        }
+        Some(d[(n + 1) * width + m + 1] as usize)
+    }
+
+    pub fn jaro(a: &str, b: &str) -> f64 {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        if a.is_empty() && b.is_empty() {
+            return 1.0;
+        }
+        if a.is_empty() || b.is_empty() {
+            return 0.0;
+        }
+
+        let search_range = (a.len().max(b.len()) / 2).saturating_sub(1);
+        let mut a_matches = vec![false; a.len()];
+        let mut b_matches = vec![false; b.len()];
+        let mut matches = 0;
+        for (i, ca) in a.iter().enumerate() {
+            let start = i.saturating_sub(search_range);
+            let end = (i + search_range + 1).min(b.len());
+            for j in start..end {
+                if !b_matches[j] && *ca == b[j] {
+                    a_matches[i] = true;
+                    b_matches[j] = true;
+                    matches += 1;
+                    break;
+                }
+            }
+        }
+        if matches == 0 {
+            return 0.0;
+        }
+
+        let mut transpositions = 0;
+        let mut k = 0;
+        for (i, ca) in a.iter().enumerate() {
+            if !a_matches[i] {
+                continue;
+            }
+            while !b_matches[k] {
+                k += 1;
+            }
+            if *ca != b[k] {
+                transpositions += 1;
+            }
+            k += 1;
+        }
+
+        let matches = matches as f64;
+        (matches / a.len() as f64
+            + matches / b.len() as f64
+            + (matches - (transpositions / 2) as f64) / matches)
+            / 3.0
+    }
+
+    pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+        let jaro = jaro(a, b);
+        let prefix = a
+            .chars()
+            .zip(b.chars())
+            .take(4)
+            .take_while(|(ca, cb)| ca == cb)
+            .count();
+        jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+    }
+
+    /// Returns the character n-grams of the string, a string shorter than `n`
+    /// is its only n-gram.
+    pub fn ngrams(s: &str, n: usize) -> Vec<&str> {
+        let mut boundaries: Vec<usize> = s.char_indices().map(|(i, _)| i).collect();
+        boundaries.push(s.len());
+        let num_chars = boundaries.len() - 1;
+        if num_chars <= n {
+            return if s.is_empty() { vec![] } else { vec![s] };
+        }
+        (0..=num_chars - n)
+            .map(|i| &s[boundaries[i]..boundaries[i + n]])
+            .collect()
+    }
+
+    /// The n-gram distance is in [0, 1]: `(|A| + |B| - 2 * |A ∩ B|) / (|A| + |B|)`,
+    /// where A and B are the multisets of the n-grams.
+    pub fn ngram_distance(a: &str, b: &str, n: usize) -> f64 {
+        let a = ngrams(a, n);
+        let b = ngrams(b, n);
+        if a.is_empty() && b.is_empty() {
+            return 0.0;
+        }
+
+        let mut counts: HashMap<&str, usize> = HashMap::with_capacity(a.len());
+        for ngram in &a {
+            *counts.entry(ngram).or_default() += 1;
+        }
+        let mut common = 0;
+        for ngram in &b {
+            if let Some(count) = counts.get_mut(ngram) {
+                if *count > 0 {
+                    *count -= 1;
+                    common += 1;
+                }
+            }
+        }
+        let total = (a.len() + b.len()) as f64;
+        (total - 2.0 * common as f64) / total
+    }
+
+    // SplitMix64, the hash values must be stable across the nodes and versions.
+    #[inline]
+    fn mix64(mut x: u64) -> u64 {
+        x = x.wrapping_add(0x9E3779B97F4A7C15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+        x ^ (x >> 31)
+    }
+
+    // FNV-1a
+    #[inline]
+    fn hash_bytes(bytes: &[u8]) -> u64 {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in bytes {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        hash
+    }
+
+    pub fn minhash(s: &str, num_hashes: usize, n: usize) -> Vec<u64> {
+        let mut signature = vec![u64::MAX; num_hashes];
+        for ngram in ngrams(s, n) {
+            let hash = hash_bytes(ngram.as_bytes());
+            for (seed, min) in signature.iter_mut().enumerate() {
+                *min = (*min).min(mix64(hash ^ mix64(seed as u64)));
+            }
+        }
+        signature
+    }
+
+    pub fn minhash_similarity(a: &[u64], b: &[u64]) -> f64 {
+        let len = a.len().max(b.len());
+        if len == 0 {
+            return 1.0;
+        }
+        let equal = a.iter().zip(b.iter()).filter(|(x, y)| x == y).count();
+        equal as f64 / len as f64
+    }
+
+    /// Hashes each of the `num_bands` bands of the signature together with the band number,
+    /// the rest of the signature which doesn't fill a whole band is ignored.
+    pub fn minhash_bands(signature: &[u64], num_bands: usize) -> Vec<u64> {
+        let rows = signature.len() / num_bands;
+        (0..num_bands)
+            .map(|band| {
+                signature[band * rows..(band + 1) * rows]
+                    .iter()
+                    .fold(mix64(band as u64), |acc, hash| mix64(acc ^ hash))
+            })
+            .collect()
+    }
+}
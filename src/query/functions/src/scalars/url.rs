@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::number::UInt16Type;
+use databend_common_expression::types::StringType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+
+/// The URL functions never fail on malformed URLs, which are common in the logs,
+/// an absent component is returned as an empty string.
+pub fn register(registry: &mut FunctionRegistry) {
+    register_url_component(registry, "url_protocol", |url| url.scheme);
+    register_url_component(registry, "url_user_info", |url| url.user_info);
+    register_url_component(registry, "domain", |url| url.host);
+    register_url_component(registry, "domain_without_www", |url| {
+        url.host.strip_prefix("www.").unwrap_or(url.host)
+    });
+    register_url_component(registry, "top_level_domain", top_level_domain);
+    register_url_component(registry, "url_path", |url| url.path);
+    register_url_component(registry, "url_query", |url| url.query);
+    register_url_component(registry, "url_fragment", |url| url.fragment);
+
+    registry.register_passthrough_nullable_1_arg::<StringType, UInt16Type, _, _>(
+        "url_port",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, UInt16Type>(|val, output, _| {
+            let port = UrlParts::parse(val).port.parse::<u16>().unwrap_or(0);
+            output.push(port);
+        }),
+    );
+
+    // parse_url(url, part), part is one of `PROTOCOL`, `USERINFO`, `HOST`, `PORT`,
+    // `PATH`, `QUERY`, `REF` and `FRAGMENT`.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "parse_url",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, StringType>(
+            |url, part, output, ctx| {
+                let url = UrlParts::parse(url);
+                let component = match part.to_ascii_uppercase().as_str() {
+                    "PROTOCOL" => url.scheme,
+                    "USERINFO" => url.user_info,
+                    "HOST" => url.host,
+                    "PORT" => url.port,
+                    "PATH" => url.path,
+                    "QUERY" => url.query,
+                    "REF" | "FRAGMENT" => url.fragment,
+                    _ => {
+                        ctx.set_error(
+                            output.len(),
+                            format!("Invalid URL part '{}' for function parse_url", part),
+                        );
+                        ""
+                    }
+                };
+                output.put_str(component);
+                output.commit_row();
+            },
+        ),
+    );
+
+    // url_parameter(url, name) returns the raw value of the first parameter called `name`
+    // in the query string.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "url_parameter",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, StringType>(
+            |url, name, output, _| {
+                let query = UrlParts::parse(url).query;
+                let value = query
+                    .split('&')
+                    .find_map(|param| match param.split_once('=') {
+                        Some((key, value)) if key == name => Some(value),
+                        None if param == name => Some(""),
+                        _ => None,
+                    })
+                    .unwrap_or("");
+                output.put_str(value);
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_encode",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|val, output, _| {
+            for byte in val.bytes() {
+                if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+                    output.put_char(byte as char);
+                } else {
+                    output.put_char('%');
+                    output.put_char(HEX_DIGITS[(byte >> 4) as usize] as char);
+                    output.put_char(HEX_DIGITS[(byte & 0xf) as usize] as char);
+                }
+            }
+            output.commit_row();
+        }),
+    );
+
+    // Invalid percent-encoded sequences are kept as they are, and `+` is decoded as space.
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_decode",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|val, output, _| {
+            output.put_str(&String::from_utf8_lossy(&url_decode(val.as_bytes())));
+            output.commit_row();
+        }),
+    );
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+fn register_url_component(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    component: for<'a> fn(&UrlParts<'a>) -> &'a str,
+) {
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        name,
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, StringType>(move |val, output, _| {
+            output.put_str(component(&UrlParts::parse(val)));
+            output.commit_row();
+        }),
+    );
+}
+
+fn top_level_domain<'a>(url: &UrlParts<'a>) -> &'a str {
+    let host = url.host.trim_end_matches('.');
+    match host.rsplit_once('.') {
+        // IPv4 addresses have no top level domain.
+        Some((_, tld)) if !tld.bytes().all(|b| b.is_ascii_digit()) => tld,
+        _ => "",
+    }
+}
+
+fn url_decode(bytes: &[u8]) -> Vec<u8> {
+    fn hex_value(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                match (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                    (Some(high), Some(low)) => {
+                        decoded.push(high << 4 | low);
+                        i += 3;
+                        continue;
+                    }
+                    _ => decoded.push(b'%'),
+                }
+            }
+            b'+' => decoded.push(b' '),
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    decoded
+}
+
+/// The components of an URL in the form of
+/// `scheme://user_info@host:port/path?query#fragment`.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct UrlParts<'a> {
+    scheme: &'a str,
+    user_info: &'a str,
+    host: &'a str,
+    port: &'a str,
+    path: &'a str,
+    query: &'a str,
+    fragment: &'a str,
+}
+
+impl<'a> UrlParts<'a> {
+    fn parse(url: &'a str) -> UrlParts<'a> {
+        let mut parts = UrlParts::default();
+        let mut rest = url.trim();
+
+        if let Some((fragment_start, fragment)) = rest.split_once('#') {
+            parts.fragment = fragment;
+            rest = fragment_start;
+        }
+        if let Some((query_start, query)) = rest.split_once('?') {
+            parts.query = query;
+            rest = query_start;
+        }
+
+        // A URL without scheme like `example.com/path` still starts with the authority,
+        // except an absolute path. The query and fragment are already split off, so the
+        // scheme separator only counts before the first `/`, e.g. not in `a.com/r/http://b`.
+        let scheme_end = rest.find("://").filter(|pos| !rest[..*pos].contains('/'));
+        if let Some(pos) = scheme_end {
+            parts.scheme = &rest[..pos];
+            rest = &rest[pos + 3..];
+        } else if let Some(stripped) = rest.strip_prefix("//") {
+            rest = stripped;
+        } else if rest.starts_with('/') {
+            parts.path = rest;
+            return parts;
+        }
+
+        let (authority, path) = match rest.find('/') {
+            Some(pos) => rest.split_at(pos),
+            None => (rest, ""),
+        };
+        parts.path = path;
+
+        let host_port = match authority.rsplit_once('@') {
+            Some((user_info, host_port)) => {
+                parts.user_info = user_info;
+                host_port
+            }
+            None => authority,
+        };
+        // IPv6 literal host, e.g. `[::1]:8080`.
+        if let Some(stripped) = host_port.strip_prefix('[') {
+            if let Some((host, port)) = stripped.split_once(']') {
+                parts.host = host;
+                parts.port = port.strip_prefix(':').unwrap_or("");
+                return parts;
+            }
+        }
+        match host_port.rsplit_once(':') {
+            Some((host, port)) => {
+                parts.host = host;
+                parts.port = port;
+            }
+            None => parts.host = host_port,
+        }
+        parts
+    }
+}
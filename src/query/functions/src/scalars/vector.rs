@@ -12,18 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
+use std::sync::Arc;
+
 use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::ArgType;
 use databend_common_expression::types::ArrayType;
 use databend_common_expression::types::Float32Type;
 use databend_common_expression::types::Float64Type;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::ValueType;
 use databend_common_expression::types::F32;
 use databend_common_expression::types::F64;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionContext;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use databend_common_openai::embedding_in_batches;
+use databend_common_openai::EmbeddingProvider;
+use databend_common_openai::OnnxEmbedding;
 use databend_common_openai::OpenAI;
+use databend_common_openai::AI_PROVIDER_ONNX;
+use databend_common_openai::AI_PROVIDER_OPENAI;
 use databend_common_vector::cosine_distance;
 use databend_common_vector::cosine_distance_64;
 use databend_common_vector::l2_distance;
@@ -128,55 +144,13 @@ pub fn register(registry: &mut FunctionRegistry) {
     );
 
     // embedding_vector
-    // This function takes two strings as input, sends an API request to OpenAI, and returns the Float32 array of embeddings.
-    // The OpenAI API key is pre-configured during the binder phase, so we rewrite this function and set the API key.
+    // This function takes a string as input, sends API requests to OpenAI (or an OpenAI-compatible server),
+    // or runs an in-process ONNX model, and returns the Float32 array of embeddings.
+    // The rows are batched into requests of at most `openai_api_embedding_batch_size` inputs.
     registry.register_passthrough_nullable_1_arg::<StringType, ArrayType<Float32Type>, _, _>(
         "ai_embedding_vector",
         |_, _| FunctionDomain::MayThrow,
-        vectorize_with_builder_1_arg::<StringType, ArrayType<Float32Type>>(|data, output, ctx| {
-            if let Some(validity) = &ctx.validity {
-                if !validity.get_bit(output.len()) {
-                    output.push(vec![F32::from(0.0)].into());
-                    return;
-                }
-            }
-
-            if ctx.func_ctx.openai_api_key.is_empty() {
-                ctx.set_error(output.len(), "openai_api_key is empty".to_string());
-                output.push(vec![F32::from(0.0)].into());
-                return;
-            }
-            let api_base = ctx.func_ctx.openai_api_embedding_base_url.clone();
-            let api_key = ctx.func_ctx.openai_api_key.clone();
-            let api_version = ctx.func_ctx.openai_api_version.clone();
-            let embedding_model = ctx.func_ctx.openai_api_embedding_model.clone();
-            let completion_model = ctx.func_ctx.openai_api_completion_model.clone();
-
-            let openai = OpenAI::create(
-                api_base,
-                api_key,
-                api_version,
-                embedding_model,
-                completion_model,
-            );
-            let result = openai.embedding_request(&[data.to_string()]);
-            match result {
-                Ok((embeddings, _)) => {
-                    let result = embeddings[0]
-                        .iter()
-                        .map(|x| F32::from(*x))
-                        .collect::<Vec<F32>>();
-                    output.push(result.into());
-                }
-                Err(e) => {
-                    ctx.set_error(
-                        output.len(),
-                        format!("openai embedding request error:{:?}", e),
-                    );
-                    output.push(vec![F32::from(0.0)].into());
-                }
-            }
-        }),
+        embedding_vector,
     );
 
     // text_completion
@@ -194,12 +168,13 @@ pub fn register(registry: &mut FunctionRegistry) {
                 }
             }
 
-            if ctx.func_ctx.openai_api_key.is_empty() {
-                ctx.set_error(output.len(), "openai_api_key is empty".to_string());
+            if !ctx.func_ctx.ai_provider_error.is_empty() {
+                ctx.set_error(output.len(), ctx.func_ctx.ai_provider_error.clone());
                 output.put_str("");
                 output.commit_row();
                 return;
             }
+
             let api_base = ctx.func_ctx.openai_api_chat_base_url.clone();
             let api_key = ctx.func_ctx.openai_api_key.clone();
             let api_version = ctx.func_ctx.openai_api_version.clone();
@@ -213,6 +188,12 @@ pub fn register(registry: &mut FunctionRegistry) {
                 embedding_model,
                 completion_model,
             );
+            if let Err(e) = openai.check_api_key() {
+                ctx.set_error(output.len(), e.message());
+                output.put_str("");
+                output.commit_row();
+                return;
+            }
             let result = openai.completion_text_request(data.to_string());
             match result {
                 Ok((resp, _)) => {
@@ -230,3 +211,77 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+fn embedding_vector(
+    arg: ValueRef<StringType>,
+    ctx: &mut EvalContext,
+) -> Value<ArrayType<Float32Type>> {
+    let inputs: Vec<&str> = match &arg {
+        ValueRef::Scalar(data) => vec![*data],
+        ValueRef::Column(col) => StringType::iter_column(col).collect(),
+    };
+
+    // Skip the NULL rows, they are not sent to the provider.
+    let rows = (0..inputs.len())
+        .filter(|row| match &ctx.validity {
+            Some(validity) => validity.get_bit(*row),
+            None => true,
+        })
+        .collect::<Vec<_>>();
+
+    let mut embeddings: Vec<Option<Vec<F32>>> = vec![None; inputs.len()];
+    match embedding_provider(ctx.func_ctx) {
+        Ok(provider) => {
+            let input = rows.iter().map(|row| inputs[*row]).collect::<Vec<_>>();
+            let batch_size = ctx.func_ctx.openai_api_embedding_batch_size as usize;
+            let results = embedding_in_batches(provider.as_ref(), &input, batch_size);
+            for (row, result) in rows.iter().zip(results) {
+                match result {
+                    Ok(vector) => {
+                        embeddings[*row] = Some(vector.into_iter().map(F32::from).collect());
+                    }
+                    Err(e) => ctx.set_error(*row, e.message()),
+                }
+            }
+        }
+        Err(e) => {
+            for row in &rows {
+                ctx.set_error(*row, e.message());
+            }
+        }
+    }
+
+    let generics = &(ctx.generics.to_owned());
+    let mut builder = ArrayType::<Float32Type>::create_builder(inputs.len(), generics);
+    for embedding in embeddings {
+        let embedding = embedding.unwrap_or_else(|| vec![F32::from(0.0)]);
+        builder.push(embedding.into());
+    }
+
+    match arg {
+        ValueRef::Scalar(_) => Value::Scalar(ArrayType::<Float32Type>::build_scalar(builder)),
+        ValueRef::Column(_) => Value::Column(ArrayType::<Float32Type>::build_column(builder)),
+    }
+}
+
+fn embedding_provider(func_ctx: &FunctionContext) -> Result<Arc<dyn EmbeddingProvider>> {
+    if !func_ctx.ai_provider_error.is_empty() {
+        return Err(ErrorCode::BadArguments(func_ctx.ai_provider_error.clone()));
+    }
+    match func_ctx.ai_embedding_provider.as_str() {
+        AI_PROVIDER_OPENAI => Ok(Arc::new(OpenAI::create(
+            func_ctx.openai_api_embedding_base_url.clone(),
+            func_ctx.openai_api_key.clone(),
+            func_ctx.openai_api_version.clone(),
+            func_ctx.openai_api_embedding_model.clone(),
+            func_ctx.openai_api_completion_model.clone(),
+        ))),
+        AI_PROVIDER_ONNX => {
+            let model = OnnxEmbedding::load(Path::new(&func_ctx.ai_onnx_embedding_model_path))?;
+            Ok(model)
+        }
+        other => Err(ErrorCode::InvalidConfig(format!(
+            "unknown ai_embedding_provider '{other}', expect '{AI_PROVIDER_OPENAI}' or '{AI_PROVIDER_ONNX}'"
+        ))),
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_functions::aggregates::SpaceSaving;
+
+#[test]
+fn test_exact_when_not_full() {
+    let mut sketch = SpaceSaving::new(6);
+    for (key, times) in [(1, 5), (2, 3), (3, 1)] {
+        for _ in 0..times {
+            sketch.add(key);
+        }
+    }
+
+    assert_eq!(sketch.top_k(2), vec![(1, 5), (2, 3)]);
+    assert_eq!(sketch.top_k(5), vec![(1, 5), (2, 3), (3, 1)]);
+}
+
+#[test]
+fn test_heavy_hitters_survive_eviction() {
+    let mut sketch = SpaceSaving::new(4);
+    for i in 0..1000u64 {
+        sketch.add(i);
+        if i % 2 == 0 {
+            sketch.add(10000);
+        }
+        if i % 4 == 0 {
+            sketch.add(20000);
+        }
+    }
+
+    let top = sketch.top_k(2);
+    assert_eq!(top[0].0, 10000);
+    assert_eq!(top[1].0, 20000);
+    // The counts are never under-estimated.
+    assert!(top[0].1 >= 500);
+    assert!(top[1].1 >= 250);
+}
+
+#[test]
+fn test_merge() {
+    let mut left = SpaceSaving::new(4);
+    let mut right = SpaceSaving::new(4);
+    for i in 0..100u64 {
+        left.add(i % 3);
+        right.add(i % 2);
+    }
+    left.merge(&right);
+
+    // 0: 34 + 50, 1: 33 + 50, 2: 33
+    assert_eq!(left.top_k(3), vec![(0, 84), (1, 83), (2, 33)]);
+}
+
+#[test]
+fn test_ties_broken_by_key() {
+    let mut forward = SpaceSaving::new(8);
+    let mut backward = SpaceSaving::new(8);
+    for key in 0..6u64 {
+        forward.add(key);
+        backward.add(5 - key);
+    }
+
+    assert_eq!(forward.top_k(3), vec![(0, 1), (1, 1), (2, 1)]);
+    assert_eq!(backward.top_k(3), forward.top_k(3));
+}
@@ -14,6 +14,8 @@
 
 mod agg;
 mod agg_hashtable;
+mod approx_top_k;
+mod sketch;
 
 use std::io::Write;
 
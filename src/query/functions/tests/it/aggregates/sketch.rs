@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::aggregates::decode_hll_sketch;
+use databend_common_functions::aggregates::decode_tdigest_sketch;
+use databend_common_functions::aggregates::encode_hll_sketch;
+use databend_common_functions::aggregates::encode_tdigest_sketch;
+use databend_common_functions::aggregates::HllSketch;
+use databend_common_functions::aggregates::QuantileTDigestState;
+use databend_common_functions::aggregates::HLL_SKETCH_P;
+use twox_hash::XxHash64;
+
+#[test]
+fn test_hll_sketch_roundtrip() {
+    let mut hll = HllSketch::default();
+    for i in 0..1000u64 {
+        hll.add_scalar(&ScalarRef::Number(NumberScalar::UInt64(i)));
+    }
+    let mut buf = vec![];
+    encode_hll_sketch(&hll, &mut buf);
+    assert_eq!(&buf[0..4], b"DBSK");
+    // the kind and the version.
+    assert_eq!(buf[4], 1);
+    assert_eq!(buf[5], 1);
+
+    // 1000 values are encoded as sparse registers.
+    assert_eq!(buf[7], 1);
+    let decoded = decode_hll_sketch(&buf).unwrap();
+    assert_eq!(decoded, hll);
+
+    for i in 1000..100000u64 {
+        hll.add_scalar(&ScalarRef::Number(NumberScalar::UInt64(i)));
+    }
+    let mut buf = vec![];
+    encode_hll_sketch(&hll, &mut buf);
+    assert_eq!(buf[7], 0);
+    assert_eq!(buf.len(), 6 + 2 + (1 << HLL_SKETCH_P));
+    let decoded = decode_hll_sketch(&buf).unwrap();
+    assert_eq!(decoded, hll);
+    let count = decoded.count() as f64;
+    assert!((count - 100000.0).abs() / 100000.0 < 0.05, "{count}");
+}
+
+#[test]
+fn test_hll_sketch_canonical_hash() {
+    let mut hll = HllSketch::default();
+    hll.add_scalar(&ScalarRef::Number(NumberScalar::UInt64(0x0102)));
+    let mut expected = HllSketch::default();
+    let mut hasher = XxHash64::with_seed(0);
+    hasher.write(&[2, 1, 0, 0, 0, 0, 0, 0]);
+    expected.add_hash(hasher.finish());
+    assert_eq!(hll, expected);
+
+    let mut hll = HllSketch::default();
+    hll.add_scalar(&ScalarRef::String("databend"));
+    let mut expected = HllSketch::default();
+    expected.add_scalar(&ScalarRef::Binary(b"databend"));
+    assert_eq!(hll, expected);
+
+    // -0.0 equals 0.0 and all the NaNs are equal.
+    let mut hll = HllSketch::default();
+    hll.add_scalar(&ScalarRef::Number(NumberScalar::Float64((-0.0).into())));
+    hll.add_scalar(&ScalarRef::Number(NumberScalar::Float64(f64::NAN.into())));
+    hll.add_scalar(&ScalarRef::Number(NumberScalar::Float64(
+        (-f64::NAN).into(),
+    )));
+    let mut expected = HllSketch::default();
+    expected.add_scalar(&ScalarRef::Number(NumberScalar::Float64(0.0.into())));
+    expected.add_scalar(&ScalarRef::Number(NumberScalar::Float64(f64::NAN.into())));
+    assert_eq!(hll, expected);
+}
+
+#[test]
+fn test_hll_sketch_layout() {
+    let mut hll = HllSketch::default();
+    hll.add_hash(0b11 << 62);
+    let mut buf = vec![];
+    encode_hll_sketch(&hll, &mut buf);
+    // the top 14 bits are the register index, the rank of the
+    // remaining zeros is capped by the sentinel bit.
+    assert_eq!(buf, vec![
+        b'D', b'B', b'S', b'K', 1, 1, 14, 1, 1, 0, 0, 0, 0x00, 0x30, 51
+    ]);
+}
+
+#[test]
+fn test_tdigest_sketch_roundtrip() {
+    let mut tdigest = QuantileTDigestState::new();
+    for i in 1..=1000 {
+        tdigest.add(i as f64, None);
+    }
+    let mut buf = vec![];
+    encode_tdigest_sketch(&mut tdigest, &mut buf);
+    assert_eq!(&buf[6..10], &100u32.to_le_bytes());
+
+    let mut decoded = decode_tdigest_sketch(&buf).unwrap();
+    for level in [0.0, 0.25, 0.5, 0.99, 1.0] {
+        assert_eq!(decoded.quantile(level), tdigest.quantile(level));
+    }
+
+    // truncated centroids.
+    assert!(decode_tdigest_sketch(&buf[..buf.len() - 1]).is_err());
+}
+
+#[test]
+fn test_tdigest_merge_keeps_pending_weights() {
+    // the lhs still has unmerged values when the rhs is merged into it.
+    let mut lhs = QuantileTDigestState::new();
+    let mut rhs = QuantileTDigestState::new();
+    for i in 1..=10 {
+        lhs.add(i as f64, None);
+        rhs.add((i + 10) as f64, None);
+    }
+    lhs.merge(&mut rhs).unwrap();
+
+    let (_, _, _, _, weights) = lhs.centroids();
+    assert_eq!(weights.iter().sum::<f64>(), 20.0);
+    assert_eq!(lhs.quantile(1.0), 20.0);
+    assert!((lhs.quantile(0.5) - 10.5).abs() <= 0.5);
+}
+
+#[test]
+fn test_invalid_sketch() {
+    let mut buf = vec![];
+    encode_tdigest_sketch(&mut QuantileTDigestState::new(), &mut buf);
+    // a tdigest sketch is not a hll sketch.
+    assert!(decode_hll_sketch(&buf).is_err());
+    assert!(decode_tdigest_sketch(&buf).is_ok());
+
+    // unknown version.
+    buf[5] = 2;
+    assert!(decode_tdigest_sketch(&buf).is_err());
+
+    assert!(decode_hll_sketch(b"DBSK").is_err());
+}
@@ -19,6 +19,7 @@ mod connection;
 mod file_format;
 mod network_policy;
 mod password_policy;
+mod plan_baseline;
 mod quota;
 mod role;
 mod serde;
@@ -39,6 +40,8 @@ pub use network_policy::NetworkPolicyApi;
 pub use network_policy::NetworkPolicyMgr;
 pub use password_policy::PasswordPolicyApi;
 pub use password_policy::PasswordPolicyMgr;
+pub use plan_baseline::PlanBaselineApi;
+pub use plan_baseline::PlanBaselineMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
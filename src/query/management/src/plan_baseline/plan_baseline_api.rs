@@ -0,0 +1,45 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::principal::PlanBaselineUsage;
+use databend_common_meta_app::schema::CreateOption;
+
+#[async_trait::async_trait]
+pub trait PlanBaselineApi: Sync + Send {
+    /// Add a plan baseline keyed by its fingerprint.
+    async fn add(&self, baseline: PlanBaseline, create_option: &CreateOption) -> Result<()>;
+
+    /// Get the plan baseline of a fingerprint, returns `None` if there is no such baseline.
+    async fn get(&self, fingerprint: &str) -> Result<Option<PlanBaseline>>;
+
+    /// Get all the plan baselines of tenant.
+    async fn list(&self) -> Result<Vec<PlanBaseline>>;
+
+    /// Get the fingerprints of all the plan baselines of tenant, along with the seq of each
+    /// baseline, which changes when the baseline is replaced.
+    async fn list_fingerprints(&self) -> Result<HashMap<String, u64>>;
+
+    /// Drop the plan baseline of a fingerprint, along with its usage.
+    async fn remove(&self, fingerprint: &str) -> Result<()>;
+
+    /// Add `usage` to the usage of the plan baseline of a fingerprint.
+    async fn add_usage(&self, fingerprint: &str, usage: PlanBaselineUsage) -> Result<()>;
+
+    /// Get the usages of all the plan baselines of tenant, keyed by fingerprint.
+    async fn list_usages(&self) -> Result<HashMap<String, PlanBaselineUsage>>;
+}
@@ -0,0 +1,210 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::principal::PlanBaselineIdent;
+use databend_common_meta_app::principal::PlanBaselineUsage;
+use databend_common_meta_app::principal::PlanBaselineUsageIdent;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::Key;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::NonEmptyString;
+use databend_common_meta_types::Operation;
+
+use crate::plan_baseline::PlanBaselineApi;
+
+const MAX_ADD_USAGE_RETRIES: usize = 10;
+
+pub struct PlanBaselineMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: Tenant,
+}
+
+impl PlanBaselineMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &NonEmptyString,
+    ) -> Self {
+        PlanBaselineMgr {
+            kv_api,
+            tenant: Tenant::new_nonempty(tenant.clone()),
+        }
+    }
+
+    fn baseline_key(&self, fingerprint: &str) -> String {
+        PlanBaselineIdent::new(self.tenant.clone(), fingerprint).to_string_key()
+    }
+
+    fn baseline_prefix(&self) -> String {
+        PlanBaselineIdent::new(self.tenant.clone(), "").to_string_key()
+    }
+
+    fn usage_key(&self, fingerprint: &str) -> String {
+        PlanBaselineUsageIdent::new(self.tenant.clone(), fingerprint).to_string_key()
+    }
+
+    fn usage_prefix(&self) -> String {
+        PlanBaselineUsageIdent::new(self.tenant.clone(), "").to_string_key()
+    }
+}
+
+#[async_trait::async_trait]
+impl PlanBaselineApi for PlanBaselineMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add(&self, baseline: PlanBaseline, create_option: &CreateOption) -> Result<()> {
+        let key = self.baseline_key(&baseline.fingerprint);
+        let seq = MatchSeq::from(*create_option);
+        let val = Operation::Update(serde_json::to_vec(&baseline)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None))
+            .await?;
+
+        if let CreateOption::Create = create_option {
+            if res.prev.is_some() {
+                return Err(ErrorCode::PlanBaselineAlreadyExists(format!(
+                    "Plan baseline for '{}' already exists.",
+                    baseline.query
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get(&self, fingerprint: &str) -> Result<Option<PlanBaseline>> {
+        let key = self.baseline_key(fingerprint);
+        match self.kv_api.get_kv(&key).await? {
+            Some(value) => Ok(Some(serde_json::from_slice(&value.data)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn list(&self) -> Result<Vec<PlanBaseline>> {
+        let prefix = self.baseline_prefix();
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut baselines = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            baselines.push(serde_json::from_slice::<PlanBaseline>(&value.data)?);
+        }
+        Ok(baselines)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn list_fingerprints(&self) -> Result<HashMap<String, u64>> {
+        let prefix = self.baseline_prefix();
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut fingerprints = HashMap::with_capacity(values.len());
+        for (_, value) in values {
+            let baseline = serde_json::from_slice::<PlanBaseline>(&value.data)?;
+            fingerprints.insert(baseline.fingerprint, value.seq);
+        }
+        Ok(fingerprints)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn remove(&self, fingerprint: &str) -> Result<()> {
+        let key = self.baseline_key(fingerprint);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(1),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+
+        if res.prev.is_none() {
+            return Err(ErrorCode::UnknownPlanBaseline(format!(
+                "Plan baseline '{}' does not exist.",
+                fingerprint
+            )));
+        }
+
+        let key = self.usage_key(fingerprint);
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_usage(&self, fingerprint: &str, usage: PlanBaselineUsage) -> Result<()> {
+        let key = self.usage_key(fingerprint);
+        // The usage is added by every node of the cluster, retry on conflicts.
+        for _ in 0..MAX_ADD_USAGE_RETRIES {
+            let (seq, mut total) = match self.kv_api.get_kv(&key).await? {
+                Some(value) => (
+                    value.seq,
+                    serde_json::from_slice::<PlanBaselineUsage>(&value.data)?,
+                ),
+                None => (0, PlanBaselineUsage::default()),
+            };
+            total.merge(&usage);
+            let val = Operation::Update(serde_json::to_vec(&total)?);
+            let res = self
+                .kv_api
+                .upsert_kv(UpsertKVReq::new(&key, MatchSeq::Exact(seq), val, None))
+                .await?;
+            if res.is_changed() {
+                return Ok(());
+            }
+        }
+        Err(ErrorCode::Internal(format!(
+            "Failed to add the usage of plan baseline '{}', it is updated concurrently",
+            fingerprint
+        )))
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn list_usages(&self) -> Result<HashMap<String, PlanBaselineUsage>> {
+        let prefix = self.usage_prefix();
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut usages = HashMap::with_capacity(values.len());
+        for (key, value) in values {
+            let ident = PlanBaselineUsageIdent::from_str_key(&key)
+                .map_err(|e| ErrorCode::MetaServiceError(e.to_string()))?;
+            let usage = serde_json::from_slice::<PlanBaselineUsage>(&value.data)?;
+            usages.insert(ident.name().to_string(), usage);
+        }
+        Ok(usages)
+    }
+}
@@ -15,6 +15,7 @@
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_exception::Result;
 use databend_common_storages_stream::stream_table::StreamTable;
+use databend_storages_common_txn::TempTblMgr;
 use databend_storages_common_txn::TxnManager;
 use log::debug;
 use poem::web::Json;
@@ -40,12 +41,27 @@ async fn check_stream_status(
     tenant: &str,
     params: Query<StreamStatusQuery>,
 ) -> Result<StreamStatusResponse> {
-    let catalog = CatalogManager::instance().get_default_catalog(TxnManager::init())?;
+    let catalog =
+        CatalogManager::instance().get_default_catalog(TxnManager::init(), TempTblMgr::init())?;
     let db_name = params.database.clone().unwrap_or("default".to_string());
     let tbl = catalog
         .get_table(tenant, &db_name, &params.stream_name)
         .await?;
     let stream = StreamTable::try_from_table(tbl.as_ref())?;
+    // The data of an external table changes without updating its meta.
+    if let Some(source_version) = stream.source_version() {
+        let source_table = catalog
+            .get_table(
+                tenant,
+                stream.source_table_database(),
+                stream.source_table_name(),
+            )
+            .await?;
+        return Ok(StreamStatusResponse {
+            has_data: source_table.changes_version().await? != source_version,
+            params: params.0,
+        });
+    }
     let (base_table_ident, _) = catalog
         .get_table_meta_by_id(stream.source_table_id())
         .await?;
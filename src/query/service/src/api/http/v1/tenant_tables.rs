@@ -17,6 +17,7 @@ use chrono::Utc;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
+use databend_storages_common_txn::TempTblMgr;
 use databend_storages_common_txn::TxnManager;
 use poem::web::Json;
 use poem::web::Path;
@@ -47,7 +48,8 @@ pub struct TenantTableInfo {
 }
 
 async fn load_tenant_tables(tenant: &str) -> Result<TenantTablesResponse> {
-    let catalog = CatalogManager::instance().get_default_catalog(TxnManager::init())?;
+    let catalog =
+        CatalogManager::instance().get_default_catalog(TxnManager::init(), TempTblMgr::init())?;
     let databases = catalog.list_databases(tenant).await?;
 
     let mut table_infos: Vec<TenantTableInfo> = vec![];
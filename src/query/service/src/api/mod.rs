@@ -16,6 +16,9 @@
 
 pub use http_service::HttpService;
 pub use rpc::serialize_block;
+pub use rpc::AdaptiveBroadcastFlightScatter;
+pub use rpc::AdaptiveJoinExchange;
+pub use rpc::AdaptiveShuffleFlightScatter;
 pub use rpc::BroadcastExchange;
 pub use rpc::BroadcastFlightScatter;
 pub use rpc::ConnectionInfo;
@@ -42,6 +45,7 @@ pub use rpc::MergeExchange;
 pub use rpc::MergeExchangeParams;
 pub use rpc::Packet;
 pub use rpc::QueryFragmentsPlanPacket;
+pub use rpc::RuntimeFilterPacket;
 pub use rpc::ShuffleDataExchange;
 pub use rpc::ShuffleExchangeParams;
 pub use rpc::TransformExchangeDeserializer;
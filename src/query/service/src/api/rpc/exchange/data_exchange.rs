@@ -19,6 +19,7 @@ pub enum DataExchange {
     Merge(MergeExchange),
     Broadcast(BroadcastExchange),
     ShuffleDataExchange(ShuffleDataExchange),
+    AdaptiveJoin(AdaptiveJoinExchange),
 }
 
 impl DataExchange {
@@ -27,6 +28,7 @@ impl DataExchange {
             DataExchange::Merge(exchange) => vec![exchange.destination_id.clone()],
             DataExchange::Broadcast(exchange) => exchange.destination_ids.clone(),
             DataExchange::ShuffleDataExchange(exchange) => exchange.destination_ids.clone(),
+            DataExchange::AdaptiveJoin(exchange) => exchange.destination_ids.clone(),
         }
     }
 }
@@ -77,3 +79,28 @@ impl BroadcastExchange {
         DataExchange::Broadcast(BroadcastExchange { destination_ids })
     }
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AdaptiveJoinExchange {
+    pub destination_ids: Vec<String>,
+    pub shuffle_keys: Vec<RemoteExpr>,
+    pub join_id: u32,
+    // Whether the exchange sends the build side of the adaptive join
+    pub build_side: bool,
+}
+
+impl AdaptiveJoinExchange {
+    pub fn create(
+        destination_ids: Vec<String>,
+        shuffle_keys: Vec<RemoteExpr>,
+        join_id: u32,
+        build_side: bool,
+    ) -> DataExchange {
+        DataExchange::AdaptiveJoin(AdaptiveJoinExchange {
+            destination_ids,
+            shuffle_keys,
+            join_id,
+            build_side,
+        })
+    }
+}
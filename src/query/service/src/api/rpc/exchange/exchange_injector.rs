@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_settings::FlightCompression;
@@ -24,6 +25,8 @@ use crate::api::rpc::exchange::serde::exchange_deserializer::TransformExchangeDe
 use crate::api::rpc::exchange::serde::exchange_serializer::TransformExchangeSerializer;
 use crate::api::rpc::exchange::serde::exchange_serializer::TransformScatterExchangeSerializer;
 use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::api::AdaptiveBroadcastFlightScatter;
+use crate::api::AdaptiveShuffleFlightScatter;
 use crate::api::BroadcastFlightScatter;
 use crate::api::DataExchange;
 use crate::api::ExchangeSorting;
@@ -91,6 +94,41 @@ impl ExchangeInjector for DefaultExchangeInjector {
                 exchange.shuffle_keys.clone(),
                 exchange.destination_ids.len(),
             )?,
+            DataExchange::AdaptiveJoin(exchange) => {
+                let hash_scatter = HashFlightScatter::try_create(
+                    ctx.get_function_context()?,
+                    exchange.shuffle_keys.clone(),
+                    exchange.destination_ids.len(),
+                )?;
+
+                match exchange.build_side {
+                    true => Box::new(AdaptiveBroadcastFlightScatter::try_create(
+                        exchange.destination_ids.len(),
+                        ctx.get_settings().get_adaptive_join_broadcast_threshold()?,
+                        hash_scatter,
+                    )?),
+                    false => {
+                        let local_id = ctx.get_cluster().local_id();
+                        let local_pos = exchange
+                            .destination_ids
+                            .iter()
+                            .position(|id| id == &local_id)
+                            .ok_or_else(|| {
+                                ErrorCode::Internal(format!(
+                                    "Cannot find local executor {} in adaptive join exchange",
+                                    local_id
+                                ))
+                            })?;
+
+                        Box::new(AdaptiveShuffleFlightScatter::try_create(
+                            exchange.destination_ids.len(),
+                            local_pos,
+                            ctx.get_adaptive_join_decision(exchange.join_id),
+                            hash_scatter,
+                        )?)
+                    }
+                }
+            }
         }))
     }
 
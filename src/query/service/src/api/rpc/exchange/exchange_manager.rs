@@ -32,6 +32,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_grpc::ConnectionFactory;
 use databend_common_sql::executor::PhysicalPlan;
+use log::warn;
 use minitrace::prelude::*;
 use parking_lot::Mutex;
 use parking_lot::ReentrantMutex;
@@ -55,8 +56,10 @@ use crate::api::FlightClient;
 use crate::api::FragmentPlanPacket;
 use crate::api::InitNodesChannelPacket;
 use crate::api::QueryFragmentsPlanPacket;
+use crate::api::RuntimeFilterPacket;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::transforms::PartialRuntimeFilter;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::schedulers::QueryFragmentActions;
@@ -97,6 +100,96 @@ impl DataExchangeManager {
         )))
     }
 
+    /// Send the partial runtime filters built by current node to the node with the smallest id,
+    /// which merges the filters of all nodes and sends the merged filters back to the others.
+    /// Each filter is sent twice instead of to every pair of nodes.
+    pub fn broadcast_runtime_filters(
+        &self,
+        ctx: &Arc<QueryContext>,
+        join_id: u32,
+        filters: Vec<PartialRuntimeFilter>,
+    ) -> Result<()> {
+        let cluster = ctx.get_cluster();
+        let merge_node = cluster.nodes.iter().min_by(|lhs, rhs| lhs.id.cmp(&rhs.id));
+        match merge_node {
+            Some(node_info) if node_info.id != cluster.local_id => {
+                let packet = RuntimeFilterPacket::create(
+                    ctx.get_id(),
+                    join_id,
+                    filters,
+                    false,
+                    node_info.clone(),
+                );
+                Self::send_runtime_filters(ctx, packet)
+            }
+            _ => Self::merge_runtime_filters(ctx, join_id, filters),
+        }
+    }
+
+    // Add the partial filters of one node on the merge node, the merged filters are applied
+    // and sent to the other nodes once the filters of all nodes are received.
+    fn merge_runtime_filters(
+        ctx: &Arc<QueryContext>,
+        join_id: u32,
+        filters: Vec<PartialRuntimeFilter>,
+    ) -> Result<()> {
+        let Some(merged) = ctx.add_partial_runtime_filters(join_id, filters)? else {
+            return Ok(());
+        };
+        let cluster = ctx.get_cluster();
+        for node_info in &cluster.nodes {
+            if node_info.id == cluster.local_id {
+                continue;
+            }
+            let packet = RuntimeFilterPacket::create(
+                ctx.get_id(),
+                join_id,
+                merged.clone(),
+                true,
+                node_info.clone(),
+            );
+            Self::send_runtime_filters(ctx, packet)?;
+        }
+        ctx.set_merged_runtime_filters(merged)
+    }
+
+    fn send_runtime_filters(ctx: &Arc<QueryContext>, packet: RuntimeFilterPacket) -> Result<()> {
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
+        GlobalIORuntime::instance().spawn(ctx.get_id(), async move {
+            let config = GlobalConfig::instance();
+            if let Err(cause) = packet.commit(config.as_ref(), timeout).await {
+                warn!(
+                    "Failed to send runtime filters of join {} to node {}, cause: {:?}",
+                    packet.join_id, packet.executor.id, cause
+                );
+            }
+        });
+        Ok(())
+    }
+
+    /// Receive the partial runtime filters on the merge node, or the merged ones on the others.
+    pub fn set_runtime_filters(&self, packet: RuntimeFilterPacket) -> Result<()> {
+        let query_ctx = {
+            let queries_coordinator_guard = self.queries_coordinator.lock();
+            let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+            match queries_coordinator
+                .get(&packet.query_id)
+                .and_then(|coordinator| coordinator.info.as_ref())
+            {
+                Some(info) => info.query_ctx.clone(),
+                // The query has finished on current node, the runtime filters are useless.
+                None => return Ok(()),
+            }
+        };
+
+        if packet.merged {
+            query_ctx.set_merged_runtime_filters(packet.filters)
+        } else {
+            Self::merge_runtime_filters(&query_ctx, packet.join_id, packet.filters)
+        }
+    }
+
     pub fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>> {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
@@ -824,6 +917,18 @@ impl FragmentCoordinator {
                             .flight_scatter(&info.query_ctx, data_exchange)?,
                     }))
                 }
+                DataExchange::AdaptiveJoin(exchange) => {
+                    Ok(ExchangeParams::ShuffleExchange(ShuffleExchangeParams {
+                        exchange_injector: exchange_injector.clone(),
+                        schema: self.physical_plan.output_schema()?,
+                        fragment_id: self.fragment_id,
+                        query_id: info.query_id.to_string(),
+                        executor_id: info.current_executor.to_string(),
+                        destination_ids: exchange.destination_ids.to_owned(),
+                        shuffle_scatter: exchange_injector
+                            .flight_scatter(&info.query_ctx, data_exchange)?,
+                    }))
+                }
             };
         }
 
@@ -28,6 +28,7 @@ mod serde;
 mod statistics_receiver;
 mod statistics_sender;
 
+pub use data_exchange::AdaptiveJoinExchange;
 pub use data_exchange::BroadcastExchange;
 pub use data_exchange::DataExchange;
 pub use data_exchange::MergeExchange;
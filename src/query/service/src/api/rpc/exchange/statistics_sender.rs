@@ -148,7 +148,7 @@ impl StatisticsSender {
     #[async_backtrace::framed]
     async fn send_copy_status(ctx: &Arc<QueryContext>, flight_sender: &FlightSender) -> Result<()> {
         let copy_status = ctx.get_copy_status();
-        if !copy_status.files.is_empty() {
+        if !copy_status.files.is_empty() || !copy_status.check_violations.is_empty() {
             let data_packet = DataPacket::CopyStatus(copy_status.as_ref().to_owned());
             flight_sender.send(data_packet).await?;
         }
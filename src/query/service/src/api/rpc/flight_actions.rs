@@ -20,6 +20,7 @@ use databend_common_exception::ToErrorCode;
 use tonic::Status;
 
 use crate::api::rpc::packets::KillQueryPacket;
+use crate::api::rpc::packets::RuntimeFilterPacket;
 use crate::api::rpc::packets::TruncateTablePacket;
 use crate::api::InitNodesChannelPacket;
 use crate::api::QueryFragmentsPlanPacket;
@@ -132,6 +133,33 @@ impl TryInto<Vec<u8>> for KillQuery {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct SetRuntimeFilters {
+    pub packet: RuntimeFilterPacket,
+}
+
+impl TryInto<SetRuntimeFilters> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<SetRuntimeFilters, Self::Error> {
+        match serde_json::from_slice::<SetRuntimeFilters>(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(action) => Ok(action),
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for SetRuntimeFilters {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(
+            ErrorCode::Internal,
+            || "Logical error: cannot serialize RuntimeFilterPacket.",
+        )
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     InitQueryFragmentsPlan(InitQueryFragmentsPlan),
@@ -139,6 +167,7 @@ pub enum FlightAction {
     ExecutePartialQuery(String),
     TruncateTable(TruncateTable),
     KillQuery(KillQuery),
+    SetRuntimeFilters(SetRuntimeFilters),
 }
 
 impl TryInto<FlightAction> for Action {
@@ -158,6 +187,7 @@ impl TryInto<FlightAction> for Action {
             },
             "TruncateTable" => Ok(FlightAction::TruncateTable(self.body.try_into()?)),
             "KillQuery" => Ok(FlightAction::KillQuery(self.body.try_into()?)),
+            "SetRuntimeFilters" => Ok(FlightAction::SetRuntimeFilters(self.body.try_into()?)),
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -191,6 +221,10 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("KillQuery"),
                 body: kill_query.try_into()?,
             }),
+            FlightAction::SetRuntimeFilters(set_runtime_filters) => Ok(Action {
+                r#type: String::from("SetRuntimeFilters"),
+                body: set_runtime_filters.try_into()?,
+            }),
         }
     }
 }
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use databend_common_base::base::tokio::sync::watch::Receiver;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+
+use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::pipelines::processors::transforms::AdaptiveJoinDecision;
+use crate::pipelines::processors::transforms::AdaptiveJoinMeta;
+
+/// Scatter for the build side of an adaptive join. Blocks are broadcast until the broadcast
+/// bytes exceed the threshold, then they are scattered by hash of the join keys.
+pub struct AdaptiveBroadcastFlightScatter {
+    scattered_size: usize,
+    threshold: usize,
+    broadcast_bytes: AtomicUsize,
+    switched: AtomicBool,
+    hash_scatter: Box<dyn FlightScatter>,
+}
+
+impl AdaptiveBroadcastFlightScatter {
+    pub fn try_create(
+        scattered_size: usize,
+        threshold: usize,
+        hash_scatter: Box<dyn FlightScatter>,
+    ) -> Result<Self> {
+        Ok(AdaptiveBroadcastFlightScatter {
+            scattered_size,
+            threshold,
+            broadcast_bytes: AtomicUsize::new(0),
+            switched: AtomicBool::new(false),
+            hash_scatter,
+        })
+    }
+}
+
+impl FlightScatter for AdaptiveBroadcastFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        if !self.switched.load(Ordering::Acquire) {
+            let block_bytes = data_block.memory_size();
+            let broadcast_bytes = self
+                .broadcast_bytes
+                .fetch_add(block_bytes, Ordering::Relaxed)
+                + block_bytes;
+
+            if broadcast_bytes <= self.threshold {
+                let mut data_blocks = Vec::with_capacity(self.scattered_size);
+                for _ in 0..self.scattered_size {
+                    data_blocks.push(data_block.clone());
+                }
+
+                return Ok(data_blocks);
+            }
+
+            if !self.switched.swap(true, Ordering::AcqRel) {
+                // Every destination receives the marker, even if no rows are scattered to it.
+                let mut data_blocks = self.hash_scatter.execute(data_block)?;
+                for data_block in data_blocks.iter_mut() {
+                    data_block.replace_meta(AdaptiveJoinMeta::create());
+                }
+
+                return Ok(data_blocks);
+            }
+        }
+
+        self.hash_scatter.execute(data_block)
+    }
+}
+
+/// Scatter for the probe side of an adaptive join. Blocks are kept on the local node if the
+/// build side is broadcast, otherwise they are scattered by hash of the join keys.
+pub struct AdaptiveShuffleFlightScatter {
+    scattered_size: usize,
+    local_pos: usize,
+    decision: Receiver<AdaptiveJoinDecision>,
+    hash_scatter: Box<dyn FlightScatter>,
+}
+
+impl AdaptiveShuffleFlightScatter {
+    pub fn try_create(
+        scattered_size: usize,
+        local_pos: usize,
+        decision: Receiver<AdaptiveJoinDecision>,
+        hash_scatter: Box<dyn FlightScatter>,
+    ) -> Result<Self> {
+        Ok(AdaptiveShuffleFlightScatter {
+            scattered_size,
+            local_pos,
+            decision,
+            hash_scatter,
+        })
+    }
+}
+
+impl FlightScatter for AdaptiveShuffleFlightScatter {
+    fn execute(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        // Shuffle is always correct, only keep the data local if the build side is surely broadcast.
+        if *self.decision.borrow() != AdaptiveJoinDecision::Broadcast {
+            return self.hash_scatter.execute(data_block);
+        }
+
+        let mut data_blocks = vec![DataBlock::empty(); self.scattered_size];
+        data_blocks[self.local_pos] = data_block;
+        Ok(data_blocks)
+    }
+}
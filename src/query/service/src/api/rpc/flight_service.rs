@@ -250,6 +250,11 @@ impl FlightService for DatabendQueryFlightService {
                     interpreter.execute2().await?;
                     FlightResult { body: vec![] }
                 }
+                FlightAction::SetRuntimeFilters(set_runtime_filters) => {
+                    DataExchangeManager::instance()
+                        .set_runtime_filters(set_runtime_filters.packet)?;
+                    FlightResult { body: vec![] }
+                }
             };
 
             Ok(RawResponse::new(
@@ -20,6 +20,7 @@ mod exchange;
 mod flight_actions;
 mod flight_client;
 mod flight_scatter;
+mod flight_scatter_adaptive;
 mod flight_scatter_broadcast;
 mod flight_scatter_hash;
 mod flight_service;
@@ -27,6 +28,7 @@ mod packets;
 mod request_builder;
 
 pub use exchange::serialize_block;
+pub use exchange::AdaptiveJoinExchange;
 pub use exchange::BroadcastExchange;
 pub use exchange::DataExchange;
 pub use exchange::DataExchangeManager;
@@ -42,6 +44,8 @@ pub use exchange::ShuffleDataExchange;
 pub use exchange::ShuffleExchangeParams;
 pub use exchange::TransformExchangeDeserializer;
 pub use flight_scatter::FlightScatter;
+pub use flight_scatter_adaptive::AdaptiveBroadcastFlightScatter;
+pub use flight_scatter_adaptive::AdaptiveShuffleFlightScatter;
 pub use flight_scatter_broadcast::BroadcastFlightScatter;
 pub use flight_scatter_hash::HashFlightScatter;
 pub use packets::ConnectionInfo;
@@ -53,4 +57,5 @@ pub use packets::InitNodesChannelPacket;
 pub use packets::KillQueryPacket;
 pub use packets::Packet;
 pub use packets::QueryFragmentsPlanPacket;
+pub use packets::RuntimeFilterPacket;
 pub use packets::TruncateTablePacket;
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_types::NodeInfo;
+
+use crate::api::rpc::flight_actions::SetRuntimeFilters;
+use crate::api::rpc::packets::packet::create_client;
+use crate::api::rpc::Packet;
+use crate::api::FlightAction;
+use crate::pipelines::processors::transforms::PartialRuntimeFilter;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct RuntimeFilterPacket {
+    pub query_id: String,
+    pub join_id: u32,
+    /// The partial filters of the sender, or the merged filters of all nodes if `merged`.
+    pub filters: Vec<PartialRuntimeFilter>,
+    pub merged: bool,
+    pub executor: Arc<NodeInfo>,
+}
+
+impl RuntimeFilterPacket {
+    pub fn create(
+        query_id: String,
+        join_id: u32,
+        filters: Vec<PartialRuntimeFilter>,
+        merged: bool,
+        executor: Arc<NodeInfo>,
+    ) -> RuntimeFilterPacket {
+        RuntimeFilterPacket {
+            query_id,
+            join_id,
+            filters,
+            merged,
+            executor,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Packet for RuntimeFilterPacket {
+    #[async_backtrace::framed]
+    async fn commit(&self, config: &InnerConfig, timeout: u64) -> Result<()> {
+        let executor_info = &self.executor;
+        let mut conn = create_client(config, &executor_info.flight_address).await?;
+        let action = FlightAction::SetRuntimeFilters(SetRuntimeFilters {
+            packet: self.clone(),
+        });
+        conn.execute_action(action, timeout).await
+    }
+}
@@ -26,6 +26,9 @@ use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_sharing::ShareEndpointManager;
+use databend_common_sql::PlanBaselineCache;
+use databend_common_sql::PlanBaselineStats;
+use databend_common_sql::PlanCache;
 use databend_common_storage::DataOperator;
 use databend_common_storage::ShareTableConfig;
 use databend_common_storages_hive::HiveCreator;
@@ -40,6 +43,7 @@ use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::locks::LockManager;
+use crate::pipelines::processors::transforms::SequenceCache;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
@@ -101,6 +105,10 @@ impl GlobalServices {
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
         LockManager::init()?;
+        SequenceCache::init()?;
+        PlanCache::init()?;
+        PlanBaselineCache::init()?;
+        PlanBaselineStats::init()?;
         AuthMgr::init(config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
@@ -80,6 +80,8 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::DropTable(_)
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
+                | Plan::CreateMaterializedView(_)
+                | Plan::DropMaterializedView(_)
                 | Plan::CreateStream(_)
                 | Plan::DropStream(_)
 
@@ -109,6 +111,15 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
                 | Plan::DropPasswordPolicy(_)
+                // Sequence.
+                | Plan::CreateSequence(_)
+                | Plan::DropSequence(_)
+                // Row access policy.
+                | Plan::CreateRowAccessPolicy(_)
+                | Plan::DropRowAccessPolicy(_)
+                // Plan baseline.
+                | Plan::CreatePlanBaseline(_)
+                | Plan::DropPlanBaseline(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
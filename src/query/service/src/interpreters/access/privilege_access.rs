@@ -241,6 +241,16 @@ impl PrivilegeAccess {
                 if catalog.exists_table_function(table_name) {
                     return Ok(());
                 }
+                // temporary tables are only visible to the session which creates them.
+                if self
+                    .ctx
+                    .temp_tbl_mgr()
+                    .lock()
+                    .get_table(db_name, table_name)
+                    .is_some()
+                {
+                    return Ok(());
+                }
                 // to keep compatibility with the legacy privileges which granted by table name,
                 // we'd both check the privileges by name and id.
                 // we'll completely move to the id side in the future.
@@ -616,13 +626,25 @@ impl AccessChecker for PrivilegeAccess {
             Plan::ShowCreateDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Select], false).await?
             }
-            Plan::CreateDatabase(_) => {
+            Plan::CreateDatabase(plan) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::CreateDatabase])
                     .await?;
+                if let Some(clone_from) = &plan.clone_from {
+                    self.validate_db_access(&plan.catalog, &clone_from.database, vec![UserPrivilegeType::Select], false).await?;
+                }
             }
             Plan::DropDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop], plan.if_exists).await?;
             }
+            Plan::BackupDatabase(plan) => {
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Write).await?;
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Select], false).await?;
+            }
+            Plan::RestoreDatabase(plan) => {
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Read).await?;
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::CreateDatabase])
+                    .await?;
+            }
             Plan::UndropDatabase(_)
             | Plan::DropUDF(_)
             | Plan::DropIndex(_)
@@ -711,6 +733,14 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
             }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?;
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::ApplyRowAccessPolicy]).await?
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?;
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::ApplyRowAccessPolicy]).await?
+            }
             Plan::AlterTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
             }
@@ -869,6 +899,16 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropView(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop], plan.if_exists).await?
             }
+            Plan::CreateMaterializedView(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create], false).await?;
+                self.validate_table_access(&plan.catalog, &plan.base_database, &plan.base_table, vec![UserPrivilegeType::Select], false).await?
+            }
+            Plan::DropMaterializedView(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop], plan.if_exists).await?
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, vec![UserPrivilegeType::Insert], false).await?
+            }
             Plan::CreateStream(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create], false).await?
             }
@@ -921,6 +961,7 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::AlterUser(_)
             | Plan::RenameDatabase(_)
+            | Plan::RevertDatabase(_)
             | Plan::RevertTable(_)
             | Plan::AlterUDF(_)
             | Plan::AlterShareTenants(_)
@@ -968,6 +1009,13 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
+            | Plan::DescSequence(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::DescRowAccessPolicy(_)
+            | Plan::DropPlanBaseline(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -988,6 +1036,11 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
             }
+            Plan::CreatePlanBaseline(plan) => {
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
+                    .await?;
+                self.check(ctx, &plan.plan).await?
+            }
             Plan::CreateDatamaskPolicy(_) | Plan::DropDatamaskPolicy(_) => {
                 self.validate_access(
                     &GrantObject::Global,
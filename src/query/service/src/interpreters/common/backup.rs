@@ -0,0 +1,258 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+const BACKUP_MANIFEST_DIR: &str = "backup_manifests/";
+
+const BACKUP_DATA_DIR: &str = "data/";
+
+/// Bump the version if the layout of the manifest changes, and keep reading the old versions.
+pub const BACKUP_FORMAT_VERSION: u64 = 1;
+
+pub const BACKUP_FUSE_ENGINE: &str = "FUSE";
+
+/// The table options kept by a backup. The others are internal to the backed up table,
+/// e.g. its storage location, its clones or the stream of a materialized view, and are
+/// never restored.
+const BACKUP_TABLE_OPTIONS: &[&str] = &[
+    FUSE_OPT_KEY_ROW_PER_PAGE,
+    FUSE_OPT_KEY_BLOCK_PER_SEGMENT,
+    FUSE_OPT_KEY_ROW_PER_BLOCK,
+    FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
+    FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD,
+    OPT_KEY_BLOOM_INDEX_COLUMNS,
+    OPT_KEY_TABLE_COMPRESSION,
+    OPT_KEY_STORAGE_FORMAT,
+    OPT_KEY_COMMENT,
+    OPT_KEY_CHANGE_TRACKING,
+];
+
+/// The manifest of a backup, written as json under `backup_manifests/` of the backup path.
+///
+/// The manifest only describes the objects by the fields listed here, instead of dumping
+/// the metadata of the catalog, so that it stays readable by later versions. It is read
+/// from a stage writable by the users, so everything in it is validated on restore.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupManifest {
+    pub format_version: u64,
+    /// Sorts in the order the backups are taken.
+    pub backup_id: String,
+    pub database: String,
+    pub timestamp: DateTime<Utc>,
+    /// The previous backup this one is incremental to.
+    pub base_backup_id: Option<String>,
+    pub comment: String,
+    /// The role owning the database.
+    pub owner: Option<String>,
+    pub tables: Vec<TableBackup>,
+    pub grants: Vec<GrantBackup>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableBackup {
+    pub name: String,
+    pub table_id: u64,
+    /// `FUSE` or `VIEW`.
+    pub engine: String,
+    /// Same layout as the schema in the fuse snapshots, the column ids must match the
+    /// blocks of the snapshot.
+    pub schema: TableSchema,
+    /// The options in [`BACKUP_TABLE_OPTIONS`], or the query of a view.
+    pub options: BTreeMap<String, String>,
+    pub cluster_key: Option<String>,
+    pub comment: String,
+    pub field_comments: Vec<String>,
+    /// The role owning the table.
+    pub owner: Option<String>,
+    /// The snapshot of a fuse table in the data directory, `None` if the table has no data
+    /// to restore, e.g. an empty table or a view.
+    pub snapshot: Option<SnapshotBackup>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SnapshotBackup {
+    /// The id of the snapshot, a table whose current snapshot has the same id is unchanged
+    /// since the backup.
+    pub id: String,
+    pub location: String,
+    pub version: u64,
+}
+
+impl SnapshotBackup {
+    pub fn location(&self) -> Location {
+        (self.location.clone(), self.version)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GrantBackup {
+    pub role: String,
+    pub table: Option<String>,
+    /// The names of the privileges, e.g. `SELECT`.
+    pub privileges: Vec<String>,
+}
+
+impl TableBackup {
+    /// Keeps the options which can be backed up, see [`BACKUP_TABLE_OPTIONS`].
+    pub fn backup_options(
+        engine: &str,
+        options: &BTreeMap<String, String>,
+    ) -> BTreeMap<String, String> {
+        options
+            .iter()
+            .filter(|(key, _)| {
+                if engine == VIEW_ENGINE {
+                    key.as_str() == QUERY
+                } else {
+                    BACKUP_TABLE_OPTIONS.contains(&key.as_str())
+                }
+            })
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
+    /// Checks the engine and the options of the table before it is restored.
+    pub fn validate(&self) -> Result<()> {
+        if self.engine != BACKUP_FUSE_ENGINE && self.engine != VIEW_ENGINE {
+            return Err(ErrorCode::StorageOther(format!(
+                "Invalid backup manifest: table {} has unsupported engine {}",
+                self.name, self.engine
+            )));
+        }
+        if self.engine == VIEW_ENGINE && self.snapshot.is_some() {
+            return Err(ErrorCode::StorageOther(format!(
+                "Invalid backup manifest: view {} has data",
+                self.name
+            )));
+        }
+        let options = Self::backup_options(&self.engine, &self.options);
+        if let Some(key) = self.options.keys().find(|key| !options.contains_key(*key)) {
+            return Err(ErrorCode::StorageOther(format!(
+                "Invalid backup manifest: table {} has option {} which can not be restored",
+                self.name, key
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl GrantBackup {
+    /// The names of the privileges which can be restored, see [`GrantBackup::privileges`].
+    pub fn privilege_names(privileges: UserPrivilegeSet) -> Vec<String> {
+        let available = UserPrivilegeSet::available_privileges_on_table(false);
+        privileges
+            .iter()
+            .filter(|p| available.has_privilege(*p))
+            .map(|p| p.to_string())
+            .collect()
+    }
+
+    /// The privileges to grant on the restored database or table, only the privileges
+    /// available on a database or table object are accepted.
+    pub fn privileges(&self) -> Result<UserPrivilegeSet> {
+        let available = UserPrivilegeSet::available_privileges_on_table(false);
+        let mut privileges = UserPrivilegeSet::empty();
+        for name in &self.privileges {
+            let privilege = available
+                .iter()
+                .find(|p: &UserPrivilegeType| p.to_string() == *name)
+                .ok_or_else(|| {
+                    ErrorCode::StorageOther(format!(
+                        "Invalid backup manifest: privilege {} can not be granted to role {}",
+                        name, self.role
+                    ))
+                })?;
+            privileges.set_privilege(privilege);
+        }
+        Ok(privileges)
+    }
+}
+
+impl BackupManifest {
+    pub fn new_backup_id(timestamp: DateTime<Utc>) -> String {
+        timestamp.format("%Y%m%d_%H%M%S_%6f").to_string()
+    }
+
+    pub fn data_root(path: &str) -> String {
+        format!("{path}{BACKUP_DATA_DIR}")
+    }
+
+    /// Reads the latest backup under `path` of the stage, `None` if there is no backup yet.
+    #[async_backtrace::framed]
+    pub async fn read_latest(op: &Operator, path: &str) -> Result<Option<BackupManifest>> {
+        let dir = format!("{path}{BACKUP_MANIFEST_DIR}");
+        let latest = op
+            .list(&dir)
+            .await?
+            .into_iter()
+            .filter(|entry| entry.name().ends_with(".json"))
+            .max_by(|a, b| a.name().cmp(b.name()));
+        let Some(latest) = latest else {
+            return Ok(None);
+        };
+
+        let data = op.read(latest.path()).await?;
+        let value: serde_json::Value = serde_json::from_slice(&data).map_err(|e| {
+            ErrorCode::StorageOther(format!("Invalid backup manifest {}: {}", latest.path(), e))
+        })?;
+        // check the version first, a newer manifest may not be parsed at all.
+        let version = value.get("format_version").and_then(|v| v.as_u64());
+        if version != Some(BACKUP_FORMAT_VERSION) {
+            return Err(ErrorCode::StorageOther(format!(
+                "Unsupported backup manifest {} of format version {:?}, the supported version is {}",
+                latest.path(),
+                version,
+                BACKUP_FORMAT_VERSION
+            )));
+        }
+        let manifest = serde_json::from_value(value).map_err(|e| {
+            ErrorCode::StorageOther(format!("Invalid backup manifest {}: {}", latest.path(), e))
+        })?;
+        Ok(Some(manifest))
+    }
+
+    /// Writes the manifest under `path` of the stage, which completes the backup.
+    #[async_backtrace::framed]
+    pub async fn write(&self, op: &Operator, path: &str) -> Result<()> {
+        let location = format!("{path}{BACKUP_MANIFEST_DIR}{}.json", self.backup_id);
+        let data = serde_json::to_vec_pretty(self)?;
+        op.write(&location, data).await?;
+        Ok(())
+    }
+}
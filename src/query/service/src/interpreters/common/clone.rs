@@ -0,0 +1,361 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_api::RowAccessPolicyApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::row_access_policy::AttachRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableStatistics;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_storages_fuse::operations::update_clone_table_ids;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::parse_rename_history;
+use databend_storages_common_table_meta::table::TableRename;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_IDS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_RENAME_HISTORY;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use log::info;
+use log::warn;
+
+use crate::interpreters::common::create_identity_sequences;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+const MAX_RECORD_RENAME_RETRIES: usize = 10;
+
+/// Whether the table existed at `time_point`, a dropped table counts until it was dropped.
+pub fn table_exists_at(meta: &TableMeta, time_point: DateTime<Utc>) -> bool {
+    meta.created_on <= time_point && meta.drop_on.map_or(true, |drop_on| drop_on > time_point)
+}
+
+/// The name the table had in the database `db_id` at `time_point`, found in the renames
+/// recorded since then. `None` if the table was in another database at the point.
+pub fn table_name_at(table: &dyn Table, db_id: u64, time_point: DateTime<Utc>) -> Option<String> {
+    match parse_rename_history(table.options())
+        .into_iter()
+        .find(|rename| rename.renamed_on > time_point)
+    {
+        Some(rename) => (rename.db_id == db_id).then_some(rename.name),
+        None => Some(table.name().to_string()),
+    }
+}
+
+/// Lists the tables of the database as they were at `time_point` along with the names they had
+/// then, including the tables dropped or renamed since then.
+///
+/// The tables moved to another database since the point are listed in that database, they are
+/// not found here.
+#[async_backtrace::framed]
+pub async fn list_tables_as_of(
+    catalog: &Arc<dyn Catalog>,
+    tenant: &str,
+    database: &str,
+    time_point: DateTime<Utc>,
+) -> Result<Vec<(String, Arc<dyn Table>)>> {
+    let db_id = catalog
+        .get_database(tenant, database)
+        .await?
+        .get_db_info()
+        .ident
+        .db_id;
+    let mut tables = catalog
+        .list_tables_history(tenant, database)
+        .await?
+        .into_iter()
+        .filter(|table| table_exists_at(&table.get_table_info().meta, time_point))
+        .filter_map(|table| Some((table_name_at(table.as_ref(), db_id, time_point)?, table)))
+        .collect::<Vec<_>>();
+    // the latest table wins if several tables had the same name, which is not expected.
+    tables.sort_by(|(_, a), (_, b)| {
+        let created_on = |table: &Arc<dyn Table>| table.get_table_info().meta.created_on;
+        created_on(b).cmp(&created_on(a))
+    });
+    let mut names = HashSet::new();
+    tables.retain(|(name, _)| names.insert(name.clone()));
+    Ok(tables)
+}
+
+/// Renames a table and records its former name under [`OPT_KEY_RENAME_HISTORY`], so that
+/// reverting the database to a point before the rename restores the name.
+#[async_backtrace::framed]
+pub async fn rename_table(catalog: &Arc<dyn Catalog>, req: RenameTableReq) -> Result<()> {
+    let tenant = req.tenant().to_string();
+    let db_id = catalog
+        .get_database(&tenant, req.db_name())
+        .await?
+        .get_db_info()
+        .ident
+        .db_id;
+    let reply = catalog.rename_table(req.clone()).await?;
+    // the table does not exist and `IF EXISTS` is specified.
+    if reply.table_id == 0 {
+        return Ok(());
+    }
+
+    let rename = TableRename {
+        renamed_on: Utc::now(),
+        db_id,
+        name: req.table_name().to_string(),
+    };
+    for _ in 0..MAX_RECORD_RENAME_RETRIES {
+        let (ident, meta) = catalog.get_table_meta_by_id(reply.table_id).await?;
+        let mut history = parse_rename_history(&meta.options);
+        history.push(rename.clone());
+        let upsert = UpsertTableOptionReq {
+            table_id: reply.table_id,
+            seq: MatchSeq::Exact(ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_RENAME_HISTORY.to_string(),
+                Some(serde_json::to_string(&history)?),
+            )]),
+        };
+        match catalog
+            .upsert_table_option(&tenant, &req.new_db_name, upsert)
+            .await
+        {
+            Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => continue,
+            Err(e) => return Err(e),
+            Ok(_) => return Ok(()),
+        }
+    }
+    Err(ErrorCode::TableVersionMismatched(format!(
+        "Can not record the rename of table {}, it is modified concurrently",
+        reply.table_id
+    )))
+}
+
+/// Registers `clone_table_id` as a clone of the table `source_table_id` and all the sources
+/// of it, so that none of them purges the files shared with the clone.
+///
+/// The GCs of the sources starting after the registration keep the files shared with the
+/// clone, see [`FuseTable::commit_clone_snapshot`] for the ones already running.
+#[async_backtrace::framed]
+pub async fn register_table_clone(
+    catalog: &Arc<dyn Catalog>,
+    source_table_id: u64,
+    clone_table_id: u64,
+) -> Result<()> {
+    let mut source = Some(source_table_id);
+    while let Some(table_id) = source.take() {
+        // the chain of sources ends at a vacuumed one, whose files are already purged.
+        let meta = match catalog.get_table_meta_by_id(table_id).await {
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => break,
+            Err(e) => return Err(e),
+            Ok((_, meta)) => meta,
+        };
+        update_clone_table_ids(table_id, |ids| {
+            if !ids.contains(&clone_table_id) {
+                ids.push(clone_table_id);
+            }
+        })
+        .await?;
+        source = meta
+            .options
+            .get(OPT_KEY_CLONE_SOURCE_TABLE_ID)
+            .map(|id| id.parse::<u64>())
+            .transpose()?;
+    }
+    Ok(())
+}
+
+/// Drops the table left by a failed clone or creation, it is vacuumed as any other dropped
+/// table.
+#[async_backtrace::framed]
+pub async fn drop_failed_table(
+    catalog: &Arc<dyn Catalog>,
+    tenant: &str,
+    table_name: &str,
+    table_id: u64,
+    db_id: u64,
+) {
+    let req = DropTableByIdReq {
+        if_exists: true,
+        tenant: tenant.to_string(),
+        table_name: table_name.to_string(),
+        tb_id: table_id,
+        db_id,
+    };
+    if let Err(e) = catalog.drop_table_by_id(req).await {
+        warn!(
+            "failed to drop the table {} left by a failed creation: {}",
+            table_name, e
+        );
+    }
+}
+
+/// Creates the table `table_name` in the database `database`, with the data `source` had at
+/// `time_point` (the latest data if `None`). `source` may have been dropped.
+///
+/// Fuse tables are cloned without copying any data and views are created again, the tables
+/// of the other engines have no history to restore and are skipped.
+#[async_backtrace::framed]
+pub async fn clone_table_as_of(
+    ctx: &Arc<QueryContext>,
+    catalog: &Arc<dyn Catalog>,
+    source: Arc<dyn Table>,
+    table_name: &str,
+    database: &str,
+    db_id: u64,
+    time_point: Option<DateTime<Utc>>,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let source_info = source.get_table_info();
+    let is_view = source.engine() == VIEW_ENGINE;
+    let is_fuse = source.engine() == "FUSE"
+        && source_info.meta.storage_params.is_none()
+        && !source.options().contains_key(OPT_KEY_STORAGE_PREFIX)
+        && !source.options().contains_key(OPT_KEY_TEMP_PREFIX)
+        && !source
+            .options()
+            .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    if !is_view && !is_fuse {
+        info!(
+            "table {} of engine {} can not be cloned, skip it",
+            source_info.desc,
+            source.engine()
+        );
+        return Ok(());
+    }
+
+    let mut table_meta = source_info.meta.clone();
+    let now = Utc::now();
+    table_meta.created_on = now;
+    table_meta.updated_on = now;
+    table_meta.drop_on = None;
+    table_meta.statistics = TableStatistics::default();
+    table_meta.shared_by = Default::default();
+    // the clone is a new table, it has not been renamed yet.
+    table_meta.options.remove(OPT_KEY_RENAME_HISTORY);
+
+    let snapshot = if is_fuse {
+        let fuse_table = FuseTable::try_from_table(source.as_ref())?;
+        let snapshot = match time_point {
+            Some(time_point) => fuse_table.read_table_snapshot_as_of(time_point).await?,
+            None => {
+                let location = fuse_table.snapshot_loc().await?;
+                fuse_table.read_table_snapshot().await?.zip(location)
+            }
+        };
+        // the same as navigating to the snapshot, the schema is recovered from it.
+        if let Some((snapshot, _)) = &snapshot {
+            table_meta.schema = Arc::new(snapshot.schema.clone());
+        }
+        let options = &mut table_meta.options;
+        options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+        options.remove(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+        options.remove(OPT_KEY_MATERIALIZED_VIEW_IDS);
+        options.insert(OPT_KEY_DATABASE_ID.to_string(), db_id.to_string());
+        options.insert(
+            OPT_KEY_CLONE_SOURCE_TABLE_ID.to_string(),
+            source.get_id().to_string(),
+        );
+        snapshot
+    } else {
+        None
+    };
+
+    let schema = table_meta.schema.clone();
+    let row_access_policy = table_meta.row_access_policy.clone();
+    let reply = catalog
+        .create_table(CreateTableReq {
+            create_option: CreateOption::Create,
+            name_ident: TableNameIdent {
+                tenant: tenant.to_string(),
+                db_name: database.to_string(),
+                table_name: table_name.to_string(),
+            },
+            table_meta,
+        })
+        .await?;
+
+    if let Some(current_role) = ctx.get_current_role() {
+        let role_api = UserApiProvider::instance().role_api(&tenant);
+        role_api
+            .grant_ownership(
+                &OwnershipObject::Table {
+                    catalog_name: catalog.name(),
+                    db_id,
+                    table_id: reply.table_id,
+                },
+                &current_role.name,
+            )
+            .await?;
+        RoleCacheManager::instance().invalidate_cache(&tenant);
+    }
+
+    if is_fuse {
+        let cloned = async {
+            register_table_clone(catalog, source.get_id(), reply.table_id).await?;
+            // the clone is protected by the policy of the source as well.
+            if let Some(policy) = &row_access_policy {
+                UserApiProvider::instance()
+                    .get_meta_store_client()
+                    .attach_row_access_policy(AttachRowAccessPolicyReq {
+                        name: RowAccessPolicyNameIdent::new(tenant.as_str(), &policy.policy),
+                        table_id: reply.table_id,
+                    })
+                    .await?;
+            }
+            create_identity_sequences(
+                tenant.as_str(),
+                reply.table_id,
+                &schema,
+                Some(source.get_id()),
+            )
+            .await?;
+            if let Some((snapshot, location)) = snapshot {
+                let table = catalog
+                    .get_table(tenant.as_str(), database, table_name)
+                    .await?;
+                FuseTable::try_from_table(table.as_ref())?
+                    .commit_clone_snapshot(ctx.as_ref(), &location, &snapshot)
+                    .await?;
+            }
+            Ok::<_, ErrorCode>(())
+        }
+        .await;
+        if let Err(e) = cloned {
+            drop_failed_table(catalog, tenant.as_str(), table_name, reply.table_id, db_id).await;
+            return Err(e);
+        }
+    }
+    Ok(())
+}
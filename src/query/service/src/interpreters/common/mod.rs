@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod backup;
+mod clone;
 mod grant;
 mod metrics;
 mod notification;
@@ -21,11 +23,25 @@ mod table;
 mod task;
 mod util;
 
+pub use backup::BackupManifest;
+pub use backup::GrantBackup;
+pub use backup::SnapshotBackup;
+pub use backup::TableBackup;
+pub use backup::BACKUP_FORMAT_VERSION;
+pub use clone::clone_table_as_of;
+pub use clone::drop_failed_table;
+pub use clone::list_tables_as_of;
+pub use clone::register_table_clone;
+pub use clone::rename_table;
+pub use clone::table_exists_at;
 pub use grant::validate_grant_object_exists;
 pub use notification::get_notification_client_config;
 pub use query_log::InterpreterQueryLog;
 pub use stream::build_update_stream_meta_seq;
+pub use table::check_referenced_check_constraints;
 pub use table::check_referenced_computed_columns;
+pub use table::create_identity_sequences;
+pub use table::purge_temp_tables;
 pub use task::get_task_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
@@ -26,6 +26,7 @@ use databend_common_storages_fuse::TableContext;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_VERSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
 
 use crate::sessions::QueryContext;
@@ -49,13 +50,18 @@ pub async fn build_update_stream_meta_seq(
         let stream = StreamTable::try_from_table(table.as_ref())?;
         let stream_info = stream.get_table_info();
         let source_table = stream.source_table(ctx.clone()).await?;
-        let inner_fuse = FuseTable::try_from_table(source_table.as_ref())?;
 
-        let table_version = inner_fuse.get_table_info().ident.seq;
+        let table_version = source_table.get_table_info().ident.seq;
         let mut options = stream.options().clone();
         options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
-        if let Some(snapshot_loc) = inner_fuse.snapshot_loc().await? {
-            options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
+        if stream.source_version().is_some() {
+            let source_version = source_table.changes_version().await?;
+            options.insert(OPT_KEY_SOURCE_VERSION.to_string(), source_version);
+        } else {
+            let inner_fuse = FuseTable::try_from_table(source_table.as_ref())?;
+            if let Some(snapshot_loc) = inner_fuse.snapshot_loc().await? {
+                options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
+            }
         }
 
         reqs.push(UpdateStreamMetaReq {
@@ -14,12 +14,29 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableSchema;
+use databend_common_meta_api::SequenceApi;
+use databend_common_meta_app::schema::identity_sequence_name;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
+use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::parse_computed_expr;
+use databend_common_sql::parse_sequence_default_expr;
+use databend_common_sql::SequenceDefault;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_memory::MemoryTable;
+use databend_common_users::UserApiProvider;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +72,91 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Checks that the CHECK constraints of the table still bind to `schema`, the
+/// table schema after the column is dropped or renamed.
+pub fn check_referenced_check_constraints(
+    ctx: Arc<dyn TableContext>,
+    table_meta: &TableMeta,
+    schema: DataSchemaRef,
+    column: &str,
+) -> Result<()> {
+    for (name, constraint) in &table_meta.constraints {
+        if let TableConstraint::Check { expr } = constraint {
+            if parse_computed_expr(ctx.clone(), schema.clone(), expr).is_err() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "column `{}` is referenced by constraint `{}`",
+                    column, name
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Purges the data of the dropped temporary tables.
+///
+/// Temporary tables are never vacuumed, so the data is removed as soon as they are dropped.
+pub async fn purge_temp_tables(tables: Vec<TableInfo>) -> Result<()> {
+    for table_info in tables {
+        match table_info.engine() {
+            "FUSE" => {
+                let prefix = FuseTable::parse_storage_prefix(&table_info)?;
+                let operator = DataOperator::instance().operator();
+                operator.remove_all(&format!("{}/", prefix)).await?;
+            }
+            "MEMORY" => MemoryTable::purge(table_info.ident.table_id),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Creates the sequences of the `IDENTITY` columns of the table `table_id`. The sequences
+/// of a clone continue from the ones of the source table `source_table_id`, so that the
+/// new values don't collide with the cloned rows.
+#[async_backtrace::framed]
+pub async fn create_identity_sequences(
+    tenant: &str,
+    table_id: u64,
+    schema: &TableSchema,
+    source_table_id: Option<u64>,
+) -> Result<()> {
+    let meta_api = UserApiProvider::instance().get_meta_store_client();
+    for field in schema.fields() {
+        let Some(SequenceDefault::Identity { mut start, step }) = field
+            .default_expr()
+            .and_then(|expr| parse_sequence_default_expr(expr))
+        else {
+            continue;
+        };
+        if let Some(source_table_id) = source_table_id {
+            let source = SequenceIdent::new(
+                tenant,
+                identity_sequence_name(source_table_id, field.column_id()),
+            );
+            match meta_api
+                .get_sequence(GetSequenceReq { ident: source })
+                .await
+            {
+                Ok(reply) => start = reply.meta.current,
+                Err(e) => {
+                    let e = ErrorCode::from(e);
+                    if e.code() != ErrorCode::UNKNOWN_SEQUENCE {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        let req = CreateSequenceReq {
+            create_option: CreateOption::Create,
+            ident: SequenceIdent::new(tenant, identity_sequence_name(table_id, field.column_id())),
+            start,
+            step,
+            comment: None,
+            create_on: Utc::now(),
+        };
+        meta_api.create_sequence(req).await?;
+    }
+    Ok(())
+}
@@ -93,6 +93,7 @@ impl HookOperator {
     /// Execute the refresh hook operator.
     // 1. Refresh aggregating index.
     // 2. Refresh virtual columns.
+    // 3. Refresh materialized views in the auto refresh mode.
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn execute_refresh(&self, pipeline: &mut Pipeline) {
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::MaterializedViewRefreshMode;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::table_context::TableContext;
@@ -22,24 +23,31 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::get_materialized_views;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::plans::RefreshIndexPlan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
 use databend_common_sql::plans::RefreshVirtualColumnPlan;
 use databend_common_sql::BindContext;
 use databend_common_sql::Binder;
 use databend_common_sql::Metadata;
 use databend_common_sql::NameResolutionContext;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_IDS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
 use log::info;
 use parking_lot::RwLock;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::RefreshIndexInterpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
 use crate::interpreters::RefreshVirtualColumnInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 
 pub struct RefreshDesc {
@@ -72,10 +80,10 @@ pub async fn hook_refresh(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc:
 }
 
 async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
-    let table_id = ctx
+    let table = ctx
         .get_table(&desc.catalog, &desc.database, &desc.table)
-        .await?
-        .get_id();
+        .await?;
+    let table_id = table.get_id();
 
     let mut plans = Vec::new();
 
@@ -89,6 +97,11 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         plans.extend_from_slice(&agg_index_plans);
     }
 
+    // Generate the materialized views refreshed after each write.
+    let materialized_view_plans =
+        generate_refresh_materialized_view_plan(ctx.clone(), &desc, table.get_table_info()).await?;
+    plans.extend_from_slice(&materialized_view_plans);
+
     // Generate virtual columns.
     if ctx
         .get_settings()
@@ -112,27 +125,17 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
                 Plan::RefreshIndex(agg_index_plan) => {
                     let refresh_agg_index_interpreter =
                         RefreshIndexInterpreter::try_create(ctx_cloned.clone(), *agg_index_plan)?;
-                    let mut build_res = refresh_agg_index_interpreter.execute2().await?;
-                    if build_res.main_pipeline.is_empty() {
-                        return Ok(());
-                    }
-
-                    let settings = ctx_cloned.get_settings();
-                    let query_id = ctx_cloned.get_id();
-                    build_res.set_max_threads(settings.get_max_threads()? as usize);
-                    let settings = ExecutorSettings::try_create(&settings, query_id)?;
-
-                    if build_res.main_pipeline.is_complete_pipeline()? {
-                        let mut pipelines = build_res.sources_pipelines;
-                        pipelines.push(build_res.main_pipeline);
-
-                        let complete_executor =
-                            PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
-                        ctx_cloned.set_executor(complete_executor.get_inner())?;
-                        complete_executor.execute()
-                    } else {
-                        Ok(())
-                    }
+                    let build_res = refresh_agg_index_interpreter.execute2().await?;
+                    execute_refresh_pipeline(ctx_cloned, build_res)
+                }
+                Plan::RefreshMaterializedView(materialized_view_plan) => {
+                    let refresh_materialized_view_interpreter =
+                        RefreshMaterializedViewInterpreter::try_create(
+                            ctx_cloned.clone(),
+                            *materialized_view_plan,
+                        )?;
+                    let build_res = refresh_materialized_view_interpreter.execute2().await?;
+                    execute_refresh_pipeline(ctx_cloned, build_res)
                 }
                 Plan::RefreshVirtualColumn(virtual_column_plan) => {
                     let refresh_virtual_column_interpreter =
@@ -157,6 +160,31 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
     Ok(())
 }
 
+fn execute_refresh_pipeline(
+    ctx: Arc<QueryContext>,
+    mut build_res: PipelineBuildResult,
+) -> Result<()> {
+    if build_res.main_pipeline.is_empty() {
+        return Ok(());
+    }
+
+    let settings = ctx.get_settings();
+    let query_id = ctx.get_id();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let settings = ExecutorSettings::try_create(&settings, query_id)?;
+
+    if build_res.main_pipeline.is_complete_pipeline()? {
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+
+        let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+        ctx.set_executor(complete_executor.get_inner())?;
+        complete_executor.execute()
+    } else {
+        Ok(())
+    }
+}
+
 async fn generate_refresh_index_plan(
     ctx: Arc<QueryContext>,
     catalog: &str,
@@ -223,6 +251,39 @@ async fn build_refresh_index_plan(
         .await
 }
 
+/// The materialized views defined on the table in the `AUTO` refresh mode.
+async fn generate_refresh_materialized_view_plan(
+    ctx: Arc<QueryContext>,
+    desc: &RefreshDesc,
+    table_info: &TableInfo,
+) -> Result<Vec<Plan>> {
+    if !table_info
+        .options()
+        .contains_key(OPT_KEY_MATERIALIZED_VIEW_IDS)
+    {
+        return Ok(vec![]);
+    }
+
+    let catalog = ctx.get_catalog(&desc.catalog).await?;
+    let views = get_materialized_views(catalog.as_ref(), table_info).await?;
+
+    let auto_refresh_mode = MaterializedViewRefreshMode::Auto.to_string();
+    let plans = views
+        .into_iter()
+        .filter(|view| {
+            view.options.get(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE) == Some(&auto_refresh_mode)
+        })
+        .map(|view| {
+            Plan::RefreshMaterializedView(Box::new(RefreshMaterializedViewPlan {
+                catalog: desc.catalog.clone(),
+                database: view.database,
+                view_name: view.name,
+            }))
+        })
+        .collect();
+    Ok(plans)
+}
+
 async fn generate_refresh_virtual_column_plan(
     ctx: Arc<QueryContext>,
     desc: &RefreshDesc,
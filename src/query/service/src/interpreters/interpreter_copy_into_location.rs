@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table::AppendMode;
 use databend_common_exception::Result;
@@ -22,10 +23,15 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_fuse::io::Files;
+use databend_common_storages_stage::unload_path_prefix;
 use databend_common_storages_stage::StageTable;
 use log::debug;
+use log::error;
 
+use crate::interpreters::common::build_update_stream_meta_seq;
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
@@ -110,7 +116,10 @@ impl CopyIntoLocationInterpreter {
             is_select: false,
             default_values: None,
         };
-        let to_table = StageTable::try_create(stage_table_info)?;
+        // The files exported from streams are named by the query, so that they can be removed
+        // if the offsets of the streams fail to advance.
+        let unload_prefix = self.plan.consume_streams.then(|| self.ctx.get_id());
+        let to_table = StageTable::try_create_with_unload_prefix(stage_table_info, unload_prefix)?;
         PipelineBuilder::build_append2table_with_commit_pipeline(
             self.ctx.clone(),
             &mut build_res.main_pipeline,
@@ -126,6 +135,35 @@ impl CopyIntoLocationInterpreter {
     }
 }
 
+/// Removes the files whose paths start with `prefix` from the stage.
+async fn remove_unloaded_files(
+    ctx: Arc<QueryContext>,
+    stage: &StageInfo,
+    prefix: &str,
+) -> Result<()> {
+    let op = StageTable::get_op(stage)?;
+    let dir = match prefix.rfind('/') {
+        Some(pos) => &prefix[..=pos],
+        None => "/",
+    };
+    let files_info = StageFilesInfo {
+        path: dir.to_string(),
+        files: None,
+        pattern: None,
+    };
+    let files = files_info
+        .list(&op, false, None)
+        .await?
+        .into_iter()
+        .map(|file| file.path)
+        .filter(|path| path.starts_with(prefix))
+        .collect::<Vec<_>>();
+    let table_ctx: Arc<dyn TableContext> = ctx;
+    Files::create(table_ctx, op)
+        .remove_file_in_batch(&files)
+        .await
+}
+
 #[async_trait::async_trait]
 impl Interpreter for CopyIntoLocationInterpreter {
     fn name(&self) -> &str {
@@ -144,11 +182,60 @@ impl Interpreter for CopyIntoLocationInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
-        self.build_local_copy_into_stage_pipeline(
-            &self.plan.stage,
-            &self.plan.path,
-            &self.plan.from,
-        )
-        .await
+        let mut build_res = self
+            .build_local_copy_into_stage_pipeline(
+                &self.plan.stage,
+                &self.plan.path,
+                &self.plan.from,
+            )
+            .await?;
+
+        if self.plan.consume_streams {
+            let Plan::Query { metadata, .. } = self.plan.from.as_ref() else {
+                unreachable!("Input plan must be Query")
+            };
+            let update_stream_metas =
+                build_update_stream_meta_seq(self.ctx.clone(), metadata).await?;
+            if !update_stream_metas.is_empty() {
+                let catalog = self
+                    .ctx
+                    .get_catalog(&self.ctx.get_current_catalog())
+                    .await?;
+                let ctx = self.ctx.clone();
+                let stage = self.plan.stage.as_ref().clone();
+                let prefix = unload_path_prefix(&self.plan.path, &self.ctx.get_id());
+                // The offsets of the streams advance only if the files are written successfully.
+                // The files are removed if the offsets fail to advance, otherwise the same
+                // changes would be exported again by the next copy.
+                build_res
+                    .main_pipeline
+                    .set_on_finished(move |may_error| match may_error {
+                        Ok(_) => GlobalIORuntime::instance().block_on(async move {
+                            let res = catalog
+                                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                                    update_table_metas: vec![],
+                                    copied_files: vec![],
+                                    update_stream_metas,
+                                    deduplicated_labels: vec![],
+                                })
+                                .await;
+                            if res.is_err() {
+                                if let Err(cause) =
+                                    remove_unloaded_files(ctx, &stage, &prefix).await
+                                {
+                                    error!(
+                                        "Failed to remove the files {prefix}* of stage {}: {cause}",
+                                        stage.stage_name
+                                    );
+                                }
+                            }
+                            res
+                        }),
+                        Err(error_code) => Err(error_code.clone()),
+                    });
+            }
+        }
+
+        Ok(build_res)
     }
 }
@@ -220,6 +220,23 @@ impl CopyIntoTableInterpreter {
                 first_error_line.push(None);
             }
         }
+        // the rows skipped by the check constraints are not attributed to a file,
+        // see `CopyStatus::check_violations`.
+        let mut check_violations = cs
+            .check_violations
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect::<Vec<_>>();
+        check_violations.sort();
+        for (constraint, num_rows) in check_violations {
+            files.push(String::new());
+            rows_loaded.push(0);
+            errors_seen.push(num_rows as i32);
+            first_error.push(Some(format!(
+                "{num_rows} rows violating check constraint `{constraint}` are skipped"
+            )));
+            first_error_line.push(None);
+        }
         let blocks = vec![DataBlock::new_from_columns(vec![
             StringType::from_data(files),
             Int32Type::from_data(rows_loaded),
@@ -0,0 +1,239 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_sql::plans::BackupDatabasePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_stage::StageTable;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use log::info;
+use opendal::Operator;
+use uuid::Uuid;
+
+use crate::interpreters::common::BackupManifest;
+use crate::interpreters::common::GrantBackup;
+use crate::interpreters::common::SnapshotBackup;
+use crate::interpreters::common::TableBackup;
+use crate::interpreters::common::BACKUP_FORMAT_VERSION;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Backs up the metadata and the data of a database to a stage.
+///
+/// The data files are shared by all the backups under the same path, only the files written
+/// since the previous backup are copied.
+pub struct BackupDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: BackupDatabasePlan,
+}
+
+impl BackupDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: BackupDatabasePlan) -> Result<Self> {
+        Ok(BackupDatabaseInterpreter { ctx, plan })
+    }
+
+    #[async_backtrace::framed]
+    async fn backup_table(
+        &self,
+        table: &Arc<dyn Table>,
+        db_id: u64,
+        op: &Operator,
+        base: Option<&BackupManifest>,
+    ) -> Result<Option<TableBackup>> {
+        let table_info = table.get_table_info();
+        let is_view = table.engine() == VIEW_ENGINE;
+        let is_fuse = table.engine() == "FUSE"
+            && table_info.meta.storage_params.is_none()
+            && !table.options().contains_key(OPT_KEY_STORAGE_PREFIX);
+        if table.options().contains_key(OPT_KEY_TEMP_PREFIX) || (!is_view && !is_fuse) {
+            info!(
+                "table {} of engine {} can not be backed up, skip it",
+                table_info.desc,
+                table.engine()
+            );
+            return Ok(None);
+        }
+
+        let snapshot = if is_fuse {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            // the snapshot of the table in the previous backup, the backup is incremental to it.
+            let base_snapshot = base
+                .and_then(|base| {
+                    base.tables
+                        .iter()
+                        .find(|backup| backup.table_id == table.get_id())
+                })
+                .and_then(|backup| backup.snapshot.as_ref())
+                .and_then(|snapshot| {
+                    let id = Uuid::parse_str(&snapshot.id).ok()?;
+                    Some((id, snapshot.location()))
+                });
+            let root = BackupManifest::data_root(&self.plan.path);
+            fuse_table
+                .backup_to(self.ctx.clone(), op, &root, base_snapshot)
+                .await?
+                .map(|(id, (location, version))| SnapshotBackup {
+                    id: id.simple().to_string(),
+                    location,
+                    version,
+                })
+        } else {
+            None
+        };
+
+        let owner = UserApiProvider::instance()
+            .get_ownership(&self.ctx.get_tenant(), &OwnershipObject::Table {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+                table_id: table.get_id(),
+            })
+            .await?
+            .map(|ownership| ownership.role);
+
+        let meta = &table_info.meta;
+        Ok(Some(TableBackup {
+            name: table.name().to_string(),
+            table_id: table.get_id(),
+            engine: table.engine().to_string(),
+            schema: meta.schema.as_ref().clone(),
+            options: TableBackup::backup_options(table.engine(), &meta.options),
+            cluster_key: meta.default_cluster_key.clone(),
+            comment: meta.comment.clone(),
+            field_comments: meta.field_comments.clone(),
+            owner,
+            snapshot,
+        }))
+    }
+
+    /// Collects the privileges granted to the roles on the database and its tables.
+    #[async_backtrace::framed]
+    async fn backup_grants(
+        &self,
+        db_id: u64,
+        tables: &HashMap<u64, String>,
+    ) -> Result<Vec<GrantBackup>> {
+        let mut grants = vec![];
+        let roles = UserApiProvider::instance()
+            .get_roles(&self.ctx.get_tenant())
+            .await?;
+        for role in roles {
+            for entry in role.grants.entries() {
+                let table = match entry.object() {
+                    GrantObject::Database(_, database) if database == &self.plan.database => None,
+                    GrantObject::DatabaseById(_, id) if *id == db_id => None,
+                    GrantObject::Table(_, database, table) if database == &self.plan.database => {
+                        Some(table.clone())
+                    }
+                    GrantObject::TableById(_, id, table_id) if *id == db_id => {
+                        match tables.get(table_id) {
+                            Some(table) => Some(table.clone()),
+                            // the table is not backed up.
+                            None => continue,
+                        }
+                    }
+                    _ => continue,
+                };
+                grants.push(GrantBackup {
+                    role: role.name.clone(),
+                    table,
+                    privileges: GrantBackup::privilege_names((*entry.privileges()).into()),
+                });
+            }
+        }
+        Ok(grants)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BackupDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "BackupDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let db = catalog
+            .get_database(tenant.as_str(), &self.plan.database)
+            .await?;
+        let db_info = db.get_db_info();
+        let db_id = db_info.ident.db_id;
+
+        let op = StageTable::get_op(&self.plan.stage)?;
+        let base = BackupManifest::read_latest(&op, &self.plan.path)
+            .await?
+            // the backups of other databases under the same path share nothing with this one.
+            .filter(|base| base.database == self.plan.database);
+
+        let mut tables = vec![];
+        for table in catalog
+            .list_tables(tenant.as_str(), &self.plan.database)
+            .await?
+        {
+            if let Some(backup) = self.backup_table(&table, db_id, &op, base.as_ref()).await? {
+                tables.push(backup);
+            }
+        }
+
+        let table_names = tables
+            .iter()
+            .map(|table| (table.table_id, table.name.clone()))
+            .collect::<HashMap<_, _>>();
+        let grants = self.backup_grants(db_id, &table_names).await?;
+        let owner = UserApiProvider::instance()
+            .get_ownership(&tenant, &OwnershipObject::Database {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+            })
+            .await?
+            .map(|ownership| ownership.role);
+
+        let timestamp = Utc::now();
+        let manifest = BackupManifest {
+            format_version: BACKUP_FORMAT_VERSION,
+            backup_id: BackupManifest::new_backup_id(timestamp),
+            database: self.plan.database.clone(),
+            timestamp,
+            base_backup_id: base.map(|base| base.backup_id),
+            comment: db_info.meta.comment.clone(),
+            owner,
+            tables,
+            grants,
+        };
+        manifest.write(&op, &self.plan.path).await?;
+        info!(
+            "backup {} of database {} is written to stage {}",
+            manifest.backup_id, self.plan.database, self.plan.stage.stage_name
+        );
+
+        Ok(PipelineBuildResult::create())
+    }
+}
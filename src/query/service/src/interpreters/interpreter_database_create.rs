@@ -14,21 +14,26 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::catalog::Catalog;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CreateDatabaseReq;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::share::ShareGrantObjectPrivilege;
 use databend_common_meta_app::share::ShareNameIdent;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sharing::ShareEndpointManager;
+use databend_common_sql::plans::CloneDatabaseSource;
 use databend_common_sql::plans::CreateDatabasePlan;
 use databend_common_storages_share::save_share_spec;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::common::clone_table_as_of;
+use crate::interpreters::common::list_tables_as_of;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -91,6 +96,43 @@ impl CreateDatabaseInterpreter {
         }
         Ok(())
     }
+
+    /// Clones the tables of the source database into the new database, as they were at the
+    /// time point of the clone.
+    #[async_backtrace::framed]
+    async fn clone_database(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        clone_from: &CloneDatabaseSource,
+        db_id: u64,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let tables = match clone_from.time_point {
+            Some(time_point) => {
+                list_tables_as_of(catalog, tenant.as_str(), &clone_from.database, time_point)
+                    .await?
+            }
+            None => catalog
+                .list_tables(tenant.as_str(), &clone_from.database)
+                .await?
+                .into_iter()
+                .map(|table| (table.name().to_string(), table))
+                .collect(),
+        };
+        for (table_name, table) in tables {
+            clone_table_as_of(
+                &self.ctx,
+                catalog,
+                table,
+                &table_name,
+                &self.plan.database,
+                db_id,
+                clone_from.time_point,
+            )
+            .await?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -124,6 +166,14 @@ impl Interpreter for CreateDatabaseInterpreter {
             self.check_create_database_from_share(&tenant.to_string(), share_name)
                 .await?;
         }
+        // the source must exist before the new database is created.
+        if let Some(clone_from) = &self.plan.clone_from {
+            catalog
+                .get_database(tenant.as_str(), &clone_from.database)
+                .await?;
+        }
+        let exists = matches!(self.plan.create_option, CreateOption::CreateIfNotExists)
+            && databases.iter().any(|db| db.name() == self.plan.database);
 
         let create_db_req: CreateDatabaseReq = self.plan.clone().into();
         let reply = catalog.create_database(create_db_req).await?;
@@ -160,6 +210,13 @@ impl Interpreter for CreateDatabaseInterpreter {
             .await?;
         }
 
+        if let Some(clone_from) = &self.plan.clone_from {
+            if !exists {
+                self.clone_database(&catalog, clone_from, reply.db_id)
+                    .await?;
+            }
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
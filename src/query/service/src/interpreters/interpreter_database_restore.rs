@@ -0,0 +1,314 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::CreateDatabaseReq;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_meta_app::schema::DropDatabaseReq;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_sql::plans::RestoreDatabasePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_stage::StageTable;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use log::info;
+use log::warn;
+use opendal::Operator;
+
+use crate::interpreters::common::BackupManifest;
+use crate::interpreters::common::TableBackup;
+use crate::interpreters::interpreter_table_create::is_valid_column;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Restores the latest backup of a stage path as a new database.
+///
+/// The manifest is read from a stage writable by the users, so the tables are created
+/// from the validated fields of the manifest only, see [`TableBackup::validate`]. If the
+/// restore fails, the database is dropped again.
+pub struct RestoreDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RestoreDatabasePlan,
+}
+
+impl RestoreDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RestoreDatabasePlan) -> Result<Self> {
+        Ok(RestoreDatabaseInterpreter { ctx, plan })
+    }
+
+    /// The role to own a restored object, `None` if it is owned by no one.
+    fn owner(&self, owner: &Option<String>, roles: &HashSet<String>) -> Option<String> {
+        match owner {
+            Some(role) if roles.contains(role) => Some(role.clone()),
+            _ => self.ctx.get_current_role().map(|role| role.name),
+        }
+    }
+
+    fn table_meta(backup: &TableBackup) -> Result<TableMeta> {
+        backup.validate()?;
+        for field in backup.schema.fields() {
+            is_valid_column(field.name())?;
+        }
+        if !backup.field_comments.is_empty()
+            && backup.field_comments.len() != backup.schema.fields().len()
+        {
+            return Err(ErrorCode::StorageOther(format!(
+                "Invalid backup manifest: table {} has {} columns but {} column comments",
+                backup.name,
+                backup.schema.fields().len(),
+                backup.field_comments.len()
+            )));
+        }
+
+        let now = Utc::now();
+        let mut table_meta = TableMeta {
+            schema: Arc::new(backup.schema.clone()),
+            engine: backup.engine.clone(),
+            options: backup.options.clone(),
+            comment: backup.comment.clone(),
+            field_comments: backup.field_comments.clone(),
+            created_on: now,
+            updated_on: now,
+            ..Default::default()
+        };
+        if let Some(cluster_key) = &backup.cluster_key {
+            table_meta = table_meta.push_cluster_key(cluster_key.clone());
+        }
+        Ok(table_meta)
+    }
+
+    #[async_backtrace::framed]
+    async fn restore_table(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        op: &Operator,
+        backup: &TableBackup,
+    ) -> Result<u64> {
+        let tenant = self.ctx.get_tenant();
+        let reply = catalog
+            .create_table(CreateTableReq {
+                create_option: CreateOption::Create,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: self.plan.database.clone(),
+                    table_name: backup.name.clone(),
+                },
+                table_meta: Self::table_meta(backup)?,
+            })
+            .await?;
+
+        if let Some(snapshot) = &backup.snapshot {
+            let table = catalog
+                .get_table(tenant.as_str(), &self.plan.database, &backup.name)
+                .await?;
+            let root = BackupManifest::data_root(&self.plan.path);
+            FuseTable::try_from_table(table.as_ref())?
+                .restore_from(self.ctx.clone(), op, &root, &snapshot.location())
+                .await?;
+        }
+        Ok(reply.table_id)
+    }
+
+    /// Restores the tables, the owners and the grants into the created database.
+    #[async_backtrace::framed]
+    async fn restore_objects(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        op: &Operator,
+        manifest: &BackupManifest,
+        db_id: u64,
+        table_ids: &mut HashMap<String, u64>,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let role_api = user_mgr.role_api(&tenant);
+        let roles = user_mgr
+            .get_roles(&tenant)
+            .await?
+            .into_iter()
+            .map(|role| role.name)
+            .collect::<HashSet<_>>();
+        if let Some(owner) = self.owner(&manifest.owner, &roles) {
+            let object = OwnershipObject::Database {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+            };
+            role_api.grant_ownership(&object, &owner).await?;
+        }
+
+        for backup in &manifest.tables {
+            let table_id = self.restore_table(catalog, op, backup).await?;
+            table_ids.insert(backup.name.clone(), table_id);
+            if let Some(owner) = self.owner(&backup.owner, &roles) {
+                let object = OwnershipObject::Table {
+                    catalog_name: self.plan.catalog.clone(),
+                    db_id,
+                    table_id,
+                };
+                role_api.grant_ownership(&object, &owner).await?;
+            }
+        }
+
+        for grant in &manifest.grants {
+            let privileges = grant.privileges()?;
+            if !roles.contains(&grant.role) {
+                info!(
+                    "role {} of the backup does not exist, skip its grants",
+                    grant.role
+                );
+                continue;
+            }
+            let object = match &grant.table {
+                None => GrantObject::DatabaseById(self.plan.catalog.clone(), db_id),
+                Some(table) => match table_ids.get(table) {
+                    Some(table_id) => {
+                        GrantObject::TableById(self.plan.catalog.clone(), db_id, *table_id)
+                    }
+                    None => continue,
+                },
+            };
+            user_mgr
+                .grant_privileges_to_role(&tenant, &grant.role, object, privileges)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Drops the partially restored database, the errors are only logged so that the
+    /// error of the restore is reported.
+    #[async_backtrace::framed]
+    async fn rollback(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        db_id: u64,
+        table_ids: &HashMap<String, u64>,
+    ) {
+        let tenant = self.ctx.get_tenant();
+        let role_api = UserApiProvider::instance().role_api(&tenant);
+        let objects = table_ids
+            .values()
+            .map(|table_id| OwnershipObject::Table {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+                table_id: *table_id,
+            })
+            .chain(std::iter::once(OwnershipObject::Database {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+            }));
+        for object in objects {
+            if let Err(e) = role_api.revoke_ownership(&object).await {
+                warn!("failed to revoke the ownership of {:?}: {}", object, e);
+            }
+        }
+
+        let req = DropDatabaseReq {
+            if_exists: true,
+            name_ident: DatabaseNameIdent {
+                tenant: tenant.to_string(),
+                db_name: self.plan.database.clone(),
+            },
+        };
+        if let Err(e) = catalog.drop_database(req).await {
+            warn!(
+                "failed to drop the partially restored database {}: {}",
+                self.plan.database, e
+            );
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RestoreDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "RestoreDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let op = StageTable::get_op(&self.plan.stage)?;
+        let manifest = BackupManifest::read_latest(&op, &self.plan.path)
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::StorageNotFound(format!(
+                    "No backup is found in stage {} at path '{}'",
+                    self.plan.stage.stage_name, self.plan.path
+                ))
+            })?;
+        // fail before creating anything if the manifest is invalid.
+        for backup in &manifest.tables {
+            Self::table_meta(backup)?;
+        }
+        for grant in &manifest.grants {
+            grant.privileges()?;
+        }
+
+        let now = Utc::now();
+        let reply = catalog
+            .create_database(CreateDatabaseReq {
+                create_option: CreateOption::Create,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: self.plan.database.clone(),
+                },
+                meta: DatabaseMeta {
+                    comment: manifest.comment.clone(),
+                    created_on: now,
+                    updated_on: now,
+                    ..Default::default()
+                },
+            })
+            .await?;
+        let db_id = reply.db_id;
+
+        let mut table_ids = HashMap::with_capacity(manifest.tables.len());
+        let result = self
+            .restore_objects(&catalog, &op, &manifest, db_id, &mut table_ids)
+            .await;
+        if let Err(e) = result {
+            self.rollback(&catalog, db_id, &table_ids).await;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+            return Err(e);
+        }
+        RoleCacheManager::instance().invalidate_cache(&tenant);
+
+        info!(
+            "database {} is restored from backup {} of database {}",
+            self.plan.database, manifest.backup_id, manifest.database
+        );
+        Ok(PipelineBuildResult::create())
+    }
+}
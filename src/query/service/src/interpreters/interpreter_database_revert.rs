@@ -0,0 +1,334 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_meta_app::schema::RenameTableReq;
+use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TruncateTableReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_sql::plans::RevertDatabasePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_share::save_share_spec;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use log::info;
+
+use crate::interpreters::common::clone_table_as_of;
+use crate::interpreters::common::list_tables_as_of;
+use crate::interpreters::common::rename_table;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Reverts all the tables of a database to the given point in time:
+///
+/// - the tables created after the point are dropped, they can still be undropped.
+/// - the tables which exist are reverted to their snapshots at the point, and renamed back to
+///   the names they had at the point.
+/// - the tables dropped after the point are created again as zero-copy clones of their
+///   snapshots at the point, so they get new table ids.
+///
+/// The snapshots of the existing tables are reverted in one meta transaction, either all of
+/// them or none are reverted. The drops, renames and clones are committed one by one, if one of
+/// them fails the database is left partially reverted, and running the revert to the same
+/// point again completes it: the reverted tables are left as they are, and the clones restored
+/// by the failed revert are dropped and cloned again.
+pub struct RevertDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RevertDatabasePlan,
+}
+
+/// The revert of the snapshot of an existing table, committed along with the other tables.
+struct SnapshotRevert {
+    table: Arc<dyn Table>,
+    req: UpdateTableMetaReq,
+    snapshot_location: String,
+    /// The table was empty at the point, it is reverted to a new empty snapshot.
+    truncated: bool,
+}
+
+impl RevertDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RevertDatabasePlan) -> Result<Self> {
+        Ok(RevertDatabaseInterpreter { ctx, plan })
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_table(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        table: &Arc<dyn Table>,
+        db_id: u64,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let resp = catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: true,
+                tenant: tenant.to_string(),
+                table_name: table.name().to_string(),
+                tb_id: table.get_id(),
+                db_id,
+            })
+            .await?;
+
+        let role_api = UserApiProvider::instance().role_api(&tenant);
+        role_api
+            .revoke_ownership(&OwnershipObject::Table {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+                table_id: table.get_id(),
+            })
+            .await?;
+        RoleCacheManager::instance().invalidate_cache(&tenant);
+
+        if let Some((spec_vec, share_table_info)) = resp.spec_vec {
+            save_share_spec(
+                &tenant.to_string(),
+                self.ctx.get_data_operator()?.operator(),
+                Some(spec_vec),
+                Some(share_table_info),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Prepares the revert of the snapshot of `table`, `None` if there is nothing to revert.
+    #[async_backtrace::framed]
+    async fn prepare_snapshot_revert(
+        &self,
+        table: Arc<dyn Table>,
+    ) -> Result<Option<SnapshotRevert>> {
+        // only the mutable fuse tables have history to revert to.
+        let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+            return Ok(None);
+        };
+        if table.check_mutable().is_err() {
+            info!(
+                "table {} is read only, skip reverting it",
+                table.get_table_info().desc
+            );
+            return Ok(None);
+        }
+
+        let (prepared, truncated) = match fuse_table
+            .read_table_snapshot_as_of(self.plan.time_point)
+            .await?
+        {
+            Some((snapshot, _)) => {
+                let point = NavigationPoint::SnapshotID(snapshot.snapshot_id.simple().to_string());
+                (fuse_table.prepare_revert_to(&point).await?, false)
+            }
+            // the table was empty at the point, it is left as it is if it is still empty.
+            None => match fuse_table.read_table_snapshot().await? {
+                Some(snapshot) if !snapshot.segments.is_empty() => {
+                    (fuse_table.prepare_truncate().await?, true)
+                }
+                _ => (None, true),
+            },
+        };
+        Ok(prepared.map(|(req, snapshot_location)| SnapshotRevert {
+            table,
+            req,
+            snapshot_location,
+            truncated,
+        }))
+    }
+
+    /// Reverts the snapshots of the existing tables in one meta transaction.
+    #[async_backtrace::framed]
+    async fn revert_snapshots(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        tables: Vec<Arc<dyn Table>>,
+    ) -> Result<()> {
+        let mut reverts = vec![];
+        for table in tables {
+            if let Some(revert) = self.prepare_snapshot_revert(table).await? {
+                reverts.push(revert);
+            }
+        }
+        if reverts.is_empty() {
+            return Ok(());
+        }
+
+        catalog
+            .update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: reverts.iter().map(|revert| revert.req.clone()).collect(),
+                copied_files: vec![],
+                update_stream_metas: vec![],
+                deduplicated_labels: vec![],
+            })
+            .await?;
+
+        for revert in reverts {
+            let table_info = revert.table.get_table_info();
+            // the same as truncating the table, the copied files are forgotten.
+            if revert.truncated {
+                catalog
+                    .truncate_table(table_info, TruncateTableReq {
+                        table_id: table_info.ident.table_id,
+                        batch_size: None,
+                    })
+                    .await?;
+            }
+            let fuse_table = FuseTable::try_from_table(revert.table.as_ref())?;
+            FuseTable::write_last_snapshot_hint(
+                &fuse_table.get_operator(),
+                fuse_table.meta_location_generator(),
+                revert.snapshot_location,
+            )
+            .await;
+        }
+        Ok(())
+    }
+
+    /// Renames the existing tables back to the names they had at the point. The tables whose
+    /// names are taken by the other renamed tables are renamed to temporary names first, so
+    /// that the names can be swapped.
+    #[async_backtrace::framed]
+    async fn rename_tables(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        renames: Vec<(Arc<dyn Table>, String)>,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let rename_req = |table_name: &str, new_table_name: &str| RenameTableReq {
+            if_exists: false,
+            name_ident: TableNameIdent {
+                tenant: tenant.to_string(),
+                db_name: self.plan.database.clone(),
+                table_name: table_name.to_string(),
+            },
+            new_db_name: self.plan.database.clone(),
+            new_table_name: new_table_name.to_string(),
+        };
+
+        let renamed = renames
+            .iter()
+            .map(|(table, _)| table.name().to_string())
+            .collect::<HashSet<_>>();
+        let mut current_names = vec![];
+        for (table, name) in &renames {
+            let mut current_name = table.name().to_string();
+            if renamed.contains(name) {
+                let temp_name = format!("{}$revert${}", current_name, table.get_id());
+                rename_table(catalog, rename_req(&current_name, &temp_name)).await?;
+                current_name = temp_name;
+            }
+            current_names.push(current_name);
+        }
+        for ((_, name), current_name) in renames.iter().zip(current_names) {
+            rename_table(catalog, rename_req(&current_name, name)).await?;
+        }
+        Ok(())
+    }
+}
+#[async_trait::async_trait]
+impl Interpreter for RevertDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "RevertDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let db = match catalog
+            .get_database(tenant.as_str(), &self.plan.database)
+            .await
+        {
+            Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE && self.plan.if_exists => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+            Ok(db) => db,
+        };
+        let db_id = db.get_db_info().ident.db_id;
+
+        let tables = list_tables_as_of(
+            &catalog,
+            tenant.as_str(),
+            &self.plan.database,
+            self.plan.time_point,
+        )
+        .await?;
+        let table_ids = tables
+            .iter()
+            .map(|(_, table)| table.get_id())
+            .collect::<HashSet<_>>();
+
+        // Drop the tables which did not exist at the point first, which frees the names of
+        // the dropped tables to be restored.
+        for table in catalog
+            .list_tables(tenant.as_str(), &self.plan.database)
+            .await?
+        {
+            if !table_ids.contains(&table.get_id())
+                && !table.options().contains_key(OPT_KEY_TEMP_PREFIX)
+            {
+                self.drop_table(&catalog, &table, db_id).await?;
+            }
+        }
+
+        let (existing, dropped): (Vec<_>, Vec<_>) = tables
+            .into_iter()
+            .partition(|(_, table)| table.get_table_info().meta.drop_on.is_none());
+
+        let renames = existing
+            .iter()
+            .filter(|(name, table)| table.name() != name.as_str())
+            .map(|(name, table)| (table.clone(), name.clone()))
+            .collect::<Vec<_>>();
+        self.revert_snapshots(
+            &catalog,
+            existing.into_iter().map(|(_, table)| table).collect(),
+        )
+        .await?;
+        self.rename_tables(&catalog, renames).await?;
+
+        // Restore the dropped tables last, their names are freed by the drops and renames.
+        for (table_name, table) in dropped {
+            clone_table_as_of(
+                &self.ctx,
+                &catalog,
+                table,
+                &table_name,
+                &self.plan.database,
+                db_id,
+                Some(self.plan.time_point),
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
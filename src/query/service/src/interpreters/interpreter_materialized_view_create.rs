@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_sql::plans::CreateMaterializedViewPlan;
+use databend_common_sql::plans::CreateStreamPlan;
+use databend_common_sql::plans::DropStreamPlan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_common_storages_fuse::operations::update_table_ids;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_IDS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_STREAM;
+use log::warn;
+
+use crate::interpreters::CreateStreamInterpreter;
+use crate::interpreters::DropStreamInterpreter;
+use crate::interpreters::Interpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::Stream)?;
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let tenant = self.ctx.get_tenant();
+        let db = catalog
+            .get_database(tenant.as_str(), &self.plan.database)
+            .await?;
+        let db_id = db.get_db_info().ident.db_id;
+
+        let existing_view = match catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.view_name)
+            .await
+        {
+            Ok(table) => Some(table),
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => None,
+            Err(e) => return Err(e),
+        };
+
+        // The name of the stream is reserved for the view, it is only taken over from the
+        // view which has the same name, never from a table or stream of the user.
+        let owns_stream = existing_view.as_ref().is_some_and(|table| {
+            table.options().get(OPT_KEY_MATERIALIZED_VIEW_STREAM) == Some(&self.plan.stream_name)
+        });
+        if !owns_stream {
+            match catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.stream_name)
+                .await
+            {
+                Ok(_) => {
+                    return Err(ErrorCode::TableAlreadyExists(format!(
+                        "{}.{} already exists, the name is reserved for the stream of materialized view {}",
+                        self.plan.database, self.plan.stream_name, self.plan.view_name
+                    )));
+                }
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        // The replaced view is removed from the views of its base table.
+        let replaced_view = match self.plan.create_option {
+            CreateOption::CreateOrReplace => existing_view.as_ref().and_then(|table| {
+                let base_table_id = table
+                    .options()
+                    .get(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID)?
+                    .parse::<u64>()
+                    .ok()?;
+                Some((table.get_id(), base_table_id))
+            }),
+            _ => None,
+        };
+
+        let mut options = BTreeMap::new();
+        options.insert(OPT_KEY_DATABASE_ID.to_string(), db_id.to_string());
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            self.plan.query.clone(),
+        );
+        if let Some(incremental_query) = &self.plan.incremental_query {
+            options.insert(
+                OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL_QUERY.to_string(),
+                incremental_query.clone(),
+            );
+        }
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_STREAM.to_string(),
+            self.plan.stream_name.clone(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE.to_string(),
+            self.plan.refresh_mode.to_string(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID.to_string(),
+            self.plan.base_table_id.to_string(),
+        );
+
+        let req = CreateTableReq {
+            create_option: self.plan.create_option,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.view_name.clone(),
+            },
+            table_meta: TableMeta {
+                engine: "FUSE".to_string(),
+                options,
+                field_comments: vec!["".to_string(); self.plan.schema.fields().len()],
+                schema: self.plan.schema.clone(),
+                ..Default::default()
+            },
+        };
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The base table keeps the ids of its views, so that the views are found without
+        // listing the tables when the base table is written or queried.
+        let view_id = reply.table_id;
+        let registered = update_table_ids(
+            catalog.as_ref(),
+            tenant.as_str(),
+            self.plan.base_table_id,
+            OPT_KEY_MATERIALIZED_VIEW_IDS,
+            |ids| {
+                if !ids.contains(&view_id) {
+                    ids.push(view_id);
+                }
+            },
+        )
+        .await;
+        let registered = match registered {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(ErrorCode::UnknownTable(format!(
+                "base table {}.{} of materialized view {} has been dropped",
+                self.plan.base_database, self.plan.base_table, self.plan.view_name
+            ))),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = registered {
+            let req = DropTableByIdReq {
+                if_exists: true,
+                tenant: self.plan.tenant.clone(),
+                table_name: self.plan.view_name.clone(),
+                tb_id: view_id,
+                db_id,
+            };
+            if let Err(drop_err) = catalog.drop_table_by_id(req).await {
+                warn!(
+                    "failed to drop the materialized view {}: {}",
+                    self.plan.view_name, drop_err
+                );
+            }
+            return Err(e);
+        }
+        if let Some((replaced_view_id, base_table_id)) = replaced_view {
+            if replaced_view_id != view_id {
+                update_table_ids(
+                    catalog.as_ref(),
+                    tenant.as_str(),
+                    base_table_id,
+                    OPT_KEY_MATERIALIZED_VIEW_IDS,
+                    |ids| ids.retain(|id| *id != replaced_view_id),
+                )
+                .await?;
+            }
+        }
+
+        // grant the ownership of the view to the current role.
+        if let Some(current_role) = self.ctx.get_current_role() {
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Table {
+                        catalog_name: self.plan.catalog.clone(),
+                        db_id,
+                        table_id: reply.table_id,
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+
+        // The stream of the replaced view is dropped and created again, the view is fully
+        // refreshed at first.
+        if owns_stream {
+            let drop_stream_plan = DropStreamPlan {
+                if_exists: true,
+                tenant: self.plan.tenant.clone(),
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                stream_name: self.plan.stream_name.clone(),
+            };
+            DropStreamInterpreter::try_create(self.ctx.clone(), drop_stream_plan)?
+                .execute2()
+                .await?;
+        }
+        let stream_plan = CreateStreamPlan {
+            create_option: CreateOption::Create,
+            tenant: self.plan.tenant.clone(),
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            stream_name: self.plan.stream_name.clone(),
+            table_database: self.plan.base_database.clone(),
+            table_name: self.plan.base_table.clone(),
+            navigation: None,
+            view: None,
+            append_only: false,
+            comment: Some(format!(
+                "change set of materialized view {}",
+                self.plan.view_name
+            )),
+        };
+        CreateStreamInterpreter::try_create(self.ctx.clone(), stream_plan)?
+            .execute2()
+            .await?;
+
+        let refresh_plan = RefreshMaterializedViewPlan {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            view_name: self.plan.view_name.clone(),
+        };
+        RefreshMaterializedViewInterpreter::try_create(self.ctx.clone(), refresh_plan)?
+            .execute2()
+            .await
+    }
+}
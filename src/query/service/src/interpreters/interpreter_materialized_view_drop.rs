@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_sql::plans::DropMaterializedViewPlan;
+use databend_common_sql::plans::DropStreamPlan;
+use databend_common_storages_fuse::operations::update_table_ids;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_IDS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_STREAM;
+
+use crate::interpreters::DropStreamInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropMaterializedViewPlan,
+}
+
+impl DropMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropMaterializedViewPlan) -> Result<Self> {
+        Ok(DropMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "DropMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.clone();
+        let db_name = self.plan.database.clone();
+        let view_name = self.plan.view_name.clone();
+        let tbl = self
+            .ctx
+            .get_table(&catalog_name, &db_name, &view_name)
+            .await
+            .ok();
+
+        let Some(table) = tbl else {
+            if self.plan.if_exists {
+                return Ok(PipelineBuildResult::create());
+            }
+            return Err(ErrorCode::UnknownTable(format!(
+                "unknown materialized view `{}`.`{}` in catalog '{}'",
+                db_name, view_name, &catalog_name
+            )));
+        };
+        if !table
+            .options()
+            .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not a materialized view",
+                &self.plan.database, &self.plan.view_name
+            )));
+        }
+
+        if let Some(stream_name) = table.options().get(OPT_KEY_MATERIALIZED_VIEW_STREAM) {
+            let stream_plan = DropStreamPlan {
+                if_exists: true,
+                tenant: self.plan.tenant.clone(),
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                stream_name: stream_name.clone(),
+            };
+            DropStreamInterpreter::try_create(self.ctx.clone(), stream_plan)?
+                .execute2()
+                .await?;
+        }
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let db = catalog
+            .get_database(&self.plan.tenant, &self.plan.database)
+            .await?;
+        let db_id = db.get_db_info().ident.db_id;
+        catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: self.plan.if_exists,
+                tenant: self.plan.tenant.clone(),
+                table_name: self.plan.view_name.clone(),
+                tb_id: table.get_id(),
+                db_id,
+            })
+            .await?;
+
+        let tenant = self.ctx.get_tenant();
+        if let Some(base_table_id) = table
+            .options()
+            .get(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID)
+            .and_then(|id| id.parse::<u64>().ok())
+        {
+            let view_id = table.get_id();
+            update_table_ids(
+                catalog.as_ref(),
+                tenant.as_str(),
+                base_table_id,
+                OPT_KEY_MATERIALIZED_VIEW_IDS,
+                |ids| ids.retain(|id| *id != view_id),
+            )
+            .await?;
+        }
+
+        let role_api = UserApiProvider::instance().role_api(&tenant);
+        role_api
+            .revoke_ownership(&OwnershipObject::Table {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+                table_id: table.get_id(),
+            })
+            .await?;
+        RoleCacheManager::instance().invalidate_cache(&tenant);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::parser::quote::quote_ident;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_common_sql::Planner;
+use databend_storages_common_table_meta::table::StreamMode;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_STREAM;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_NAME;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Refreshes the materialized view from the stream on its base table.
+///
+/// If the view is incremental and only insertions happened since the last refresh, the
+/// change set of the stream is appended to the view. Otherwise the whole query is
+/// recomputed to overwrite the view. In both cases the offset of the stream is advanced in
+/// the same commit as the data of the view.
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::Stream)?;
+
+        let catalog = &self.plan.catalog;
+        let database = &self.plan.database;
+        let view_name = &self.plan.view_name;
+
+        // The tables may be cached by the query which writes the base table.
+        self.ctx
+            .evict_table_from_cache(catalog, database, view_name)?;
+        let view = self.ctx.get_table(catalog, database, view_name).await?;
+        let options = view.options();
+        let (Some(query), Some(stream_name)) = (
+            options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY),
+            options.get(OPT_KEY_MATERIALIZED_VIEW_STREAM),
+        ) else {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not a materialized view",
+                database, view_name
+            )));
+        };
+
+        self.ctx
+            .evict_table_from_cache(catalog, database, stream_name)?;
+        let stream = self.ctx.get_table(catalog, database, stream_name).await?;
+        if let (Some(base_database), Some(base_table)) = (
+            stream.options().get(OPT_KEY_DATABASE_NAME),
+            stream.options().get(OPT_KEY_TABLE_NAME),
+        ) {
+            self.ctx
+                .evict_table_from_cache(catalog, base_database, base_table)?;
+        }
+
+        let incremental_query = match options.get(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL_QUERY) {
+            Some(incremental_query) => match stream.get_stream_mode(self.ctx.clone()).await? {
+                StreamMode::AppendOnly => Some(incremental_query),
+                StreamMode::Standard => None,
+            },
+            None => None,
+        };
+        let quoted_database = quote_ident(database, '`', true);
+        let quoted_view_name = quote_ident(view_name, '`', true);
+        let full_refresh = incremental_query.is_none();
+        let sql = match incremental_query {
+            Some(incremental_query) => {
+                format!("INSERT INTO {quoted_database}.{quoted_view_name} {incremental_query}")
+            }
+            None => format!("INSERT OVERWRITE {quoted_database}.{quoted_view_name} {query}"),
+        };
+        info!("Refresh materialized view via sql {:?}", sql);
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let Plan::Insert(insert) = &plan else {
+            return Err(ErrorCode::Internal(
+                "Logical error, refresh materialized view must be an insert plan",
+            ));
+        };
+        let InsertInputSource::SelectPlan(select_plan) = &insert.source else {
+            return Err(ErrorCode::Internal(
+                "Logical error, refresh materialized view must insert from a query",
+            ));
+        };
+        let Plan::Query { metadata, .. } = select_plan.as_ref() else {
+            return Err(ErrorCode::Internal(
+                "Logical error, refresh materialized view must insert from a query",
+            ));
+        };
+        // The full refresh reads the base table instead of the stream, the stream is added to
+        // the tables of the query so that the insert advances its offset to the snapshot of
+        // the base table which is read, in the same commit as the overwrite.
+        if full_refresh {
+            metadata.write().add_table(
+                catalog.clone(),
+                database.clone(),
+                stream.clone(),
+                None,
+                false,
+                false,
+                false,
+            );
+        }
+
+        let interpreter = InterpreterFactory::get_inner(self.ctx.clone(), &plan)?;
+        interpreter.execute2().await
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_sql::capture_join_outline;
+use databend_common_sql::plans::CreatePlanBaselinePlan;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanBaselineCache;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreatePlanBaselineInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreatePlanBaselinePlan,
+}
+
+impl CreatePlanBaselineInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreatePlanBaselinePlan) -> Result<Self> {
+        Ok(CreatePlanBaselineInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreatePlanBaselineInterpreter {
+    fn name(&self) -> &str {
+        "CreatePlanBaselineInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_plan_baseline_execute");
+
+        let Plan::Query {
+            s_expr, metadata, ..
+        } = self.plan.plan.as_ref()
+        else {
+            return Err(ErrorCode::BadArguments(
+                "Plan baseline can only be created for a query",
+            ));
+        };
+        let outline = capture_join_outline(&metadata.read(), s_expr)?;
+        if outline.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "Plan baseline can't be created for a query without joins",
+            ));
+        }
+
+        let query = &self.plan.query;
+        let baseline = PlanBaseline {
+            fingerprint: query.fingerprint.clone(),
+            query: query.query.clone(),
+            catalog: query.catalog.clone(),
+            database: query.database.clone(),
+            outline,
+            created_on: Utc::now(),
+        };
+
+        let tenant = self.ctx.get_tenant();
+        UserApiProvider::instance()
+            .add_plan_baseline(&tenant, baseline, &self.plan.create_option)
+            .await?;
+
+        // The cached plans of the query are planned without the baseline, they are dropped
+        // once the new generation of the baselines is loaded.
+        PlanBaselineCache::instance().invalidate(&tenant);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::DropPlanBaselinePlan;
+use databend_common_sql::PlanBaselineCache;
+use databend_common_sql::PlanBaselineStats;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropPlanBaselineInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropPlanBaselinePlan,
+}
+
+impl DropPlanBaselineInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropPlanBaselinePlan) -> Result<Self> {
+        Ok(DropPlanBaselineInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropPlanBaselineInterpreter {
+    fn name(&self) -> &str {
+        "DropPlanBaselineInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_plan_baseline_execute");
+
+        let tenant = self.ctx.get_tenant();
+        let fingerprint = &self.plan.query.fingerprint;
+        UserApiProvider::instance()
+            .drop_plan_baseline(&tenant, fingerprint, self.plan.if_exists)
+            .await?;
+        PlanBaselineStats::instance().remove(&tenant, fingerprint);
+
+        // The cached plans of the query are planned with the baseline, they are dropped once
+        // the new generation of the baselines is loaded.
+        PlanBaselineCache::instance().invalidate(&tenant);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_api::RowAccessPolicyApi;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_sql::plans::DescRowAccessPolicyPlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct DescRowAccessPolicyInterpreter {
+    #[allow(dead_code)]
+    ctx: Arc<QueryContext>,
+    plan: DescRowAccessPolicyPlan,
+}
+
+impl DescRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescRowAccessPolicyPlan) -> Result<Self> {
+        Ok(DescRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DescRowAccessPolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let req = GetRowAccessPolicyReq {
+            name: RowAccessPolicyNameIdent::new(&self.plan.tenant, &self.plan.name),
+        };
+        let policy = meta_api.get_row_access_policy(req).await?.policy;
+
+        let signature = format!(
+            "({})",
+            policy
+                .args
+                .iter()
+                .map(|(arg_name, arg_type)| format!("{} {}", arg_name, arg_type))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        let blocks = vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![self.plan.name.clone()]),
+            StringType::from_data(vec![policy.create_on.to_string()]),
+            StringType::from_data(vec![signature]),
+            StringType::from_data(vec!["BOOLEAN".to_string()]),
+            StringType::from_data(vec![policy.body.clone()]),
+            StringType::from_data(vec![policy.comment.clone().unwrap_or_default()]),
+        ])];
+        PipelineBuildResult::from_blocks(blocks)
+    }
+}
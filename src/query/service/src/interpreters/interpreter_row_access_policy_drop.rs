@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::RowAccessPolicyApi;
+use databend_common_meta_app::row_access_policy::DetachRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_sql::plans::DropRowAccessPolicyPlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropRowAccessPolicyPlan,
+}
+
+impl DropRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropRowAccessPolicyPlan) -> Result<Self> {
+        Ok(DropRowAccessPolicyInterpreter { ctx, plan })
+    }
+
+    /// Detaches the policy from the tables which are dropped, or no longer use the policy,
+    /// a dropped table which is undropped later can not be read until the policy is detached
+    /// from it or re-created.
+    #[async_backtrace::framed]
+    async fn detach_dropped_tables(&self) -> Result<()> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let name = RowAccessPolicyNameIdent::new(&self.plan.tenant, &self.plan.name);
+        let policy = match meta_api
+            .get_row_access_policy(GetRowAccessPolicyReq { name: name.clone() })
+            .await
+        {
+            Ok(reply) => reply.policy,
+            Err(e) => {
+                let e = ErrorCode::from(e);
+                // the unknown policy is reported by the drop.
+                if e.code() == ErrorCode::UNKNOWN_ROW_ACCESS_POLICY {
+                    return Ok(());
+                }
+                return Err(e);
+            }
+        };
+
+        let catalog = self.ctx.get_default_catalog()?;
+        for table_id in policy.table_ids {
+            let attached = match catalog.get_table_meta_by_id(table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => false,
+                Err(e) => return Err(e),
+                Ok((_, meta)) => {
+                    meta.drop_on.is_none()
+                        && meta
+                            .row_access_policy
+                            .as_ref()
+                            .is_some_and(|p| p.policy == self.plan.name)
+                }
+            };
+            if !attached {
+                let req = DetachRowAccessPolicyReq {
+                    name: name.clone(),
+                    table_id,
+                };
+                meta_api.detach_row_access_policy(req).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DropRowAccessPolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_row_access_policy_execute");
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        self.detach_dropped_tables().await?;
+        meta_api
+            .drop_row_access_policy(self.plan.clone().into())
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
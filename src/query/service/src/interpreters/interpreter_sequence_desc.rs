@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_api::SequenceApi;
+use databend_common_meta_app::schema::GetSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_sql::plans::DescSequencePlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct DescSequenceInterpreter {
+    #[allow(dead_code)]
+    ctx: Arc<QueryContext>,
+    plan: DescSequencePlan,
+}
+
+impl DescSequenceInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescSequencePlan) -> Result<Self> {
+        Ok(DescSequenceInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescSequenceInterpreter {
+    fn name(&self) -> &str {
+        "DescSequenceInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let req = GetSequenceReq {
+            ident: SequenceIdent::new(&self.plan.tenant, &self.plan.sequence),
+        };
+        let meta = meta_api.get_sequence(req).await?.meta;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![self.plan.sequence.clone()]),
+            UInt64Type::from_data(vec![meta.start]),
+            UInt64Type::from_data(vec![meta.step]),
+            UInt64Type::from_data(vec![meta.current]),
+            StringType::from_opt_data(vec![meta.comment]),
+            TimestampType::from_data(vec![meta.create_on.timestamp_micros()]),
+            TimestampType::from_data(vec![meta.update_on.timestamp_micros()]),
+        ])])
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_api::SequenceApi;
+use databend_common_sql::plans::DropSequencePlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::processors::transforms::SequenceCache;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DropSequenceInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropSequencePlan,
+}
+
+impl DropSequenceInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropSequencePlan) -> Result<Self> {
+        Ok(DropSequenceInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropSequenceInterpreter {
+    fn name(&self) -> &str {
+        "DropSequenceInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_sequence_execute");
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        meta_api.drop_sequence(self.plan.clone().into()).await?;
+        SequenceCache::instance().invalidate(&self.plan.tenant, &self.plan.sequence);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::RowAccessPolicyApi;
+use databend_common_meta_app::row_access_policy::AttachRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::DetachRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AddTableRowAccessPolicyPlan;
+use databend_common_sql::resolve_type_name_by_str;
+use databend_common_storages_share::save_share_table_info;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+
+        let table_info = table.get_table_info();
+        let engine = table_info.engine();
+        if matches!(engine, VIEW_ENGINE | STREAM_ENGINE) {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support row access policy",
+                &self.plan.database, &self.plan.table, engine
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support row access policy",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+        if let Some(attached) = &table_info.meta.row_access_policy {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table {}.{} already has row access policy '{}'",
+                &self.plan.database, &self.plan.table, attached.policy
+            )));
+        }
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let req = GetRowAccessPolicyReq {
+            name: RowAccessPolicyNameIdent::new(&self.plan.tenant, &self.plan.policy),
+        };
+        let policy = meta_api.get_row_access_policy(req).await?.policy;
+        if policy.args.len() != self.plan.columns.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Row access policy '{}' takes {} arguments, but {} columns are given",
+                self.plan.policy,
+                policy.args.len(),
+                self.plan.columns.len()
+            )));
+        }
+
+        // The columns are kept by id, so renaming them does not detach the policy.
+        let schema = table.schema();
+        let mut column_ids = Vec::with_capacity(self.plan.columns.len());
+        for (column, (_, arg_type)) in self.plan.columns.iter().zip(policy.args.iter()) {
+            let field = schema.field_with_name(column)?;
+            if field.computed_expr().is_some() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Computed column '{}' can not be used by row access policy",
+                    column
+                )));
+            }
+            let arg_type = resolve_type_name_by_str(arg_type, true)?;
+            if field.data_type().remove_nullable() != arg_type.remove_nullable() {
+                return Err(ErrorCode::UnmatchColumnDataType(format!(
+                    "Column '{}' data type {} does not match to the row access policy argument type {}",
+                    column,
+                    field.data_type(),
+                    arg_type,
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.row_access_policy = Some(TableRowAccessPolicy {
+            policy: self.plan.policy.clone(),
+            column_ids,
+        });
+
+        // The table is recorded in the policy first, so that the policy can not be dropped
+        // while it is attached.
+        let name = RowAccessPolicyNameIdent::new(&self.plan.tenant, &self.plan.policy);
+        let table_id = table_info.ident.table_id;
+        meta_api
+            .attach_row_access_policy(AttachRowAccessPolicyReq {
+                name: name.clone(),
+                table_id,
+            })
+            .await?;
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+
+        let res = match catalog.update_table_meta(table_info, req).await {
+            Ok(res) => res,
+            Err(e) => {
+                let req = DetachRowAccessPolicyReq { name, table_id };
+                if let Err(cause) = meta_api.detach_row_access_policy(req).await {
+                    warn!(
+                        "failed to detach row access policy '{}' from table {}: {}",
+                        self.plan.policy, table_id, cause
+                    );
+                }
+                return Err(e);
+            }
+        };
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                self.ctx.get_tenant().as_str(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
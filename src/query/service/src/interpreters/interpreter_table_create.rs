@@ -17,10 +17,12 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use databend_common_catalog::catalog::Catalog;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::is_internal_column;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
@@ -36,10 +38,12 @@ use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_types::MatchSeq;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_sql::field_default_value;
+use databend_common_sql::plans::CloneTableSource;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storage::DataOperator;
 use databend_common_storages_fuse::io::MetaReaders;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
@@ -55,6 +59,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
 use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
@@ -65,8 +70,13 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 
+use crate::interpreters::common::create_identity_sequences;
+use crate::interpreters::common::drop_failed_table;
+use crate::interpreters::common::purge_temp_tables;
+use crate::interpreters::common::register_table_clone;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -162,6 +172,19 @@ impl Interpreter for CreateTableInterpreter {
 }
 
 impl CreateTableInterpreter {
+    fn is_temp_table(&self) -> bool {
+        self.plan.options.contains_key(OPT_KEY_TEMP_PREFIX)
+    }
+
+    /// Temporary tables are never vacuumed, the data of the replaced one is purged at once.
+    async fn purge_replaced_temp_table(&self) -> Result<()> {
+        if self.is_temp_table() {
+            let dropped_tables = self.ctx.temp_tbl_mgr().lock().take_dropped_tables();
+            purge_temp_tables(dropped_tables).await?;
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn create_table_as_select(&self, select_plan: Box<Plan>) -> Result<PipelineBuildResult> {
         assert!(
@@ -177,13 +200,25 @@ impl CreateTableInterpreter {
         if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
             return Ok(PipelineBuildResult::create());
         }
+        self.purge_replaced_temp_table().await?;
 
         let table = catalog
             .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
             .await?;
 
-        // grant the ownership of the table to the current role.
-        let current_role = self.ctx.get_current_role();
+        // The sequences of the identity columns must exist before the rows are inserted.
+        if let Err(e) =
+            create_identity_sequences(tenant.as_str(), table.get_id(), &table.schema(), None).await
+        {
+            self.drop_failed_table(&catalog, table.get_id()).await?;
+            return Err(e);
+        }
+
+        // grant the ownership of the table to the current role, temporary tables have no ownership.
+        let current_role = self
+            .ctx
+            .get_current_role()
+            .filter(|_| !self.is_temp_table());
         if let Some(current_role) = current_role {
             let db = catalog
                 .get_database(tenant.as_str(), &self.plan.database)
@@ -266,6 +301,7 @@ impl CreateTableInterpreter {
                 });
             }
         }
+
         let req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
             self.build_attach_request(storage_prefix).await
         } else {
@@ -273,9 +309,15 @@ impl CreateTableInterpreter {
         }?;
 
         let reply = catalog.create_table(req.clone()).await?;
+        self.purge_replaced_temp_table().await?;
 
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
-        if let Some(current_role) = self.ctx.get_current_role() {
+        // temporary tables have no ownership.
+        let current_role = self
+            .ctx
+            .get_current_role()
+            .filter(|_| !self.is_temp_table());
+        if let Some(current_role) = current_role {
             let tenant = self.ctx.get_tenant();
             let db = catalog
                 .get_database(tenant.as_str(), &self.plan.database)
@@ -307,9 +349,93 @@ impl CreateTableInterpreter {
             .await?;
         }
 
+        let res = match &self.plan.clone_source {
+            Some(clone_source) if reply.new_table => {
+                self.clone_table(
+                    catalog.clone(),
+                    clone_source,
+                    reply.table_id,
+                    &req.table_meta.schema,
+                )
+                .await
+            }
+            Some(_) => Ok(()),
+            None if reply.new_table || self.plan.create_option == CreateOption::CreateOrReplace => {
+                create_identity_sequences(
+                    self.ctx.get_tenant().as_str(),
+                    reply.table_id,
+                    &req.table_meta.schema,
+                    None,
+                )
+                .await
+            }
+            None => Ok(()),
+        };
+        if let Err(e) = res {
+            self.drop_failed_table(&catalog, reply.table_id).await?;
+            return Err(e);
+        }
+
         Ok(PipelineBuildResult::create())
     }
 
+    /// Drops the newly created table if it can't be made usable.
+    #[async_backtrace::framed]
+    async fn drop_failed_table(&self, catalog: &Arc<dyn Catalog>, table_id: u64) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let db_id = catalog
+            .get_database(tenant.as_str(), &self.plan.database)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        drop_failed_table(catalog, tenant.as_str(), &self.plan.table, table_id, db_id).await;
+        Ok(())
+    }
+
+    /// Makes the newly created table a zero-copy clone of the source table, then commits the
+    /// snapshot of the new table which references the cloned segments.
+    ///
+    /// The clone is registered on the source before the snapshot to share is read, so that
+    /// the GCs of the source starting from then on keep the files of the snapshot.
+    #[async_backtrace::framed]
+    async fn clone_table(
+        &self,
+        catalog: Arc<dyn Catalog>,
+        clone_source: &CloneTableSource,
+        table_id: u64,
+        schema: &TableSchema,
+    ) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let source = catalog
+            .get_table(tenant.as_str(), &clone_source.database, &clone_source.table)
+            .await?;
+        register_table_clone(&catalog, source.get_id(), table_id).await?;
+        create_identity_sequences(tenant.as_str(), table_id, schema, Some(source.get_id())).await?;
+
+        let source = FuseTable::try_from_table(source.as_ref())?;
+        let navigated;
+        let source = match &clone_source.navigation {
+            Some(point) => {
+                navigated = source.navigate_to(point).await?;
+                navigated.as_ref()
+            }
+            None => source,
+        };
+        let (Some(location), Some(snapshot)) = (
+            source.snapshot_loc().await?,
+            source.read_table_snapshot().await?,
+        ) else {
+            return Ok(());
+        };
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+        FuseTable::try_from_table(table.as_ref())?
+            .commit_clone_snapshot(self.ctx.as_ref(), &location, &snapshot)
+            .await
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -327,7 +453,12 @@ impl CreateTableInterpreter {
         } else {
             self.plan.field_comments.clone()
         };
-        let schema = TableSchemaRefExt::create(fields);
+        // the blocks shared by a clone are read by the column ids of its source.
+        let schema = if self.plan.clone_source.is_some() {
+            self.plan.schema.clone()
+        } else {
+            TableSchemaRefExt::create(fields)
+        };
         let mut options = self.plan.options.clone();
         let comment = options.remove(OPT_KEY_COMMENT);
 
@@ -347,6 +478,7 @@ impl CreateTableInterpreter {
                 Default::default()
             },
             comment: comment.unwrap_or_default(),
+            constraints: self.plan.constraints.clone(),
             ..Default::default()
         };
 
@@ -465,6 +597,8 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_ID);
 
     r.insert(OPT_KEY_ENGINE);
 
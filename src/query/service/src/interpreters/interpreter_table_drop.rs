@@ -27,7 +27,10 @@ use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_txn::TempTblMgr;
 
+use crate::interpreters::common::purge_temp_tables;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -86,6 +89,12 @@ impl Interpreter for DropTableInterpreter {
                 &self.plan.table
             )));
         }
+        if tbl.options().contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is a materialized view, use `DROP MATERIALIZED VIEW {}.{}` instead",
+                &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
+            )));
+        }
         let catalog = self.ctx.get_catalog(catalog_name).await?;
 
         // Although even if data is in READ_ONLY mode,
@@ -113,6 +122,13 @@ impl Interpreter for DropTableInterpreter {
             })
             .await?;
 
+        // temporary tables have no ownership, and their data is purged at once.
+        if TempTblMgr::is_temp_table_id(tbl.get_id()) {
+            let dropped_tables = self.ctx.temp_tbl_mgr().lock().take_dropped_tables();
+            purge_temp_tables(dropped_tables).await?;
+            return Ok(PipelineBuildResult::create());
+        }
+
         // we should do `drop ownership` after actually drop table, otherwise when we drop the ownership,
         // but the table still exists, in the interval maybe some unexpected things will happen.
         // drop the ownership
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::RowAccessPolicyApi;
+use databend_common_meta_app::row_access_policy::DetachRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::DropTableRowAccessPolicyPlan;
+use databend_common_storages_share::save_share_table_info;
+use databend_common_users::UserApiProvider;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableRowAccessPolicyPlan,
+}
+
+impl DropTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(DropTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DropTableRowAccessPolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+
+        let table_info = table.get_table_info();
+        let Some(attached) = &table_info.meta.row_access_policy else {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table {}.{} has no row access policy",
+                &self.plan.database, &self.plan.table
+            )));
+        };
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.row_access_policy = None;
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                self.ctx.get_tenant().as_str(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        // The table left in the policy if this fails is removed when the policy is dropped.
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let req = DetachRowAccessPolicyReq {
+            name: RowAccessPolicyNameIdent::new(self.ctx.get_tenant().as_str(), &attached.policy),
+            table_id: table_info.ident.table_id,
+        };
+        if let Err(cause) = meta_api.detach_row_access_policy(req).await {
+            warn!(
+                "failed to detach row access policy '{}' from table {}: {}",
+                attached.policy, table_info.ident.table_id, cause
+            );
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
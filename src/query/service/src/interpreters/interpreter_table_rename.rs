@@ -19,6 +19,7 @@ use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_sql::plans::RenameTablePlan;
 
+use crate::interpreters::common::rename_table;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -51,18 +52,17 @@ impl Interpreter for RenameTableInterpreter {
         // You must have ALTER and DROP privileges for the original table,
         // and CREATE and INSERT privileges for the new table.
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
-        catalog
-            .rename_table(RenameTableReq {
-                if_exists: self.plan.if_exists,
-                name_ident: TableNameIdent {
-                    tenant: self.plan.tenant.clone(),
-                    db_name: self.plan.database.clone(),
-                    table_name: self.plan.table.clone(),
-                },
-                new_db_name: self.plan.new_database.clone(),
-                new_table_name: self.plan.new_table.clone(),
-            })
-            .await?;
+        rename_table(&catalog, RenameTableReq {
+            if_exists: self.plan.if_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.table.clone(),
+            },
+            new_db_name: self.plan.new_database.clone(),
+            new_table_name: self.plan.new_table.clone(),
+        })
+        .await?;
 
         Ok(PipelineBuildResult::create())
     }
@@ -23,7 +23,10 @@ use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_sql::parse_sequence_default_expr;
 use databend_common_sql::plans::ShowCreateTablePlan;
+use databend_common_sql::SequenceDefault;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::QUERY;
@@ -32,6 +35,9 @@ use databend_storages_common_table_meta::table::is_internal_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_DATABASE;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_NAME;
 use log::debug;
 
 use crate::interpreters::Interpreter;
@@ -91,6 +97,8 @@ impl ShowCreateTableInterpreter {
         let mut table_create_sql = format!("CREATE TABLE `{}` (\n", name);
         if table.options().contains_key("TRANSIENT") {
             table_create_sql = format!("CREATE TRANSIENT TABLE `{}` (\n", name)
+        } else if table.options().contains_key(OPT_KEY_TEMP_PREFIX) {
+            table_create_sql = format!("CREATE TEMPORARY TABLE `{}` (\n", name)
         }
 
         // Append columns.
@@ -103,9 +111,13 @@ impl ShowCreateTableInterpreter {
                     " NOT NULL".to_string()
                 };
                 let default_expr = match field.default_expr() {
-                    Some(expr) => {
-                        format!(" DEFAULT {expr}")
-                    }
+                    Some(expr) => match parse_sequence_default_expr(expr) {
+                        // the sequence of an identity column is created along with the table.
+                        Some(SequenceDefault::Identity { start, step }) => {
+                            format!(" IDENTITY({start}, {step})")
+                        }
+                        _ => format!(" DEFAULT {expr}"),
+                    },
                     None => "".to_string(),
                 };
                 let computed_expr = match field.computed_expr() {
@@ -141,6 +153,43 @@ impl ShowCreateTableInterpreter {
 
                 columns.push(column);
             }
+
+            // Append constraints.
+            let column_name = |column_id: &u32| {
+                schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.column_id() == *column_id)
+                    .map(|f| format!("`{}`", f.name()))
+                    .unwrap_or_default()
+            };
+            for (name, constraint) in &table.get_table_info().meta.constraints {
+                let key_columns = constraint
+                    .column_ids()
+                    .iter()
+                    .map(column_name)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let definition = match constraint {
+                    TableConstraint::Check { expr } => format!("CHECK ({expr})"),
+                    TableConstraint::PrimaryKey { .. } => format!("PRIMARY KEY ({key_columns})"),
+                    TableConstraint::Unique { .. } => format!("UNIQUE ({key_columns})"),
+                    TableConstraint::ForeignKey {
+                        ref_database,
+                        ref_table,
+                        ref_columns,
+                        ..
+                    } => format!(
+                        "FOREIGN KEY ({key_columns}) REFERENCES `{ref_database}`.`{ref_table}` ({})",
+                        ref_columns
+                            .iter()
+                            .map(|c| format!("`{c}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                columns.push(format!("  CONSTRAINT `{name}` {definition}"));
+            }
             // Format is:
             //  (
             //      x,
@@ -236,11 +285,22 @@ impl ShowCreateTableInterpreter {
 
     fn show_create_stream(&self, table: &dyn Table) -> Result<PipelineBuildResult> {
         let stream_table = StreamTable::try_from_table(table)?;
+        let options = stream_table.options();
+        let (database, name) = match (
+            options.get(OPT_KEY_VIEW_DATABASE),
+            options.get(OPT_KEY_VIEW_NAME),
+        ) {
+            (Some(database), Some(name)) => (database.as_str(), name.as_str()),
+            _ => (
+                stream_table.source_table_database(),
+                stream_table.source_table_name(),
+            ),
+        };
         let mut create_sql = format!(
             "CREATE STREAM `{}` ON TABLE `{}`.`{}`",
             stream_table.name(),
-            stream_table.source_table_database(),
-            stream_table.source_table_name()
+            database,
+            name
         );
 
         let comment = stream_table.get_table_info().meta.comment.clone();
@@ -14,6 +14,7 @@
 
 use std::cmp::min;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use chrono::Duration;
@@ -31,6 +32,7 @@ use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::ListDroppedTableReq;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_sql::plans::VacuumDropTablePlan;
+use databend_common_storages_fuse::FuseTable;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
 use log::info;
 
@@ -160,6 +162,31 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .filter(|tbl| !tbl.as_ref().is_read_only())
             .collect::<Vec<_>>();
 
+        // The data of a table is kept as long as any of its clones exists, since the clones
+        // share its segments and blocks.
+        let mut tables_with_clones = HashSet::new();
+        let mut vacuum_tables = Vec::with_capacity(tables.len());
+        for table in tables {
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                if fuse_table.has_clones(catalog.as_ref()).await? {
+                    info!(
+                        "vacuum drop table {:?} skipped, it still has clones",
+                        table.get_table_info().desc
+                    );
+                    tables_with_clones.insert(table.get_id());
+                    continue;
+                }
+            }
+            vacuum_tables.push(table);
+        }
+        let tables = vacuum_tables;
+        let drop_ids = drop_ids
+            .into_iter()
+            .filter(|drop_id| {
+                !matches!(drop_id, DroppedId::Table(_, table_id, _) if tables_with_clones.contains(table_id))
+            })
+            .collect::<Vec<_>>();
+
         let handler = get_vacuum_handler();
         let files_opt = handler
             .do_vacuum_drop_tables(
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
@@ -22,11 +23,17 @@ use databend_common_license::license::Feature::Vacuum;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_sql::plans::VacuumTemporaryFilesPlan;
+use databend_common_storage::DataOperator;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
+use databend_enterprise_vacuum_handler::VacuumHandlerWrapper;
+use databend_storages_common_table_meta::table::temp_tables_storage_prefix;
+use opendal::Entry;
+use opendal::Operator;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
 use crate::sessions::TableContext;
 
 pub struct VacuumTemporaryFilesInterpreter {
@@ -38,6 +45,62 @@ impl VacuumTemporaryFilesInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: VacuumTemporaryFilesPlan) -> Result<Self> {
         Ok(VacuumTemporaryFilesInterpreter { ctx, plan })
     }
+
+    /// Removes the data of the temporary tables whose sessions are gone.
+    ///
+    /// The data of the sessions on the current node is removed as soon as the sessions end.
+    /// A query node gets a new id each time it starts, so the data under the nodes which are
+    /// not in the cluster is removed once it is not modified within the retention period.
+    #[async_backtrace::framed]
+    async fn vacuum_temp_tables(
+        &self,
+        handler: &VacuumHandlerWrapper,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let operator = DataOperator::instance().operator();
+        let tenant_prefix = format!(
+            "{}/",
+            temp_tables_storage_prefix(self.ctx.get_tenant().as_str())
+        );
+        let cluster = self.ctx.get_cluster();
+        let local_id = GlobalConfig::instance().query.node_id.clone();
+        let session_manager = SessionManager::instance();
+
+        let mut remove_files = vec![];
+        for node_dir in list_dirs(&operator, &tenant_prefix).await? {
+            if remove_files.len() >= limit {
+                break;
+            }
+            let node_id = node_dir.name().trim_end_matches('/');
+            if node_id == local_id {
+                let live_prefixes = session_manager.temp_table_prefixes();
+                for session_dir in list_dirs(&operator, node_dir.path()).await? {
+                    if remove_files.len() >= limit {
+                        break;
+                    }
+                    let session_prefix = session_dir.path().trim_end_matches('/');
+                    let session_id = session_dir.name().trim_end_matches('/');
+                    if live_prefixes.contains(session_prefix)
+                        || session_manager.get_session_by_id(session_id).is_some()
+                    {
+                        continue;
+                    }
+                    operator.remove_all(session_dir.path()).await?;
+                    remove_files.push(session_prefix.to_string());
+                }
+            } else if !cluster.nodes.iter().any(|node| node.id == node_id) {
+                let removed = handler
+                    .do_vacuum_temporary_files(
+                        node_dir.path().to_string(),
+                        self.plan.retain,
+                        Some(limit - remove_files.len()),
+                    )
+                    .await?;
+                remove_files.extend(removed);
+            }
+        }
+        Ok(remove_files)
+    }
 }
 
 #[async_trait::async_trait]
@@ -59,17 +122,32 @@ impl Interpreter for VacuumTemporaryFilesInterpreter {
 
         let handler = get_vacuum_handler();
 
+        let limit = self.plan.limit.map(|x| x as usize);
         let temporary_files_prefix = query_spill_prefix(self.ctx.get_tenant().as_str());
-        let remove_files = handler
-            .do_vacuum_temporary_files(
-                temporary_files_prefix,
-                self.plan.retain,
-                self.plan.limit.map(|x| x as usize),
-            )
+        let mut remove_files = handler
+            .do_vacuum_temporary_files(temporary_files_prefix, self.plan.retain, limit)
             .await?;
 
+        let limit = limit.unwrap_or(usize::MAX);
+        if remove_files.len() < limit {
+            let removed = self
+                .vacuum_temp_tables(&handler, limit - remove_files.len())
+                .await?;
+            remove_files.extend(removed);
+        }
+
         PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
             StringType::from_data(remove_files),
         ])])
     }
 }
+
+/// Lists the sub directories right under `path`.
+async fn list_dirs(operator: &Operator, path: &str) -> Result<Vec<Entry>> {
+    Ok(operator
+        .list(path)
+        .await?
+        .into_iter()
+        .filter(|entry| entry.metadata().is_dir() && entry.path() != path)
+        .collect())
+}
@@ -23,6 +23,7 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableLockKey;
 use databend_common_meta_kvapi::kvapi::Key;
 use databend_common_pipeline_core::LockGuard;
+use databend_storages_common_txn::TempTblMgr;
 
 use crate::locks::LockManager;
 
@@ -70,7 +71,9 @@ impl Lock for TableLock {
 
     async fn try_lock(&self, ctx: Arc<dyn TableContext>) -> Result<Option<LockGuard>> {
         let enabled_table_lock = ctx.get_settings().get_enable_table_lock().unwrap_or(false);
-        if enabled_table_lock {
+        // temporary tables are private to the session, so they are never locked.
+        let is_temp_table = TempTblMgr::is_temp_table_id(self.table_info.ident.table_id);
+        if enabled_table_lock && !is_temp_table {
             self.lock_mgr.try_lock(ctx, self).await
         } else {
             Ok(None)
@@ -59,6 +59,14 @@ impl PipelineBuilder {
                 input.query_source_schema.clone()
             }
             CopyIntoTableSource::Stage(source) => {
+                // The rows violating the check constraints are skipped with `ON_ERROR = CONTINUE`.
+                self.ctx.set_on_error_mode(
+                    copy.stage_table_info
+                        .stage_info
+                        .copy_options
+                        .on_error
+                        .clone(),
+                );
                 let stage_table = StageTable::try_create(copy.stage_table_info.clone())?;
                 stage_table.set_block_thresholds(to_table.get_block_thresholds());
                 stage_table.read_data(self.ctx.clone(), source, &mut self.main_pipeline, false)?;
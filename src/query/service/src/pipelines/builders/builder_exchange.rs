@@ -15,7 +15,9 @@
 use databend_common_exception::Result;
 use databend_common_sql::executor::physical_plans::ExchangeSink;
 use databend_common_sql::executor::physical_plans::ExchangeSource;
+use databend_common_sql::executor::physical_plans::FragmentKind;
 
+use crate::pipelines::processors::transforms::TransformWaitAdaptiveJoin;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -40,6 +42,20 @@ impl PipelineBuilder {
 
     pub fn build_exchange_sink(&mut self, exchange_sink: &ExchangeSink) -> Result<()> {
         // ExchangeSink will be appended by `ExchangeManager::execute_pipeline`
-        self.build_pipeline(&exchange_sink.input)
+        self.build_pipeline(&exchange_sink.input)?;
+
+        // The probe side of adaptive join can't be scattered until its build side has decided
+        // whether to keep broadcast or switch to shuffle.
+        if let FragmentKind::AdaptiveShuffle(join_id) = exchange_sink.kind {
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(TransformWaitAdaptiveJoin::create(
+                    input,
+                    output,
+                    self.ctx.get_adaptive_join_decision(join_id),
+                ))
+            })?;
+        }
+
+        Ok(())
     }
 }
@@ -16,10 +16,15 @@ use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::parse_sequence_default_expr;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAddSequenceColumns;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -39,6 +44,37 @@ impl PipelineBuilder {
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
 
+        // Fill missing columns with sequence default, they are appended to the end of the block.
+        let mut sequences = vec![];
+        let mut source_fields = source_schema.fields().clone();
+        for f in table_default_schema.fields() {
+            if source_schema.has_field(f.name()) {
+                continue;
+            }
+            if let Some(sequence) = f
+                .default_expr()
+                .and_then(|e| parse_sequence_default_expr(e))
+            {
+                let data_type = DataType::from(f.data_type());
+                let sequence = sequence.sequence_name(table.get_id(), f.column_id());
+                sequences.push((sequence, data_type.clone()));
+                source_fields.push(DataField::new(f.name(), data_type));
+            }
+        }
+        let source_schema = if !sequences.is_empty() {
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformAddSequenceColumns::try_create(
+                    ctx.clone(),
+                    transform_input_port,
+                    transform_output_port,
+                    sequences.clone(),
+                )
+            })?;
+            Arc::new(DataSchema::new(source_fields))
+        } else {
+            source_schema
+        };
+
         // Fill missing default columns and resort the columns.
         if source_schema != default_schema {
             pipeline.add_transform(|transform_input_port, transform_output_port| {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::plan::TableSample;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -26,6 +27,7 @@ use databend_common_sql::StreamContext;
 
 use crate::pipelines::processors::transforms::MaterializedCteSource;
 use crate::pipelines::processors::transforms::TransformAddInternalColumns;
+use crate::pipelines::processors::transforms::TransformRowSample;
 use crate::pipelines::processors::TransformAddStreamColumns;
 use crate::pipelines::PipelineBuilder;
 
@@ -40,6 +42,16 @@ impl PipelineBuilder {
             true,
         )?;
 
+        // Sample rows if needed, before the internal columns take the positions of the rows.
+        if let Some(sample) = TableSample::row_sample(&scan.source.push_downs) {
+            let probability = sample.probability.0;
+            let seed = sample.seed.unwrap_or_else(rand::random);
+            let keep_meta = scan.internal_column.is_some();
+            self.main_pipeline.add_transform(|input, output| {
+                TransformRowSample::try_create(input, output, probability, seed, keep_meta)
+            })?;
+        }
+
         // Fill internal columns if needed.
         if let Some(internal_columns) = &scan.internal_column {
             self.main_pipeline.add_transform(|input, output| {
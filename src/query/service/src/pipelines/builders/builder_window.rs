@@ -56,7 +56,8 @@ impl PipelineBuilder {
             .collect::<Result<Vec<_>>>()?;
 
         let old_output_len = self.main_pipeline.output_len();
-        if !partition_by.is_empty() || !order_by.is_empty() {
+        // The sort is shared with the window below.
+        if !window.presorted && (!partition_by.is_empty() || !order_by.is_empty()) {
             let mut sort_desc = Vec::with_capacity(partition_by.len() + order_by.len());
 
             for offset in &partition_by {
@@ -134,6 +135,9 @@ impl PipelineBuilder {
             Ok(ProcessorPtr::create(transform))
         })?;
 
+        if window.keep_order {
+            return Ok(());
+        }
         self.main_pipeline.try_resize(old_output_len)
     }
 }
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::sync::watch::Receiver;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::AsyncTransformer;
+
+/// The distribution of an adaptive join, decided by the build side of each node
+/// after all build data is received.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdaptiveJoinDecision {
+    /// The build side is still receiving data.
+    Pending,
+    /// Every node holds the whole build side, the probe side is kept local.
+    Broadcast,
+    /// At least one node switched to shuffle the build side by the join keys,
+    /// the probe side must be shuffled by the join keys too.
+    Shuffle,
+}
+
+/// Attached to the blocks of the build side which make a node switch from broadcast
+/// to shuffle, every destination receives one of them, so all nodes make the same decision.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct AdaptiveJoinMeta;
+
+impl AdaptiveJoinMeta {
+    pub fn create() -> BlockMetaInfoPtr {
+        Box::new(AdaptiveJoinMeta)
+    }
+
+    /// Remove the marker from `data_block`, return true if the block is marked.
+    pub fn take_from(data_block: &mut DataBlock) -> bool {
+        if data_block
+            .get_meta()
+            .and_then(AdaptiveJoinMeta::downcast_ref_from)
+            .is_none()
+        {
+            return false;
+        }
+
+        data_block.take_meta();
+        true
+    }
+}
+
+#[typetag::serde(name = "adaptive_join")]
+impl BlockMetaInfo for AdaptiveJoinMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        AdaptiveJoinMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Hold the probe side of an adaptive join until the build side of current node has made
+/// the decision, so that the scatter of probe side knows where to send the data.
+pub struct TransformWaitAdaptiveJoin {
+    decision: Receiver<AdaptiveJoinDecision>,
+}
+
+impl TransformWaitAdaptiveJoin {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        decision: Receiver<AdaptiveJoinDecision>,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(AsyncTransformer::create(
+            input,
+            output,
+            TransformWaitAdaptiveJoin { decision },
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformWaitAdaptiveJoin {
+    const NAME: &'static str = "TransformWaitAdaptiveJoin";
+
+    async fn on_start(&mut self) -> Result<()> {
+        self.decision
+            .wait_for(|decision| *decision != AdaptiveJoinDecision::Pending)
+            .await
+            .map_err(|_| ErrorCode::TokioError("adaptive join decision's sender is dropped"))?;
+        Ok(())
+    }
+
+    async fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        Ok(data)
+    }
+}
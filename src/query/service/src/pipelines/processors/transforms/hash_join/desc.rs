@@ -47,6 +47,12 @@ pub struct HashJoinDesc {
     pub(crate) probe_keys_rt: Vec<Option<(Expr<String>, IndexType)>>,
     // Under cluster, mark if the join is broadcast join.
     pub broadcast: bool,
+    // Under cluster, mark if the join is shuffle join.
+    pub shuffle: bool,
+    // Under cluster, the plan id of the join, which is the same on all nodes.
+    pub join_id: u32,
+    // Under cluster, the id of the adaptive join if the broadcast join can switch to shuffle join.
+    pub adaptive_join_id: Option<u32>,
     // If enable bloom runtime filter
     pub enable_bloom_runtime_filter: bool,
 }
@@ -88,6 +94,9 @@ impl HashJoinDesc {
             from_correlated_subquery: join.from_correlated_subquery,
             probe_keys_rt,
             broadcast: join.broadcast,
+            shuffle: join.shuffle,
+            join_id: join.plan_id,
+            adaptive_join_id: join.adaptive_join_id,
             original_join_type: join.original_join_type.clone(),
             enable_bloom_runtime_filter: join.enable_bloom_runtime_filter,
         })
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::IndexType;
+use xorf::BinaryFuse16;
+
+/// The max number of the build key hashes a bloom runtime filter of a shuffle join is built
+/// from, the hashes are sent between the nodes. Bigger filters are left out, the inlist and
+/// min max filters are still applied.
+pub const DISTRIBUTED_BLOOM_RUNTIME_FILTER_MAX_HASHES: usize = 1 << 20;
+
+/// The runtime filter of one probe key built by the build side of a shuffle join on one node.
+/// It only covers the build keys shuffled to the node, so the partial filters of all nodes are
+/// merged on one node, which sends the merged filters back to the others to be applied.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct PartialRuntimeFilter {
+    /// The table index of the probe side scan.
+    pub table_index: IndexType,
+    /// The build side of the node has no rows, nothing is contributed to the merged filter.
+    pub empty: bool,
+    pub inlist: Option<RemoteExpr<String>>,
+    pub min_max: Option<RemoteExpr<String>>,
+    /// The probe column name and the distinct hashes of the build keys.
+    pub bloom: Option<(String, Vec<u64>)>,
+}
+
+/// Merge the partial runtime filters sent by all nodes of the cluster, one item for each node,
+/// into the filters of the whole build side. A filter is kept only if every node with a
+/// non-empty build side has built it.
+pub fn merge_partial_runtime_filters(
+    partials: Vec<Vec<PartialRuntimeFilter>>,
+) -> Result<Vec<PartialRuntimeFilter>> {
+    let num_filters = partials.first().map_or(0, |filters| filters.len());
+    // Some node spilled, its build side is incomplete when building runtime filters.
+    if partials.iter().any(|filters| filters.len() != num_filters) {
+        return Ok(vec![]);
+    }
+
+    let mut merged = Vec::with_capacity(num_filters);
+    for idx in 0..num_filters {
+        let table_index = partials[0][idx].table_index;
+        let filters = partials
+            .iter()
+            .map(|filters| &filters[idx])
+            .filter(|filter| !filter.empty)
+            .collect::<Vec<_>>();
+        if filters.is_empty() {
+            merged.push(PartialRuntimeFilter {
+                table_index,
+                empty: true,
+                inlist: None,
+                min_max: None,
+                bloom: None,
+            });
+            continue;
+        }
+
+        let inlist = filters
+            .iter()
+            .map(|filter| filter.inlist.as_ref())
+            .collect::<Option<Vec<_>>>()
+            .map(disjunction)
+            .transpose()?;
+        let min_max = filters
+            .iter()
+            .map(|filter| filter.min_max.as_ref())
+            .collect::<Option<Vec<_>>>()
+            .map(disjunction)
+            .transpose()?;
+        let bloom = filters
+            .iter()
+            .map(|filter| filter.bloom.as_ref())
+            .collect::<Option<Vec<_>>>()
+            .and_then(|blooms| {
+                let hashes = blooms
+                    .iter()
+                    .flat_map(|(_, hashes)| hashes.iter().copied())
+                    .collect::<HashSet<_>>();
+                (hashes.len() <= DISTRIBUTED_BLOOM_RUNTIME_FILTER_MAX_HASHES)
+                    .then(|| (blooms[0].0.clone(), hashes.into_iter().collect()))
+            });
+        merged.push(PartialRuntimeFilter {
+            table_index,
+            empty: false,
+            inlist,
+            min_max,
+            bloom,
+        });
+    }
+    Ok(merged)
+}
+
+/// Build the runtime filters to apply from the merged filters of the whole build side.
+pub fn build_merged_runtime_filters(
+    merged: Vec<PartialRuntimeFilter>,
+) -> Result<Vec<(IndexType, RuntimeFilterInfo)>> {
+    let mut runtime_filters = Vec::with_capacity(merged.len());
+    for filter in merged {
+        let mut runtime_filter = RuntimeFilterInfo::default();
+        if let Some(inlist) = &filter.inlist {
+            runtime_filter.add_inlist(inlist.as_expr(&BUILTIN_FUNCTIONS));
+        }
+        if let Some(min_max) = &filter.min_max {
+            runtime_filter.add_min_max(min_max.as_expr(&BUILTIN_FUNCTIONS));
+        }
+        if let Some((column_name, hashes)) = &filter.bloom {
+            let bloom = BinaryFuse16::try_from(hashes)?;
+            runtime_filter.add_bloom((column_name.clone(), bloom));
+        }
+        if !runtime_filter.is_empty() {
+            runtime_filters.push((filter.table_index, runtime_filter));
+        }
+    }
+    Ok(runtime_filters)
+}
+
+fn disjunction(exprs: Vec<&RemoteExpr<String>>) -> Result<RemoteExpr<String>> {
+    let expr = exprs
+        .into_iter()
+        .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS))
+        .try_reduce(|lhs, rhs| check_function(None, "or", &[], &[lhs, rhs], &BUILTIN_FUNCTIONS))?;
+    Ok(expr.unwrap().as_remote_expr())
+}
@@ -14,6 +14,7 @@
 
 use std::collections::HashSet;
 use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::AtomicU8;
 use std::sync::atomic::AtomicUsize;
@@ -22,6 +23,8 @@ use std::sync::Arc;
 
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_base::base::tokio::sync::Barrier;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -68,10 +71,13 @@ use crate::pipelines::processors::transforms::hash_join::util::dedup_build_key_c
 use crate::pipelines::processors::transforms::hash_join::util::hash_by_method;
 use crate::pipelines::processors::transforms::hash_join::util::inlist_filter;
 use crate::pipelines::processors::transforms::hash_join::util::min_max_filter;
+use crate::pipelines::processors::transforms::hash_join::AdaptiveJoinDecision;
 use crate::pipelines::processors::transforms::hash_join::FixedKeyHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::HashJoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::PartialRuntimeFilter;
 use crate::pipelines::processors::transforms::hash_join::SerializerHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::SingleBinaryHashJoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::DISTRIBUTED_BLOOM_RUNTIME_FILTER_MAX_HASHES;
 use crate::pipelines::processors::HashJoinState;
 use crate::sessions::QueryContext;
 
@@ -125,6 +131,13 @@ pub struct HashJoinBuildState {
     pub(crate) enable_min_max_runtime_filter: bool,
     /// Need to open runtime filter setting.
     pub(crate) enable_bloom_runtime_filter: bool,
+    /// Under cluster, the runtime filters of the shuffle join are built from the partial build side
+    /// of each node, and merged with the ones of other nodes.
+    pub(crate) enable_distributed_runtime_filter: bool,
+
+    /// Adaptive join related states
+    /// Whether the build side of the adaptive join has been switched to shuffle by any node.
+    pub(crate) adaptive_join_shuffled: AtomicBool,
 }
 
 impl HashJoinBuildState {
@@ -150,11 +163,20 @@ impl HashJoinBuildState {
         let mut enable_bloom_runtime_filter = false;
         let mut enable_inlist_runtime_filter = false;
         let mut enable_min_max_runtime_filter = false;
+        let mut enable_distributed_runtime_filter = false;
         if supported_join_type_for_runtime_filter(&hash_join_state.hash_join_desc.join_type) {
             let is_cluster = !ctx.get_cluster().is_empty();
-            // For cluster, only support runtime filter for broadcast join.
+            // For cluster, the build side of broadcast join is complete on each node,
+            // the runtime filters of shuffle join need to be merged from all nodes.
             let is_broadcast_join = hash_join_state.hash_join_desc.broadcast;
-            if !is_cluster || is_broadcast_join {
+            let is_shuffle_join = hash_join_state.hash_join_desc.shuffle;
+            if is_cluster
+                && is_shuffle_join
+                && ctx.get_settings().get_enable_distributed_runtime_filter()?
+            {
+                enable_distributed_runtime_filter = true;
+            }
+            if !is_cluster || is_broadcast_join || enable_distributed_runtime_filter {
                 enable_inlist_runtime_filter = true;
                 enable_min_max_runtime_filter = true;
                 enable_bloom_runtime_filter =
@@ -184,8 +206,10 @@ impl HashJoinBuildState {
             enable_bloom_runtime_filter,
             enable_inlist_runtime_filter,
             enable_min_max_runtime_filter,
+            enable_distributed_runtime_filter,
             spilling_threshold_per_proc,
             spilled_partition_set: Default::default(),
+            adaptive_join_shuffled: AtomicBool::new(false),
         }))
     }
 
@@ -284,6 +308,36 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    pub(crate) fn mark_adaptive_join_shuffled(&self) {
+        self.adaptive_join_shuffled.store(true, Ordering::Release);
+    }
+
+    /// Notify the probe side of the adaptive join whether the build side is broadcast or shuffled,
+    /// return true if it's shuffled.
+    fn publish_adaptive_join_decision(&self) -> bool {
+        let Some(join_id) = self.hash_join_state.hash_join_desc.adaptive_join_id else {
+            return false;
+        };
+
+        let (decision, statistics_name) = match self.adaptive_join_shuffled.load(Ordering::Acquire)
+        {
+            true => (
+                AdaptiveJoinDecision::Shuffle,
+                ProfileStatisticsName::AdaptiveJoinShuffle,
+            ),
+            false => (
+                AdaptiveJoinDecision::Broadcast,
+                ProfileStatisticsName::AdaptiveJoinBroadcast,
+            ),
+        };
+        let previous = self.ctx.set_adaptive_join_decision(join_id, decision);
+        if previous == AdaptiveJoinDecision::Pending {
+            info!("Adaptive join {} decides to {:?}", join_id, decision);
+            Profile::record_usize_profile(statistics_name, 1);
+        }
+        decision == AdaptiveJoinDecision::Shuffle
+    }
+
     /// Attach to state: `row_space_builders` and `hash_table_builders`.
     pub fn build_attach(&self) -> usize {
         let worker_id = self.row_space_builders.fetch_add(1, Ordering::Relaxed);
@@ -322,8 +376,13 @@ impl HashJoinBuildState {
                     .clone()
             };
 
+            let adaptive_join_shuffled = self.publish_adaptive_join_decision();
+
             // If spilling happened, skip adding runtime filter, because probe data is ready and spilled.
-            if self.spilled_partition_set.read().is_empty() {
+            // If the adaptive join switched to shuffle, the build side of current node is partial.
+            if self.enable_distributed_runtime_filter {
+                self.add_distributed_runtime_filter(&build_chunks, build_num_rows)?;
+            } else if self.spilled_partition_set.read().is_empty() && !adaptive_join_shuffled {
                 self.add_runtime_filter(&build_chunks, build_num_rows)?;
             }
 
@@ -864,6 +923,73 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    // Build the partial runtime filters of current node, and send them to the node which merges
+    // the filters of all nodes. A spilled node sends no filters, so the merged filters are
+    // discarded on all nodes.
+    fn add_distributed_runtime_filter(
+        &self,
+        build_chunks: &[DataBlock],
+        build_num_rows: usize,
+    ) -> Result<()> {
+        let mut filters = Vec::new();
+        if self.spilled_partition_set.read().is_empty() {
+            for (build_key, probe_key, table_index) in self
+                .hash_join_state
+                .hash_join_desc
+                .build_keys
+                .iter()
+                .zip(self.hash_join_state.hash_join_desc.probe_keys_rt.iter())
+                .filter_map(|(b, p)| p.as_ref().map(|(p, index)| (b, p, index)))
+            {
+                let mut runtime_filter = RuntimeFilterInfo::default();
+                let mut bloom = None;
+                if build_num_rows > 0 {
+                    if build_num_rows < INLIST_RUNTIME_FILTER_THRESHOLD {
+                        self.inlist_runtime_filter(
+                            &mut runtime_filter,
+                            build_chunks,
+                            build_key,
+                            probe_key,
+                        )?;
+                    }
+                    if self.enable_bloom_runtime_filter {
+                        // the merged filter would be even bigger, it's not worth sending.
+                        bloom = self
+                            .bloom_runtime_filter_hashes(build_chunks, build_key, probe_key)?
+                            .filter(|(_, hashes)| {
+                                hashes.len() <= DISTRIBUTED_BLOOM_RUNTIME_FILTER_MAX_HASHES
+                            });
+                    }
+                    self.min_max_runtime_filter(
+                        build_chunks,
+                        &mut runtime_filter,
+                        build_key,
+                        probe_key,
+                    )?;
+                }
+                filters.push(PartialRuntimeFilter {
+                    table_index: *table_index,
+                    empty: build_num_rows == 0,
+                    inlist: runtime_filter
+                        .get_inlist()
+                        .first()
+                        .map(Expr::as_remote_expr),
+                    min_max: runtime_filter
+                        .get_min_max()
+                        .first()
+                        .map(Expr::as_remote_expr),
+                    bloom,
+                });
+            }
+        }
+
+        self.ctx.get_exchange_manager().broadcast_runtime_filters(
+            &self.ctx,
+            self.hash_join_state.hash_join_desc.join_id,
+            filters,
+        )
+    }
+
     fn bloom_runtime_filter(
         &self,
         data_blocks: &[DataBlock],
@@ -871,10 +997,26 @@ impl HashJoinBuildState {
         build_key: &Expr,
         probe_key: &Expr<String>,
     ) -> Result<()> {
+        if let Some((column_name, hashes)) =
+            self.bloom_runtime_filter_hashes(data_blocks, build_key, probe_key)?
+        {
+            let filter = BinaryFuse16::try_from(&hashes)?;
+            runtime_filter.add_bloom((column_name, filter));
+        }
+        Ok(())
+    }
+
+    // Get the probe column name and the distinct hashes of build keys to build the bloom filter.
+    fn bloom_runtime_filter_hashes(
+        &self,
+        data_blocks: &[DataBlock],
+        build_key: &Expr,
+        probe_key: &Expr<String>,
+    ) -> Result<Option<(String, Vec<u64>)>> {
         if !build_key.data_type().remove_nullable().is_numeric()
             && !build_key.data_type().remove_nullable().is_string()
         {
-            return Ok(());
+            return Ok(None);
         }
         if let Expr::ColumnRef { id, .. } = probe_key {
             let mut columns = Vec::with_capacity(data_blocks.len());
@@ -889,7 +1031,7 @@ impl HashJoinBuildState {
                 columns.push(column);
             }
             if columns.is_empty() {
-                return Ok(());
+                return Ok(None);
             }
             let build_key_column = Column::concat_columns(columns.into_iter())?;
             // Generate bloom filter using build column
@@ -907,10 +1049,9 @@ impl HashJoinBuildState {
             hashes.into_iter().for_each(|hash| {
                 hashes_vec.push(hash);
             });
-            let filter = BinaryFuse16::try_from(&hashes_vec)?;
-            runtime_filter.add_bloom((id.to_string(), filter));
+            return Ok(Some((id.to_string(), hashes_vec)));
         }
-        Ok(())
+        Ok(None)
     }
 
     fn inlist_runtime_filter(
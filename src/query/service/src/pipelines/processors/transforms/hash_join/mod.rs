@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod adaptive_join;
 mod build_spill;
 mod build_state;
 mod common;
 mod desc;
+mod distributed_runtime_filter;
 mod hash_join_build_state;
 mod hash_join_probe_state;
 mod hash_join_state;
@@ -30,8 +32,15 @@ mod transform_hash_join_build;
 mod transform_hash_join_probe;
 mod util;
 
+pub use adaptive_join::AdaptiveJoinDecision;
+pub use adaptive_join::AdaptiveJoinMeta;
+pub use adaptive_join::TransformWaitAdaptiveJoin;
 pub use build_spill::BuildSpillState;
 pub use desc::HashJoinDesc;
+pub use distributed_runtime_filter::build_merged_runtime_filters;
+pub use distributed_runtime_filter::merge_partial_runtime_filters;
+pub use distributed_runtime_filter::PartialRuntimeFilter;
+pub use distributed_runtime_filter::DISTRIBUTED_BLOOM_RUNTIME_FILTER_MAX_HASHES;
 pub use hash_join_build_state::HashJoinBuildState;
 pub use hash_join_probe_state::HashJoinProbeState;
 pub use hash_join_state::*;
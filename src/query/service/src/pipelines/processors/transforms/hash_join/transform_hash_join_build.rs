@@ -22,6 +22,7 @@ use databend_common_expression::DataBlock;
 use databend_common_sql::plans::JoinType;
 
 use crate::pipelines::processors::transforms::hash_join::build_spill::BuildSpillHandler;
+use crate::pipelines::processors::transforms::hash_join::AdaptiveJoinMeta;
 use crate::pipelines::processors::transforms::hash_join::BuildSpillState;
 use crate::pipelines::processors::transforms::hash_join::HashJoinBuildState;
 use crate::pipelines::processors::Event;
@@ -149,7 +150,16 @@ impl Processor for TransformHashJoinBuild {
 
                 match self.input_port.has_data() {
                     true => {
-                        self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                        let mut data_block = self.input_port.pull_data().unwrap()?;
+                        if AdaptiveJoinMeta::take_from(&mut data_block) {
+                            self.build_state.mark_adaptive_join_shuffled();
+                            // The marker may come with an empty block which has no columns.
+                            if data_block.is_empty() {
+                                self.input_port.set_need_data();
+                                return Ok(Event::NeedData);
+                            }
+                        }
+                        self.input_data = Some(data_block);
                         if self.spill_handler.check_need_spill(&mut self.input_data)? {
                             self.step = HashJoinBuildStep::Spill;
                             self.step_logs.push(HashJoinBuildStep::Spill);
@@ -22,6 +22,7 @@ pub(crate) mod range_join;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
 mod transform_add_internal_columns;
+mod transform_add_sequence_columns;
 mod transform_add_stream_columns;
 mod transform_cast_schema;
 mod transform_create_sets;
@@ -31,6 +32,7 @@ mod transform_materialized_cte;
 mod transform_merge_block;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
+mod transform_row_sample;
 mod transform_runtime_cast_schema;
 mod transform_sort_spill;
 mod transform_srf;
@@ -45,6 +47,8 @@ pub use processor_extract_hash_table_by_row_number::ExtractHashTableByRowNumber;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
 pub use transform_add_internal_columns::TransformAddInternalColumns;
+pub use transform_add_sequence_columns::SequenceCache;
+pub use transform_add_sequence_columns::TransformAddSequenceColumns;
 pub use transform_add_stream_columns::TransformAddStreamColumns;
 pub use transform_cast_schema::TransformCastSchema;
 pub use transform_create_sets::TransformCreateSets;
@@ -56,6 +60,7 @@ pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
+pub use transform_row_sample::TransformRowSample;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_sort_spill::create_transform_sort_spill;
 pub use transform_srf::TransformSRF;
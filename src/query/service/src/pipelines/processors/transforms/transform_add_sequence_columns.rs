@@ -0,0 +1,202 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_api::SequenceApi;
+use databend_common_meta_app::schema::GetSequenceNextValueReq;
+use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::AsyncTransformer;
+use databend_common_users::UserApiProvider;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::Processor;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The number of values a query node reserves from a sequence at least, the values not
+/// used by the block are cached for the following ones.
+const SEQUENCE_RESERVE_SIZE: u64 = 1000;
+
+/// The values `next, next + step, ..., end - step` reserved from a sequence.
+struct ReservedRange {
+    next: u64,
+    step: u64,
+    end: u64,
+}
+
+impl ReservedRange {
+    fn is_empty(&self) -> bool {
+        self.next >= self.end
+    }
+
+    /// Moves the values of the range to `values` until it has `count` values.
+    fn take(&mut self, count: usize, values: &mut Vec<u64>) {
+        while values.len() < count && !self.is_empty() {
+            values.push(self.next);
+            self.next += self.step;
+        }
+    }
+}
+
+/// The values of the sequences reserved by the query node, so that the blocks of the inserts
+/// do not request the meta service for each sequence one by one.
+///
+/// The values reserved by a node are not visible to the others, so the values of a sequence
+/// are unique but not ordered across the nodes. They are dropped with the sequence on the
+/// node which drops or replaces it.
+pub struct SequenceCache {
+    /// The reserved ranges of each sequence, by the tenant and the sequence name.
+    ranges: Mutex<HashMap<(String, String), Vec<ReservedRange>>>,
+}
+
+impl SequenceCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(SequenceCache {
+            ranges: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<SequenceCache> {
+        GlobalInstance::get()
+    }
+
+    /// Allocates `count` values of the sequence, from the values reserved by the node first.
+    /// A new range of at least [`SEQUENCE_RESERVE_SIZE`] values is reserved from the meta
+    /// service only when they are exhausted.
+    #[async_backtrace::framed]
+    pub async fn next_values(
+        &self,
+        tenant: &str,
+        sequence: &str,
+        count: usize,
+    ) -> Result<Vec<u64>> {
+        let key = (tenant.to_string(), sequence.to_string());
+        let mut values = Vec::with_capacity(count);
+        if let Some(ranges) = self.ranges.lock().get_mut(&key) {
+            for range in ranges.iter_mut() {
+                range.take(count, &mut values);
+            }
+            ranges.retain(|range| !range.is_empty());
+        }
+        if values.len() == count {
+            return Ok(values);
+        }
+
+        let missing = (count - values.len()) as u64;
+        let reply = UserApiProvider::instance()
+            .get_meta_store_client()
+            .get_sequence_next_value(GetSequenceNextValueReq {
+                ident: SequenceIdent::new(tenant, sequence),
+                count: missing.max(SEQUENCE_RESERVE_SIZE),
+            })
+            .await?;
+        let mut range = ReservedRange {
+            next: reply.start,
+            step: reply.step,
+            end: reply.end,
+        };
+        range.take(count, &mut values);
+        if !range.is_empty() {
+            self.ranges.lock().entry(key).or_default().push(range);
+        }
+        Ok(values)
+    }
+
+    /// Drops the values reserved from the sequence, which is dropped or replaced.
+    pub fn invalidate(&self, tenant: &str, sequence: &str) {
+        self.ranges
+            .lock()
+            .remove(&(tenant.to_string(), sequence.to_string()));
+    }
+}
+
+/// Appends the columns whose default expression is `nextval('<sequence>')`.
+///
+/// The values are allocated from the ranges reserved by the node, see [`SequenceCache`].
+pub struct TransformAddSequenceColumns {
+    func_ctx: FunctionContext,
+    tenant: String,
+    /// The sequence name and the data type of each appended column.
+    sequences: Vec<(String, DataType)>,
+}
+
+impl TransformAddSequenceColumns {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        sequences: Vec<(String, DataType)>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AsyncTransformer::create(input, output, Self {
+            func_ctx: ctx.get_function_context()?,
+            tenant: ctx.get_tenant().to_string(),
+            sequences,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAddSequenceColumns {
+    const NAME: &'static str = "AddSequenceColumnsTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let cache = SequenceCache::instance();
+        for (sequence, data_type) in &self.sequences {
+            let values = if num_rows == 0 {
+                vec![]
+            } else {
+                cache.next_values(&self.tenant, sequence, num_rows).await?
+            };
+
+            let input = DataBlock::new_from_columns(vec![UInt64Type::from_data(values)]);
+            let expr = check_cast(
+                None,
+                false,
+                Expr::ColumnRef {
+                    span: None,
+                    id: 0,
+                    data_type: DataType::Number(NumberDataType::UInt64),
+                    display_name: sequence.clone(),
+                },
+                data_type,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let evaluator = Evaluator::new(&input, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let value = evaluator.run(&expr)?;
+            data_block.add_column(BlockEntry::new(data_type.clone(), value));
+        }
+        Ok(data_block)
+    }
+}
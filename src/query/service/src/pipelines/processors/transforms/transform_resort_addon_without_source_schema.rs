@@ -32,6 +32,7 @@ use databend_common_pipeline_transforms::processors::Transformer;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::parse_exprs;
+use databend_common_sql::parse_sequence_default_expr;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::operations::UnMatchedExprs;
 
@@ -67,6 +68,13 @@ pub fn build_expression_transform(
     for f in output_schema.fields().iter() {
         let expr = if !input_schema.has_field(f.name()) {
             if let Some(default_expr) = f.default_expr() {
+                if let Some(sequence) = parse_sequence_default_expr(default_expr) {
+                    return Err(ErrorCode::SequenceError(format!(
+                        "the value of column `{}` is allocated by `{}`, it can't be omitted here",
+                        f.name(),
+                        sequence
+                    )));
+                }
                 let expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?.remove(0);
                 check_cast(None, false, expr, f.data_type(), &BUILTIN_FUNCTIONS)?
             } else {
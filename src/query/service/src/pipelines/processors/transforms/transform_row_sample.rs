@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_catalog::plan::InternalColumnMeta;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use databend_storages_common_pruner::sample_point;
+
+/// Samples every row independently with the same probability, for `TABLESAMPLE ROW`.
+///
+/// A row is kept if the hash of the seed, the location of its block and its position in the
+/// block falls below the probability, so a seeded sample keeps the same rows however the blocks
+/// are spread over processors and nodes. The positions come with the [`InternalColumnMeta`],
+/// which is kept for the internal columns if they are queried and removed otherwise.
+pub struct TransformRowSample {
+    probability: f64,
+    seed: u64,
+    keep_meta: bool,
+}
+
+impl TransformRowSample
+where Self: Transform
+{
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        probability: f64,
+        seed: u64,
+        keep_meta: bool,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            Self {
+                probability,
+                seed,
+                keep_meta,
+            },
+        )))
+    }
+}
+
+impl Transform for TransformRowSample {
+    const NAME: &'static str = "RowSampleTransform";
+
+    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        let mut meta = block
+            .take_meta()
+            .and_then(InternalColumnMeta::downcast_from)
+            .ok_or(ErrorCode::Internal("It's a bug"))?;
+        if self.probability < 1.0 {
+            let num_rows = block.num_rows();
+            let offsets = meta
+                .offsets
+                .take()
+                .unwrap_or_else(|| (0..num_rows).collect());
+            let location = meta.block_location.as_bytes();
+            let mut bitmap = MutableBitmap::with_capacity(num_rows);
+            let mut sampled = Vec::with_capacity(num_rows);
+            for offset in offsets {
+                let point = sample_point(self.seed, &[location, &(offset as u64).to_le_bytes()]);
+                let keep = point < self.probability;
+                bitmap.push(keep);
+                if keep {
+                    sampled.push(offset);
+                }
+            }
+            block = block.filter_with_bitmap(&bitmap.into())?;
+            meta.offsets = Some(sampled);
+        }
+        if self.keep_meta {
+            block.add_meta(Some(Box::new(meta)))
+        } else {
+            block.add_meta(meta.inner)
+        }
+    }
+}
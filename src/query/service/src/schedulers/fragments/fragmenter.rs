@@ -32,6 +32,7 @@ use databend_common_sql::executor::physical_plans::TableScan;
 use databend_common_sql::executor::physical_plans::UnionAll;
 use databend_common_sql::executor::PhysicalPlanReplacer;
 
+use crate::api::AdaptiveJoinExchange;
 use crate::api::BroadcastExchange;
 use crate::api::DataExchange;
 use crate::api::MergeExchange;
@@ -112,6 +113,18 @@ impl Fragmenter {
                 FragmentKind::Expansive => {
                     Ok(Some(BroadcastExchange::create(Self::get_executors(ctx))))
                 }
+                FragmentKind::AdaptiveBroadcast(join_id) => Ok(Some(AdaptiveJoinExchange::create(
+                    Self::get_executors(ctx),
+                    plan.keys.clone(),
+                    join_id,
+                    true,
+                ))),
+                FragmentKind::AdaptiveShuffle(join_id) => Ok(Some(AdaptiveJoinExchange::create(
+                    Self::get_executors(ctx),
+                    plan.keys.clone(),
+                    join_id,
+                    false,
+                ))),
                 _ => Ok(None),
             },
             _ => Ok(None),
@@ -253,6 +266,8 @@ impl PhysicalPlanReplacer for Fragmenter {
             probe_keys_rt: plan.probe_keys_rt.clone(),
             enable_bloom_runtime_filter: plan.enable_bloom_runtime_filter,
             broadcast: plan.broadcast,
+            shuffle: plan.shuffle,
+            adaptive_join_id: plan.adaptive_join_id,
             original_join_type: plan.original_join_type.clone(),
         }))
     }
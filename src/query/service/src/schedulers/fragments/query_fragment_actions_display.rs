@@ -72,6 +72,7 @@ impl<'a> Display for QueryFragmentActionsWrap<'a> {
                 DataExchange::Merge(_) => writeln!(f, "  DataExchange: Merge")?,
                 DataExchange::Broadcast(_) => writeln!(f, "  DataExchange: Broadcast")?,
                 DataExchange::ShuffleDataExchange(_) => writeln!(f, "  DataExchange: Shuffle")?,
+                DataExchange::AdaptiveJoin(_) => writeln!(f, "  DataExchange: AdaptiveJoin")?,
             }
         }
 
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use arrow_array::builder::Int32Builder;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
@@ -23,8 +24,10 @@ use arrow_schema::Field;
 use arrow_schema::Schema;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_meta_app::schema::TableConstraint;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
@@ -57,12 +60,17 @@ impl CatalogInfoProvider {
             vec![(
                 catalog_name.clone(),
                 catalog_mgr
-                    .get_catalog(tenant.as_str(), &catalog_name, ctx.txn_mgr())
+                    .get_catalog(
+                        tenant.as_str(),
+                        &catalog_name,
+                        ctx.txn_mgr(),
+                        ctx.temp_tbl_mgr(),
+                    )
                     .await?,
             )]
         } else {
             catalog_mgr
-                .list_catalogs(tenant.as_str(), ctx.txn_mgr())
+                .list_catalogs(tenant.as_str(), ctx.txn_mgr(), ctx.temp_tbl_mgr())
                 .await?
                 .iter()
                 .map(|r| (r.name(), r.clone()))
@@ -127,6 +135,63 @@ impl CatalogInfoProvider {
         Self::batch_to_get_stream(batch)
     }
 
+    /// Returns the columns of the primary key of the table, ordered by the key sequence.
+    pub(crate) async fn get_primary_keys(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: String,
+    ) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+
+        let catalog_name = catalog_name.unwrap_or_else(|| CATALOG_DEFAULT.to_string());
+        let database_name = database_name.unwrap_or_else(|| ctx.get_current_database());
+        let table = ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let meta = &table.get_table_info().meta;
+
+        let mut column_names = vec![];
+        let mut key_names = vec![];
+        let mut key_sequences = Int32Builder::new();
+        for (name, constraint) in &meta.constraints {
+            if let TableConstraint::PrimaryKey { column_ids } = constraint {
+                for (i, column_id) in column_ids.iter().enumerate() {
+                    if let Some(field) = meta
+                        .schema
+                        .fields()
+                        .iter()
+                        .find(|f| f.column_id() == *column_id)
+                    {
+                        column_names.push(field.name().clone());
+                        key_names.push(name.clone());
+                        key_sequences.append_value(i as i32 + 1);
+                    }
+                }
+            }
+        }
+
+        let num_rows = column_names.len();
+        let batch = RecordBatch::try_new(schema, vec![
+            Self::string_array(vec![catalog_name; num_rows]),
+            Self::string_array(vec![database_name; num_rows]),
+            Self::string_array(vec![table_name; num_rows]),
+            Self::string_array(column_names),
+            Self::string_array(key_names),
+            Arc::new(key_sequences.finish()),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -30,6 +30,7 @@ use databend_common_settings::Settings;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 use futures::StreamExt;
 use log::error;
@@ -141,6 +142,7 @@ pub struct ExecutorSessionState {
     pub secondary_roles: Option<Vec<String>>,
     pub settings: Arc<Settings>,
     pub txn_manager: TxnManagerRef,
+    pub temp_tbl_manager: TempTblMgrRef,
 }
 
 impl ExecutorSessionState {
@@ -151,6 +153,7 @@ impl ExecutorSessionState {
             secondary_roles: session.get_secondary_roles(),
             settings: session.get_settings(),
             txn_manager: session.txn_mgr(),
+            temp_tbl_manager: session.temp_tbl_mgr(),
         }
     }
 }
@@ -51,5 +51,7 @@ impl Expirable for Arc<Session> {
         }
     }
 
-    fn on_expire(&self) {}
+    fn on_expire(&self) {
+        self.drop_temp_tables();
+    }
 }
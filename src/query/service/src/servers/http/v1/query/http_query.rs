@@ -260,6 +260,18 @@ pub struct HttpQuery {
     /// exceed this result_timeout_secs.
     pub(crate) result_timeout_secs: u64,
     pub(crate) is_txn_mgr_saved: AtomicBool,
+    /// The user who owns the temporary tables handed over to the next query.
+    temp_tbl_owner: String,
+}
+
+/// Temporary tables handed over between the queries of a http session are only
+/// taken by the queries of the same user.
+fn temp_tbl_owner(session: &Arc<Session>) -> Result<String> {
+    Ok(format!(
+        "{}/{}",
+        session.get_current_tenant(),
+        session.get_current_user()?.identity()
+    ))
 }
 
 fn try_set_txn(
@@ -391,6 +403,16 @@ impl HttpQuery {
                 }
             }
             try_set_txn(&ctx.query_id, &session, session_conf, &http_query_manager)?;
+            if request.session_id.is_none() {
+                if let Some(last_query_id) = session_conf.last_query_ids.first() {
+                    let owner = temp_tbl_owner(&session)?;
+                    if let Some(temp_tbl_mgr) =
+                        http_query_manager.get_temp_tbl_mgr(last_query_id, &owner)
+                    {
+                        session.set_temp_tbl_mgr(temp_tbl_mgr);
+                    }
+                }
+            }
 
             if let Some(secs) = session_conf.keep_server_session_secs {
                 if secs > 0 && request.session_id.is_none() {
@@ -425,6 +447,7 @@ impl HttpQuery {
 
         let session_id = session.get_id().clone();
         let node_id = ctx.get_cluster().local_id.clone();
+        let temp_tbl_owner = temp_tbl_owner(&session)?;
         let sql = &request.sql;
         info!(query_id = query_id, session_id = session_id, node_id = node_id, sql = sql; "create query");
 
@@ -519,6 +542,7 @@ impl HttpQuery {
             result_timeout_secs,
             expire_state: Arc::new(TokioMutex::new(ExpireState::Working)),
             is_txn_mgr_saved: AtomicBool::new(false),
+            temp_tbl_owner,
         };
 
         Ok(Arc::new(query))
@@ -611,6 +635,19 @@ impl HttpQuery {
             HttpQueryManager::instance()
                 .add_txn(self.id.clone(), session_state.txn_manager.clone(), timeout)
                 .await;
+            // The server session keeps its temporary tables by itself.
+            let is_server_session = self.request.session_id.is_some()
+                || keep_server_session_secs.map_or(false, |secs| secs > 0);
+            if !is_server_session && !session_state.temp_tbl_manager.lock().is_empty() {
+                HttpQueryManager::instance()
+                    .add_temp_tbl_mgr(
+                        self.id.clone(),
+                        self.temp_tbl_owner.clone(),
+                        session_state.temp_tbl_manager.clone(),
+                        timeout,
+                    )
+                    .await;
+            }
         }
         HttpSessionConf {
             database: Some(database),
@@ -29,6 +29,7 @@ use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 use log::warn;
 use parking_lot::Mutex;
@@ -36,6 +37,7 @@ use tokio::task;
 
 use super::expiring_map::ExpiringMap;
 use super::HttpQueryContext;
+use crate::interpreters::common::purge_temp_tables;
 use crate::servers::http::v1::query::http_query::ExpireResult;
 use crate::servers::http::v1::query::http_query::HttpQuery;
 use crate::servers::http::v1::query::http_query::ServerInfo;
@@ -98,6 +100,9 @@ pub struct HttpQueryManager {
     pub(crate) removed_queries: Arc<RwLock<SizeLimitedIndexMap<String, RemoveReason>>>,
     #[allow(clippy::type_complexity)]
     pub(crate) txn_managers: Arc<Mutex<HashMap<String, (TxnManagerRef, task::JoinHandle<()>)>>>,
+    #[allow(clippy::type_complexity)]
+    pub(crate) temp_tbl_mgrs:
+        Arc<Mutex<HashMap<String, (String, TempTblMgrRef, task::JoinHandle<()>)>>>,
     pub(crate) sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
 }
 
@@ -113,6 +118,7 @@ impl HttpQueryManager {
             sessions: Mutex::new(ExpiringMap::default()),
             removed_queries: Arc::new(RwLock::new(SizeLimitedIndexMap::new(1000))),
             txn_managers: Arc::new(Mutex::new(HashMap::new())),
+            temp_tbl_mgrs: Arc::new(Mutex::new(HashMap::new())),
         }));
 
         Ok(())
@@ -275,6 +281,80 @@ impl HttpQueryManager {
         }
     }
 
+    /// Keeps the temporary tables of a stateless http session until the next query of it,
+    /// the tables are dropped if no query arrives within `timeout_secs`.
+    ///
+    /// `owner` identifies the tenant and the user of the session, the tables are only
+    /// handed over to the queries of the same owner.
+    #[async_backtrace::framed]
+    pub(crate) async fn add_temp_tbl_mgr(
+        self: &Arc<Self>,
+        last_query_id: String,
+        owner: String,
+        temp_tbl_mgr: TempTblMgrRef,
+        timeout_secs: u64,
+    ) {
+        let mut temp_tbl_mgrs = self.temp_tbl_mgrs.lock();
+        let deleter = {
+            let self_clone = self.clone();
+            let last_query_id_clone = last_query_id.clone();
+            GlobalIORuntime::instance().spawn(last_query_id.clone(), async move {
+                sleep(Duration::from_secs(timeout_secs)).await;
+                let temp_tbl_mgr = self_clone
+                    .temp_tbl_mgrs
+                    .lock()
+                    .remove(&last_query_id_clone)
+                    .map(|(_, temp_tbl_mgr, _)| temp_tbl_mgr);
+                if let Some(temp_tbl_mgr) = temp_tbl_mgr {
+                    log::info!(
+                        "temporary tables timeout after {} secs, last_query_id = {}.",
+                        timeout_secs,
+                        last_query_id_clone
+                    );
+                    let tables = temp_tbl_mgr.lock().drop_all_tables();
+                    if let Err(e) = purge_temp_tables(tables).await {
+                        warn!("failed to purge temporary tables: {}", e);
+                    }
+                }
+            })
+        };
+        temp_tbl_mgrs.insert(last_query_id, (owner, temp_tbl_mgr, deleter));
+    }
+
+    /// Takes the temporary tables kept for `last_query_id`, returns None if they
+    /// belong to another owner.
+    #[async_backtrace::framed]
+    pub(crate) fn get_temp_tbl_mgr(
+        self: &Arc<Self>,
+        last_query_id: &str,
+        owner: &str,
+    ) -> Option<TempTblMgrRef> {
+        let mut temp_tbl_mgrs = self.temp_tbl_mgrs.lock();
+        match temp_tbl_mgrs.get(last_query_id) {
+            Some((tables_owner, _, _)) if tables_owner == owner => {}
+            Some(_) => {
+                warn!(
+                    "temporary tables of last query {} are not owned by {}",
+                    last_query_id, owner
+                );
+                return None;
+            }
+            None => return None,
+        }
+        let (_, temp_tbl_mgr, task_handle) = temp_tbl_mgrs.remove(last_query_id)?;
+        task_handle.abort();
+        Some(temp_tbl_mgr)
+    }
+
+    /// Returns the temporary tables which are waiting for the next query of their sessions.
+    pub(crate) fn pending_temp_tbl_mgrs(&self) -> Vec<TempTblMgrRef> {
+        self.temp_tbl_mgrs
+            .lock()
+            .values()
+            .map(|(_, temp_tbl_mgr, _)| temp_tbl_mgr.clone())
+            .collect()
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn get_session(self: &Arc<Self>, session_id: &str) -> Option<Arc<Session>> {
         let sessions = self.sessions.lock();
@@ -32,6 +32,8 @@ use std::time::UNIX_EPOCH;
 use chrono_tz::Tz;
 use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
+use databend_common_base::base::tokio::sync::watch;
+use databend_common_base::base::tokio::sync::watch::Receiver;
 use databend_common_base::base::tokio::task::JoinHandle;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
@@ -72,6 +74,10 @@ use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_metrics::storage::*;
+use databend_common_openai::onnx_model_path;
+use databend_common_openai::AIConnection;
+use databend_common_openai::AI_PROVIDER_ONNX;
+use databend_common_openai::AI_PROVIDER_OPENAI;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::InputError;
 use databend_common_settings::Settings;
@@ -91,6 +97,7 @@ use databend_common_storages_stage::StageTable;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 use log::debug;
 use log::info;
@@ -101,6 +108,10 @@ use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::build_merged_runtime_filters;
+use crate::pipelines::processors::transforms::merge_partial_runtime_filters;
+use crate::pipelines::processors::transforms::AdaptiveJoinDecision;
+use crate::pipelines::processors::transforms::PartialRuntimeFilter;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueriesQueueManager;
@@ -225,6 +236,56 @@ impl QueryContext {
         DataExchangeManager::instance()
     }
 
+    /// Subscribe the decision of the adaptive join with `join_id` on current node.
+    pub fn get_adaptive_join_decision(&self, join_id: u32) -> Receiver<AdaptiveJoinDecision> {
+        let mut adaptive_joins = self.shared.adaptive_joins.lock();
+        adaptive_joins
+            .entry(join_id)
+            .or_insert_with(|| watch::channel(AdaptiveJoinDecision::Pending).0)
+            .subscribe()
+    }
+
+    /// Notify the decision of the adaptive join with `join_id` on current node, return the previous one.
+    pub fn set_adaptive_join_decision(
+        &self,
+        join_id: u32,
+        decision: AdaptiveJoinDecision,
+    ) -> AdaptiveJoinDecision {
+        let mut adaptive_joins = self.shared.adaptive_joins.lock();
+        adaptive_joins
+            .entry(join_id)
+            .or_insert_with(|| watch::channel(AdaptiveJoinDecision::Pending).0)
+            .send_replace(decision)
+    }
+
+    /// Add the partial runtime filters of the shuffle join with `join_id` built by one node,
+    /// returns the merged filters once all nodes of the cluster have added theirs.
+    pub fn add_partial_runtime_filters(
+        &self,
+        join_id: u32,
+        filters: Vec<PartialRuntimeFilter>,
+    ) -> Result<Option<Vec<PartialRuntimeFilter>>> {
+        let num_nodes = self.get_cluster().nodes.len();
+        let partials = {
+            let mut partial_runtime_filters = self.shared.partial_runtime_filters.lock();
+            let partials = partial_runtime_filters.entry(join_id).or_default();
+            partials.push(filters);
+            if partials.len() < num_nodes {
+                return Ok(None);
+            }
+            partial_runtime_filters.remove(&join_id).unwrap_or_default()
+        };
+        Ok(Some(merge_partial_runtime_filters(partials)?))
+    }
+
+    /// Apply the merged runtime filters of the shuffle join to the probe scans of current node.
+    pub fn set_merged_runtime_filters(&self, merged: Vec<PartialRuntimeFilter>) -> Result<()> {
+        for runtime_filter in build_merged_runtime_filters(merged)? {
+            self.set_runtime_filter(runtime_filter);
+        }
+        Ok(())
+    }
+
     // Get the current session.
     pub fn get_current_session(&self) -> Arc<Session> {
         self.shared.session.clone()
@@ -517,6 +578,7 @@ impl TableContext for QueryContext {
                 self.get_tenant().as_str(),
                 catalog_name.as_ref(),
                 self.txn_mgr(),
+                self.temp_tbl_mgr(),
             )
             .await
     }
@@ -524,7 +586,7 @@ impl TableContext for QueryContext {
     fn get_default_catalog(&self) -> Result<Arc<dyn Catalog>> {
         self.shared
             .catalog_manager
-            .get_default_catalog(self.txn_mgr())
+            .get_default_catalog(self.txn_mgr(), self.temp_tbl_mgr())
     }
 
     fn get_id(&self) -> String {
@@ -616,7 +678,10 @@ impl TableContext for QueryContext {
 
         let query_config = &GlobalConfig::instance().query;
 
-        Ok(FunctionContext {
+        let openai_api_embedding_batch_size =
+            self.get_settings().get_openai_api_embedding_batch_size()?;
+
+        let mut func_ctx = FunctionContext {
             tz,
             rounding_mode,
             disable_variant_check,
@@ -627,10 +692,51 @@ impl TableContext for QueryContext {
             openai_api_embedding_base_url: query_config.openai_api_embedding_base_url.clone(),
             openai_api_embedding_model: query_config.openai_api_embedding_model.clone(),
             openai_api_completion_model: query_config.openai_api_completion_model.clone(),
+            openai_api_embedding_batch_size,
+            ai_embedding_provider: query_config.ai_embedding_provider.clone(),
+            ai_onnx_embedding_model_path: String::new(),
+            ai_provider_error: String::new(),
 
             external_server_connect_timeout_secs,
             external_server_request_timeout_secs,
-        })
+        };
+
+        // The api key of the config is only sent to the endpoints of the config,
+        // a connection carries its own api key.
+        let mut onnx_embedding_model = query_config.ai_onnx_embedding_model.clone();
+        match self.shared.get_ai_connection() {
+            None => {}
+            Some(Ok(AIConnection::OpenAI {
+                endpoint_url,
+                api_key,
+                api_version,
+                embedding_model,
+                completion_model,
+            })) => {
+                func_ctx.openai_api_chat_base_url = endpoint_url.clone();
+                func_ctx.openai_api_embedding_base_url = endpoint_url;
+                func_ctx.openai_api_key = api_key;
+                func_ctx.openai_api_version = api_version;
+                func_ctx.openai_api_embedding_model = embedding_model;
+                func_ctx.openai_api_completion_model = completion_model;
+                func_ctx.ai_embedding_provider = AI_PROVIDER_OPENAI.to_string();
+            }
+            Some(Ok(AIConnection::Onnx { model })) => {
+                func_ctx.ai_embedding_provider = AI_PROVIDER_ONNX.to_string();
+                onnx_embedding_model = model;
+            }
+            Some(Err(e)) => func_ctx.ai_provider_error = e.message(),
+        }
+        if func_ctx.ai_embedding_provider == AI_PROVIDER_ONNX {
+            match onnx_model_path(&query_config.ai_onnx_models_dir, &onnx_embedding_model) {
+                Ok(path) => {
+                    func_ctx.ai_onnx_embedding_model_path = path.to_string_lossy().to_string()
+                }
+                Err(e) => func_ctx.ai_provider_error = e.message(),
+            }
+        }
+
+        Ok(func_ctx)
     }
 
     fn get_connection_id(&self) -> String {
@@ -1026,6 +1132,10 @@ impl TableContext for QueryContext {
     fn txn_mgr(&self) -> TxnManagerRef {
         self.shared.session.session_ctx.txn_mgr()
     }
+
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.shared.session.session_ctx.temp_tbl_mgr()
+    }
 }
 
 impl TrySpawn for QueryContext {
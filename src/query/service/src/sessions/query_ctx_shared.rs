@@ -21,6 +21,7 @@ use std::sync::Weak;
 use std::time::SystemTime;
 
 use dashmap::DashMap;
+use databend_common_base::base::tokio::sync::watch::Sender;
 use databend_common_base::base::Progress;
 use databend_common_base::runtime::drop_guard;
 use databend_common_base::runtime::Runtime;
@@ -38,6 +39,7 @@ use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_types::NonEmptyString;
+use databend_common_openai::AIConnection;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::InputError;
 use databend_common_settings::Settings;
@@ -54,6 +56,8 @@ use uuid::Uuid;
 
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::transforms::AdaptiveJoinDecision;
+use crate::pipelines::processors::transforms::PartialRuntimeFilter;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
 use crate::storages::Table;
@@ -121,8 +125,18 @@ pub struct QueryContextShared {
 
     pub(in crate::sessions) merge_into_join: Arc<RwLock<MergeIntoJoin>>,
 
+    /// Key is the id of adaptive join, value notifies the decision made by its build side.
+    pub(in crate::sessions) adaptive_joins: Arc<Mutex<HashMap<u32, Sender<AdaptiveJoinDecision>>>>,
+
+    /// Key is the id of a shuffle join, value is the partial runtime filters received from each node.
+    pub(in crate::sessions) partial_runtime_filters:
+        Arc<Mutex<HashMap<u32, Vec<Vec<PartialRuntimeFilter>>>>>,
+
     // Records query level data cache metrics
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
+
+    /// The connection of the `ai_connection` setting, resolved when the query context is created.
+    pub(in crate::sessions) ai_connection: Arc<RwLock<Option<Result<AIConnection>>>>,
 }
 
 impl QueryContextShared {
@@ -170,6 +184,9 @@ impl QueryContextShared {
             query_profiles: Arc::new(RwLock::new(HashMap::new())),
             runtime_filters: Default::default(),
             merge_into_join: Default::default(),
+            adaptive_joins: Default::default(),
+            partial_runtime_filters: Default::default(),
+            ai_connection: Default::default(),
         }))
     }
 
@@ -335,7 +352,12 @@ impl QueryContextShared {
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self
             .catalog_manager
-            .get_catalog(tenant.as_str(), catalog, self.session.session_ctx.txn_mgr())
+            .get_catalog(
+                tenant.as_str(),
+                catalog,
+                self.session.session_ctx.txn_mgr(),
+                self.session.session_ctx.temp_tbl_mgr(),
+            )
             .await?;
         let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
 
@@ -365,7 +387,12 @@ impl QueryContextShared {
                 let tenant = self.get_tenant();
                 let catalog = self
                     .catalog_manager
-                    .get_catalog(tenant.as_str(), catalog, self.session.session_ctx.txn_mgr())
+                    .get_catalog(
+                        tenant.as_str(),
+                        catalog,
+                        self.session.session_ctx.txn_mgr(),
+                        self.session.session_ctx.temp_tbl_mgr(),
+                    )
                     .await?;
                 let source_table = match catalog.get_stream_source_table(stream_desc)? {
                     Some(source_table) => source_table,
@@ -503,6 +530,32 @@ impl QueryContextShared {
     pub fn get_query_cache_metrics(&self) -> &DataCacheMetrics {
         &self.query_cache_metrics
     }
+
+    /// Resolves the connection of the `ai_connection` setting. An error doesn't fail the query,
+    /// it's kept and reported by the AI functions only.
+    #[async_backtrace::framed]
+    pub async fn resolve_ai_connection(&self) -> Result<()> {
+        let name = self.get_settings().get_ai_connection()?;
+        let connection = if name.is_empty() {
+            None
+        } else {
+            let connection = self.get_connection(&name).await.and_then(|connection| {
+                AIConnection::try_create(&connection.storage_type, &connection.storage_params)
+            });
+            Some(connection.map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "ai_connection '{name}' can't be used: {}",
+                    e.message()
+                ))
+            }))
+        };
+        *self.ai_connection.write() = connection;
+        Ok(())
+    }
+
+    pub fn get_ai_connection(&self) -> Option<Result<AIConnection>> {
+        self.ai_connection.read().clone()
+    }
 }
 
 impl Drop for QueryContextShared {
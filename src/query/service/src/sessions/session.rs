@@ -16,6 +16,7 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use databend_common_base::runtime::drop_guard;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -28,11 +29,14 @@ use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_settings::Settings;
 use databend_common_users::GrantObjectVisibilityChecker;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 use log::debug;
+use log::warn;
 use parking_lot::RwLock;
 
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::common::purge_temp_tables;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session_privilege_mgr::SessionPrivilegeManager;
 use crate::sessions::session_privilege_mgr::SessionPrivilegeManagerImpl;
@@ -151,6 +155,7 @@ impl Session {
         let session = self.clone();
         let cluster = ClusterDiscovery::instance().discover(&config).await?;
         let shared = QueryContextShared::try_create(session, cluster)?;
+        shared.resolve_ai_connection().await?;
 
         self.session_ctx
             .set_query_context_shared(Arc::downgrade(&shared));
@@ -318,12 +323,42 @@ impl Session {
     pub fn set_txn_mgr(&self, txn_mgr: TxnManagerRef) {
         self.session_ctx.set_txn_mgr(txn_mgr)
     }
+
+    pub fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.session_ctx.temp_tbl_mgr()
+    }
+    pub fn set_temp_tbl_mgr(&self, temp_tbl_mgr: TempTblMgrRef) {
+        self.session_ctx.set_temp_tbl_mgr(temp_tbl_mgr)
+    }
+
+    /// Drops all the temporary tables of the session, the data is purged in background.
+    pub fn drop_temp_tables(&self) {
+        let tables = self.temp_tbl_mgr().lock().drop_all_tables();
+        if tables.is_empty() {
+            return;
+        }
+
+        let session_id = self.id.clone();
+        GlobalIORuntime::instance().spawn(session_id.clone(), async move {
+            if let Err(cause) = purge_temp_tables(tables).await {
+                warn!(
+                    "Purge temporary tables of session {} failed: {:?}",
+                    session_id, cause
+                );
+            }
+        });
+    }
 }
 
 impl Drop for Session {
     fn drop(&mut self) {
         drop_guard(move || {
             debug!("Drop session {}", self.id.clone());
+            // The temporary tables of http query sessions are handed over to the next query
+            // of the http session, they are dropped when the http session expires.
+            if !matches!(self.get_type(), SessionType::HTTPQuery) {
+                self.drop_temp_tables();
+            }
             SessionManager::instance().destroy_session(&self.id.clone());
         })
     }
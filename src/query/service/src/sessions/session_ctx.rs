@@ -25,6 +25,8 @@ use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_settings::Settings;
+use databend_storages_common_txn::TempTblMgr;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManager;
 use databend_storages_common_txn::TxnManagerRef;
 use parking_lot::Mutex;
@@ -68,6 +70,7 @@ pub struct SessionContext {
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
     typ: SessionType,
     txn_mgr: Mutex<TxnManagerRef>,
+    temp_tbl_mgr: Mutex<TempTblMgrRef>,
 }
 
 impl SessionContext {
@@ -88,6 +91,7 @@ impl SessionContext {
             query_ids_results: Default::default(),
             typ,
             txn_mgr: Mutex::new(TxnManager::init()),
+            temp_tbl_mgr: Mutex::new(TempTblMgr::init()),
         }))
     }
 
@@ -300,4 +304,12 @@ impl SessionContext {
     pub fn set_txn_mgr(&self, txn_mgr: TxnManagerRef) {
         *self.txn_mgr.lock() = txn_mgr;
     }
+
+    pub fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.temp_tbl_mgr.lock().clone()
+    }
+
+    pub fn set_temp_tbl_mgr(&self, temp_tbl_mgr: TempTblMgrRef) {
+        *self.temp_tbl_mgr.lock() = temp_tbl_mgr;
+    }
 }
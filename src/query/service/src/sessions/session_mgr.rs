@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::future::Future;
 use std::ops::DerefMut;
 use std::sync::atomic::AtomicU32;
@@ -33,11 +34,13 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_metrics::session::*;
 use databend_common_settings::Settings;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use futures::future::Either;
 use futures::StreamExt;
 use log::info;
 use parking_lot::RwLock;
 
+use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session::Session;
 use crate::sessions::ProcessInfo;
 use crate::sessions::SessionContext;
@@ -163,6 +166,29 @@ impl SessionManager {
         sessions.get(id).and_then(|weak_ptr| weak_ptr.upgrade())
     }
 
+    /// Returns the storage prefixes of the temporary tables held by the sessions of this node,
+    /// including the ones waiting for the next query of a stateless http session.
+    pub fn temp_table_prefixes(&self) -> HashSet<String> {
+        let sessions = {
+            let sessions = self.active_sessions.read();
+            sessions
+                .values()
+                .filter_map(Weak::upgrade)
+                .collect::<Vec<_>>()
+        };
+        let mut temp_tbl_mgrs = sessions
+            .iter()
+            .map(|session| session.temp_tbl_mgr())
+            .collect::<Vec<_>>();
+        temp_tbl_mgrs.extend(HttpQueryManager::instance().pending_temp_tbl_mgrs());
+
+        temp_tbl_mgrs
+            .iter()
+            .flat_map(|temp_tbl_mgr| temp_tbl_mgr.lock().list_all_tables())
+            .filter_map(|table_info| table_info.options().get(OPT_KEY_TEMP_PREFIX).cloned())
+            .collect()
+    }
+
     pub fn get_id_by_mysql_conn_id(&self, mysql_conn_id: &Option<u32>) -> Option<String> {
         let sessions = self.mysql_conn_map.read();
         sessions.get(mysql_conn_id).cloned()
@@ -25,7 +25,6 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_function::TableFunction;
-use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
@@ -217,27 +216,18 @@ impl AsyncSource for GPT2SQLSource {
         info!("openai request prompt: {}", prompt);
 
         // Response.
-        let api_base = GlobalConfig::instance()
-            .query
-            .openai_api_chat_base_url
-            .clone();
-        let api_key = GlobalConfig::instance().query.openai_api_key.clone();
-        let api_version = GlobalConfig::instance().query.openai_api_version.clone();
-        let api_embedding_model = GlobalConfig::instance()
-            .query
-            .openai_api_embedding_model
-            .clone();
-        let api_completion_model = GlobalConfig::instance()
-            .query
-            .openai_api_completion_model
-            .clone();
+        let func_ctx = self.ctx.get_function_context()?;
+        if !func_ctx.ai_provider_error.is_empty() {
+            return Err(ErrorCode::BadArguments(func_ctx.ai_provider_error));
+        }
         let openai = OpenAI::create(
-            api_base,
-            api_key,
-            api_version,
-            api_embedding_model,
-            api_completion_model,
+            func_ctx.openai_api_chat_base_url,
+            func_ctx.openai_api_key,
+            func_ctx.openai_api_version,
+            func_ctx.openai_api_embedding_model,
+            func_ctx.openai_api_completion_model,
         );
+        openai.check_api_key()?;
 
         info!("openai info: {:?}", openai);
 
@@ -320,6 +320,8 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            constraints: Default::default(),
+            clone_source: None,
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -345,6 +347,8 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            constraints: Default::default(),
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -381,6 +385,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            constraints: Default::default(),
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -417,6 +423,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            constraints: Default::default(),
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -462,6 +470,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            constraints: Default::default(),
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -511,6 +521,7 @@ impl TestFixture {
                 engine: "".to_string(),
                 ..Default::default()
             },
+            clone_from: None,
         };
 
         self.default_ctx
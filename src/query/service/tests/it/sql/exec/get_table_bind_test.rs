@@ -127,6 +127,7 @@ use databend_common_users::GrantObjectVisibilityChecker;
 use databend_query::sessions::QueryContext;
 use databend_query::test_kits::*;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
@@ -423,6 +424,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        todo!()
+    }
+
     fn incr_total_scan_value(&self, _value: ProgressValues) {
         todo!()
     }
@@ -84,6 +84,8 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         .into(),
         field_comments: vec![],
         as_select: None,
+        constraints: Default::default(),
+        clone_source: None,
         cluster_key: None,
     }
 }
@@ -131,6 +131,7 @@ use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::Statistics;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_txn::TempTblMgrRef;
 use databend_storages_common_txn::TxnManagerRef;
 use futures::TryStreamExt;
 use parking_lot::RwLock;
@@ -381,6 +382,10 @@ impl TableContext for CtxDelegation {
         self.ctx.txn_mgr()
     }
 
+    fn temp_tbl_mgr(&self) -> TempTblMgrRef {
+        self.ctx.temp_tbl_mgr()
+    }
+
     fn incr_total_scan_value(&self, _value: ProgressValues) {
         todo!()
     }
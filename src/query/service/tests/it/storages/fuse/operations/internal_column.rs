@@ -50,6 +50,7 @@ fn expected_data_block(
             block_location: block_meta.block_location.clone(),
             segment_location: block_meta.segment_location.clone(),
             snapshot_location: block_meta.snapshot_location.clone(),
+            create_on: fuse_part.create_on,
             offsets: None,
             base_block_ids: None,
             inner: None,
@@ -160,6 +161,9 @@ async fn test_internal_column() -> Result<()> {
         INTERNAL_COLUMN_FACTORY
             .get_internal_column("_block_name")
             .unwrap(),
+        INTERNAL_COLUMN_FACTORY
+            .get_internal_column("_block_timestamp")
+            .unwrap(),
     ];
 
     // insert 5 times
@@ -176,7 +180,7 @@ async fn test_internal_column() -> Result<()> {
     }
 
     let query = format!(
-        "select _row_id,_snapshot_name,_segment_name,_block_name from {}.{} order by _row_id",
+        "select _row_id,_snapshot_name,_segment_name,_block_name,_block_timestamp from {}.{} order by _row_id",
         db, tbl
     );
     let res = fixture.execute_query(&query).await?;
@@ -201,7 +205,7 @@ async fn test_internal_column() -> Result<()> {
     let ctx = fixture.new_query_ctx().await?;
     // ctx.evict_table_from_cache(&catalog, &db, &tbl)?;
     let query = format!(
-        "select _row_id,_snapshot_name,_segment_name,_block_name from {}.{} order by _row_id",
+        "select _row_id,_snapshot_name,_segment_name,_block_name,_block_timestamp from {}.{} order by _row_id",
         db, tbl
     );
     let res = fixture.execute_query(&query).await?;
@@ -107,6 +107,8 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        constraints: Default::default(),
+        clone_source: None,
         cluster_key: None,
     };
 
@@ -38,8 +38,11 @@ use databend_common_expression::TableSchema;
 use databend_common_functions::aggregates::eval_aggr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::evaluator::BlockOperator;
+use databend_common_storages_fuse::statistics::build_column_histogram;
 use databend_common_storages_fuse::statistics::reducers::reduce_block_metas;
 use databend_common_storages_fuse::statistics::Trim;
+use databend_common_storages_fuse::statistics::HISTOGRAM_MAX_VALUE_SIZE;
+use databend_common_storages_fuse::statistics::HISTOGRAM_NUM_BUCKETS;
 use databend_common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
 use databend_common_storages_fuse::statistics::STATS_STRING_PREFIX_LEN;
 use databend_common_storages_fuse::FuseStorageFormat;
@@ -51,6 +54,7 @@ use databend_query::storages::fuse::statistics::StatisticsAccumulator;
 use databend_query::test_kits::*;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::Compression;
 use databend_storages_common_table_meta::meta::Statistics;
@@ -428,6 +432,85 @@ async fn test_ft_cluster_stats_with_stats() -> databend_common_exception::Result
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_build_column_histogram() -> databend_common_exception::Result<()> {
+    let int = |v: i32| Scalar::Number(NumberScalar::Int32(v));
+
+    // half of the values are 0, the others are distinct.
+    let mut values = vec![int(0); 500];
+    values.extend((1..=500).map(int));
+    let histogram = build_column_histogram(values, 2.0, Some(1001));
+
+    assert_eq!(histogram.most_common_values.len(), 1);
+    assert_eq!(histogram.most_common_values[0].value, int(0));
+    assert_eq!(histogram.most_common_values[0].num_values, 1000.0);
+
+    assert_eq!(histogram.buckets.len(), HISTOGRAM_NUM_BUCKETS);
+    let first = histogram.buckets.first().unwrap();
+    assert_eq!(first.lower_bound, int(1));
+    assert_eq!(first.upper_bound, int(5));
+    assert_eq!(histogram.buckets.last().unwrap().upper_bound, int(500));
+    for bucket in histogram.buckets.iter() {
+        assert_eq!(bucket.num_values, 10.0);
+        // the distinct values are scaled up to the ndv of the table.
+        assert_eq!(bucket.num_distinct, 10.0);
+    }
+    for pair in histogram.buckets.windows(2) {
+        assert!(pair[0].upper_bound < pair[1].lower_bound);
+    }
+    assert_eq!(histogram.num_values(), 2000.0);
+
+    // uniform values have no most common values.
+    let histogram = build_column_histogram((0..10).map(int).collect(), 1.0, None);
+    assert!(histogram.most_common_values.is_empty());
+    assert_eq!(histogram.num_values(), 10.0);
+
+    let histogram = build_column_histogram(vec![], 1.0, None);
+    assert_eq!(histogram, ColumnHistogram::default());
+    Ok(())
+}
+
+#[test]
+fn test_ft_stats_build_column_histogram_skip_values() -> databend_common_exception::Result<()> {
+    // the non-finite floats are skipped, json can't hold them.
+    let float = |v: f64| Scalar::Number(NumberScalar::Float64(v.into()));
+    let mut values = vec![float(f64::NAN); 100];
+    values.extend(vec![float(f64::INFINITY); 50]);
+    values.extend(vec![float(f64::NEG_INFINITY); 50]);
+    values.extend((0..10).map(|v| float(v as f64)));
+    let histogram = build_column_histogram(values, 1.0, None);
+    assert!(histogram.most_common_values.is_empty());
+    assert_eq!(histogram.buckets.first().unwrap().lower_bound, float(0.0));
+    assert_eq!(histogram.buckets.last().unwrap().upper_bound, float(9.0));
+    assert_eq!(histogram.num_values(), 10.0);
+
+    let json = serde_json::to_vec(&histogram)?;
+    let decoded: ColumnHistogram = serde_json::from_slice(&json)?;
+    assert_eq!(decoded, histogram);
+
+    // the long strings are skipped, even if they are the most common values.
+    let long = "a".repeat(HISTOGRAM_MAX_VALUE_SIZE + 1);
+    let mut values = vec![Scalar::String(long.clone()); 100];
+    values.extend((0..10).map(|v| Scalar::String(format!("{v}"))));
+    values.push(Scalar::String("b".repeat(HISTOGRAM_MAX_VALUE_SIZE)));
+    let histogram = build_column_histogram(values, 1.0, None);
+    assert!(histogram.most_common_values.is_empty());
+    assert_eq!(histogram.num_values(), 11.0);
+    for bucket in histogram.buckets.iter() {
+        assert_ne!(bucket.lower_bound, Scalar::String(long.clone()));
+        assert_ne!(bucket.upper_bound, Scalar::String(long.clone()));
+    }
+    assert_eq!(
+        histogram.buckets.last().unwrap().upper_bound,
+        Scalar::String("b".repeat(HISTOGRAM_MAX_VALUE_SIZE))
+    );
+
+    let json = serde_json::to_vec(&histogram)?;
+    let decoded: ColumnHistogram = serde_json::from_slice(&json)?;
+    assert_eq!(decoded, histogram);
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_block_stats_string_columns_trimming() -> databend_common_exception::Result<()> {
     let suite = || -> databend_common_exception::Result<()> {
@@ -253,6 +253,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_eager_aggregation", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables aggregating one side of a join before the join, if it's estimated to reduce the rows of the side.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_cbo", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables cost-based optimization.",
@@ -289,6 +295,30 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_distributed_runtime_filter", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables runtime filters for shuffle joins in cluster, the partial filters built by every node are merged and pushed into the probe side scans of all nodes.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_common_subexpression_reuse", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables computing the equivalent subtrees of a query once and sharing the materialized result.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("common_subexpression_reuse_max_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000000),
+                    desc: "Sets the maximum estimated rows of a subtree to be materialized for sharing.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_shared_window_sort", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables sorting the input once for the window functions whose partition and order keys are satisfied by the same order.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
@@ -313,6 +343,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("enable_adaptive_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables switching a broadcast join to a shuffle join at runtime when the build side turns out larger than expected.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("adaptive_join_broadcast_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100 * 1024 * 1024),
+                    desc: "Sets the maximum number of bytes each node broadcasts to the build side of an adaptive join before switching to shuffle.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("enforce_broadcast_join", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enforce broadcast join.",
@@ -380,6 +422,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("enable_plan_cache", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables caching the plans of queries, queries that differ only in the literals compared against columns share a plan.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("plan_cache_capacity", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1024),
+                    desc: "Sets the maximum number of plans kept in the plan cache of a node.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("enable_plan_baseline", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables planning queries in the join order and distribution of the plan baselines created by CREATE PLAN BASELINE.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enable hive parquet predict pushdown  by setting this variable to 1, default value: 1",
@@ -509,6 +569,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_materialized_view_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enable rewriting the queries to scan the fresh materialized views defined by the same query.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_compact_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables compact after write(copy/insert/replace-into/merge-into), need more memory.",
@@ -618,6 +684,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: None,
                 }),
+                ("ai_connection", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_string()),
+                    desc: "Sets the connection (with STORAGE_TYPE 'openai' or 'onnx') used by the AI functions instead of the config.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("openai_api_embedding_batch_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(32),
+                    desc: "Sets the maximum number of rows sent in one embedding request.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=2048)),
+                }),
                 ("enable_parquet_prewhere", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables parquet prewhere",
@@ -260,6 +260,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_dphyp")? != 0)
     }
 
+    pub fn get_enable_eager_aggregation(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_eager_aggregation")? != 0)
+    }
+
     pub fn get_enable_cbo(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_cbo")? != 0)
     }
@@ -293,6 +297,30 @@ impl Settings {
         Ok(self.try_get_u64("enforce_broadcast_join")? != 0)
     }
 
+    pub fn get_enable_distributed_runtime_filter(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_distributed_runtime_filter")? != 0)
+    }
+
+    pub fn get_enable_common_subexpression_reuse(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_common_subexpression_reuse")? != 0)
+    }
+
+    pub fn get_common_subexpression_reuse_max_rows(&self) -> Result<u64> {
+        self.try_get_u64("common_subexpression_reuse_max_rows")
+    }
+
+    pub fn get_enable_shared_window_sort(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_shared_window_sort")? != 0)
+    }
+
+    pub fn get_enable_adaptive_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_adaptive_join")? != 0)
+    }
+
+    pub fn get_adaptive_join_broadcast_threshold(&self) -> Result<usize> {
+        Ok(self.try_get_u64("adaptive_join_broadcast_threshold")? as usize)
+    }
+
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
         match self.try_get_string("sql_dialect")?.to_lowercase().as_str() {
             "hive" => Ok(Dialect::Hive),
@@ -349,6 +377,18 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn get_enable_plan_cache(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_plan_cache")? != 0)
+    }
+
+    pub fn get_plan_cache_capacity(&self) -> Result<usize> {
+        Ok(self.try_get_u64("plan_cache_capacity")? as usize)
+    }
+
+    pub fn get_enable_plan_baseline(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_plan_baseline")? != 0)
+    }
+
     pub fn get_aggregate_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("aggregate_spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -454,6 +494,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
     pub fn get_enable_compact_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
@@ -555,6 +599,14 @@ impl Settings {
         self.try_get_u64("external_server_request_timeout_secs")
     }
 
+    pub fn get_ai_connection(&self) -> Result<String> {
+        self.try_get_string("ai_connection")
+    }
+
+    pub fn get_openai_api_embedding_batch_size(&self) -> Result<u64> {
+        self.try_get_u64("openai_api_embedding_batch_size")
+    }
+
     pub fn get_create_query_flight_client_with_current_rt(&self) -> Result<bool> {
         Ok(self.try_get_u64("create_query_flight_client_with_current_rt")? != 0)
     }
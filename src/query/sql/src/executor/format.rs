@@ -689,6 +689,10 @@ fn window_to_format_tree(
         children.push(FormatTreeNode::new(format!("limit: [{limit}]")))
     }
 
+    if plan.presorted {
+        children.push(FormatTreeNode::new("sort: [shared]".to_string()))
+    }
+
     append_profile_info(&mut children, profs, plan.plan_id);
 
     children.push(to_format_tree(&plan.input, metadata, profs)?);
@@ -911,6 +915,10 @@ fn hash_join_to_format_tree(
         FormatTreeNode::new(format!("filters: [{filters}]")),
     ];
 
+    if plan.adaptive_join_id.is_some() {
+        children.push(FormatTreeNode::new("adaptive: true".to_string()));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -932,6 +940,13 @@ fn exchange_to_format_tree(
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
 ) -> Result<FormatTreeNode<String>> {
+    let keys = plan
+        .keys
+        .iter()
+        .map(|key| key.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+
     Ok(FormatTreeNode::with_children("Exchange".to_string(), vec![
         FormatTreeNode::new(format!(
             "output columns: [{}]",
@@ -939,16 +954,11 @@ fn exchange_to_format_tree(
         )),
         FormatTreeNode::new(format!("exchange type: {}", match plan.kind {
             FragmentKind::Init => "Init-Partition".to_string(),
-            FragmentKind::Normal => format!(
-                "Hash({})",
-                plan.keys
-                    .iter()
-                    .map(|key| { key.as_expr(&BUILTIN_FUNCTIONS).sql_display() })
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            ),
+            FragmentKind::Normal => format!("Hash({})", keys),
             FragmentKind::Expansive => "Broadcast".to_string(),
             FragmentKind::Merge => "Merge".to_string(),
+            FragmentKind::AdaptiveBroadcast(_) => format!("Adaptive(Broadcast, Hash({}))", keys),
+            FragmentKind::AdaptiveShuffle(_) => format!("Adaptive(Local, Hash({}))", keys),
         })),
         to_format_tree(&plan.input, metadata, profs)?,
     ]))
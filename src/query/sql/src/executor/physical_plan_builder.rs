@@ -36,6 +36,10 @@ pub struct PhysicalPlanBuilder {
     pub(crate) dry_run: bool,
     // Record cte_idx and the cte's output columns
     pub(crate) cte_output_columns: HashMap<IndexType, Vec<ColumnBinding>>,
+    // Record cte_idx and the columns referred by the offsets of cte scans
+    pub(crate) cte_source_columns: HashMap<IndexType, Vec<ColumnBinding>>,
+    // The id of the next adaptive join
+    pub(crate) next_adaptive_join_id: u32,
 }
 
 impl PhysicalPlanBuilder {
@@ -47,6 +51,8 @@ impl PhysicalPlanBuilder {
             func_ctx,
             dry_run,
             cte_output_columns: Default::default(),
+            cte_source_columns: Default::default(),
+            next_adaptive_join_id: 0,
         }
     }
 
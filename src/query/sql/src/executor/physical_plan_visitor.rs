@@ -213,6 +213,8 @@ pub trait PhysicalPlanReplacer {
             order_by: plan.order_by.clone(),
             window_frame: plan.window_frame.clone(),
             limit: plan.limit,
+            presorted: plan.presorted,
+            keep_order: plan.keep_order,
         }))
     }
 
@@ -240,6 +242,8 @@ pub trait PhysicalPlanReplacer {
             probe_keys_rt: plan.probe_keys_rt.clone(),
             enable_bloom_runtime_filter: plan.enable_bloom_runtime_filter,
             broadcast: plan.broadcast,
+            shuffle: plan.shuffle,
+            adaptive_join_id: plan.adaptive_join_id,
             original_join_type: plan.original_join_type.clone(),
         }))
     }
@@ -69,6 +69,12 @@ pub enum FragmentKind {
     // Broadcast
     Expansive,
     Merge,
+    // Broadcast the build side of the adaptive join with the id, switch
+    // to partitioned by hash once the build side is larger than expected
+    AdaptiveBroadcast(u32),
+    // Keep the probe side of the adaptive join with the id local, or
+    // partitioned by hash if its build side has been switched
+    AdaptiveShuffle(u32),
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Copy)]
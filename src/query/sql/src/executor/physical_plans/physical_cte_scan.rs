@@ -50,17 +50,18 @@ impl PhysicalPlanBuilder {
         let mut pruned_fields = vec![];
         let mut pruned_offsets = vec![];
         let cte_output_columns = self.cte_output_columns.get(&cte_scan.cte_idx.0).unwrap();
-        for field in cte_scan.fields.iter() {
-            if used_columns.contains(&field.name().parse()?) {
-                pruned_fields.push(field.clone());
+        let cte_source_columns = self.cte_source_columns.get(&cte_scan.cte_idx.0).unwrap();
+        for (field, offset) in cte_scan.fields.iter().zip(cte_scan.offsets.iter()) {
+            if !used_columns.contains(&field.name().parse()?) {
+                continue;
             }
-        }
-        for field in pruned_fields.iter() {
-            for (offset, col) in cte_output_columns.iter().enumerate() {
-                if col.index.eq(&field.name().parse::<IndexType>()?) {
-                    pruned_offsets.push(offset);
-                    break;
-                }
+            let source_index = cte_source_columns[*offset].index;
+            if let Some(offset) = cte_output_columns
+                .iter()
+                .position(|col| col.index == source_index)
+            {
+                pruned_fields.push(field.clone());
+                pruned_offsets.push(offset);
             }
         }
 
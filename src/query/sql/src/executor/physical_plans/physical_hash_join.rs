@@ -82,6 +82,11 @@ pub struct HashJoin {
     pub enable_bloom_runtime_filter: bool,
     // Under cluster, mark if the join is broadcast join.
     pub broadcast: bool,
+    // Under cluster, mark if the join is shuffle join, both sides are shuffled by join keys.
+    pub shuffle: bool,
+    // Under cluster, the id of the adaptive join if the broadcast join
+    // is allowed to switch to shuffle join at runtime.
+    pub adaptive_join_id: Option<u32>,
     // Original join type. Left/Right single join may be convert to inner join
     // Record the original join type and do some special processing during runtime.
     pub original_join_type: Option<JoinType>,
@@ -115,6 +120,29 @@ impl PhysicalPlanBuilder {
         {
             is_broadcast = true;
         }
+        // Check if join is shuffle join
+        let is_shuffle = matches!(
+            build_side.as_ref(),
+            PhysicalPlan::Exchange(Exchange {
+                kind: FragmentKind::Normal,
+                ..
+            })
+        );
+        let mut adaptive_join_id = None;
+        if is_broadcast
+            && let Some((id, probe_keys)) =
+                self.build_adaptive_join_exchanges(join, &probe_side, &mut build_side)?
+        {
+            probe_side = Box::new(PhysicalPlan::Exchange(Exchange {
+                plan_id: 0,
+                input: probe_side,
+                kind: FragmentKind::AdaptiveShuffle(id),
+                keys: probe_keys,
+                ignore_exchange: false,
+                allow_adjust_parallelism: true,
+            }));
+            adaptive_join_id = Some(id);
+        }
         // Unify the data types of the left and right exchange keys.
         if let (
             PhysicalPlan::Exchange(Exchange {
@@ -510,6 +538,8 @@ impl PhysicalPlanBuilder {
             need_hold_hash_table: join.need_hold_hash_table,
             stat_info: Some(stat_info),
             broadcast: is_broadcast,
+            shuffle: is_shuffle,
+            adaptive_join_id,
             original_join_type: join.original_join_type.clone(),
             enable_bloom_runtime_filter: adjust_bloom_runtime_filter(
                 self.ctx.clone(),
@@ -520,6 +550,69 @@ impl PhysicalPlanBuilder {
             .await?,
         }))
     }
+
+    // Turn the broadcast join into an adaptive join: the build side is broadcast until it
+    // grows larger than `adaptive_join_broadcast_threshold`, then both sides are shuffled
+    // by the join keys. Return the id of the adaptive join and the shuffle keys of probe side.
+    fn build_adaptive_join_exchanges(
+        &mut self,
+        join: &Join,
+        probe_side: &PhysicalPlan,
+        build_side: &mut PhysicalPlan,
+    ) -> Result<Option<(u32, Vec<RemoteExpr>)>> {
+        if !self.ctx.get_settings().get_enable_adaptive_join()?
+            || join.need_hold_hash_table
+            || join.left_conditions.is_empty()
+            // The distribution is pinned by hint.
+            || join.hint.distribution.is_some()
+            // Only the join types whose results are not affected by the build rows
+            // of other nodes can be switched to shuffle join.
+            || !matches!(
+                join.join_type,
+                JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+            )
+            || matches!(probe_side, PhysicalPlan::Exchange(_))
+        {
+            return Ok(None);
+        }
+
+        let PhysicalPlan::Exchange(build_exchange) = build_side else {
+            return Ok(None);
+        };
+        let build_schema = build_exchange.output_schema()?;
+        let probe_schema = probe_side.output_schema()?;
+        if build_schema.column_with_name(ROW_NUMBER_COL_NAME).is_some() {
+            return Ok(None);
+        }
+
+        let mut build_keys = Vec::with_capacity(join.right_conditions.len());
+        let mut probe_keys = Vec::with_capacity(join.left_conditions.len());
+        for (left_condition, right_condition) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+        {
+            let build_key = right_condition
+                .type_check(build_schema.as_ref())?
+                .project_column_ref(|index| build_schema.index_of(&index.to_string()).unwrap());
+            let probe_key = left_condition
+                .type_check(probe_schema.as_ref())?
+                .project_column_ref(|index| probe_schema.index_of(&index.to_string()).unwrap());
+            let (build_key, _) =
+                ConstantFolder::fold(&build_key, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let (probe_key, _) =
+                ConstantFolder::fold(&probe_key, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            build_keys.push(build_key.as_remote_expr());
+            probe_keys.push(probe_key.as_remote_expr());
+        }
+
+        let adaptive_join_id = self.next_adaptive_join_id;
+        self.next_adaptive_join_id += 1;
+        build_exchange.kind = FragmentKind::AdaptiveBroadcast(adaptive_join_id);
+        build_exchange.keys = build_keys;
+
+        Ok(Some((adaptive_join_id, probe_keys)))
+    }
 }
 
 // Check if enable bloom runtime filter
@@ -39,6 +39,11 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         return Ok(PhysicalJoinType::Hash);
     }
 
+    if join.hint.hash_join {
+        // Forced by `HASH_JOIN` or `NO_MERGE` hint
+        return Ok(PhysicalJoinType::Hash);
+    }
+
     let left_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
     let right_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
     let mut range_conditions = vec![];
@@ -18,8 +18,8 @@ use databend_common_expression::DataSchemaRefExt;
 
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
-use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
 use crate::ColumnBinding;
 use crate::ColumnSet;
 use crate::IndexType;
@@ -49,19 +49,9 @@ impl PhysicalPlanBuilder {
         required: ColumnSet,
     ) -> Result<PhysicalPlan> {
         // 1. Prune unused Columns.
-        let left_output_column = RelExpr::with_s_expr(s_expr)
-            .derive_relational_prop_child(0)?
-            .output_columns
-            .clone();
-        let right_used_column = RelExpr::with_s_expr(s_expr)
-            .derive_relational_prop_child(1)?
-            .used_columns
-            .clone();
-        // Get the intersection of `left_used_column` and `right_used_column`
-        let left_required = left_output_column
-            .intersection(&right_used_column)
-            .cloned()
-            .collect::<ColumnSet>();
+        // The columns of cte scans are bound to the offsets of `left_output_columns`.
+        let mut left_required = ColumnSet::new();
+        collect_cte_scan_columns(s_expr.child(1)?, cte, &mut left_required);
 
         let mut required_output_columns = vec![];
         for column in cte.left_output_columns.iter() {
@@ -71,6 +61,8 @@ impl PhysicalPlanBuilder {
         }
         self.cte_output_columns
             .insert(cte.cte_idx, required_output_columns.clone());
+        self.cte_source_columns
+            .insert(cte.cte_idx, cte.left_output_columns.clone());
 
         // 2. Build physical plan.
         Ok(PhysicalPlan::MaterializedCte(MaterializedCte {
@@ -82,3 +74,20 @@ impl PhysicalPlanBuilder {
         }))
     }
 }
+
+fn collect_cte_scan_columns(
+    s_expr: &SExpr,
+    cte: &crate::plans::MaterializedCte,
+    columns: &mut ColumnSet,
+) {
+    if let RelOperator::CteScan(cte_scan) = s_expr.plan() {
+        if cte_scan.cte_idx.0 == cte.cte_idx {
+            for offset in cte_scan.offsets.iter() {
+                columns.insert(cte.left_output_columns[*offset].index);
+            }
+        }
+    }
+    for child in s_expr.children() {
+        collect_cte_scan_columns(child, cte, columns);
+    }
+}
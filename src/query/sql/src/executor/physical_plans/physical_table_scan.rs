@@ -25,6 +25,7 @@ use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::plan::PrewhereInfo;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::TableSampleLevel;
 use databend_common_catalog::plan::VirtualColumnInfo;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -255,7 +256,7 @@ impl PhysicalPlanBuilder {
     pub(crate) async fn build_dummy_table_scan(&mut self) -> Result<PhysicalPlan> {
         let catalogs = CatalogManager::instance();
         let table = catalogs
-            .get_default_catalog(self.ctx.txn_mgr())?
+            .get_default_catalog(self.ctx.txn_mgr(), self.ctx.temp_tbl_mgr())?
             .get_table(self.ctx.get_tenant().as_str(), "system", "one")
             .await?;
 
@@ -475,18 +476,25 @@ impl PhysicalPlanBuilder {
 
         let virtual_columns = self.build_virtual_columns(&scan.columns);
 
+        // The rows are sampled after being read, the limit can't be applied to the rows read.
+        let limit = match &scan.sample {
+            Some(sample) if sample.level == TableSampleLevel::Row => None,
+            _ => scan.limit,
+        };
+
         Ok(PushDownInfo {
             projection: Some(projection),
             output_columns,
             filters: push_down_filter,
             is_deterministic,
             prewhere: prewhere_info,
-            limit: scan.limit,
+            limit,
             order_by: order_by.unwrap_or_default(),
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
             change_type: scan.change_type.clone(),
+            sample: scan.sample.clone(),
         })
     }
 
@@ -56,6 +56,10 @@ pub struct Window {
     pub order_by: Vec<SortDesc>,
     pub window_frame: WindowFuncFrame,
     pub limit: Option<usize>,
+    /// The input is sorted by the window below, the window doesn't sort it again.
+    pub presorted: bool,
+    /// The window above reuses the order of the output, it's not resized to multiple outputs.
+    pub keep_order: bool,
 }
 
 impl Window {
@@ -178,7 +182,8 @@ impl PhysicalPlanBuilder {
         let column_projections = required.clone().into_iter().collect::<Vec<_>>();
 
         // 2. Build physical plan.
-        let input = self.build(s_expr.child(0)?, required).await?;
+        let mut input = self.build(s_expr.child(0)?, required).await?;
+        let presorted = window.presorted && keep_window_order(&mut input);
         let mut w = window.clone();
         // Generate a `EvalScalar` as the input of `Window`.
         let mut scalar_items: Vec<ScalarItem> = Vec::new();
@@ -363,6 +368,22 @@ impl PhysicalPlanBuilder {
             order_by: order_by_items,
             window_frame: w.frame.clone(),
             limit: w.limit,
+            presorted,
+            keep_order: false,
         }))
     }
 }
+
+/// Lets the window below keep the order of its output for the window above, returns false
+/// if there are operators changing the order between the windows.
+fn keep_window_order(plan: &mut PhysicalPlan) -> bool {
+    match plan {
+        PhysicalPlan::Window(window) => {
+            window.keep_order = true;
+            true
+        }
+        PhysicalPlan::EvalScalar(plan) => keep_window_order(&mut plan.input),
+        PhysicalPlan::Filter(plan) => keep_window_order(&mut plan.input),
+        _ => false,
+    }
+}
@@ -26,6 +26,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::StreamTablePart;
+use databend_common_catalog::plan::TableSample;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -285,6 +286,10 @@ impl ToReadDataSourcePlan for dyn Table {
         ));
         // TODO pass in catalog name
 
+        // Rows are sampled by their positions in the blocks, which come with the internal columns.
+        let query_internal_columns =
+            internal_columns.is_some() || TableSample::row_sample(&push_downs).is_some();
+
         Ok(DataSourcePlan {
             catalog_info,
             source_info,
@@ -294,7 +299,7 @@ impl ToReadDataSourcePlan for dyn Table {
             description,
             tbl_args: self.table_args(),
             push_downs,
-            query_internal_columns: internal_columns.is_some(),
+            query_internal_columns,
             base_block_ids,
             update_stream_columns,
             data_mask_policy,
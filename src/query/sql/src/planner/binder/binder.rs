@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -20,6 +21,7 @@ use chrono_tz::Tz;
 use databend_common_ast::ast::format_statement;
 use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::JoinHintItem;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
@@ -42,6 +44,7 @@ use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::normalize_identifier;
+use crate::optimizer::JoinHints;
 use crate::optimizer::SExpr;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
@@ -131,6 +134,14 @@ impl<'a> Binder {
     pub async fn bind(mut self, stmt: &Statement) -> Result<Plan> {
         self.ctx.set_status_info("binding");
         let mut init_bind_context = BindContext::new();
+        let rewritten = match stmt {
+            Statement::Query(query) => self
+                .rewrite_query_by_materialized_views(query)
+                .await?
+                .map(|query| Statement::Query(Box::new(query))),
+            _ => None,
+        };
+        let stmt = rewritten.as_ref().unwrap_or(stmt);
         let plan = self.bind_statement(&mut init_bind_context, stmt).await?;
         self.bind_query_index(&mut init_bind_context, &plan).await?;
         Ok(plan)
@@ -179,6 +190,76 @@ impl<'a> Binder {
         self.ctx.get_settings().set_batch_settings(&hint_settings)
     }
 
+    /// Binds the join hints of a query block, the tables are resolved in the `FROM` clause of
+    /// the block, which is bound to `bind_context`.
+    pub(crate) fn bind_join_hints(&self, bind_context: &BindContext, hints: &Hint) {
+        let mut join_hints = JoinHints::default();
+        for hint in hints.join_hints.iter() {
+            match hint {
+                JoinHintItem::Leading(tables) => {
+                    if let Some(tables) = self.resolve_join_hint_tables(bind_context, hint, tables)
+                    {
+                        join_hints.leading.push(tables);
+                    }
+                }
+                JoinHintItem::Broadcast(tables) => {
+                    if let Some(tables) = self.resolve_join_hint_tables(bind_context, hint, tables)
+                    {
+                        join_hints.broadcast.extend(tables);
+                    }
+                }
+                JoinHintItem::Shuffle(tables) => {
+                    if let Some(tables) = self.resolve_join_hint_tables(bind_context, hint, tables)
+                    {
+                        join_hints.shuffle.extend(tables);
+                    }
+                }
+                JoinHintItem::HashJoin(tables) => {
+                    if let Some(tables) = self.resolve_join_hint_tables(bind_context, hint, tables)
+                    {
+                        join_hints.hash_join.extend(tables);
+                    }
+                }
+                JoinHintItem::NoMerge => join_hints.no_merge = true,
+            }
+        }
+        self.metadata.write().add_join_hints(join_hints);
+    }
+
+    fn resolve_join_hint_tables(
+        &self,
+        bind_context: &BindContext,
+        hint: &JoinHintItem,
+        tables: &[Identifier],
+    ) -> Option<Vec<IndexType>> {
+        let mut table_indexes = Vec::with_capacity(tables.len());
+        for table in tables.iter() {
+            let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+            let candidates = bind_context
+                .columns
+                .iter()
+                .filter(|column| column.table_name.as_ref() == Some(&table_name))
+                .filter_map(|column| column.table_index)
+                .collect::<HashSet<_>>();
+            let reason = match candidates.len() {
+                1 => {
+                    table_indexes.extend(candidates);
+                    continue;
+                }
+                0 => "is not found",
+                _ => "is ambiguous",
+            };
+            let msg = format!(
+                "Join hint {} is ignored, because table {} {}",
+                hint, table_name, reason
+            );
+            warn!("{}", msg);
+            self.ctx.push_warning(msg);
+            return None;
+        }
+        Some(table_indexes)
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub(crate) async fn bind_statement(
@@ -274,6 +355,8 @@ impl<'a> Binder {
             Statement::CreateDatabase(stmt) => self.bind_create_database(stmt).await?,
             Statement::DropDatabase(stmt) => self.bind_drop_database(stmt).await?,
             Statement::UndropDatabase(stmt) => self.bind_undrop_database(stmt).await?,
+            Statement::BackupDatabase(stmt) => self.bind_backup_database(stmt).await?,
+            Statement::RestoreDatabase(stmt) => self.bind_restore_database(stmt).await?,
             Statement::AlterDatabase(stmt) => self.bind_alter_database(stmt).await?,
             Statement::UseDatabase { database } => {
                 let database = normalize_identifier(database, &self.name_resolution_ctx).name;
@@ -311,6 +394,9 @@ impl<'a> Binder {
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => self.bind_create_materialized_view(stmt).await?,
+            Statement::DropMaterializedView(stmt) => self.bind_drop_materialized_view(stmt).await?,
+            Statement::RefreshMaterializedView(stmt) => self.bind_refresh_materialized_view(stmt).await?,
 
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
@@ -579,6 +665,30 @@ impl<'a> Binder {
             Statement::DescPasswordPolicy(stmt) => {
                 self.bind_desc_password_policy(stmt).await?
             }
+            Statement::CreateSequence(stmt) => {
+                self.bind_create_sequence(stmt).await?
+            }
+            Statement::DropSequence(stmt) => {
+                self.bind_drop_sequence(stmt).await?
+            }
+            Statement::DescSequence(stmt) => {
+                self.bind_desc_sequence(stmt).await?
+            }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
+            Statement::CreatePlanBaseline(stmt) => {
+                self.bind_create_plan_baseline(bind_context, stmt).await?
+            }
+            Statement::DropPlanBaseline(stmt) => {
+                self.bind_drop_plan_baseline(stmt).await?
+            }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
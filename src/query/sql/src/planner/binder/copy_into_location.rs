@@ -12,14 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::Utc;
 use databend_common_ast::ast::CopyIntoLocationSource;
 use databend_common_ast::ast::CopyIntoLocationStmt;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::StageInfo;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_QUERY;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::Binder;
@@ -34,6 +43,12 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         stmt: &CopyIntoLocationStmt,
     ) -> Result<Plan> {
+        if stmt.change_format.is_some() && !matches!(stmt.src, CopyIntoLocationSource::Stream(_)) {
+            return Err(ErrorCode::SemanticError(
+                "CHANGE_FORMAT is only supported for COPY INTO <location> FROM STREAM",
+            ));
+        }
+
         let query = match &stmt.src {
             CopyIntoLocationSource::Table(table) => {
                 let (catalog_name, database_name, table_name) = self
@@ -42,7 +57,12 @@ impl<'a> Binder {
                         &table.database,
                         &table.table,
                     );
-                let subquery = format!("SELECT * FROM {catalog_name}.{database_name}.{table_name}");
+                let subquery = format!(
+                    "SELECT * FROM {}.{}.{}",
+                    quote_ident(&catalog_name),
+                    quote_ident(&database_name),
+                    quote_ident(&table_name)
+                );
                 let tokens = tokenize_sql(&subquery)?;
                 let sub_stmt_msg = parse_sql(&tokens, self.dialect)?;
                 let sub_stmt = sub_stmt_msg.0;
@@ -58,6 +78,50 @@ impl<'a> Binder {
                     }
                 }
             }
+            CopyIntoLocationSource::Stream(stream) => {
+                let (catalog_name, database_name, stream_name) = self
+                    .normalize_object_identifier_triple(
+                        &stream.catalog,
+                        &stream.database,
+                        &stream.table,
+                    );
+                let table = self
+                    .ctx
+                    .get_table(&catalog_name, &database_name, &stream_name)
+                    .await?;
+                if table.engine() != "STREAM" {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "'{database_name}.{stream_name}' is not a stream"
+                    )));
+                }
+
+                let stream_query = format!(
+                    "SELECT * FROM {}.{}.{}",
+                    quote_ident(&catalog_name),
+                    quote_ident(&database_name),
+                    quote_ident(&stream_name)
+                );
+                let subquery = match &stmt.change_format {
+                    None => stream_query,
+                    Some(format) if format.eq_ignore_ascii_case("debezium") => {
+                        self.debezium_change_events_query(
+                            table.as_ref(),
+                            &catalog_name,
+                            &database_name,
+                            &stream_query,
+                        )
+                        .await?
+                    }
+                    Some(format) => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Unsupported CHANGE_FORMAT '{format}', only 'debezium' is supported"
+                        )));
+                    }
+                };
+                let tokens = tokenize_sql(&subquery)?;
+                let (sub_stmt, _) = parse_sql(&tokens, self.dialect)?;
+                self.bind_statement(bind_context, &sub_stmt).await
+            }
             CopyIntoLocationSource::Query(query) => {
                 let select_plan = self
                     .bind_statement(bind_context, &Statement::Query(query.clone()))
@@ -81,9 +145,110 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             from: Box::new(query),
+            consume_streams: matches!(stmt.src, CopyIntoLocationSource::Stream(_)),
         }))
     }
 
+    /// Builds the query of the change events of a stream in the format of Debezium, each
+    /// event has the images of the row before and after the change, the operation type
+    /// (`c`, `u` or `d`) and the time of the change in the source.
+    /// The deletion and insertion of an update are joined by the row id into one event.
+    ///
+    /// The time of a change is the time that its row was written. The time of a deletion is
+    /// not recorded, so it is the time of the last commit on the source table, as is the time
+    /// of the rows written by old versions.
+    async fn debezium_change_events_query(
+        &mut self,
+        stream: &dyn Table,
+        catalog: &str,
+        database: &str,
+        stream_query: &str,
+    ) -> Result<String> {
+        let options = stream.options();
+        let (Some(source_database), Some(source_table)) = (
+            options.get(OPT_KEY_DATABASE_NAME),
+            options.get(OPT_KEY_TABLE_NAME),
+        ) else {
+            return Err(ErrorCode::IllegalStream(format!(
+                "Illegal stream '{}'",
+                stream.name()
+            )));
+        };
+        let source = self
+            .ctx
+            .get_table(catalog, source_database, source_table)
+            .await?;
+        let commit_ts = source.get_table_info().meta.updated_on.timestamp_millis();
+
+        // The columns of a stream on view are named by the select list of the view.
+        let column_names = match options.get(OPT_KEY_VIEW_QUERY) {
+            Some(view_query) => self.stream_view_column_names(view_query)?,
+            None => stream
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| field.name().clone())
+                .collect(),
+        };
+        let image = column_names
+            .into_iter()
+            .map(|name| format!("{}, {}", Literal::String(name.clone()), quote_ident(&name)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let source_info = format!(
+            "json_object_keep_null('connector', 'databend', 'db', {}, 'table', {}, 'stream', {}, \
+                                   'ts_ms', coalesce(to_int64(coalesce(i._ts, d._ts)) DIV 1000, {commit_ts}))",
+            Literal::String(source_database.clone()),
+            Literal::String(source_table.clone()),
+            Literal::String(format!("{database}.{}", stream.name())),
+        );
+        let ts_ms = Utc::now().timestamp_millis();
+        Ok(format!(
+            "WITH _change AS ( \
+                SELECT change$action AS _action, \
+                       if(change$is_update, change$row_id, NULL) AS _update_id, \
+                       json_object_keep_null({image}) AS _image, \
+                       change$timestamp AS _ts \
+                FROM ({stream_query}) \
+            ) \
+            SELECT d._image AS before, \
+                   i._image AS after, \
+                   {source_info} AS source, \
+                   CASE WHEN d._action IS NULL THEN 'c' \
+                        WHEN i._action IS NULL THEN 'd' \
+                        ELSE 'u' END AS op, \
+                   {ts_ms} AS ts_ms \
+            FROM (SELECT * FROM _change WHERE _action = 'INSERT') AS i \
+            FULL OUTER JOIN (SELECT * FROM _change WHERE _action = 'DELETE') AS d \
+            ON i._update_id = d._update_id"
+        ))
+    }
+
+    /// The names of the columns of a stream on view, the stars of the view are expanded
+    /// when the stream is created.
+    fn stream_view_column_names(&self, view_query: &str) -> Result<Vec<String>> {
+        let tokens = tokenize_sql(view_query)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let invalid = || ErrorCode::Internal(format!("Invalid view query of stream: {view_query}"));
+        let Statement::Query(query) = &stmt else {
+            return Err(invalid());
+        };
+        let SetExpr::Select(select) = &query.body else {
+            return Err(invalid());
+        };
+        select
+            .select_list
+            .iter()
+            .map(|target| match target {
+                SelectTarget::AliasedExpr { expr, alias } => {
+                    Ok(self.select_target_name(expr, alias.as_ref()))
+                }
+                SelectTarget::StarColumns { .. } => Err(invalid()),
+            })
+            .collect()
+    }
+
     #[async_backtrace::framed]
     pub async fn apply_copy_into_location_options(
         &mut self,
@@ -107,3 +272,7 @@ impl<'a> Binder {
         Ok(())
     }
 }
+
+fn quote_ident(name: &str) -> String {
+    Identifier::from_name_with_quoted(name, Some('`')).to_string()
+}
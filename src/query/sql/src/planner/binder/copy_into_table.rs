@@ -64,6 +64,7 @@ use parking_lot::RwLock;
 use crate::binder::location::parse_uri_location;
 use crate::binder::select::MaxColumnPosition;
 use crate::binder::Binder;
+use crate::parse_sequence_default_expr;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::Plan;
@@ -396,6 +397,7 @@ impl<'a> Binder {
                         lit: Literal::UInt64(1),
                     },
                 }],
+                join_hints: vec![],
             };
             if let Some(e) = self
                 .opt_hints_set_var(&mut output_context, &hints)
@@ -500,6 +502,15 @@ impl<'a> Binder {
 
         let mut values = vec![];
         for field in &data_schema.fields {
+            if field
+                .default_expr()
+                .is_some_and(|expr| parse_sequence_default_expr(expr).is_some())
+            {
+                return Err(ErrorCode::SequenceError(format!(
+                    "COPY INTO a table with sequence default column `{}` is not supported yet",
+                    field.name()
+                )));
+            }
             let expr = scalar_binder.get_default_value(field, data_schema).await?;
             values.push(evaluator.run(&expr)?.as_scalar().unwrap().clone());
         }
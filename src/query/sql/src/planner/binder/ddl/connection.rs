@@ -15,6 +15,7 @@
 use databend_common_ast::ast::CreateConnectionStmt;
 use databend_common_ast::ast::UriLocation;
 use databend_common_exception::Result;
+use databend_common_openai::AIConnection;
 
 use crate::binder::parse_storage_params_from_uri;
 use crate::plans::CreateConnectionPlan;
@@ -27,14 +28,19 @@ impl Binder {
         &mut self,
         stmt: &CreateConnectionStmt,
     ) -> Result<Plan> {
-        let mut location = UriLocation::new(
-            stmt.storage_type.clone(),
-            "".to_string(),
-            "/".to_string(),
-            "".to_string(),
-            stmt.storage_params.clone(),
-        );
-        parse_storage_params_from_uri(&mut location, None, "when CREATE CONNECTION").await?;
+        if AIConnection::is_ai_provider(&stmt.storage_type) {
+            // A connection of the AI functions, see `ai_connection` setting.
+            AIConnection::try_create(&stmt.storage_type, &stmt.storage_params)?;
+        } else {
+            let mut location = UriLocation::new(
+                stmt.storage_type.clone(),
+                "".to_string(),
+                "/".to_string(),
+                "".to_string(),
+                stmt.storage_params.clone(),
+            );
+            parse_storage_params_from_uri(&mut location, None, "when CREATE CONNECTION").await?;
+        }
         Ok(Plan::CreateConnection(Box::new(CreateConnectionPlan {
             create_option: stmt.create_option,
             name: stmt.name.to_string(),
@@ -14,32 +14,45 @@
 
 use std::collections::BTreeMap;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_ast::ast::AlterDatabaseAction;
 use databend_common_ast::ast::AlterDatabaseStmt;
+use databend_common_ast::ast::BackupDatabaseStmt;
 use databend_common_ast::ast::CreateDatabaseStmt;
 use databend_common_ast::ast::DatabaseEngine;
 use databend_common_ast::ast::DatabaseRef;
 use databend_common_ast::ast::DropDatabaseStmt;
+use databend_common_ast::ast::RestoreDatabaseStmt;
 use databend_common_ast::ast::SQLProperty;
 use databend_common_ast::ast::ShowCreateDatabaseStmt;
 use databend_common_ast::ast::ShowDatabasesStmt;
 use databend_common_ast::ast::ShowLimit;
+use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::UndropDatabaseStmt;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::share::ShareNameIdent;
 use log::debug;
 
+use crate::binder::resolve_stage_location;
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::BackupDatabasePlan;
+use crate::plans::CloneDatabaseSource;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
 use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RestoreDatabasePlan;
+use crate::plans::RevertDatabasePlan;
 use crate::plans::RewriteKind;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::UndropDatabasePlan;
@@ -152,6 +165,33 @@ impl Binder {
                     entities: vec![entry],
                 })))
             }
+            AlterDatabaseAction::RevertTo { point } => {
+                let time_point = self.resolve_database_travel_point(point).await?;
+                Ok(Plan::RevertDatabase(Box::new(RevertDatabasePlan {
+                    if_exists: *if_exists,
+                    tenant: tenant.to_string(),
+                    catalog,
+                    database,
+                    time_point,
+                })))
+            }
+        }
+    }
+
+    /// A database has no snapshots of its own, so it can only travel to a point in time,
+    /// which every table of the database is resolved to.
+    async fn resolve_database_travel_point(
+        &self,
+        point: &TimeTravelPoint,
+    ) -> Result<DateTime<Utc>> {
+        match self
+            .resolve_data_travel_point(&mut BindContext::new(), point)
+            .await?
+        {
+            NavigationPoint::TimePoint(time_point) => Ok(time_point),
+            NavigationPoint::SnapshotID(_) => Err(ErrorCode::SemanticError(
+                "Time travel of a database only supports TIMESTAMP",
+            )),
         }
     }
 
@@ -213,6 +253,7 @@ impl Binder {
             engine,
             options,
             from_share,
+            clone_from,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -230,12 +271,39 @@ impl Binder {
         };
         let meta = self.database_meta(engine, options, from_share)?;
 
+        let clone_from = match clone_from {
+            Some(clone_from) => {
+                let source_catalog = clone_from
+                    .catalog
+                    .as_ref()
+                    .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| self.ctx.get_current_catalog());
+                if source_catalog != catalog {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Can not clone database across catalogs, '{}' vs '{}'",
+                        source_catalog, catalog
+                    )));
+                }
+                let time_point = match &clone_from.travel_point {
+                    Some(point) => Some(self.resolve_database_travel_point(point).await?),
+                    None => None,
+                };
+                Some(CloneDatabaseSource {
+                    database: normalize_identifier(&clone_from.database, &self.name_resolution_ctx)
+                        .name,
+                    time_point,
+                })
+            }
+            None => None,
+        };
+
         Ok(Plan::CreateDatabase(Box::new(CreateDatabasePlan {
             create_option: *create_option,
             tenant,
             catalog,
             database,
             meta,
+            clone_from,
         })))
     }
 
@@ -264,4 +332,72 @@ impl Binder {
             ..Default::default()
         })
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_backup_database(
+        &self,
+        stmt: &BackupDatabaseStmt,
+    ) -> Result<Plan> {
+        let BackupDatabaseStmt {
+            catalog,
+            database,
+            location,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage, path) = self.resolve_backup_location(location).await?;
+
+        Ok(Plan::BackupDatabase(Box::new(BackupDatabasePlan {
+            tenant: tenant.to_string(),
+            catalog,
+            database,
+            stage,
+            path,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_restore_database(
+        &self,
+        stmt: &RestoreDatabaseStmt,
+    ) -> Result<Plan> {
+        let RestoreDatabaseStmt {
+            catalog,
+            database,
+            location,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage, path) = self.resolve_backup_location(location).await?;
+
+        Ok(Plan::RestoreDatabase(Box::new(RestoreDatabasePlan {
+            tenant: tenant.to_string(),
+            catalog,
+            database,
+            stage,
+            path,
+        })))
+    }
+
+    /// Resolves the stage and the directory in it that keeps the backups.
+    async fn resolve_backup_location(&self, location: &str) -> Result<(StageInfo, String)> {
+        let (stage, path) = resolve_stage_location(self.ctx.as_ref(), location).await?;
+        let path = path.trim_matches('/');
+        let path = if path.is_empty() {
+            String::new()
+        } else {
+            format!("{path}/")
+        };
+        Ok((stage, path))
+    }
 }
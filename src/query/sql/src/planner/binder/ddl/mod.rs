@@ -22,7 +22,10 @@ mod index;
 mod network_policy;
 mod notification;
 mod password_policy;
+mod plan_baseline;
 mod role;
+mod row_access_policy;
+mod sequence;
 mod share;
 mod stage;
 mod stream;
@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::CreatePlanBaselineStmt;
+use databend_common_ast::ast::DropPlanBaselineStmt;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::Statement;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::CreatePlanBaselinePlan;
+use crate::plans::DropPlanBaselinePlan;
+use crate::plans::Plan;
+use crate::BindContext;
+use crate::PlanBaselineQuery;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_plan_baseline(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CreatePlanBaselineStmt,
+    ) -> Result<Plan> {
+        let CreatePlanBaselineStmt {
+            create_option,
+            query,
+        } = stmt;
+
+        let baseline_query = self.plan_baseline_query(query)?;
+        let plan = self
+            .bind_statement(bind_context, &Statement::Query(query.clone()))
+            .await?;
+
+        Ok(Plan::CreatePlanBaseline(Box::new(CreatePlanBaselinePlan {
+            create_option: *create_option,
+            query: baseline_query,
+            plan: Box::new(plan),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_plan_baseline(
+        &mut self,
+        stmt: &DropPlanBaselineStmt,
+    ) -> Result<Plan> {
+        let DropPlanBaselineStmt { if_exists, query } = stmt;
+
+        Ok(Plan::DropPlanBaseline(Box::new(DropPlanBaselinePlan {
+            if_exists: *if_exists,
+            query: self.plan_baseline_query(query)?,
+        })))
+    }
+
+    fn plan_baseline_query(&self, query: &Query) -> Result<PlanBaselineQuery> {
+        PlanBaselineQuery::try_create(self.ctx.as_ref(), query)?.ok_or_else(|| {
+            ErrorCode::SemanticError("Plan baseline is not supported for time travel queries")
+        })
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::CreateRowAccessPolicyStmt;
+use databend_common_ast::ast::DescRowAccessPolicyStmt;
+use databend_common_ast::ast::DropRowAccessPolicyStmt;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+
+use crate::binder::Binder;
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+use crate::BindContext;
+use crate::ScalarExpr;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            create_option,
+            name,
+            args,
+            body,
+            comment,
+        } = stmt;
+
+        // Check the body against the arguments, so a broken policy is rejected
+        // at creation rather than on every scan of the attached tables.
+        let mut arg_names = Vec::with_capacity(args.len());
+        let mut aliases = Vec::with_capacity(args.len());
+        for (i, arg) in args.iter().enumerate() {
+            let arg_name = normalize_identifier(&arg.name, &self.name_resolution_ctx).name;
+            if arg_names.contains(&arg_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Duplicate argument '{arg_name}' in row access policy"
+                ))
+                .set_span(arg.name.span));
+            }
+            let data_type = DataType::from(&resolve_type_name(&arg.data_type, false)?);
+            let column = ColumnBindingBuilder::new(
+                arg_name.clone(),
+                i,
+                Box::new(data_type),
+                Visibility::Visible,
+            )
+            .build();
+            aliases.push((
+                arg_name.clone(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column }),
+            ));
+            arg_names.push(arg_name);
+        }
+        self.resolve_row_access_policy_body(&body.to_string(), &aliases)
+            .await?;
+
+        let plan = CreateRowAccessPolicyPlan {
+            create_option: *create_option,
+            tenant: self.ctx.get_tenant().to_string(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            args: arg_names
+                .into_iter()
+                .zip(args.iter().map(|arg| arg.data_type.to_string()))
+                .collect(),
+            body: body.to_string(),
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        let plan = DropRowAccessPolicyPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant().to_string(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        };
+        Ok(Plan::DropRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_row_access_policy(
+        &mut self,
+        stmt: &DescRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DescRowAccessPolicyStmt { name } = stmt;
+
+        let plan = DescRowAccessPolicyPlan {
+            tenant: self.ctx.get_tenant().to_string(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        };
+        Ok(Plan::DescRowAccessPolicy(Box::new(plan)))
+    }
+
+    /// Resolves the body of a row access policy, the arguments are bound by `aliases`
+    /// and nothing else is visible to the body.
+    pub(in crate::planner::binder) async fn resolve_row_access_policy_body(
+        &self,
+        body: &str,
+        aliases: &[(String, ScalarExpr)],
+    ) -> Result<ScalarExpr> {
+        let tokens = tokenize_sql(body)?;
+        let ast_expr = parse_expr(&tokens, self.dialect)?;
+        let mut bind_context = BindContext::new();
+        let mut type_checker = TypeChecker::try_create(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            aliases,
+            true,
+        )?;
+        let (scalar, data_type) = *type_checker.resolve(&ast_expr).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy must return BOOLEAN, but got {data_type}"
+            )));
+        }
+        if !self.check_allowed_scalar_expr(&scalar)? {
+            return Err(ErrorCode::SemanticError(
+                "Row access policy can't contain subquery|window|aggregate|udf functions",
+            ));
+        }
+        Ok(scalar)
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::CreateSequenceStmt;
+use databend_common_ast::ast::DescSequenceStmt;
+use databend_common_ast::ast::DropSequenceStmt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::IDENTITY_SEQUENCE_PREFIX;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::CreateSequencePlan;
+use crate::plans::DescSequencePlan;
+use crate::plans::DropSequencePlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_sequence(
+        &mut self,
+        stmt: &CreateSequenceStmt,
+    ) -> Result<Plan> {
+        let CreateSequenceStmt {
+            create_option,
+            sequence,
+            start,
+            increment,
+            comment,
+        } = stmt;
+
+        let increment = increment.unwrap_or(1);
+        if increment == 0 {
+            return Err(ErrorCode::SequenceError(
+                "INCREMENT of sequence must not be zero",
+            ));
+        }
+        let sequence = normalize_identifier(sequence, &self.name_resolution_ctx).name;
+        check_sequence_name(&sequence)?;
+
+        let plan = CreateSequencePlan {
+            create_option: *create_option,
+            tenant: self.ctx.get_tenant().to_string(),
+            sequence,
+            start: start.unwrap_or(1),
+            increment,
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateSequence(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_sequence(
+        &mut self,
+        stmt: &DropSequenceStmt,
+    ) -> Result<Plan> {
+        let DropSequenceStmt {
+            if_exists,
+            sequence,
+        } = stmt;
+
+        let sequence = normalize_identifier(sequence, &self.name_resolution_ctx).name;
+        check_sequence_name(&sequence)?;
+
+        let plan = DropSequencePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant().to_string(),
+            sequence,
+        };
+        Ok(Plan::DropSequence(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_sequence(
+        &mut self,
+        stmt: &DescSequenceStmt,
+    ) -> Result<Plan> {
+        let DescSequenceStmt { sequence } = stmt;
+
+        let plan = DescSequencePlan {
+            tenant: self.ctx.get_tenant().to_string(),
+            sequence: normalize_identifier(sequence, &self.name_resolution_ctx).name,
+        };
+        Ok(Plan::DescSequence(Box::new(plan)))
+    }
+}
+
+/// The sequences of identity columns are managed along with their tables.
+fn check_sequence_name(sequence: &str) -> Result<()> {
+    if sequence.starts_with(IDENTITY_SEQUENCE_PREFIX) {
+        return Err(ErrorCode::SequenceError(format!(
+            "sequence `{}` is reserved for identity columns",
+            sequence
+        )));
+    }
+    Ok(())
+}
@@ -12,26 +12,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::CreateStreamStmt;
 use databend_common_ast::ast::DescribeStreamStmt;
 use databend_common_ast::ast::DropStreamStmt;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowStreamsStmt;
+use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::StreamPoint;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::get_license_manager;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
 use log::debug;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
+use crate::planner::semantic::MaterializedViewChecker;
 use crate::plans::CreateStreamPlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::Plan;
 use crate::plans::RewriteKind;
 use crate::plans::StreamNavigation;
+use crate::plans::StreamView;
 use crate::BindContext;
 use crate::SelectBuilder;
+use crate::ViewRewriter;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -60,6 +78,9 @@ impl Binder {
             .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
             .unwrap_or_else(|| self.ctx.get_current_database());
         let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let (table_database, table_name, view) = self
+            .resolve_stream_view(&catalog, table_database, table_name)
+            .await?;
 
         let navigation = stream_point.as_ref().map(|point| match point {
             StreamPoint::AtStream { database, name } => {
@@ -81,12 +102,119 @@ impl Binder {
             table_database,
             table_name,
             navigation,
+            view,
             append_only: *append_only,
             comment: comment.clone(),
         };
         Ok(Plan::CreateStream(plan.into()))
     }
 
+    /// Resolves the table that a stream on a view tracks. The view must be the filter and
+    /// projection of a single table, its stars are expanded to the columns of the table,
+    /// so that they don't include the change columns of the stream.
+    #[async_backtrace::framed]
+    async fn resolve_stream_view(
+        &self,
+        catalog: &str,
+        database: String,
+        name: String,
+    ) -> Result<(String, String, Option<StreamView>)> {
+        let table = self.ctx.get_table(catalog, &database, &name).await?;
+        if table.engine() != VIEW_ENGINE {
+            return Ok((database, name, None));
+        }
+
+        let query = table
+            .options()
+            .get(QUERY)
+            .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
+        let tokens = tokenize_sql(query)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(mut query) = stmt else {
+            return Err(ErrorCode::Internal(format!("Invalid VIEW object: {name}")));
+        };
+        let mut visitor = ViewRewriter {
+            current_database: database.clone(),
+        };
+        query.drive_mut(&mut visitor);
+
+        let illegal_view = || {
+            ErrorCode::IllegalStream(format!(
+                "The view '{database}.{name}' must be a filter or projection of a single table, can't create stream"
+            ))
+        };
+        let SetExpr::Select(select) = &mut query.body else {
+            return Err(illegal_view());
+        };
+        let [
+            TableReference::Table {
+                catalog: base_catalog,
+                database: base_database,
+                table: base_table,
+                ..
+            },
+        ] = select.from.as_slice()
+        else {
+            return Err(illegal_view());
+        };
+        let (base_catalog, base_database, base_table) =
+            self.normalize_object_identifier_triple(base_catalog, base_database, base_table);
+        if base_catalog != catalog {
+            return Err(illegal_view());
+        }
+
+        let base = self
+            .ctx
+            .get_table(catalog, &base_database, &base_table)
+            .await?;
+        let mut select_list = Vec::with_capacity(select.select_list.len());
+        for target in select.select_list.drain(..) {
+            match target {
+                SelectTarget::StarColumns {
+                    column_filter: None,
+                    ..
+                } if target.is_star() => {
+                    select_list.extend(base.schema().fields().iter().map(|field| {
+                        SelectTarget::AliasedExpr {
+                            expr: Box::new(Expr::ColumnRef {
+                                span: None,
+                                column: ColumnRef {
+                                    database: None,
+                                    table: None,
+                                    column: ColumnID::Name(Identifier::from_name(
+                                        field.name().clone(),
+                                    )),
+                                },
+                            }),
+                            alias: None,
+                        }
+                    }));
+                }
+                SelectTarget::StarColumns { .. } => return Err(illegal_view()),
+                target => select_list.push(target),
+            }
+        }
+        select.select_list = select_list;
+
+        let mut checker = MaterializedViewChecker::default();
+        query.drive(&mut checker);
+        if !checker.is_incremental() || checker.unsupported_source.is_some() {
+            return Err(illegal_view());
+        }
+        if let Some(func) = &checker.non_deterministic_function {
+            return Err(ErrorCode::IllegalStream(format!(
+                "The view '{database}.{name}' contains non-deterministic function {func}, can't create stream"
+            )));
+        }
+
+        let view = StreamView {
+            database,
+            name,
+            query: query.to_string(),
+        };
+        Ok((base_database, base_table, Some(view)))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_stream(
         &mut self,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -42,6 +43,8 @@ use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableConstraintDefinition;
+use databend_common_ast::ast::TableConstraintKind;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::TypeName;
@@ -67,6 +70,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_delta::DeltaTable;
@@ -74,12 +78,16 @@ use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
+use databend_storages_common_table_meta::table::temp_table_storage_prefix;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use derive_visitor::DriveMut;
 use log::debug;
 use log::error;
@@ -92,6 +100,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
+use crate::parse_check_expr_to_string;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
@@ -99,13 +108,16 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -129,6 +141,7 @@ use crate::plans::VacuumTemporaryFilesPlan;
 use crate::BindContext;
 use crate::Planner;
 use crate::SelectBuilder;
+use crate::SequenceDefault;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -412,6 +425,7 @@ impl Binder {
             cluster_by,
             as_query,
             transient,
+            temporary,
             engine,
             uri_location,
         } = stmt;
@@ -471,8 +485,25 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // If table is TEMPORARY, its data is kept under the prefix private to the session
+        if *temporary {
+            if !matches!(engine, Engine::Fuse | Engine::Memory) || storage_params.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "Incorrect CREATE query: CREATE TEMPORARY TABLE is only supported for FUSE and MEMORY engine without external location",
+                ));
+            }
+            options.insert(
+                OPT_KEY_TEMP_PREFIX.to_owned(),
+                temp_table_storage_prefix(
+                    self.ctx.get_tenant().as_str(),
+                    &GlobalConfig::instance().query.node_id,
+                    self.ctx.get_current_session_id(),
+                ),
+            );
+        }
+
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols)) = &source {
+        if let Some(CreateTableSource::Columns(cols, _)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry))
@@ -565,6 +596,81 @@ impl Binder {
             }
         };
 
+        // `CREATE TABLE ... CLONE` shares the segments and blocks of the source table, thus
+        // the new table inherits the options of the source, and is stored in the same format.
+        let mut source_cluster_key = None;
+        let clone_source = match &source {
+            Some(CreateTableSource::Clone {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                travel_point,
+            }) => {
+                if engine != Engine::Fuse
+                    || as_query.is_some()
+                    || storage_params.is_some()
+                    || *temporary
+                {
+                    return Err(ErrorCode::BadArguments(
+                        "Incorrect CREATE query: CREATE TABLE ... CLONE is only supported for FUSE engine without AS SELECT, external location or TEMPORARY",
+                    ));
+                }
+                let (source_catalog, source_database, source_table) = self
+                    .normalize_object_identifier_triple(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                    );
+                if source_catalog != catalog {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Incorrect CREATE query: can not clone table across catalogs, from {} to {}",
+                        source_catalog, catalog
+                    )));
+                }
+                let table = self
+                    .ctx
+                    .get_table(&source_catalog, &source_database, &source_table)
+                    .await?;
+                let table_info = table.get_table_info();
+                if table.engine() != "FUSE"
+                    || table_info.meta.storage_params.is_some()
+                    || table_info.options().contains_key(OPT_KEY_TEMP_PREFIX)
+                {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "Only the FUSE tables without external location can be cloned, but got {}.{}",
+                        source_database, source_table
+                    )));
+                }
+                for (key, value) in table_info.options() {
+                    if key == OPT_KEY_STORAGE_FORMAT || key == OPT_KEY_TABLE_COMPRESSION {
+                        options.insert(key.clone(), value.clone());
+                    } else if !is_reserved_opt_key(key) && key != OPT_KEY_SNAPSHOT_LOCATION {
+                        options.entry(key.clone()).or_insert_with(|| value.clone());
+                    }
+                }
+                options.insert(
+                    OPT_KEY_CLONE_SOURCE_TABLE_ID.to_string(),
+                    table.get_id().to_string(),
+                );
+                source_cluster_key = table_info.meta.default_cluster_key.clone();
+
+                let navigation = match travel_point {
+                    Some(point) => Some(
+                        self.resolve_data_travel_point(&mut BindContext::new(), point)
+                            .await?,
+                    ),
+                    None => None,
+                };
+                Some(CloneTableSource {
+                    catalog: source_catalog,
+                    database: source_database,
+                    table: source_table,
+                    navigation,
+                })
+            }
+            _ => None,
+        };
+
         // for fuse engine, we will insert database_id, so if we check it in execute phase,
         // we can't distinct user key and our internal key.
         if options.contains_key(&OPT_KEY_DATABASE_ID.to_lowercase()) {
@@ -633,12 +739,59 @@ impl Binder {
             }
         }
 
+        // `IDENTITY` columns take values from implicit sequences, which are created along
+        // with the table and named after the ids of the table and the columns.
+        let schema = match &source {
+            Some(CreateTableSource::Columns(columns, _))
+                if columns
+                    .iter()
+                    .any(|c| matches!(c.expr, Some(ColumnExpr::Identity { .. }))) =>
+            {
+                let mut fields = schema.fields().clone();
+                for (column, field) in columns.iter().zip(fields.iter_mut()) {
+                    if let Some(ColumnExpr::Identity { start, step }) = &column.expr {
+                        let default = SequenceDefault::Identity {
+                            start: *start,
+                            step: *step,
+                        };
+                        *field = field.clone().with_default_expr(Some(default.to_string()));
+                    }
+                }
+                TableSchemaRefExt::create(fields)
+            }
+            _ => schema,
+        };
+
+        let constraints = match &source {
+            Some(CreateTableSource::Columns(_, constraints)) if !constraints.is_empty() => {
+                if engine != Engine::Fuse
+                    && constraints
+                        .iter()
+                        .any(|c| matches!(c.kind, TableConstraintKind::Check(_)))
+                {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "CHECK constraint is only supported for FUSE engine, but got {}",
+                        engine
+                    )));
+                }
+                self.analyze_table_constraints(
+                    &catalog,
+                    &database,
+                    &table,
+                    schema.clone(),
+                    constraints,
+                )
+                .await?
+            }
+            _ => BTreeMap::new(),
+        };
+
         let cluster_key = {
             let keys = self
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
             if keys.is_empty() {
-                None
+                source_cluster_key
             } else {
                 Some(format!("({})", keys.join(", ")))
             }
@@ -670,6 +823,8 @@ impl Binder {
             } else {
                 None
             },
+            constraints,
+            clone_source,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -742,6 +897,8 @@ impl Binder {
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
+            constraints: BTreeMap::new(),
+            clone_source: None,
         })))
     }
 
@@ -928,6 +1085,29 @@ impl Binder {
                     column: column.to_string(),
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                    .collect();
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant: tenant.to_string(),
+                        catalog,
+                        database,
+                        table,
+                        policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                        columns,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy => Ok(Plan::DropTableRowAccessPolicy(Box::new(
+                DropTableRowAccessPolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                },
+            ))),
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
                 let schema = self
                     .ctx
@@ -1301,6 +1481,11 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::Identity { .. } => {
+                    return Err(ErrorCode::SemanticError(
+                        "can't add an identity column".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1333,7 +1518,25 @@ impl Binder {
                         )?;
                         field = field.with_default_expr(Some(expr));
                     }
-                    _ => has_computed = true,
+                    ColumnExpr::Identity { step, .. } => {
+                        // The default expression is set after the name of the sequence is known.
+                        if *step == 0 {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "step of identity column `{}` must not be zero",
+                                name
+                            )));
+                        }
+                        if !DataType::from(&schema_data_type)
+                            .remove_nullable()
+                            .is_integer()
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "identity column `{}` must be an integer column, but got {}",
+                                name, schema_data_type
+                            )));
+                        }
+                    }
+                    ColumnExpr::Virtual(_) | ColumnExpr::Stored(_) => has_computed = true,
                 }
             }
             fields.push(field);
@@ -1388,13 +1591,163 @@ impl Binder {
         Ok((schema, fields_comments))
     }
 
+    #[async_backtrace::framed]
+    async fn analyze_table_constraints(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        schema: TableSchemaRef,
+        constraints: &[TableConstraintDefinition],
+    ) -> Result<BTreeMap<String, TableConstraint>> {
+        let resolve_columns = |columns: &[Identifier]| -> Result<(Vec<u32>, Vec<String>)> {
+            let mut column_ids = Vec::with_capacity(columns.len());
+            let mut names = Vec::with_capacity(columns.len());
+            for column in columns {
+                let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                let field = schema.field_with_name(&name).map_err(|_| {
+                    ErrorCode::SemanticError(format!(
+                        "column `{}` named in constraint does not exist",
+                        name
+                    ))
+                })?;
+                if names.contains(&name) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "column `{}` appears twice in constraint",
+                        name
+                    )));
+                }
+                column_ids.push(field.column_id());
+                names.push(name);
+            }
+            Ok((column_ids, names))
+        };
+
+        let mut result = BTreeMap::new();
+        let mut has_primary_key = false;
+        for constraint in constraints {
+            let (default_name, table_constraint) = match &constraint.kind {
+                TableConstraintKind::Check(expr) => {
+                    let expr = parse_check_expr_to_string(self.ctx.clone(), schema.clone(), expr)?;
+                    (format!("{table}_check"), TableConstraint::Check { expr })
+                }
+                TableConstraintKind::PrimaryKey(columns) => {
+                    if has_primary_key {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "multiple primary keys for table `{}` are not allowed",
+                            table
+                        )));
+                    }
+                    has_primary_key = true;
+                    let (column_ids, _) = resolve_columns(columns)?;
+                    (format!("{table}_pkey"), TableConstraint::PrimaryKey {
+                        column_ids,
+                    })
+                }
+                TableConstraintKind::Unique(columns) => {
+                    let (column_ids, names) = resolve_columns(columns)?;
+                    (
+                        format!("{}_{}_key", table, names.join("_")),
+                        TableConstraint::Unique { column_ids },
+                    )
+                }
+                TableConstraintKind::ForeignKey {
+                    columns,
+                    ref_database,
+                    ref_table,
+                    ref_columns,
+                } => {
+                    let (column_ids, names) = resolve_columns(columns)?;
+                    let ref_database = ref_database
+                        .as_ref()
+                        .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                        .unwrap_or_else(|| database.to_string());
+                    let ref_table = normalize_identifier(ref_table, &self.name_resolution_ctx).name;
+                    let ref_columns = ref_columns
+                        .iter()
+                        .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                        .collect::<Vec<_>>();
+                    if ref_columns.len() != column_ids.len() {
+                        return Err(ErrorCode::SemanticError(
+                            "number of referencing and referenced columns for foreign key disagree"
+                                .to_string(),
+                        ));
+                    }
+
+                    // A table may reference itself, e.g. `parent_id REFERENCES t(id)`.
+                    let ref_schema = if ref_database == database && ref_table == table {
+                        schema.clone()
+                    } else {
+                        self.ctx
+                            .get_table(catalog, &ref_database, &ref_table)
+                            .await?
+                            .schema()
+                    };
+                    for (name, ref_name) in names.iter().zip(ref_columns.iter()) {
+                        let ref_field = ref_schema.field_with_name(ref_name).map_err(|_| {
+                            ErrorCode::SemanticError(format!(
+                                "column `{}` referenced in foreign key constraint does not exist",
+                                ref_name
+                            ))
+                        })?;
+                        let field = schema.field_with_name(name)?;
+                        if field.data_type().remove_nullable()
+                            != ref_field.data_type().remove_nullable()
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "foreign key column `{}` of type {} can't reference column `{}` of type {}",
+                                name,
+                                field.data_type(),
+                                ref_name,
+                                ref_field.data_type()
+                            )));
+                        }
+                    }
+                    (
+                        format!("{}_{}_fkey", table, names.join("_")),
+                        TableConstraint::ForeignKey {
+                            column_ids,
+                            ref_database,
+                            ref_table,
+                            ref_columns,
+                        },
+                    )
+                }
+            };
+
+            let name = match &constraint.name {
+                Some(name) => {
+                    let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+                    if result.contains_key(&name) {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "constraint `{}` for table `{}` already exists",
+                            name, table
+                        )));
+                    }
+                    name
+                }
+                None => {
+                    let mut name = default_name.clone();
+                    let mut suffix = 1;
+                    while result.contains_key(&name) {
+                        name = format!("{default_name}{suffix}");
+                        suffix += 1;
+                    }
+                    name
+                }
+            };
+            result.insert(name, table_constraint);
+        }
+        Ok(result)
+    }
+
     #[async_backtrace::framed]
     async fn analyze_create_table_schema(
         &self,
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         match source {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, _) => {
                 self.analyze_create_table_schema_by_columns(columns).await
             }
             CreateTableSource::Like {
@@ -1420,11 +1773,51 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                let table = self.ctx.get_table(&catalog, &database, &table).await?;
+                let Some(point) = travel_point else {
+                    return Ok((table.schema(), table.field_comments().clone()));
+                };
+                // the clone takes the schema of the snapshot it shares, the comments of the
+                // columns dropped since then are lost.
+                let point = self
+                    .resolve_data_travel_point(&mut BindContext::new(), point)
+                    .await?;
+                let navigated = table.navigate_since_to(&None, &Some(point)).await?;
+                let comments = table
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| field.column_id())
+                    .zip(table.field_comments().iter())
+                    .collect::<HashMap<_, _>>();
+                let field_comments = navigated
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        comments
+                            .get(&field.column_id())
+                            .map(|comment| comment.to_string())
+                            .unwrap_or_default()
+                    })
+                    .collect();
+                Ok((navigated.schema(), field_comments))
+            }
         }
     }
 
     /// Validate the schema of the table to be created.
-    fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
+    pub(in crate::planner::binder) fn validate_create_table_schema(
+        schema: &TableSchemaRef,
+    ) -> Result<()> {
         // Check if there are duplicated column names
         let mut name_set = HashSet::new();
         for field in schema.fields() {
@@ -12,18 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+
 use databend_common_ast::ast::AlterViewStmt;
+use databend_common_ast::ast::CreateMaterializedViewStmt;
 use databend_common_ast::ast::CreateViewStmt;
+use databend_common_ast::ast::DropMaterializedViewStmt;
 use databend_common_ast::ast::DropViewStmt;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::RefreshMaterializedViewStmt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::infer_schema_type;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::schema::TableInfo;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_IDS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_STREAM;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
 use crate::binder::Binder;
+use crate::get_materialized_views;
 use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::MaterializedViewChecker;
+use crate::planner::semantic::MaterializedViewRewriter;
+use crate::planner::semantic::MaterializedViewStreamRewriter;
+use crate::planner::semantic::MaterializedViewTarget;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
+use crate::plans::DropMaterializedViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
 use crate::ViewRewriter;
 
 impl Binder {
@@ -128,4 +157,331 @@ impl Binder {
         };
         Ok(Plan::DropView(plan.into()))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            create_option,
+            catalog,
+            database,
+            view,
+            refresh_mode,
+            query,
+        } = stmt;
+        let mut query = *query.clone();
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let mut visitor = ViewRewriter {
+            current_database: database.clone(),
+        };
+        query.drive_mut(&mut visitor);
+
+        let mut checker = MaterializedViewChecker::default();
+        query.drive(&mut checker);
+        if let Some(func) = &checker.non_deterministic_function {
+            return Err(ErrorCode::SemanticError(format!(
+                "Materialized view can't contain non-deterministic function {}",
+                func
+            )));
+        }
+        if let Some(source) = &checker.unsupported_source {
+            return Err(ErrorCode::SemanticError(format!(
+                "Materialized view can't be defined on {}",
+                source
+            )));
+        }
+
+        // The changes of the view are tracked by the stream on the base table.
+        let mut base_tables = vec![];
+        for (base_catalog, base_database, base_table) in &checker.tables {
+            let (base_catalog, base_database, base_table) =
+                self.normalize_object_identifier_triple(base_catalog, base_database, base_table);
+            if base_catalog != catalog {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Materialized view must be in the same catalog as the table {}.{}",
+                    base_database, base_table
+                )));
+            }
+            if !base_tables.contains(&(base_database.clone(), base_table.clone())) {
+                base_tables.push((base_database, base_table));
+            }
+        }
+        if base_tables.len() != 1 {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view must be defined on exactly one table",
+            ));
+        }
+        let (base_database, base_table) = base_tables.remove(0);
+        let table = self
+            .ctx
+            .get_table(&catalog, &base_database, &base_table)
+            .await?;
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(format!(
+                "Materialized view only supports the table of FUSE engine, but {}.{} is {}",
+                base_database,
+                base_table,
+                table.engine()
+            )));
+        }
+
+        let mut init_bind_context = BindContext::new();
+        let (_, bind_context) = self.bind_query(&mut init_bind_context, &query).await?;
+        let fields = bind_context
+            .columns
+            .iter()
+            .map(|column_binding| {
+                Ok(TableField::new(
+                    &column_binding.column_name,
+                    infer_schema_type(&column_binding.data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = TableSchemaRefExt::create(fields);
+        Self::validate_create_table_schema(&schema)?;
+
+        let stream_name = format!("_mv_{}", view_name);
+        let incremental_query = if checker.is_incremental() {
+            let mut incremental_query = query.clone();
+            let mut visitor = MaterializedViewStreamRewriter {
+                stream_database: database.clone(),
+                stream_name: stream_name.clone(),
+            };
+            incremental_query.drive_mut(&mut visitor);
+            Some(format!("{}", incremental_query))
+        } else {
+            None
+        };
+
+        let plan = CreateMaterializedViewPlan {
+            create_option: *create_option,
+            tenant: tenant.to_string(),
+            catalog,
+            database,
+            view_name,
+            schema,
+            refresh_mode: *refresh_mode,
+            base_database,
+            base_table,
+            base_table_id: table.get_id(),
+            stream_name,
+            query: format!("{}", query),
+            incremental_query,
+        };
+        Ok(Plan::CreateMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = DropMaterializedViewPlan {
+            if_exists: *if_exists,
+            tenant: tenant.to_string(),
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::DropMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(plan.into()))
+    }
+
+    /// Rewrite the query to scan the materialized views which are defined by the same
+    /// query (or subquery) and are fresh, i.e. no changes of the base table since the last
+    /// refresh. The views are looked up by the ids kept on the referenced tables.
+    ///
+    /// The rewrite must not change what the user can read, so it is skipped if any of the
+    /// tables has a row access policy or masking policies, and a view is only used if the
+    /// user can select from both the view and its base table.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn rewrite_query_by_materialized_views(
+        &mut self,
+        query: &Query,
+    ) -> Result<Option<Query>> {
+        if !self
+            .ctx
+            .get_settings()
+            .get_enable_materialized_view_rewrite()?
+        {
+            return Ok(None);
+        }
+
+        let mut query = query.clone();
+        let mut visitor = ViewRewriter {
+            current_database: self.ctx.get_current_database(),
+        };
+        query.drive_mut(&mut visitor);
+        let mut checker = MaterializedViewChecker::default();
+        query.drive(&mut checker);
+        if checker.has_cte {
+            return Ok(None);
+        }
+
+        // The views are found by the ids kept on their base tables, the base tables are
+        // cached by the query context, so the tables without views cost no meta calls.
+        let mut views = HashMap::new();
+        let mut visited = HashSet::new();
+        for (catalog, database, table) in &checker.tables {
+            let (catalog_name, database, table) =
+                self.normalize_object_identifier_triple(catalog, database, table);
+            let Ok(base_table) = self.ctx.get_table(&catalog_name, &database, &table).await else {
+                continue;
+            };
+            let base_table_info = base_table.get_table_info();
+            if base_table_info.meta.row_access_policy.is_some()
+                || base_table_info
+                    .meta
+                    .column_mask_policy
+                    .as_ref()
+                    .is_some_and(|policies| !policies.is_empty())
+            {
+                return Ok(None);
+            }
+            if !visited.insert(base_table.get_id())
+                || !base_table
+                    .options()
+                    .contains_key(OPT_KEY_MATERIALIZED_VIEW_IDS)
+                || !self
+                    .has_select_privilege(&catalog_name, &database, base_table_info)
+                    .await?
+            {
+                continue;
+            }
+            let catalog = self.ctx.get_catalog(&catalog_name).await?;
+            for view in get_materialized_views(catalog.as_ref(), base_table_info).await? {
+                let (Some(view_query), Some(stream_name)) = (
+                    view.options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY),
+                    view.options.get(OPT_KEY_MATERIALIZED_VIEW_STREAM),
+                ) else {
+                    continue;
+                };
+                // The stream records the snapshot of the base table at the last refresh, the
+                // view is fresh if the base table still has the same snapshot. Unlike the
+                // version of the table meta, the snapshot doesn't change with the options.
+                let Ok(stream) = self
+                    .ctx
+                    .get_table(&catalog_name, &view.database, stream_name)
+                    .await
+                else {
+                    continue;
+                };
+                if stream.options().get(OPT_KEY_SNAPSHOT_LOCATION)
+                    != base_table.options().get(OPT_KEY_SNAPSHOT_LOCATION)
+                {
+                    continue;
+                }
+                let Ok(view_table) = self
+                    .ctx
+                    .get_table(&catalog_name, &view.database, &view.name)
+                    .await
+                else {
+                    continue;
+                };
+                if !self
+                    .has_select_privilege(
+                        &catalog_name,
+                        &view.database,
+                        view_table.get_table_info(),
+                    )
+                    .await?
+                {
+                    continue;
+                }
+                let columns = view_table
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| field.name().clone())
+                    .collect();
+                views.insert(view_query.clone(), MaterializedViewTarget {
+                    database: view.database,
+                    view: view.name,
+                    columns,
+                });
+            }
+        }
+        if views.is_empty() {
+            return Ok(None);
+        }
+
+        let mut rewriter = MaterializedViewRewriter {
+            views,
+            name_resolution_ctx: self.name_resolution_ctx.clone(),
+            rewritten: false,
+        };
+        query.drive_mut(&mut rewriter);
+        Ok(rewriter.rewritten.then_some(query))
+    }
+
+    /// Checks if the current user or any of its roles is granted SELECT on the table, by
+    /// name or by id, the same as the privilege check of the queries.
+    async fn has_select_privilege(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &TableInfo,
+    ) -> Result<bool> {
+        let mut objects = vec![GrantObject::Table(
+            catalog.to_string(),
+            database.to_string(),
+            table.name.clone(),
+        )];
+        if let Some(db_id) = table
+            .options()
+            .get(OPT_KEY_DATABASE_ID)
+            .and_then(|id| id.parse::<u64>().ok())
+        {
+            objects.push(GrantObject::TableById(
+                catalog.to_string(),
+                db_id,
+                table.ident.table_id,
+            ));
+        }
+
+        let user = self.ctx.get_current_user()?;
+        let roles = self.ctx.get_all_effective_roles().await?;
+        Ok(objects.iter().any(|object| {
+            user.grants
+                .verify_privilege(object, vec![UserPrivilegeType::Select])
+                || roles.iter().any(|role| {
+                    role.grants
+                        .verify_privilege(object, vec![UserPrivilegeType::Select])
+                })
+        }))
+    }
 }
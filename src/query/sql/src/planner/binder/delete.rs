@@ -29,6 +29,7 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::DeletePlan;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Operator;
 use crate::plans::Plan;
 use crate::plans::RelOp;
@@ -46,7 +47,9 @@ impl<'a> Binder {
         table_expr: SExpr,
         scalar_binder: &mut ScalarBinder<'_>,
     ) -> Result<(Option<ScalarExpr>, Vec<SubqueryDesc>)> {
-        Ok(if let Some(expr) = filter {
+        // Only the rows visible through the row access policy can be mutated.
+        let (table_expr, policy) = Self::split_row_access_policy(table_expr);
+        let (selection, subquery_desc) = if let Some(expr) = filter {
             let (scalar, _) = scalar_binder.bind(expr).await?;
             let mut subquery_desc = vec![];
             self.subquery_desc(&scalar, table_expr, &mut subquery_desc)
@@ -54,7 +57,17 @@ impl<'a> Binder {
             (Some(scalar), subquery_desc)
         } else {
             (None, vec![])
-        })
+        };
+        let selection = match (selection, policy) {
+            (Some(selection), Some(policy)) => Some(ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![selection, policy],
+            })),
+            (selection, policy) => selection.or(policy),
+        };
+        Ok((selection, subquery_desc))
     }
 
     #[async_backtrace::framed]
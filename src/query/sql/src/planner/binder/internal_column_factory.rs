@@ -20,6 +20,7 @@ use databend_common_catalog::plan::InternalColumnType;
 use databend_common_expression::BASE_BLOCK_IDS_COL_NAME;
 use databend_common_expression::BASE_ROW_ID_COL_NAME;
 use databend_common_expression::BLOCK_NAME_COL_NAME;
+use databend_common_expression::BLOCK_TIMESTAMP_COL_NAME;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_expression::SEGMENT_NAME_COL_NAME;
 use databend_common_expression::SNAPSHOT_NAME_COL_NAME;
@@ -45,6 +46,11 @@ impl InternalColumnFactory {
             InternalColumn::new(BLOCK_NAME_COL_NAME, InternalColumnType::BlockName),
         );
 
+        internal_columns.insert(
+            BLOCK_TIMESTAMP_COL_NAME.to_string(),
+            InternalColumn::new(BLOCK_TIMESTAMP_COL_NAME, InternalColumnType::BlockTimestamp),
+        );
+
         internal_columns.insert(
             SEGMENT_NAME_COL_NAME.to_string(),
             InternalColumn::new(SEGMENT_NAME_COL_NAME, InternalColumnType::SegmentName),
@@ -243,6 +243,7 @@ impl Binder {
             need_hold_hash_table: false,
             is_lateral,
             original_join_type: None,
+            hint: Default::default(),
         };
         Ok(SExpr::create_binary(
             Arc::new(logical_join.into()),
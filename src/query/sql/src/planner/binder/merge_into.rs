@@ -45,6 +45,7 @@ use crate::binder::InternalColumnBinding;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
 use crate::plans::MatchedEvaluator;
 use crate::plans::MaterializedCte;
 use crate::plans::MergeInto;
@@ -214,6 +215,7 @@ impl Binder {
             since_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         // get_source_table_reference
@@ -318,9 +320,18 @@ impl Binder {
             .await?;
 
         if table.change_tracking_enabled() && merge_type != MergeIntoType::InsertOnly {
-            if let RelOperator::Scan(scan) = target_expr.plan() {
+            // The scan may be filtered by the row access policy of the target table.
+            let (scan_expr, policy) = Self::split_row_access_policy(target_expr.clone());
+            if let RelOperator::Scan(scan) = scan_expr.plan() {
                 let new_scan = scan.update_stream_columns(true);
-                target_expr = SExpr::create_leaf(Arc::new(new_scan.into()))
+                target_expr = SExpr::create_leaf(Arc::new(new_scan.into()));
+                if let Some(policy) = policy {
+                    let filter = Filter {
+                        predicates: vec![policy],
+                    };
+                    target_expr =
+                        SExpr::create_unary(Arc::new(filter.into()), Arc::new(target_expr));
+                }
             }
         }
 
@@ -212,6 +212,31 @@ impl Binder {
     /// For scalar expressions and aggregate expressions, we will register new columns for
     /// them in `Metadata`. And notice that, the semantic of aggregate expressions won't be checked
     /// in this function.
+    /// The name of the output column of a select target. If alias is not specified,
+    /// we will generate a name for the scalar expression.
+    pub(crate) fn select_target_name(&self, expr: &Expr, alias: Option<&Identifier>) -> String {
+        match (expr, alias) {
+            (
+                Expr::ColumnRef {
+                    column:
+                        ColumnRef {
+                            column: ColumnID::Name(column),
+                            ..
+                        },
+                    ..
+                },
+                None,
+            ) => normalize_identifier(column, &self.name_resolution_ctx).name,
+            (_, Some(alias)) => normalize_identifier(alias, &self.name_resolution_ctx).name,
+            _ => {
+                let mut expr = expr.clone();
+                let mut remove_quote_visitor = RemoveIdentifierQuote;
+                expr.drive_mut(&mut remove_quote_visitor);
+                format!("{:#}", expr).to_lowercase()
+            }
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn normalize_select_list<'a>(
         &mut self,
@@ -260,29 +285,7 @@ impl Binder {
                     );
                     let (bound_expr, _) = scalar_binder.bind(expr).await?;
 
-                    // If alias is not specified, we will generate a name for the scalar expression.
-                    let expr_name = match (expr.as_ref(), alias) {
-                        (
-                            Expr::ColumnRef {
-                                column:
-                                    ColumnRef {
-                                        column: ColumnID::Name(column),
-                                        ..
-                                    },
-                                ..
-                            },
-                            None,
-                        ) => normalize_identifier(column, &self.name_resolution_ctx).name,
-                        (_, Some(alias)) => {
-                            normalize_identifier(alias, &self.name_resolution_ctx).name
-                        }
-                        _ => {
-                            let mut expr = expr.clone();
-                            let mut remove_quote_visitor = RemoveIdentifierQuote;
-                            expr.drive_mut(&mut remove_quote_visitor);
-                            format!("{:#}", expr).to_lowercase()
-                        }
-                    };
+                    let expr_name = self.select_target_name(expr, alias.as_ref());
 
                     prev_aliases.push((expr_name.clone(), bound_expr.clone()));
 
@@ -20,6 +20,7 @@ use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
@@ -31,6 +32,7 @@ use indexmap::IndexMap;
 use crate::binder::wrap_cast;
 use crate::binder::CteInfo;
 use crate::planner::binder::BindContext;
+use crate::planner::parse_sequence_default_expr;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::ScalarExpr;
@@ -104,6 +106,13 @@ impl<'a> ScalarBinder<'a> {
         schema: &DataSchema,
     ) -> Result<databend_common_expression::Expr> {
         if let Some(default_expr) = field.default_expr() {
+            if let Some(sequence) = parse_sequence_default_expr(default_expr) {
+                return Err(ErrorCode::SequenceError(format!(
+                    "the value of column `{}` is allocated by `{}`, it can only be omitted in INSERT or REPLACE",
+                    field.name(),
+                    sequence
+                )));
+            }
             let tokens = tokenize_sql(default_expr)?;
             let ast = parse_expr(&tokens, self.dialect)?;
             let (mut scalar, _) = self.bind(&ast).await?;
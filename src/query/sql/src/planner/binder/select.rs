@@ -137,6 +137,10 @@ impl Binder {
                 .await?
         };
 
+        if let Some(hints) = &stmt.hints {
+            self.bind_join_hints(&from_context, hints);
+        }
+
         let mut rewriter = SelectRewriter::new(
             from_context.all_column_bindings(),
             self.name_resolution_ctx.unquoted_ident_case_sensitive,
@@ -22,6 +22,8 @@ use async_recursion::async_recursion;
 use chrono::TimeZone;
 use chrono::Utc;
 use dashmap::DashMap;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Connection;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FileLocation;
@@ -31,9 +33,12 @@ use databend_common_ast::ast::Indirection;
 use databend_common_ast::ast::Join;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SampleConfig;
+use databend_common_ast::ast::SampleLevel;
 use databend_common_ast::ast::SelectStageOptions;
 use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TableReference;
@@ -44,6 +49,8 @@ use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::ParquetReadOptions;
 use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::plan::TableSample;
+use databend_common_catalog::plan::TableSampleLevel;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
@@ -63,12 +70,18 @@ use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::CHANGE_TIMESTAMP_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_api::RowAccessPolicyApi;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
 use databend_common_meta_types::MetaId;
 use databend_common_storage::DataOperator;
 use databend_common_storage::StageFileInfo;
@@ -83,7 +96,11 @@ use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::table::get_change_type;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::StreamMode;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_VERSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
+use databend_storages_common_table_meta::table::OPT_KEY_VIEW_QUERY;
 use log::info;
 use parking_lot::RwLock;
 
@@ -98,10 +115,14 @@ use crate::binder::Visibility;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::resolve_type_name_by_str;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
 use crate::plans::CteScan;
 use crate::plans::DummyTableScan;
 use crate::plans::EvalScalar;
+use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
@@ -176,6 +197,7 @@ impl Binder {
         alias: &Option<TableAlias>,
         travel_point: &Option<TimeTravelPoint>,
         since_point: &Option<TimeTravelPoint>,
+        sample: &Option<SampleConfig>,
     ) -> Result<(SExpr, BindContext)> {
         let (catalog, database, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table);
@@ -260,6 +282,14 @@ impl Binder {
             }
         };
 
+        if sample.is_some() && table_meta.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(format!(
+                "TABLESAMPLE is not supported by the table `{database}`.`{table_name}` of engine {}",
+                table_meta.engine()
+            ))
+            .set_span(*span));
+        }
+
         match table_meta.engine() {
             "VIEW" => {
                 // TODO(leiysky): this check is error-prone,
@@ -309,6 +339,10 @@ impl Binder {
             "STREAM" => {
                 let change_type = get_change_type(&table_alias_name);
                 if change_type.is_some() {
+                    // The changes of a stream are protected by the policy of its source table.
+                    let row_access_policy = self
+                        .get_row_access_policy(catalog.as_str(), table_meta.as_ref())
+                        .await?;
                     let table_index = self.metadata.write().add_table(
                         catalog,
                         database.clone(),
@@ -318,9 +352,20 @@ impl Binder {
                         bind_context.planning_agg_index,
                         false,
                     );
-                    let (s_expr, mut bind_context) = self
+                    let (mut s_expr, mut bind_context) = self
                         .bind_base_table(bind_context, database.as_str(), table_index, change_type)
                         .await?;
+                    if let Some((policy, schema)) = row_access_policy {
+                        s_expr = self
+                            .bind_row_access_policy(
+                                &bind_context,
+                                table_index,
+                                &policy,
+                                &schema,
+                                s_expr,
+                            )
+                            .await?;
+                    }
 
                     if let Some(alias) = alias {
                         bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -343,7 +388,42 @@ impl Binder {
                     .collect::<Vec<_>>();
 
                 let suffix = format!("{:08x}", Utc::now().timestamp());
+                // The changes of an external table are the rows of the data files added or
+                // removed since the version of the stream, they have no row ids. So an update,
+                // or a compaction that rewrites the data files, is a DELETE and an INSERT
+                // with change$is_update false.
+                let external = table_meta.options().contains_key(OPT_KEY_SOURCE_VERSION);
                 let query = match mode {
+                    StreamMode::AppendOnly if external => {
+                        let append_alias = format!("_change_append${}", suffix);
+                        format!(
+                            "select *, \
+                                    'INSERT' as change$action, \
+                                    false as change$is_update, \
+                                    CAST(NULL AS STRING NULL) as change$row_id, \
+                                    CAST(NULL AS TIMESTAMP NULL) as change$timestamp \
+                             from {database}.{table_name} as {append_alias}",
+                        )
+                    }
+                    StreamMode::Standard if external => {
+                        let a_table_alias = format!("_change_insert${}", suffix);
+                        let d_table_alias = format!("_change_delete${}", suffix);
+                        format!(
+                            "select *, \
+                                    'INSERT' as change$action, \
+                                    CAST(NULL AS STRING NULL) as change$row_id, \
+                                    false as change$is_update, \
+                                    CAST(NULL AS TIMESTAMP NULL) as change$timestamp \
+                             from {database}.{table_name} as {a_table_alias} \
+                             union all \
+                             select *, \
+                                    'DELETE' as change$action, \
+                                    CAST(NULL AS STRING NULL) as change$row_id, \
+                                    false as change$is_update, \
+                                    CAST(NULL AS TIMESTAMP NULL) as change$timestamp \
+                             from {database}.{table_name} as {d_table_alias}",
+                        )
+                    }
                     StreamMode::AppendOnly => {
                         let append_alias = format!("_change_append${}", suffix);
                         format!(
@@ -353,7 +433,8 @@ impl Binder {
                                     if(is_not_null(_origin_block_id), \
                                        concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
                                        {append_alias}._base_row_id \
-                                    ) as change$row_id \
+                                    ) as change$row_id, \
+                                    {append_alias}._block_timestamp as change$timestamp \
                              from {database}.{table_name} as {append_alias} \
                              where not(is_not_null(_origin_version) and \
                                        (_origin_version < {table_version} or \
@@ -372,7 +453,7 @@ impl Binder {
                             .join(", ");
 
                         format!(
-                            "with _change({a_cols}, change$action, change$row_id, \
+                            "with _change({a_cols}, change$action, change$row_id, change$timestamp, \
                                           {d_cols}, d_change$action, d_change$row_id) as materialized \
                             ( \
                                 select * \
@@ -383,7 +464,8 @@ impl Binder {
                                            if(is_not_null(_origin_block_id), \
                                               concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
                                               {a_table_alias}._base_row_id \
-                                           ) as change$row_id \
+                                           ) as change$row_id, \
+                                           {a_table_alias}._block_timestamp as change$timestamp \
                                     from {database}.{table_name} as {a_table_alias} \
                                 ) as A \
                                 FULL OUTER JOIN ( \
@@ -402,14 +484,16 @@ impl Binder {
                             select {a_cols}, \
                                    change$action, \
                                    change$row_id, \
-                                   d_change$action is not null as change$is_update \
+                                   d_change$action is not null as change$is_update, \
+                                   change$timestamp \
                             from _change \
                             where change$action is not null \
                             union all \
                             select {d_cols}, \
                                    d_change$action, \
                                    d_change$row_id, \
-                                   change$action is not null as change$is_update \
+                                   change$action is not null as change$is_update, \
+                                   change$timestamp \
                             from _change \
                             where d_change$action is not null",
                         )
@@ -419,11 +503,28 @@ impl Binder {
                 let tokens = tokenize_sql(query.as_str())?;
                 let (stmt, _) = parse_sql(&tokens, self.dialect)?;
                 if let Statement::Query(query) = &stmt {
-                    let (s_expr, mut new_bind_context) =
-                        self.bind_query(&mut new_bind_context, query).await?;
+                    let view_query = table_meta
+                        .options()
+                        .get(OPT_KEY_VIEW_QUERY)
+                        .map(|view_query| self.bind_stream_view_query(view_query, query))
+                        .transpose()?;
+                    let (s_expr, mut new_bind_context) = match &view_query {
+                        Some(view_query) => self.bind_query(&mut new_bind_context, view_query),
+                        None => self.bind_query(&mut new_bind_context, query),
+                    }
+                    .await?;
 
-                    for (index, column_name) in cols.iter().enumerate() {
-                        new_bind_context.columns[index].column_name = column_name.clone();
+                    // The columns of a stream on view are named by the query of the view.
+                    if view_query.is_none() {
+                        for (index, column_name) in cols.iter().enumerate() {
+                            new_bind_context.columns[index].column_name = column_name.clone();
+                        }
+                    }
+                    // The time of the changes can be selected, but is not a column of the stream.
+                    for column in new_bind_context.columns.iter_mut() {
+                        if column.column_name == CHANGE_TIMESTAMP_COL_NAME {
+                            column.visibility = Visibility::InVisible;
+                        }
                     }
                     if let Some(alias) = alias {
                         new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -440,6 +541,9 @@ impl Binder {
                 }
             }
             _ => {
+                let row_access_policy = self
+                    .get_row_access_policy(catalog.as_str(), table_meta.as_ref())
+                    .await?;
                 let table_index = self.metadata.write().add_table(
                     catalog,
                     database.clone(),
@@ -450,9 +554,25 @@ impl Binder {
                     false,
                 );
 
-                let (s_expr, mut bind_context) = self
+                let (mut s_expr, mut bind_context) = self
                     .bind_base_table(bind_context, database.as_str(), table_index, None)
                     .await?;
+                if let Some(sample) = sample {
+                    let mut scan: Scan = s_expr.plan().clone().try_into()?;
+                    scan.sample = Some(Self::bind_table_sample(sample, span)?);
+                    s_expr = SExpr::create_leaf(Arc::new(scan.into()));
+                }
+                if let Some((policy, schema)) = row_access_policy {
+                    s_expr = self
+                        .bind_row_access_policy(
+                            &bind_context,
+                            table_index,
+                            &policy,
+                            &schema,
+                            s_expr,
+                        )
+                        .await?;
+                }
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
@@ -461,6 +581,211 @@ impl Binder {
         }
     }
 
+    fn bind_table_sample(sample: &SampleConfig, span: &Span) -> Result<TableSample> {
+        if !(0.0..=100.0).contains(&sample.probability) {
+            return Err(ErrorCode::SemanticError(format!(
+                "TABLESAMPLE probability must be between 0 and 100, but got {}",
+                sample.probability
+            ))
+            .set_span(*span));
+        }
+        let level = match sample.level {
+            SampleLevel::Block => TableSampleLevel::Block,
+            SampleLevel::Row => TableSampleLevel::Row,
+        };
+        Ok(TableSample {
+            level,
+            probability: (sample.probability / 100.0).into(),
+            seed: sample.seed,
+        })
+    }
+
+    /// Applies the query of the view that a stream is created on to the changes of the
+    /// stream, the table of the view is replaced by the changes aliased as the table.
+    fn bind_stream_view_query(&self, view_query: &str, changes: &Query) -> Result<Query> {
+        let tokens = tokenize_sql(view_query)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let invalid = || ErrorCode::Internal(format!("Invalid view query of stream: {view_query}"));
+        let Statement::Query(mut query) = stmt else {
+            return Err(invalid());
+        };
+        let SetExpr::Select(select) = &mut query.body else {
+            return Err(invalid());
+        };
+        let Some(TableReference::Table {
+            span, table, alias, ..
+        }) = select.from.pop()
+        else {
+            return Err(invalid());
+        };
+
+        let alias = alias.unwrap_or(TableAlias {
+            name: table,
+            columns: vec![],
+        });
+        select.from.push(TableReference::Subquery {
+            span,
+            lateral: false,
+            subquery: Box::new(changes.clone()),
+            alias: Some(alias),
+        });
+        for column in [
+            "change$action",
+            "change$is_update",
+            "change$row_id",
+            CHANGE_TIMESTAMP_COL_NAME,
+        ] {
+            select.select_list.push(SelectTarget::AliasedExpr {
+                expr: Box::new(Expr::ColumnRef {
+                    span: None,
+                    column: ColumnRef {
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(Identifier::from_name(column)),
+                    },
+                }),
+                alias: None,
+            });
+        }
+        Ok(*query)
+    }
+
+    /// Returns the row access policy that protects the rows of `table`, together with the
+    /// schema its column ids refer to. A stream is protected by the policy of its source table.
+    async fn get_row_access_policy(
+        &self,
+        catalog: &str,
+        table: &dyn Table,
+    ) -> Result<Option<(TableRowAccessPolicy, TableSchemaRef)>> {
+        let source = if table.engine() == "STREAM" {
+            let options = table.options();
+            let (Some(database), Some(name)) = (
+                options.get(OPT_KEY_DATABASE_NAME),
+                options.get(OPT_KEY_TABLE_NAME),
+            ) else {
+                return Ok(None);
+            };
+            match self.ctx.get_table(catalog, database, name).await {
+                Ok(source) => source,
+                // The source table is gone, reading the stream fails later with a better message.
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => return Ok(None),
+                Err(e) => return Err(e),
+            }
+        } else {
+            return Ok(table
+                .get_table_info()
+                .meta
+                .row_access_policy
+                .clone()
+                .map(|policy| (policy, table.schema())));
+        };
+        Ok(source
+            .get_table_info()
+            .meta
+            .row_access_policy
+            .clone()
+            .map(|policy| (policy, source.schema())))
+    }
+
+    /// Filters the scan of a table by the row access policy attached to it,
+    /// the arguments of the policy are bound to the columns of the scan.
+    async fn bind_row_access_policy(
+        &self,
+        bind_context: &BindContext,
+        table_index: IndexType,
+        policy: &TableRowAccessPolicy,
+        schema: &TableSchemaRef,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let req = GetRowAccessPolicyReq {
+            name: RowAccessPolicyNameIdent::new(self.ctx.get_tenant(), &policy.policy),
+        };
+        let policy_meta = meta_api.get_row_access_policy(req).await?.policy;
+        if policy_meta.args.len() != policy.column_ids.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Row access policy '{}' takes {} arguments, but {} columns are attached",
+                policy.policy,
+                policy_meta.args.len(),
+                policy.column_ids.len()
+            )));
+        }
+
+        let mut aliases = Vec::with_capacity(policy_meta.args.len());
+        for ((arg_name, arg_type), column_id) in
+            policy_meta.args.iter().zip(policy.column_ids.iter())
+        {
+            let column = schema
+                .fields()
+                .iter()
+                .find(|field| field.column_id() == *column_id)
+                .and_then(|field| {
+                    bind_context.columns.iter().find(|column| {
+                        column.table_index == Some(table_index)
+                            && &column.column_name == field.name()
+                    })
+                })
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "Cannot find the column of argument '{}' of row access policy '{}'",
+                        arg_name, policy.policy
+                    ))
+                })?;
+            let mut scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            });
+            let arg_type = DataType::from(&resolve_type_name_by_str(arg_type, true)?);
+            if column.data_type.remove_nullable() != arg_type {
+                let target_type = if column.data_type.is_nullable() {
+                    arg_type.wrap_nullable()
+                } else {
+                    arg_type
+                };
+                scalar = ScalarExpr::CastExpr(CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(scalar),
+                    target_type: Box::new(target_type),
+                });
+            }
+            aliases.push((arg_name.clone(), scalar));
+        }
+
+        let predicate = self
+            .resolve_row_access_policy_body(&policy_meta.body, &aliases)
+            .await?;
+        let filter = Filter {
+            predicates: vec![predicate],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// Splits the row access policy filter added by `bind_row_access_policy` from the scan,
+    /// for the statements that mutate the scanned table in place.
+    pub(in crate::planner::binder) fn split_row_access_policy(
+        s_expr: SExpr,
+    ) -> (SExpr, Option<ScalarExpr>) {
+        if let RelOperator::Filter(filter) = s_expr.plan() {
+            let child = s_expr.child(0).unwrap();
+            if matches!(child.plan(), RelOperator::Scan(_)) {
+                let predicate = filter.predicates.iter().cloned().reduce(|lhs, rhs| {
+                    ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "and".to_string(),
+                        params: vec![],
+                        arguments: vec![lhs, rhs],
+                    })
+                });
+                return (child.clone(), predicate);
+            }
+        }
+        (s_expr, None)
+    }
+
     /// Extract the srf inner tuple fields as columns.
     #[async_backtrace::framed]
     async fn extract_srf_table_function_columns(
@@ -793,7 +1118,7 @@ impl Binder {
             // Other table functions always reside is default catalog
             let table_meta: Arc<dyn TableFunction> = self
                 .catalogs
-                .get_default_catalog(self.ctx.txn_mgr())?
+                .get_default_catalog(self.ctx.txn_mgr(), self.ctx.temp_tbl_mgr())?
                 .get_table_function(&func_name.name, table_args)?;
             let table = table_meta.as_table();
             let table_alias_name = if let Some(table_alias) = alias {
@@ -909,6 +1234,7 @@ impl Binder {
                 since_point,
                 pivot: _,
                 unpivot: _,
+                sample,
             } => {
                 self.bind_table(
                     bind_context,
@@ -919,6 +1245,7 @@ impl Binder {
                     alias,
                     travel_point,
                     since_point,
+                    sample,
                 )
                 .await
             }
@@ -1337,6 +1664,7 @@ impl Binder {
         let statistics_provider = table.column_statistics_provider(self.ctx.clone()).await?;
 
         let mut col_stats = HashMap::new();
+        let mut histograms = HashMap::new();
         let columns = self.metadata.read().columns_by_table_index(table_index);
         for column in columns.iter() {
             match column {
@@ -1374,6 +1702,11 @@ impl Binder {
                             let col_stat =
                                 statistics_provider.column_statistics(col_id as ColumnId);
                             col_stats.insert(*column_index, col_stat.cloned());
+                            if let Some(histogram) =
+                                statistics_provider.histogram(col_id as ColumnId)
+                            {
+                                histograms.insert(*column_index, histogram.clone());
+                            }
                         }
                     }
                 }
@@ -1399,6 +1732,7 @@ impl Binder {
                     statistics: Statistics {
                         statistics: stat,
                         col_stats,
+                        histograms,
                     },
                     change_type,
                     ..Default::default()
@@ -1492,7 +1826,12 @@ impl Binder {
     ) -> Result<Vec<(u64, String, IndexMeta)>> {
         let catalog = self
             .catalogs
-            .get_catalog(tenant, catalog_name, self.ctx.txn_mgr())
+            .get_catalog(
+                tenant,
+                catalog_name,
+                self.ctx.txn_mgr(),
+                self.ctx.temp_tbl_mgr(),
+            )
             .await?;
         let index_metas = catalog
             .list_indexes(ListIndexesReq::new(tenant, Some(table_id)))
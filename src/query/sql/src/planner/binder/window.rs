@@ -61,6 +61,7 @@ impl Binder {
             order_by: window_info.order_by_items.clone(),
             frame: window_info.frame.clone(),
             limit: None,
+            presorted: false,
         };
 
         Ok(SExpr::create_unary(
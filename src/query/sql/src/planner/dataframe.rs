@@ -68,6 +68,7 @@ impl Dataframe {
             since_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         let settings = query_ctx.get_settings();
@@ -485,6 +486,7 @@ impl Dataframe {
                 since_point: None,
                 pivot: None,
                 unpivot: None,
+                sample: None,
             };
             table_ref.push(table);
         }
@@ -12,11 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 
 use databend_common_ast::ast::Expr as AExpr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Literal;
 use databend_common_ast::parser::parse_comma_separated_exprs;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::table::Table;
@@ -28,6 +34,7 @@ use databend_common_expression::infer_table_schema;
 use databend_common_expression::type_check::check_cast;
 use databend_common_expression::type_check::check_function;
 use databend_common_expression::types::DataType;
+use databend_common_expression::ComputedExpr;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
@@ -39,7 +46,9 @@ use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::identity_sequence_name;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::IDENTITY_SEQUENCE_PREFIX;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_settings::Settings;
 use derive_visitor::DriveMut;
@@ -235,12 +244,151 @@ pub fn parse_computed_expr(
     Ok(expr)
 }
 
+/// Returns the sequence name if the expression is `nextval('<sequence>')`.
+pub fn sequence_name_of_expr(ast: &AExpr) -> Option<String> {
+    match ast {
+        AExpr::FunctionCall {
+            func:
+                FunctionCall {
+                    name,
+                    args,
+                    params,
+                    window: None,
+                    lambda: None,
+                    ..
+                },
+            ..
+        } if name.name.eq_ignore_ascii_case("nextval") && args.len() == 1 && params.is_empty() => {
+            match &args[0] {
+                AExpr::Literal {
+                    lit: Literal::String(sequence),
+                    ..
+                } => Some(sequence.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The default of a column whose values are allocated from a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceDefault {
+    /// `nextval('<sequence>')`, the sequence is created by the user.
+    Sequence(String),
+    /// `identity(<start>, <step>)`, the default of an `IDENTITY` column. The sequence is
+    /// created along with the table, and is named after the ids of the table and the column.
+    Identity { start: u64, step: u64 },
+}
+
+impl SequenceDefault {
+    pub fn sequence_name(&self, table_id: u64, column_id: u32) -> String {
+        match self {
+            SequenceDefault::Sequence(sequence) => sequence.clone(),
+            SequenceDefault::Identity { .. } => identity_sequence_name(table_id, column_id),
+        }
+    }
+}
+
+impl Display for SequenceDefault {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SequenceDefault::Sequence(sequence) => write!(f, "nextval('{}')", sequence),
+            SequenceDefault::Identity { start, step } => {
+                write!(f, "identity({}, {})", start, step)
+            }
+        }
+    }
+}
+
+/// Returns `(start, step)` if the expression is `identity(<start>, <step>)`.
+fn identity_of_expr(ast: &AExpr) -> Option<(u64, u64)> {
+    match ast {
+        AExpr::FunctionCall {
+            func:
+                FunctionCall {
+                    name,
+                    args,
+                    params,
+                    window: None,
+                    lambda: None,
+                    ..
+                },
+            ..
+        } if name.name.eq_ignore_ascii_case("identity") && args.len() == 2 && params.is_empty() => {
+            match (&args[0], &args[1]) {
+                (
+                    AExpr::Literal {
+                        lit: Literal::UInt64(start),
+                        ..
+                    },
+                    AExpr::Literal {
+                        lit: Literal::UInt64(step),
+                        ..
+                    },
+                ) => Some((*start, *step)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the sequence default if the default expression of a column is
+/// `nextval('<sequence>')` or `identity(<start>, <step>)`.
+///
+/// The values of such columns can't be evaluated by a scalar expression, they are allocated
+/// from the sequence when the rows are appended to the table.
+pub fn parse_sequence_default_expr(default_expr: &str) -> Option<SequenceDefault> {
+    let is_prefixed = |prefix: &str| {
+        default_expr
+            .get(..prefix.len())
+            .is_some_and(|s| s.eq_ignore_ascii_case(prefix))
+    };
+    if !is_prefixed("nextval(") && !is_prefixed("identity(") {
+        return None;
+    }
+    let tokens = tokenize_sql(default_expr).ok()?;
+    let ast = parse_expr(&tokens, Dialect::PostgreSQL).ok()?;
+    if let Some((start, step)) = identity_of_expr(&ast) {
+        return Some(SequenceDefault::Identity { start, step });
+    }
+    sequence_name_of_expr(&ast).map(SequenceDefault::Sequence)
+}
+
 pub fn parse_default_expr_to_string(
     ctx: Arc<dyn TableContext>,
     field: &TableField,
     ast: &AExpr,
     is_add_column: bool,
 ) -> Result<String> {
+    if let Some(sequence) = sequence_name_of_expr(ast) {
+        if sequence.starts_with(IDENTITY_SEQUENCE_PREFIX) {
+            return Err(ErrorCode::SemanticError(format!(
+                "sequence `{}` belongs to an identity column, it can't be referenced",
+                sequence
+            )));
+        }
+        if is_add_column {
+            return Err(ErrorCode::SemanticError(format!(
+                "default expression `{}` can't be used in an added column, the existing rows have no value of the sequence",
+                ast
+            )));
+        }
+        if !DataType::from(field.data_type())
+            .remove_nullable()
+            .is_integer()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "default expression `{}` requires an integer column, but column `{}` is {}",
+                ast,
+                field.name(),
+                field.data_type()
+            )));
+        }
+        return Ok(format!("nextval('{}')", sequence));
+    }
+
     let settings = Settings::create(NonEmptyString::new("dummy").unwrap());
     let mut bind_context = BindContext::new();
     let metadata = Metadata::default();
@@ -281,13 +429,7 @@ pub fn parse_default_expr_to_string(
     Ok(expr.sql_display())
 }
 
-pub fn parse_computed_expr_to_string(
-    ctx: Arc<dyn TableContext>,
-    table_schema: TableSchemaRef,
-    field: &TableField,
-    ast: &AExpr,
-) -> Result<String> {
-    let settings = Settings::create(NonEmptyString::new("dummy").unwrap());
+fn bind_table_schema(table_schema: &TableSchemaRef) -> (BindContext, Metadata) {
     let mut bind_context = BindContext::new();
     let mut metadata = Metadata::default();
     for (index, field) in table_schema.fields().iter().enumerate() {
@@ -310,6 +452,17 @@ pub fn parse_computed_expr_to_string(
             None,
         );
     }
+    (bind_context, metadata)
+}
+
+pub fn parse_computed_expr_to_string(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    field: &TableField,
+    ast: &AExpr,
+) -> Result<String> {
+    let settings = Settings::create(NonEmptyString::new("dummy").unwrap());
+    let (mut bind_context, metadata) = bind_table_schema(&table_schema);
 
     let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
     let mut type_checker = TypeChecker::try_create(
@@ -345,6 +498,63 @@ pub fn parse_computed_expr_to_string(
     Ok(format!("{:#}", ast))
 }
 
+/// Checks the expression of a CHECK constraint and returns it in normalized form.
+///
+/// The expression must be a deterministic boolean expression on the columns of the table,
+/// virtual computed columns are not stored and can't be referenced.
+pub fn parse_check_expr_to_string(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    ast: &AExpr,
+) -> Result<String> {
+    let settings = Settings::create(NonEmptyString::new("dummy").unwrap());
+    let (mut bind_context, metadata) = bind_table_schema(&table_schema);
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+        false,
+    )?;
+
+    let (scalar, data_type) = *databend_common_base::runtime::block_on(type_checker.resolve(ast))?;
+    if data_type.remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "expected check constraint expression have type Boolean, but `{}` has type {}.",
+            ast, data_type,
+        )));
+    }
+    let check_expr = scalar.as_expr()?;
+    // the virtual computed columns are not stored, so they are not in the written blocks.
+    if let Some(field) = check_expr
+        .column_refs()
+        .keys()
+        .map(|column| table_schema.field(column.index))
+        .find(|field| matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))))
+    {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint expression `{}` can not reference the virtual computed column `{}`.",
+            ast,
+            field.name(),
+        )));
+    }
+    if !check_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint expression `{}` is not deterministic.",
+            check_expr.sql_display(),
+        )));
+    }
+    let mut ast = ast.clone();
+    let mut normalizer = IdentifierNormalizer {
+        ctx: &name_resolution_ctx,
+    };
+    ast.drive_mut(&mut normalizer);
+    Ok(format!("{:#}", ast))
+}
+
 pub fn parse_lambda_expr(
     ctx: Arc<dyn TableContext>,
     columns: &[(String, DataType)],
@@ -403,6 +613,10 @@ pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Re
     let data_type = DataType::from(data_type);
 
     match field.default_expr() {
+        // Columns with sequence default can't be added to a table, so the blocks always contain them.
+        Some(default_expr) if parse_sequence_default_expr(default_expr).is_some() => {
+            Ok(Scalar::default_value(&data_type))
+        }
         Some(default_expr) => {
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
             let mut exprs = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
@@ -70,6 +70,9 @@ impl Plan {
             Plan::DropDatabase(_) => Ok("DropDatabase".to_string()),
             Plan::UndropDatabase(_) => Ok("UndropDatabase".to_string()),
             Plan::RenameDatabase(_) => Ok("RenameDatabase".to_string()),
+            Plan::RevertDatabase(_) => Ok("RevertDatabase".to_string()),
+            Plan::BackupDatabase(_) => Ok("BackupDatabase".to_string()),
+            Plan::RestoreDatabase(_) => Ok("RestoreDatabase".to_string()),
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
@@ -83,6 +86,8 @@ impl Plan {
             Plan::AddTableColumn(_) => Ok("AddTableColumn".to_string()),
             Plan::ModifyTableColumn(_) => Ok("ModifyTableColumn".to_string()),
             Plan::DropTableColumn(_) => Ok("DropTableColumn".to_string()),
+            Plan::AddTableRowAccessPolicy(_) => Ok("AddTableRowAccessPolicy".to_string()),
+            Plan::DropTableRowAccessPolicy(_) => Ok("DropTableRowAccessPolicy".to_string()),
             Plan::AlterTableClusterKey(_) => Ok("AlterTableClusterKey".to_string()),
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
             Plan::ReclusterTable(_) => Ok("ReclusterTable".to_string()),
@@ -98,6 +103,9 @@ impl Plan {
             Plan::CreateView(_) => Ok("CreateView".to_string()),
             Plan::AlterView(_) => Ok("AlterView".to_string()),
             Plan::DropView(_) => Ok("DropView".to_string()),
+            Plan::CreateMaterializedView(_) => Ok("CreateMaterializedView".to_string()),
+            Plan::DropMaterializedView(_) => Ok("DropMaterializedView".to_string()),
+            Plan::RefreshMaterializedView(_) => Ok("RefreshMaterializedView".to_string()),
 
             // Streams
             Plan::CreateStream(_) => Ok("CreateStream".to_string()),
@@ -189,6 +197,14 @@ impl Plan {
             Plan::AlterPasswordPolicy(_) => Ok("AlterPasswordPolicy".to_string()),
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
+            Plan::CreateSequence(_) => Ok("CreateSequence".to_string()),
+            Plan::DropSequence(_) => Ok("DropSequence".to_string()),
+            Plan::DescSequence(_) => Ok("DescSequence".to_string()),
+            Plan::CreateRowAccessPolicy(_) => Ok("CreateRowAccessPolicy".to_string()),
+            Plan::DropRowAccessPolicy(_) => Ok("DropRowAccessPolicy".to_string()),
+            Plan::DescRowAccessPolicy(_) => Ok("DescRowAccessPolicy".to_string()),
+            Plan::CreatePlanBaseline(_) => Ok("CreatePlanBaseline".to_string()),
+            Plan::DropPlanBaseline(_) => Ok("DropPlanBaseline".to_string()),
 
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
@@ -260,6 +276,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             change_type: None,
             statistics: Default::default(),
             update_stream_columns: false,
+            sample: None,
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
         let mut predicates = vec![];
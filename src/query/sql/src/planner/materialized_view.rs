@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_catalog::catalog::Catalog;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_storages_common_table_meta::table::parse_table_ids;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_IDS;
+
+/// A materialized view defined on a base table.
+pub struct MaterializedViewInfo {
+    pub table_id: u64,
+    pub database: String,
+    pub name: String,
+    pub options: BTreeMap<String, String>,
+}
+
+/// The materialized views on the base table, they are found by the ids kept in the options
+/// of the base table. The dropped views and the views replaced by other tables are skipped.
+#[async_backtrace::framed]
+pub async fn get_materialized_views(
+    catalog: &dyn Catalog,
+    base_table: &TableInfo,
+) -> Result<Vec<MaterializedViewInfo>> {
+    let view_ids = parse_table_ids(base_table.options(), OPT_KEY_MATERIALIZED_VIEW_IDS);
+    let base_table_id = base_table.ident.table_id.to_string();
+
+    let mut views = Vec::with_capacity(view_ids.len());
+    for table_id in view_ids {
+        let meta = match catalog.get_table_meta_by_id(table_id).await {
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+            Err(e) => return Err(e),
+            Ok((_, meta)) => meta,
+        };
+        if meta.drop_on.is_some()
+            || meta.options.get(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID) != Some(&base_table_id)
+        {
+            continue;
+        }
+        let Some(db_id) = meta
+            .options
+            .get(OPT_KEY_DATABASE_ID)
+            .and_then(|id| id.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        let database = catalog.get_db_name_by_id(db_id).await?;
+        let name = catalog.get_table_name_by_id(table_id).await?;
+        views.push(MaterializedViewInfo {
+            table_id,
+            database,
+            name,
+            options: meta.options.clone(),
+        });
+    }
+    Ok(views)
+}
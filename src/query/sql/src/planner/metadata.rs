@@ -30,6 +30,7 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use parking_lot::RwLock;
 
+use crate::optimizer::JoinHints;
 use crate::optimizer::SExpr;
 
 /// Planner use [`usize`] as it's index type.
@@ -67,6 +68,10 @@ pub struct Metadata {
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
     max_column_position: usize, // for CSV
+    /// The join hints of the query.
+    join_hints: JoinHints,
+    /// The names of the UDFs resolved by the query.
+    udfs: HashSet<String>,
 }
 
 impl Metadata {
@@ -288,6 +293,26 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn has_agg_indexes(&self) -> bool {
+        !self.agg_indexes.is_empty()
+    }
+
+    pub fn add_join_hints(&mut self, hints: JoinHints) {
+        self.join_hints.merge(hints);
+    }
+
+    pub fn join_hints(&self) -> &JoinHints {
+        &self.join_hints
+    }
+
+    pub fn add_udf(&mut self, name: String) {
+        self.udfs.insert(name);
+    }
+
+    pub fn udfs(&self) -> &HashSet<String> {
+        &self.udfs
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
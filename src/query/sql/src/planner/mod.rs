@@ -14,7 +14,10 @@
 
 mod bloom_index;
 mod format;
+mod materialized_view;
 mod metadata;
+mod plan_baseline;
+mod plan_cache;
 #[allow(clippy::module_inception)]
 mod planner;
 mod semantic;
@@ -38,7 +41,18 @@ pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
+pub use materialized_view::get_materialized_views;
+pub use materialized_view::MaterializedViewInfo;
 pub use metadata::*;
+pub use plan_baseline::apply_plan_baseline;
+pub use plan_baseline::capture_join_outline;
+pub use plan_baseline::PlanBaselineCache;
+pub use plan_baseline::PlanBaselineFingerprints;
+pub use plan_baseline::PlanBaselineQuery;
+pub use plan_baseline::PlanBaselineStats;
+pub use plan_cache::ParameterizedQuery;
+pub use plan_cache::PlanCache;
+pub use plan_cache::PlanCacheItem;
 pub use planner::PlanExtras;
 pub use planner::Planner;
 pub use plans::insert::InsertInputSource;
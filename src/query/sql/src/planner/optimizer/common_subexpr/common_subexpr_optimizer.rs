@@ -0,0 +1,339 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use parking_lot::RwLock;
+
+use super::fingerprint::Fingerprint;
+use super::fingerprint::Fingerprinter;
+use super::fingerprint::SubtreeFingerprint;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::CteScan;
+use crate::plans::MaterializedCte;
+use crate::plans::RelOperator;
+use crate::ColumnBinding;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::Visibility;
+
+struct Candidate {
+    // The pre-order id of the subtree root.
+    id: usize,
+    // The number of operators in the subtree.
+    size: usize,
+    fingerprint: Fingerprint,
+    columns: HashMap<IndexType, String>,
+    // The estimated rows processed by all the operators of the subtree.
+    cost: f64,
+    s_expr: SExpr,
+}
+
+struct SharedSubtree {
+    cte_idx: IndexType,
+    // The canonical names of the materialized columns, in the order of `left_output_columns`.
+    columns: Vec<String>,
+    left: SExpr,
+    left_output_columns: Vec<ColumnBinding>,
+}
+
+// The CommonSubexprOptimizer finds the subtrees that are computed more than once in a query,
+// for example: select a, count(*) from t where b > 1 group by a union all select a, count(*) from t where b > 1 group by a
+//
+//    UnionAll                                  MaterializedCte
+//    /      \                                  /             \
+//  Aggregate  Aggregate           =>       Aggregate        UnionAll
+//     |          |                             |            /      \
+//  Filter     Filter                        Filter      CteScan  CteScan
+//     |          |                             |
+//   Scan       Scan                          Scan
+//
+// Two subtrees are equivalent if they have the same fingerprint. If reading the materialized result
+// is estimated to be cheaper than computing the subtree again, the subtree is computed once by
+// a `MaterializedCte` and all the occurrences are replaced by `CteScan`.
+pub struct CommonSubexprOptimizer {
+    ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    fingerprinter: Fingerprinter,
+    max_rows: u64,
+    candidates: Vec<Candidate>,
+}
+
+impl CommonSubexprOptimizer {
+    pub fn new(ctx: Arc<dyn TableContext>, metadata: MetadataRef) -> Result<Self> {
+        let max_rows = ctx
+            .get_settings()
+            .get_common_subexpression_reuse_max_rows()?;
+        Ok(CommonSubexprOptimizer {
+            ctx,
+            fingerprinter: Fingerprinter::new(metadata.clone()),
+            metadata,
+            max_rows,
+            candidates: vec![],
+        })
+    }
+
+    pub fn run(mut self, s_expr: &SExpr) -> Result<SExpr> {
+        self.collect(s_expr, &mut 0)?;
+        let shared = self.choose_shared_subtrees()?;
+        if shared.is_empty() {
+            return Ok(s_expr.clone());
+        }
+
+        let mut replacements = HashMap::new();
+        for (i, (subtree, occurrences)) in shared.iter().enumerate() {
+            for (used_count, candidate) in occurrences.iter().enumerate() {
+                replacements.insert(candidate.id, (i, used_count + 1));
+            }
+            for used_count in 1..=occurrences.len() {
+                self.ctx.set_materialized_cte(
+                    (subtree.cte_idx, used_count),
+                    Arc::new(RwLock::new(vec![])),
+                )?;
+            }
+        }
+
+        let mut s_expr = self.replace(s_expr, &mut 0, &shared, &replacements)?;
+        for (subtree, _) in shared {
+            s_expr = SExpr::create_binary(
+                Arc::new(RelOperator::MaterializedCte(MaterializedCte {
+                    left_output_columns: subtree.left_output_columns,
+                    cte_idx: subtree.cte_idx,
+                })),
+                Arc::new(subtree.left),
+                Arc::new(s_expr),
+            );
+        }
+        Ok(s_expr)
+    }
+
+    // Collect the subtrees that can be shared, return the fingerprint, the size and the cost of `s_expr`.
+    fn collect(
+        &mut self,
+        s_expr: &SExpr,
+        next_id: &mut usize,
+    ) -> Result<(Option<SubtreeFingerprint>, usize, f64)> {
+        let id = *next_id;
+        *next_id += 1;
+
+        let mut children = Vec::with_capacity(s_expr.arity());
+        let mut size = 1;
+        let mut cost = match s_expr.plan() {
+            RelOperator::Scan(scan) => match scan
+                .statistics
+                .statistics
+                .as_ref()
+                .and_then(|statistics| statistics.num_rows)
+            {
+                Some(num_rows) => num_rows as f64,
+                None => {
+                    RelExpr::with_s_expr(s_expr)
+                        .derive_cardinality()?
+                        .cardinality
+                }
+            },
+            _ => {
+                RelExpr::with_s_expr(s_expr)
+                    .derive_cardinality()?
+                    .cardinality
+            }
+        };
+        for child in s_expr.children() {
+            let (child_fingerprint, child_size, child_cost) = self.collect(child, next_id)?;
+            children.push(child_fingerprint);
+            size += child_size;
+            cost += child_cost;
+        }
+
+        let Some(fingerprint) = self.fingerprinter.fingerprint(s_expr, children, size)? else {
+            return Ok((None, size, cost));
+        };
+        let rel_prop = RelExpr::with_s_expr(s_expr).derive_relational_prop()?;
+        if rel_prop.outer_columns.is_empty()
+            && rel_prop
+                .output_columns
+                .iter()
+                .all(|index| fingerprint.columns.contains_key(index))
+        {
+            self.candidates.push(Candidate {
+                id,
+                size,
+                fingerprint: fingerprint.fingerprint.clone(),
+                columns: fingerprint.columns.clone(),
+                cost,
+                s_expr: s_expr.clone(),
+            });
+        }
+        Ok((Some(fingerprint), size, cost))
+    }
+
+    fn choose_shared_subtrees(&mut self) -> Result<Vec<(SharedSubtree, Vec<Candidate>)>> {
+        let mut groups: Vec<Vec<Candidate>> = vec![];
+        let mut group_indexes: HashMap<Fingerprint, usize> = HashMap::new();
+        for candidate in std::mem::take(&mut self.candidates) {
+            match group_indexes.get(&candidate.fingerprint) {
+                Some(index) => groups[*index].push(candidate),
+                None => {
+                    group_indexes.insert(candidate.fingerprint.clone(), groups.len());
+                    groups.push(vec![candidate]);
+                }
+            }
+        }
+        // Prefer the largest subtrees, the smaller ones inside them are shared as a whole.
+        groups.retain(|group| group.len() > 1);
+        groups.sort_by_key(|group| (std::cmp::Reverse(group[0].size), group[0].id));
+
+        let mut next_cte_idx = self
+            .ctx
+            .get_materialized_ctes()
+            .read()
+            .keys()
+            .map(|(cte_idx, _)| cte_idx + 1)
+            .max()
+            .unwrap_or(0);
+        let mut covered: Vec<(usize, usize)> = vec![];
+        let mut shared = vec![];
+        for group in groups {
+            let occurrences = group
+                .into_iter()
+                .filter(|candidate| {
+                    !covered
+                        .iter()
+                        .any(|(start, end)| candidate.id >= *start && candidate.id < *end)
+                })
+                .collect::<Vec<_>>();
+            if occurrences.len() < 2 {
+                continue;
+            }
+
+            let first = &occurrences[0];
+            let cardinality = RelExpr::with_s_expr(&first.s_expr)
+                .derive_cardinality()?
+                .cardinality;
+            let n = occurrences.len() as f64;
+            // Computing the subtree n times costs `cost * n`, sharing it costs `cost` to compute
+            // once, plus `cardinality` to write the result and `cardinality * n` to read it back.
+            if cardinality > self.max_rows as f64
+                || first.cost * (n - 1.0) <= cardinality * (n + 1.0)
+            {
+                continue;
+            }
+
+            covered.extend(
+                occurrences
+                    .iter()
+                    .map(|candidate| (candidate.id, candidate.id + candidate.size)),
+            );
+
+            let output_columns = RelExpr::with_s_expr(&first.s_expr)
+                .derive_relational_prop()?
+                .output_columns
+                .clone();
+            let metadata = self.metadata.read();
+            let mut columns = Vec::with_capacity(output_columns.len());
+            let mut left_output_columns = Vec::with_capacity(output_columns.len());
+            for index in output_columns.iter() {
+                let column = metadata.column(*index);
+                columns.push(first.columns[index].clone());
+                left_output_columns.push(
+                    ColumnBindingBuilder::new(
+                        column.name(),
+                        *index,
+                        Box::new(column.data_type()),
+                        Visibility::Visible,
+                    )
+                    .build(),
+                );
+            }
+            shared.push((
+                SharedSubtree {
+                    cte_idx: next_cte_idx,
+                    columns,
+                    left: first.s_expr.clone(),
+                    left_output_columns,
+                },
+                occurrences,
+            ));
+            next_cte_idx += 1;
+        }
+        Ok(shared)
+    }
+
+    fn replace(
+        &self,
+        s_expr: &SExpr,
+        next_id: &mut usize,
+        shared: &[(SharedSubtree, Vec<Candidate>)],
+        replacements: &HashMap<usize, (usize, usize)>,
+    ) -> Result<SExpr> {
+        let id = *next_id;
+        *next_id += 1;
+        if let Some((i, used_count)) = replacements.get(&id) {
+            let (subtree, occurrences) = &shared[*i];
+            let occurrence = &occurrences[*used_count - 1];
+            *next_id += occurrence.size - 1;
+            return self.build_cte_scan(subtree, occurrence, *used_count);
+        }
+
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            children.push(Arc::new(self.replace(
+                child,
+                next_id,
+                shared,
+                replacements,
+            )?));
+        }
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn build_cte_scan(
+        &self,
+        subtree: &SharedSubtree,
+        occurrence: &Candidate,
+        used_count: usize,
+    ) -> Result<SExpr> {
+        let indexes = occurrence
+            .columns
+            .iter()
+            .map(|(index, name)| (name, *index))
+            .collect::<HashMap<_, _>>();
+        let metadata = self.metadata.read();
+        let mut fields = Vec::with_capacity(subtree.columns.len());
+        let mut offsets = Vec::with_capacity(subtree.columns.len());
+        for (offset, name) in subtree.columns.iter().enumerate() {
+            let index = indexes[name];
+            fields.push(DataField::new(
+                index.to_string().as_str(),
+                metadata.column(index).data_type(),
+            ));
+            offsets.push(offset);
+        }
+        Ok(SExpr::create_leaf(Arc::new(
+            CteScan {
+                cte_idx: (subtree.cte_idx, used_count),
+                fields,
+                offsets,
+                stat: RelExpr::with_s_expr(&occurrence.s_expr).derive_cardinality()?,
+            }
+            .into(),
+        )))
+    }
+}
@@ -0,0 +1,389 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::Result;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateFunction;
+use crate::plans::BoundColumnRef;
+use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::SubqueryExpr;
+use crate::plans::UDFCall;
+use crate::plans::UDFLambdaCall;
+use crate::plans::VisitorMut;
+use crate::plans::WindowFunc;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::Visibility;
+
+/// The fingerprint of a subtree. Like the group of a memo, subtrees with the same fingerprint
+/// produce the same rows, but the column indexes are replaced by canonical names, so that
+/// the subtrees bound from the same SQL text in different places of a query are equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    plan: String,
+    scalars: Vec<ScalarExpr>,
+}
+
+pub struct SubtreeFingerprint {
+    pub fingerprint: Fingerprint,
+    /// The canonical names of the columns produced in the subtree.
+    pub columns: HashMap<IndexType, String>,
+}
+
+/// Computes the [`Fingerprint`] of the subtrees bottom up.
+///
+/// The shared subtrees are not found with the groups of the cascades memo: the memo is built
+/// after this pass, and a shared subtree must become a `MaterializedCte` before cascades so that
+/// the join order and the distribution are explored around the `CteScan`s. Besides, two subtrees
+/// bound from the same SQL text get different column indexes, so they never land in the same
+/// memo group; the fingerprint renames the columns canonically to match them.
+pub struct Fingerprinter {
+    metadata: MetadataRef,
+}
+
+impl Fingerprinter {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self { metadata }
+    }
+
+    /// Get the fingerprint of `s_expr` from the fingerprints of its children, `size` is the
+    /// number of operators in the subtree. Return None if the subtree can't be shared.
+    pub fn fingerprint(
+        &self,
+        s_expr: &SExpr,
+        children: Vec<Option<SubtreeFingerprint>>,
+        size: usize,
+    ) -> Result<Option<SubtreeFingerprint>> {
+        let Some(children) = children.into_iter().collect::<Option<Vec<_>>>() else {
+            return Ok(None);
+        };
+        let mut children = children.into_iter();
+
+        let mut plan = String::new();
+        let mut scalars = Vec::new();
+        let mut columns = HashMap::new();
+        match s_expr.plan.as_ref() {
+            RelOperator::Scan(scan) => {
+                // Sampled scans are random, sharing them changes the result.
+                if scan.sample.is_some() || scan.agg_index.is_some() {
+                    return Ok(None);
+                }
+                let metadata = self.metadata.read();
+                let table_entry = metadata.table(scan.table_index);
+                let table = table_entry.table();
+                if table.engine() != "FUSE" {
+                    return Ok(None);
+                }
+                for index in scan.columns.iter() {
+                    columns.insert(*index, format!("s.{}", metadata.column(*index).name()));
+                }
+                let mut names = columns.values().cloned().collect::<Vec<_>>();
+                names.sort();
+                plan.push_str(&format!(
+                    "Scan({}.{}.{}, {:?}, columns: {:?}",
+                    table_entry.catalog(),
+                    table_entry.database(),
+                    table_entry.name(),
+                    table.get_table_info().ident,
+                    names
+                ));
+
+                if let Some(predicates) = &scan.push_down_predicates {
+                    plan.push_str(&format!(", filters: {}", predicates.len()));
+                    for predicate in predicates {
+                        let Some(scalar) = canonicalize(predicate, &columns)? else {
+                            return Ok(None);
+                        };
+                        scalars.push(scalar);
+                    }
+                }
+                if let Some(prewhere) = &scan.prewhere {
+                    plan.push_str(&format!(
+                        ", prewhere: ({:?}, {:?}, {})",
+                        canonical_names(prewhere.output_columns.iter(), &columns),
+                        canonical_names(prewhere.prewhere_columns.iter(), &columns),
+                        prewhere.predicates.len()
+                    ));
+                    for predicate in prewhere.predicates.iter() {
+                        let Some(scalar) = canonicalize(predicate, &columns)? else {
+                            return Ok(None);
+                        };
+                        scalars.push(scalar);
+                    }
+                }
+                if let Some(order_by) = &scan.order_by {
+                    plan.push_str(", order_by: [");
+                    for item in order_by {
+                        let Some(name) = columns.get(&item.index) else {
+                            return Ok(None);
+                        };
+                        plan.push_str(&format!("({}, {}, {}), ", name, item.asc, item.nulls_first));
+                    }
+                    plan.push(']');
+                }
+                plan.push_str(&format!(
+                    ", limit: {:?}, change_type: {:?}, update_stream_columns: {})",
+                    scan.limit, scan.change_type, scan.update_stream_columns
+                ));
+            }
+            RelOperator::Filter(filter) => {
+                let child = children.next().unwrap();
+                columns = child.columns;
+                for predicate in filter.predicates.iter() {
+                    let Some(scalar) = canonicalize(predicate, &columns)? else {
+                        return Ok(None);
+                    };
+                    scalars.push(scalar);
+                }
+                plan.push_str(&format!("Filter({})", filter.predicates.len()));
+                plan.push_str(&child.fingerprint.plan);
+                scalars.extend(child.fingerprint.scalars);
+            }
+            RelOperator::EvalScalar(eval_scalar) => {
+                let child = children.next().unwrap();
+                columns = child.columns;
+                for item in eval_scalar.items.iter() {
+                    let Some(scalar) = canonicalize(&item.scalar, &columns)? else {
+                        return Ok(None);
+                    };
+                    scalars.push(scalar);
+                }
+                for (i, item) in eval_scalar.items.iter().enumerate() {
+                    columns.insert(item.index, format!("#{size}.{i}"));
+                }
+                plan.push_str(&format!("EvalScalar({})", eval_scalar.items.len()));
+                plan.push_str(&child.fingerprint.plan);
+                scalars.extend(child.fingerprint.scalars);
+            }
+            RelOperator::Aggregate(aggregate) => {
+                if aggregate.grouping_sets.is_some() {
+                    return Ok(None);
+                }
+                let child = children.next().unwrap();
+                columns = child.columns;
+                for item in aggregate.group_items.iter() {
+                    let Some(scalar) = canonicalize(&item.scalar, &columns)? else {
+                        return Ok(None);
+                    };
+                    scalars.push(scalar);
+                }
+                for item in aggregate.aggregate_functions.iter() {
+                    let Some(scalar) = canonicalize(&item.scalar, &columns)? else {
+                        return Ok(None);
+                    };
+                    scalars.push(scalar);
+                }
+                for (i, item) in aggregate.group_items.iter().enumerate() {
+                    columns.insert(item.index, format!("#{size}.g{i}"));
+                }
+                for (i, item) in aggregate.aggregate_functions.iter().enumerate() {
+                    columns.insert(item.index, format!("#{size}.a{i}"));
+                }
+                plan.push_str(&format!(
+                    "Aggregate({:?}, {}, {}, {}, {:?})",
+                    aggregate.mode,
+                    aggregate.group_items.len(),
+                    aggregate.aggregate_functions.len(),
+                    aggregate.from_distinct,
+                    aggregate.limit
+                ));
+                plan.push_str(&child.fingerprint.plan);
+                scalars.extend(child.fingerprint.scalars);
+            }
+            RelOperator::Join(join) => {
+                let left = children.next().unwrap();
+                let right = children.next().unwrap();
+                // Prefix the columns of children, so that their names don't conflict.
+                for (index, name) in left.columns {
+                    columns.insert(index, format!("l.{name}"));
+                }
+                for (index, name) in right.columns {
+                    columns.insert(index, format!("r.{name}"));
+                }
+                for condition in join
+                    .left_conditions
+                    .iter()
+                    .chain(join.right_conditions.iter())
+                    .chain(join.non_equi_conditions.iter())
+                {
+                    let Some(scalar) = canonicalize(condition, &columns)? else {
+                        return Ok(None);
+                    };
+                    scalars.push(scalar);
+                }
+                if let Some(marker_index) = join.marker_index {
+                    columns.insert(marker_index, format!("#{size}.m"));
+                }
+                plan.push_str(&format!(
+                    "Join({:?}, {}, {}, {:?}, {}, {}, {}, {:?}, {:?})[",
+                    join.join_type,
+                    join.left_conditions.len(),
+                    join.non_equi_conditions.len(),
+                    join.marker_index.is_some(),
+                    join.from_correlated_subquery,
+                    join.need_hold_hash_table,
+                    join.is_lateral,
+                    join.original_join_type,
+                    join.hint
+                ));
+                plan.push_str(&left.fingerprint.plan);
+                plan.push_str(", ");
+                plan.push_str(&right.fingerprint.plan);
+                plan.push(']');
+                scalars.extend(left.fingerprint.scalars);
+                scalars.extend(right.fingerprint.scalars);
+            }
+            RelOperator::Sort(sort) => {
+                let child = children.next().unwrap();
+                columns = child.columns;
+                plan.push_str("Sort([");
+                for item in sort.items.iter() {
+                    let Some(name) = columns.get(&item.index) else {
+                        return Ok(None);
+                    };
+                    plan.push_str(&format!("({}, {}, {}), ", name, item.asc, item.nulls_first));
+                }
+                plan.push_str(&format!("], {:?}, {:?}", sort.limit, sort.after_exchange));
+                if let Some(pre_projection) = &sort.pre_projection {
+                    plan.push_str(&format!(
+                        ", {:?}",
+                        canonical_names(pre_projection.iter(), &columns)
+                    ));
+                }
+                plan.push(')');
+                plan.push_str(&child.fingerprint.plan);
+                scalars.extend(child.fingerprint.scalars);
+            }
+            RelOperator::Limit(limit) => {
+                let child = children.next().unwrap();
+                columns = child.columns;
+                plan.push_str(&format!(
+                    "Limit({:?}, {}, {})",
+                    limit.limit, limit.offset, limit.before_exchange
+                ));
+                plan.push_str(&child.fingerprint.plan);
+                scalars.extend(child.fingerprint.scalars);
+            }
+            _ => return Ok(None),
+        }
+
+        Ok(Some(SubtreeFingerprint {
+            fingerprint: Fingerprint { plan, scalars },
+            columns,
+        }))
+    }
+}
+
+fn canonical_names<'a>(
+    indexes: impl Iterator<Item = &'a IndexType>,
+    columns: &HashMap<IndexType, String>,
+) -> Vec<String> {
+    let mut names = indexes
+        .map(|index| columns.get(index).cloned().unwrap_or_default())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Replace the columns of `scalar` with their canonical names. The scalar is marked as
+/// unsupported (None) if it refers to unknown columns or its result is not deterministic.
+fn canonicalize(
+    scalar: &ScalarExpr,
+    columns: &HashMap<IndexType, String>,
+) -> Result<Option<ScalarExpr>> {
+    struct Canonicalizer<'a> {
+        columns: &'a HashMap<IndexType, String>,
+        supported: bool,
+    }
+
+    impl<'a> VisitorMut<'a> for Canonicalizer<'_> {
+        fn visit_bound_column_ref(&mut self, col: &'a mut BoundColumnRef) -> Result<()> {
+            match self.columns.get(&col.column.index) {
+                Some(name) => {
+                    col.column = ColumnBindingBuilder::new(
+                        name.clone(),
+                        0,
+                        col.column.data_type.clone(),
+                        Visibility::Visible,
+                    )
+                    .build();
+                }
+                None => self.supported = false,
+            }
+            Ok(())
+        }
+
+        fn visit_aggregate_function(&mut self, aggregate: &'a mut AggregateFunction) -> Result<()> {
+            aggregate.display_name.clear();
+            for expr in &mut aggregate.args {
+                self.visit(expr)?;
+            }
+            Ok(())
+        }
+
+        fn visit_function_call(&mut self, func: &'a mut FunctionCall) -> Result<()> {
+            if BUILTIN_FUNCTIONS
+                .get_property(&func.func_name)
+                .map_or(true, |property| property.non_deterministic)
+            {
+                self.supported = false;
+            }
+            for expr in &mut func.arguments {
+                self.visit(expr)?;
+            }
+            Ok(())
+        }
+
+        fn visit_window_function(&mut self, _: &'a mut WindowFunc) -> Result<()> {
+            self.supported = false;
+            Ok(())
+        }
+
+        fn visit_lambda_function(&mut self, _: &'a mut LambdaFunc) -> Result<()> {
+            self.supported = false;
+            Ok(())
+        }
+
+        fn visit_subquery_expr(&mut self, _: &'a mut SubqueryExpr) -> Result<()> {
+            self.supported = false;
+            Ok(())
+        }
+
+        fn visit_udf_call(&mut self, _: &'a mut UDFCall) -> Result<()> {
+            self.supported = false;
+            Ok(())
+        }
+
+        fn visit_udf_lambda_call(&mut self, _: &'a mut UDFLambdaCall) -> Result<()> {
+            self.supported = false;
+            Ok(())
+        }
+    }
+
+    let mut scalar = scalar.clone();
+    let mut canonicalizer = Canonicalizer {
+        columns,
+        supported: true,
+    };
+    canonicalizer.visit(&mut scalar)?;
+    Ok(canonicalizer.supported.then_some(scalar))
+}
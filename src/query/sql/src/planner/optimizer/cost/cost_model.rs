@@ -27,6 +27,7 @@ use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
+use crate::plans::Window;
 
 #[derive(Default)]
 pub struct DefaultCostModel {
@@ -85,10 +86,10 @@ impl DefaultCostModel {
             RelOperator::UnionAll(_) => self.compute_cost_union_all(memo, m_expr),
             RelOperator::Aggregate(_) => self.compute_aggregate(memo, m_expr),
             RelOperator::MaterializedCte(_) => self.compute_materialized_cte(memo, m_expr),
+            RelOperator::Window(plan) => self.compute_cost_window(memo, m_expr, plan),
 
             RelOperator::EvalScalar(_)
             | RelOperator::Filter(_)
-            | RelOperator::Window(_)
             | RelOperator::Sort(_)
             | RelOperator::ProjectSet(_)
             | RelOperator::Udf(_)
@@ -141,6 +142,24 @@ impl DefaultCostModel {
         Ok(Cost(cost))
     }
 
+    /// The window sorts its input by the partition and order keys, unless the
+    /// sort is shared with the window below.
+    fn compute_cost_window(&self, memo: &Memo, m_expr: &MExpr, plan: &Window) -> Result<Cost> {
+        let group = m_expr.child_group(memo, 0)?;
+        let card = group.stat_info.cardinality;
+        let mut cost = card * self.compute_per_row;
+        let num_keys = plan.partition_by.len() + plan.order_by.len();
+        if !plan.presorted && num_keys > 0 {
+            cost += self.compute_cost_sort(card, num_keys);
+        }
+        Ok(Cost(cost))
+    }
+
+    /// Compute cost for sorting the rows by `num_keys` keys.
+    pub fn compute_cost_sort(&self, cardinality: f64, num_keys: usize) -> f64 {
+        cardinality * cardinality.max(2.0).log2() * num_keys as f64 * self.compute_per_row
+    }
+
     fn compute_cost_union_all(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
         let left_group = m_expr.child_group(memo, 0)?;
         let right_group = m_expr.child_group(memo, 1)?;
@@ -201,6 +201,7 @@ impl SubqueryRewriter {
             need_hold_hash_table: false,
             is_lateral: false,
             original_join_type: None,
+            hint: Default::default(),
         };
 
         // Rewrite plan to semi-join.
@@ -291,6 +292,7 @@ impl SubqueryRewriter {
                     need_hold_hash_table: false,
                     is_lateral: false,
                     original_join_type: None,
+                    hint: Default::default(),
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -340,6 +342,7 @@ impl SubqueryRewriter {
                     need_hold_hash_table: false,
                     is_lateral: false,
                     original_join_type: None,
+                    hint: Default::default(),
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -404,6 +407,7 @@ impl SubqueryRewriter {
                     need_hold_hash_table: false,
                     is_lateral: false,
                     original_join_type: None,
+                    hint: Default::default(),
                 }
                 .into();
                 Ok((
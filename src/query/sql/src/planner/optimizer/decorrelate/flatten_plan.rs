@@ -137,6 +137,7 @@ impl SubqueryRewriter {
                 need_hold_hash_table: false,
                 is_lateral: false,
                 original_join_type: None,
+                hint: Default::default(),
             }
             .into();
 
@@ -470,6 +471,7 @@ impl SubqueryRewriter {
                     need_hold_hash_table: false,
                     is_lateral: false,
                     original_join_type: None,
+                    hint: Default::default(),
                 }
                 .into(),
             ),
@@ -693,6 +695,7 @@ impl SubqueryRewriter {
                     order_by: op.order_by.clone(),
                     frame: op.frame.clone(),
                     limit: op.limit,
+                    presorted: op.presorted,
                 }
                 .into(),
             ),
@@ -484,6 +484,7 @@ impl SubqueryRewriter {
                     need_hold_hash_table: false,
                     is_lateral: false,
                     original_join_type: None,
+                    hint: Default::default(),
                 }
                 .into();
                 Ok((
@@ -554,6 +555,7 @@ impl SubqueryRewriter {
                     need_hold_hash_table: false,
                     is_lateral: false,
                     original_join_type: None,
+                    hint: Default::default(),
                 }
                 .into();
                 let s_expr = SExpr::create_binary(
@@ -585,6 +587,7 @@ impl SubqueryRewriter {
             need_hold_hash_table: false,
             is_lateral: false,
             original_join_type: None,
+            hint: Default::default(),
         }
         .into();
 
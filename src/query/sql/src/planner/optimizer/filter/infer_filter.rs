@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 
 use databend_common_exception::Result;
+use databend_common_exception::Span;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
@@ -90,6 +91,7 @@ impl InferFilterOptimizer {
                         (true, false) => {
                             if let ScalarExpr::ConstantExpr(constant) = &func.arguments[1] {
                                 let (is_adjusted, constant) = adjust_scalar(
+                                    constant.span,
                                     constant.value.clone(),
                                     func.arguments[0].data_type()?,
                                 );
@@ -108,6 +110,7 @@ impl InferFilterOptimizer {
                         (false, true) => {
                             if let ScalarExpr::ConstantExpr(constant) = &func.arguments[0] {
                                 let (is_adjusted, constant) = adjust_scalar(
+                                    constant.span,
                                     constant.value.clone(),
                                     func.arguments[1].data_type()?,
                                 );
@@ -457,14 +460,14 @@ enum MergeResult {
     None,
 }
 
-pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr) {
+pub fn adjust_scalar(span: Span, scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr) {
     match data_type {
         DataType::Number(NumberDataType::UInt8)
         | DataType::Nullable(box DataType::Number(NumberDataType::UInt8)) => {
             let (ok, v) = check_uint_range(u8::MAX as u64, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::UInt8(v as u8)),
                 });
             }
@@ -474,7 +477,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_uint_range(u16::MAX as u64, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::UInt16(v as u16)),
                 });
             }
@@ -484,7 +487,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_uint_range(u32::MAX as u64, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::UInt32(v as u32)),
                 });
             }
@@ -494,7 +497,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_uint_range(u64::MAX, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::UInt64(v)),
                 });
             }
@@ -504,7 +507,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_int_range(i8::MIN as i64, i8::MAX as i64, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::Int8(v as i8)),
                 });
             }
@@ -514,7 +517,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_int_range(i16::MIN as i64, i16::MAX as i64, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::Int16(v as i16)),
                 });
             }
@@ -524,7 +527,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_int_range(i32::MIN as i64, i32::MAX as i64, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::Int32(v as i32)),
                 });
             }
@@ -534,7 +537,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_int_range(i64::MIN, i64::MAX, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::Int64(v)),
                 });
             }
@@ -544,7 +547,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_float_range(f32::MIN as f64, f32::MAX as f64, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::Float32(OrderedFloat(v as f32))),
                 });
             }
@@ -554,7 +557,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
             let (ok, v) = check_float_range(f64::MIN, f64::MAX, &scalar);
             if ok {
                 return (true, ConstantExpr {
-                    span: None,
+                    span,
                     value: Scalar::Number(NumberScalar::Float64(OrderedFloat(v))),
                 });
             }
@@ -562,7 +565,7 @@ pub fn adjust_scalar(scalar: Scalar, data_type: DataType) -> (bool, ConstantExpr
         _ => (),
     }
     (false, ConstantExpr {
-        span: None,
+        span,
         value: scalar,
     })
 }
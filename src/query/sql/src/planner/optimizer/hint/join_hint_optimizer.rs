@@ -0,0 +1,310 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use log::warn;
+
+use crate::optimizer::hint::JoinHints;
+use crate::optimizer::hint::JoinTree;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinDistribution;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::MetadataRef;
+
+/// Apply the join hints of query to the join operators. A table hint is applied
+/// to the join whose one side is exactly the table, a join of plan baseline is
+/// applied to the join with the same tables on each side. The hints that can't
+/// be applied are reported as warnings of the query.
+pub struct JoinHintOptimizer {
+    ctx: Arc<dyn TableContext>,
+    metadata: MetadataRef,
+    hints: JoinHints,
+    // The distribution hints only make sense if the query is executed in cluster.
+    distributed: bool,
+    // The (hint name, table index) pairs that have been applied.
+    applied: HashSet<(&'static str, IndexType)>,
+    // The tables of each join in the plan.
+    join_tables: Vec<HashSet<IndexType>>,
+    // The joins of plan baseline, and whether they have been applied.
+    baseline_joins: Vec<(BaselineJoin, bool)>,
+    // Whether an applied hint fixes the join order, so that it is kept by cascades optimizer.
+    fixed_join_order: bool,
+}
+
+struct BaselineJoin {
+    probe: HashSet<IndexType>,
+    build: HashSet<IndexType>,
+    distribution: Option<JoinDistribution>,
+    hash_join: bool,
+}
+
+impl JoinHintOptimizer {
+    pub fn new(
+        ctx: Arc<dyn TableContext>,
+        metadata: MetadataRef,
+        hints: JoinHints,
+        distributed: bool,
+    ) -> Self {
+        let mut baseline_joins = vec![];
+        for tree in hints.join_trees.iter() {
+            collect_baseline_joins(tree, &mut baseline_joins);
+        }
+        Self {
+            ctx,
+            metadata,
+            hints,
+            distributed,
+            applied: HashSet::new(),
+            join_tables: vec![],
+            baseline_joins: baseline_joins
+                .into_iter()
+                .map(|join| (join, false))
+                .collect(),
+            fixed_join_order: false,
+        }
+    }
+
+    /// Returns the new expression, and whether the join order is fixed by the `LEADING` and
+    /// `BROADCAST` hints or the joins of plan baseline applied to it.
+    pub fn run(mut self, s_expr: &SExpr) -> Result<(SExpr, bool)> {
+        let (s_expr, _) = self.apply(s_expr)?;
+
+        // The `LEADING` hint is applied by join reorder, check that every prefix
+        // of the tables is joined before the other tables.
+        for leading in self.hints.leading.iter() {
+            let applied = (2..=leading.len()).all(|len| {
+                let prefix = leading[..len].iter().cloned().collect::<HashSet<_>>();
+                self.join_tables.contains(&prefix)
+            });
+            if applied {
+                self.fixed_join_order = true;
+            } else {
+                self.warn("LEADING", leading, "the join order can't be changed");
+            }
+        }
+
+        for (name, tables) in [
+            ("HASH_JOIN", &self.hints.hash_join),
+            ("BROADCAST", &self.hints.broadcast),
+            ("SHUFFLE", &self.hints.shuffle),
+        ] {
+            let reason = if !self.distributed && name != "HASH_JOIN" {
+                "the query is not executed in cluster"
+            } else {
+                "no join with the table supports it"
+            };
+            for table in tables.iter() {
+                if !self.applied.contains(&(name, *table)) {
+                    self.warn(name, &[*table], reason);
+                }
+            }
+        }
+
+        for (join, applied) in self.baseline_joins.iter() {
+            if !applied {
+                let msg = format!(
+                    "Plan baseline join of ({}) and ({}) is ignored, because the join is not found in the plan",
+                    self.table_names(&join.probe),
+                    self.table_names(&join.build)
+                );
+                warn!("{}", msg);
+                self.ctx.push_warning(msg);
+            }
+        }
+
+        Ok((s_expr, self.fixed_join_order))
+    }
+
+    // Apply the hints bottom-up, return the new expression and the tables of it.
+    fn apply(&mut self, s_expr: &SExpr) -> Result<(SExpr, HashSet<IndexType>)> {
+        let mut children = Vec::with_capacity(s_expr.arity());
+        let mut children_tables = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let (child, tables) = self.apply(child)?;
+            children.push(Arc::new(child));
+            children_tables.push(tables);
+        }
+        let mut tables: HashSet<IndexType> = children_tables.iter().flatten().cloned().collect();
+
+        let s_expr = match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                tables.insert(scan.table_index);
+                s_expr.clone()
+            }
+            RelOperator::Join(join) => {
+                self.join_tables.push(tables.clone());
+                self.apply_join(join.clone(), children, children_tables)
+            }
+            _ => s_expr.replace_children(children),
+        };
+        Ok((s_expr, tables))
+    }
+
+    fn apply_join(
+        &mut self,
+        mut join: Join,
+        mut children: Vec<Arc<SExpr>>,
+        mut children_tables: Vec<HashSet<IndexType>>,
+    ) -> SExpr {
+        // Non-equi join is always executed in single node.
+        let non_equi = join.left_conditions.is_empty() && !join.non_equi_conditions.is_empty();
+        let can_distribute = self.distributed && !join.need_hold_hash_table;
+
+        // The joins of plan baseline keep the build side, other hints may override the distribution.
+        if let Some((baseline, applied)) = self.baseline_joins.iter_mut().find(|(baseline, _)| {
+            (baseline.probe == children_tables[0] && baseline.build == children_tables[1])
+                || (baseline.probe == children_tables[1] && baseline.build == children_tables[0])
+        }) {
+            if baseline.probe == children_tables[1] {
+                commute(&mut join, &mut children);
+                children_tables.swap(0, 1);
+            }
+            join.hint.keep_sides = true;
+            join.hint.hash_join |= baseline.hash_join;
+            if can_distribute {
+                match baseline.distribution {
+                    Some(JoinDistribution::Broadcast)
+                        if join.join_type.can_broadcast() && !non_equi =>
+                    {
+                        join.hint.distribution = Some(JoinDistribution::Broadcast);
+                    }
+                    Some(JoinDistribution::Shuffle) if !join.left_conditions.is_empty() => {
+                        join.hint.distribution = Some(JoinDistribution::Shuffle);
+                    }
+                    _ => {}
+                }
+            }
+            *applied = true;
+            self.fixed_join_order = true;
+        }
+
+        let single_table = |tables: &HashSet<IndexType>| {
+            (tables.len() == 1).then(|| *tables.iter().next().unwrap())
+        };
+        let left_table = single_table(&children_tables[0]);
+        let right_table = single_table(&children_tables[1]);
+        // Prefer the table of build side if both sides are hinted.
+        let hinted = |hints: &[IndexType]| {
+            [right_table, left_table]
+                .into_iter()
+                .flatten()
+                .find(|table| hints.contains(table))
+        };
+
+        if self.hints.no_merge {
+            join.hint.hash_join = true;
+        }
+        if let Some(table) = hinted(&self.hints.hash_join) {
+            join.hint.hash_join = true;
+            self.applied.insert(("HASH_JOIN", table));
+        }
+
+        if !can_distribute {
+            return SExpr::create_binary(
+                Arc::new(join.into()),
+                children[0].clone(),
+                children[1].clone(),
+            );
+        }
+
+        if let Some(table) = hinted(&self.hints.broadcast) {
+            // The broadcast table must be the build side.
+            let need_commute = left_table == Some(table);
+            let join_type = if need_commute {
+                join.join_type.opposite()
+            } else {
+                join.join_type.clone()
+            };
+            if join_type.can_broadcast() && !non_equi {
+                if need_commute {
+                    commute(&mut join, &mut children);
+                }
+                join.hint.distribution = Some(JoinDistribution::Broadcast);
+                join.hint.keep_sides = true;
+                self.applied.insert(("BROADCAST", table));
+                self.fixed_join_order = true;
+            }
+        } else if let Some(table) = hinted(&self.hints.shuffle) {
+            if !join.left_conditions.is_empty() {
+                join.hint.distribution = Some(JoinDistribution::Shuffle);
+                self.applied.insert(("SHUFFLE", table));
+            }
+        }
+
+        SExpr::create_binary(
+            Arc::new(join.into()),
+            children[0].clone(),
+            children[1].clone(),
+        )
+    }
+
+    fn warn(&self, name: &str, tables: &[IndexType], reason: &str) {
+        let msg = format!(
+            "Join hint {}({}) is ignored, because {}",
+            name,
+            self.table_names(tables),
+            reason
+        );
+        warn!("{}", msg);
+        self.ctx.push_warning(msg);
+    }
+
+    fn table_names<'a>(&self, tables: impl IntoIterator<Item = &'a IndexType>) -> String {
+        let metadata = self.metadata.read();
+        let mut names = tables
+            .into_iter()
+            .map(|index| {
+                let table = metadata.table(*index);
+                table
+                    .alias_name()
+                    .clone()
+                    .unwrap_or_else(|| table.name().to_string())
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+        names.join(" ")
+    }
+}
+
+// Swap the children of join, the build side becomes the probe side.
+fn commute(join: &mut Join, children: &mut [Arc<SExpr>]) {
+    std::mem::swap(&mut join.left_conditions, &mut join.right_conditions);
+    join.join_type = join.join_type.opposite();
+    children.swap(0, 1);
+}
+
+fn collect_baseline_joins(tree: &JoinTree, joins: &mut Vec<BaselineJoin>) {
+    if let JoinTree::Join {
+        probe,
+        build,
+        distribution,
+        hash_join,
+    } = tree
+    {
+        collect_baseline_joins(probe, joins);
+        collect_baseline_joins(build, joins);
+        joins.push(BaselineJoin {
+            probe: probe.tables(),
+            build: build.tables(),
+            distribution: distribution.clone(),
+            hash_join: *hash_join,
+        });
+    }
+}
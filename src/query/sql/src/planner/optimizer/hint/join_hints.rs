@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use crate::plans::JoinDistribution;
+use crate::IndexType;
+
+/// The join hints of a query, the tables in hints are resolved to table indexes by binder.
+#[derive(Clone, Debug, Default)]
+pub struct JoinHints {
+    /// `LEADING(t1 t2 ...)`: join the tables first, in the given order.
+    pub leading: Vec<Vec<IndexType>>,
+    /// `BROADCAST(t ...)`: broadcast the table to the join with it.
+    pub broadcast: Vec<IndexType>,
+    /// `SHUFFLE(t ...)`: shuffle both sides of the join with the table by the join keys.
+    pub shuffle: Vec<IndexType>,
+    /// `HASH_JOIN(t ...)`: use hash join for the join with the table.
+    pub hash_join: Vec<IndexType>,
+    /// `NO_MERGE`: never use merge join in the query.
+    pub no_merge: bool,
+    /// The join trees of plan baseline, the joins are built in the same shape.
+    pub join_trees: Vec<JoinTree>,
+}
+
+/// A join tree captured by plan baseline.
+#[derive(Clone, Debug)]
+pub enum JoinTree {
+    /// A relation of join reorder, it is joined as a whole.
+    Relation(Vec<IndexType>),
+    Join {
+        probe: Box<JoinTree>,
+        build: Box<JoinTree>,
+        distribution: Option<JoinDistribution>,
+        hash_join: bool,
+    },
+}
+
+impl JoinTree {
+    pub fn tables(&self) -> HashSet<IndexType> {
+        match self {
+            JoinTree::Relation(tables) => tables.iter().cloned().collect(),
+            JoinTree::Join { probe, build, .. } => {
+                let mut tables = probe.tables();
+                tables.extend(build.tables());
+                tables
+            }
+        }
+    }
+
+    /// The relations of the tree from left to right.
+    pub fn relations(&self) -> Vec<&[IndexType]> {
+        match self {
+            JoinTree::Relation(tables) => vec![tables.as_slice()],
+            JoinTree::Join { probe, build, .. } => {
+                let mut relations = probe.relations();
+                relations.extend(build.relations());
+                relations
+            }
+        }
+    }
+}
+
+impl JoinHints {
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty()
+            && self.broadcast.is_empty()
+            && self.shuffle.is_empty()
+            && self.hash_join.is_empty()
+            && !self.no_merge
+            && self.join_trees.is_empty()
+    }
+
+    pub fn merge(&mut self, other: JoinHints) {
+        self.leading.extend(other.leading);
+        self.broadcast.extend(other.broadcast);
+        self.shuffle.extend(other.shuffle);
+        self.hash_join.extend(other.hash_join);
+        self.no_merge |= other.no_merge;
+        self.join_trees.extend(other.join_trees);
+    }
+}
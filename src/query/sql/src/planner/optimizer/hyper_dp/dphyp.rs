@@ -27,6 +27,7 @@ use crate::optimizer::hyper_dp::query_graph::QueryGraph;
 use crate::optimizer::hyper_dp::util::intersect;
 use crate::optimizer::hyper_dp::util::union;
 use crate::optimizer::rule::TransformResult;
+use crate::optimizer::JoinTree;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -359,16 +360,117 @@ impl DPhpy {
             self.dp_table.insert(nodes, join);
         }
 
+        // If the query has a plan baseline, join the relations in the shape of it.
+        if let Some(tree) = self.baseline_join_tree() {
+            self.join_reorder_by_tree(&tree)?;
+            return Ok(());
+        }
+
+        // If the query has `LEADING` hint, join the leading relations first in the given order.
+        if let Some(leading) = self.leading_relations() {
+            return self.join_reorder_by_leading(&leading);
+        }
+
         // First, try to use dynamic programming to find the optimal join order.
         if !self.join_reorder_by_dphyp()? {
             // When DPhpy takes too much time during join ordering, it is necessary to exit the dynamic programming algorithm
             // and switch to a greedy algorithm to minimizes the overall query time.
-            self.join_reorder_by_greedy()?;
+            let join_relations = (0..self.join_relations.len())
+                .map(|idx| self.relation_set_tree.get_relation_set_by_index(idx))
+                .collect::<Result<Vec<_>>>()?;
+            self.join_reorder_by_greedy(join_relations)?;
         }
 
         Ok(())
     }
 
+    // Find the join tree of plan baseline whose relations are exactly the relations of current join graph.
+    fn baseline_join_tree(&self) -> Option<JoinTree> {
+        let metadata = self.metadata.read();
+        metadata
+            .join_hints()
+            .join_trees
+            .iter()
+            .find(|tree| {
+                let mut relations = HashSet::new();
+                for tables in tree.relations() {
+                    let Some(relation) = tables
+                        .first()
+                        .and_then(|table| self.table_index_map.get(table))
+                    else {
+                        return false;
+                    };
+                    if tables
+                        .iter()
+                        .any(|table| self.table_index_map.get(table) != Some(relation))
+                        || !relations.insert(*relation)
+                    {
+                        return false;
+                    }
+                }
+                relations.len() == self.join_relations.len()
+                    && tree.tables().len() == self.table_index_map.len()
+            })
+            .cloned()
+    }
+
+    // Join the relations in the shape of the join tree, returns the relations of the tree.
+    fn join_reorder_by_tree(&mut self, tree: &JoinTree) -> Result<Vec<IndexType>> {
+        match tree {
+            JoinTree::Relation(tables) => {
+                let relation = self.table_index_map[&tables[0]];
+                self.relation_set_tree.get_relation_set_by_index(relation)
+            }
+            JoinTree::Join { probe, build, .. } => {
+                let left = self.join_reorder_by_tree(probe)?;
+                let right = self.join_reorder_by_tree(build)?;
+                let join_conditions = self.query_graph.is_connected(&left, &right)?;
+                self.emit_csg_cmp(&left, &right, join_conditions)?;
+                Ok(union(&left, &right))
+            }
+        }
+    }
+
+    // Find the first `LEADING` hint whose tables are different relations of current join graph.
+    fn leading_relations(&self) -> Option<Vec<IndexType>> {
+        let metadata = self.metadata.read();
+        metadata.join_hints().leading.iter().find_map(|tables| {
+            let mut relations = Vec::with_capacity(tables.len());
+            for table in tables.iter() {
+                let relation = self.table_index_map.get(table)?;
+                if relations.contains(relation) {
+                    return None;
+                }
+                relations.push(*relation);
+            }
+            (relations.len() > 1).then_some(relations)
+        })
+    }
+
+    // Join the leading relations as a left-deep tree, then join the others by greedy algorithm.
+    fn join_reorder_by_leading(&mut self, leading: &[IndexType]) -> Result<()> {
+        let mut leading_relations = self
+            .relation_set_tree
+            .get_relation_set_by_index(leading[0])?;
+        for idx in leading.iter().skip(1) {
+            let relation = self.relation_set_tree.get_relation_set_by_index(*idx)?;
+            let join_conditions = self
+                .query_graph
+                .is_connected(&leading_relations, &relation)?;
+            self.emit_csg_cmp(&leading_relations, &relation, join_conditions)?;
+            leading_relations = union(&leading_relations, &relation);
+        }
+
+        let mut join_relations = vec![leading_relations];
+        for idx in 0..self.join_relations.len() {
+            if !leading.contains(&idx) {
+                join_relations.push(self.relation_set_tree.get_relation_set_by_index(idx)?);
+            }
+        }
+        self.join_reorder_by_greedy(join_relations)?;
+        Ok(())
+    }
+
     // Join reorder by dynamic programming algorithm.
     fn join_reorder_by_dphyp(&mut self) -> Result<bool> {
         // Choose all nodes as enumeration start node once (desc order)
@@ -391,14 +493,11 @@ impl DPhpy {
     }
 
     // Join reorder by greedy algorithm.
-    fn join_reorder_by_greedy(&mut self) -> Result<bool> {
+    fn join_reorder_by_greedy(&mut self, mut join_relations: Vec<Vec<IndexType>>) -> Result<bool> {
         // The Greedy Operator Ordering starts with a single relation and iteratively adds the relation that minimizes the cost of the join.
         // the algorithm terminates when all relations have been added, the cost of a join is the sum of the cardinalities of the node involved
         // in the tree, the algorithm is not guaranteed to find the optimal join tree, it is guaranteed to find it in polynomial time.
-        // Create join relations list, all relations have been inserted into dp_table in func `join_reorder`.
-        let mut join_relations = (0..self.join_relations.len())
-            .map(|idx| self.relation_set_tree.get_relation_set_by_index(idx))
-            .collect::<Result<Vec<_>>>()?;
+        // All relations in `join_relations` have been inserted into dp_table.
         // When all relations have been added, the algorithm terminates.
         while join_relations.len() > 1 {
             // The cost is the sum of the cardinalities of the node involved in the tree.
@@ -766,8 +865,12 @@ impl DPhpy {
 
     fn apply_rule(&self, s_expr: &SExpr) -> Result<SExpr> {
         let mut s_expr = s_expr.clone();
-        let rule =
-            RuleFactory::create_rule(RuleID::PushDownFilterJoin, self.metadata.clone(), false)?;
+        let rule = RuleFactory::create_rule(
+            RuleID::PushDownFilterJoin,
+            self.metadata.clone(),
+            false,
+            false,
+        )?;
         let mut state = TransformResult::new();
         if rule
             .matchers()
@@ -89,6 +89,7 @@ impl JoinNode {
             need_hold_hash_table: false,
             is_lateral: false,
             original_join_type: None,
+            hint: Default::default(),
         });
         let children = self
             .children
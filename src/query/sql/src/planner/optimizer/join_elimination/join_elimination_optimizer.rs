@@ -0,0 +1,257 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableConstraint;
+
+use crate::optimizer::matches_at_most_one_row;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Eliminates the joins whose one side is unused by the query, if the join doesn't change the
+/// rows of the other side:
+///
+/// - a left (right) join produces each row of the left (right) side exactly once, if the row
+///   matches at most one row of the other side, see [`matches_at_most_one_row`].
+/// - an inner join on a declared foreign key produces each row with non-null foreign keys
+///   exactly once, if the other side scans the whole referenced table.
+///
+/// The columns used by the query are collected from the top, so the optimizer only runs on
+/// queries whose output columns are known.
+pub struct JoinEliminationOptimizer {
+    metadata: MetadataRef,
+}
+
+impl JoinEliminationOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self { metadata }
+    }
+
+    /// `required` are the output columns of `s_expr` used by the query.
+    pub fn run(&self, s_expr: &SExpr, required: ColumnSet) -> Result<SExpr> {
+        self.eliminate(s_expr, required)
+    }
+
+    fn eliminate(&self, s_expr: &SExpr, mut required: ColumnSet) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::Join(join) => {
+                if let Some(result) = self.try_eliminate_join(s_expr, join, &required)? {
+                    return self.eliminate(&result, required);
+                }
+                required.extend(join.used_columns()?);
+            }
+            RelOperator::EvalScalar(eval_scalar) => {
+                required.extend(eval_scalar.used_columns()?);
+            }
+            RelOperator::Filter(filter) => {
+                required.extend(filter.used_columns()?);
+            }
+            RelOperator::Sort(sort) => {
+                required.extend(sort.items.iter().map(|item| item.index));
+            }
+            RelOperator::Limit(_) => {}
+            // The aggregate only outputs the group items and the aggregate functions.
+            RelOperator::Aggregate(aggregate) => {
+                required = aggregate.used_columns()?;
+            }
+            // The other operators may use any column of their children.
+            _ => {
+                let mut children = Vec::with_capacity(s_expr.arity());
+                for child in s_expr.children() {
+                    let child_required = RelExpr::with_s_expr(child)
+                        .derive_relational_prop()?
+                        .output_columns
+                        .clone();
+                    children.push(Arc::new(self.eliminate(child, child_required)?));
+                }
+                return Ok(s_expr.replace_children(children));
+            }
+        }
+
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            children.push(Arc::new(self.eliminate(child, required.clone())?));
+        }
+        Ok(s_expr.replace_children(children))
+    }
+
+    // Returns the side of the join replacing the join if the columns of the other side are
+    // unused and the join doesn't change the rows of the side.
+    fn try_eliminate_join(
+        &self,
+        s_expr: &SExpr,
+        join: &Join,
+        required: &ColumnSet,
+    ) -> Result<Option<SExpr>> {
+        if join.need_hold_hash_table || join.is_lateral || join.marker_index.is_some() {
+            return Ok(None);
+        }
+        for side in [0, 1] {
+            let other_columns = RelExpr::with_s_expr(s_expr.child(1 - side)?)
+                .derive_relational_prop()?
+                .output_columns
+                .clone();
+            if !required.is_disjoint(&other_columns) {
+                continue;
+            }
+            match (&join.join_type, side) {
+                // Every row of the preserved side is produced exactly once if it matches at
+                // most one row of the other side.
+                (JoinType::Left | JoinType::LeftSingle, 0)
+                | (JoinType::Right | JoinType::RightSingle, 1) => {
+                    if matches_at_most_one_row(s_expr, join, side, &self.metadata)? {
+                        return Ok(Some(s_expr.child(side)?.clone()));
+                    }
+                }
+                // Every row with non-null foreign keys matches exactly one referenced row.
+                (JoinType::Inner, _) => {
+                    if let Some(predicates) =
+                        self.foreign_key_join_predicates(s_expr, join, side)?
+                    {
+                        return Ok(Some(SExpr::create_unary(
+                            Arc::new(Filter { predicates }.into()),
+                            Arc::new(s_expr.child(side)?.clone()),
+                        )));
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
+    // Check if the join keys of `side` are a declared foreign key referencing the join keys of
+    // the other side, which scans the whole referenced table. Return the predicates filtering
+    // out the rows with null foreign keys.
+    fn foreign_key_join_predicates(
+        &self,
+        s_expr: &SExpr,
+        join: &Join,
+        side: usize,
+    ) -> Result<Option<Vec<ScalarExpr>>> {
+        if join.left_conditions.is_empty() || !join.non_equi_conditions.is_empty() {
+            return Ok(None);
+        }
+        let referenced = s_expr.child(1 - side)?;
+        let RelOperator::Scan(scan) = referenced.plan() else {
+            return Ok(None);
+        };
+        if scan
+            .push_down_predicates
+            .as_ref()
+            .map_or(false, |predicates| !predicates.is_empty())
+            || scan.prewhere.is_some()
+            || scan.limit.is_some()
+            || scan.sample.is_some()
+            || scan.change_type.is_some()
+            || !matches_at_most_one_row(s_expr, join, side, &self.metadata)?
+        {
+            return Ok(None);
+        }
+
+        let (keys, referenced_keys) = match side {
+            0 => (&join.left_conditions, &join.right_conditions),
+            _ => (&join.right_conditions, &join.left_conditions),
+        };
+        let metadata = self.metadata.read();
+        let base_column = |scalar: &ScalarExpr| match scalar {
+            ScalarExpr::BoundColumnRef(column) => match metadata.column(column.column.index) {
+                ColumnEntry::BaseTableColumn(column) if column.path_indices.is_none() => {
+                    Some(column.clone())
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        let mut table_index = None;
+        let mut columns = Vec::with_capacity(keys.len());
+        for (key, referenced_key) in keys.iter().zip(referenced_keys.iter()) {
+            match (base_column(key), base_column(referenced_key)) {
+                (Some(column), Some(referenced_column))
+                    if referenced_column.table_index == scan.table_index
+                        && *table_index.get_or_insert(column.table_index) == column.table_index =>
+                {
+                    columns.push((column.column_name, referenced_column.column_name));
+                }
+                _ => return Ok(None),
+            }
+        }
+
+        let Some(table_index) = table_index else {
+            return Ok(None);
+        };
+        let table_entry = metadata.table(table_index);
+        let referenced_entry = metadata.table(scan.table_index);
+        if table_entry.catalog() != referenced_entry.catalog() {
+            return Ok(None);
+        }
+        let table = table_entry.table();
+        let schema = table.schema();
+        let is_foreign_key = table
+            .get_table_info()
+            .meta
+            .constraints
+            .values()
+            .any(|constraint| match constraint {
+                TableConstraint::ForeignKey {
+                    column_ids,
+                    ref_database,
+                    ref_table,
+                    ref_columns,
+                } => {
+                    let mut positions = HashSet::new();
+                    ref_database == referenced_entry.database()
+                        && ref_table == referenced_entry.name()
+                        && column_ids.len() == columns.len()
+                        && columns.iter().all(|(column, referenced_column)| {
+                            let position = schema.column_id_of(column).ok().and_then(|id| {
+                                column_ids.iter().position(|column_id| *column_id == id)
+                            });
+                            matches!(position, Some(position)
+                                if &ref_columns[position] == referenced_column
+                                    && positions.insert(position))
+                        })
+                }
+                _ => false,
+            });
+        if !is_foreign_key {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            keys.iter()
+                .map(|key| {
+                    ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "is_not_null".to_string(),
+                        params: vec![],
+                        arguments: vec![key.clone()],
+                    })
+                })
+                .collect(),
+        ))
+    }
+}
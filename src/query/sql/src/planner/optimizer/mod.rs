@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod cascades;
+mod common_subexpr;
 mod cost;
 mod decorrelate;
 mod distributed;
@@ -20,7 +21,9 @@ mod extract;
 mod filter;
 mod format;
 mod group;
+mod hint;
 mod hyper_dp;
+mod join_elimination;
 mod m_expr;
 mod memo;
 #[allow(clippy::module_inception)]
@@ -29,11 +32,14 @@ mod property;
 mod rule;
 pub mod s_expr;
 mod util;
+mod window;
 
 pub use cascades::CascadesOptimizer;
 pub use decorrelate::FlattenInfo;
 pub use decorrelate::SubqueryRewriter;
 pub use extract::PatternExtractor;
+pub use hint::JoinHints;
+pub use hint::JoinTree;
 pub use hyper_dp::DPhpy;
 pub use m_expr::MExpr;
 pub use memo::Memo;
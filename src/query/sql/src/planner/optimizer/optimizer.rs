@@ -29,14 +29,19 @@ use super::format::display_memo;
 use super::Memo;
 use crate::binder::MergeIntoType;
 use crate::optimizer::cascades::CascadesOptimizer;
+use crate::optimizer::common_subexpr::CommonSubexprOptimizer;
 use crate::optimizer::decorrelate::decorrelate_subquery;
 use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::distributed::SortAndLimitPushDownOptimizer;
 use crate::optimizer::filter::DeduplicateJoinConditionOptimizer;
 use crate::optimizer::filter::PullUpFilterOptimizer;
+use crate::optimizer::hint::JoinHintOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
+use crate::optimizer::join_elimination::JoinEliminationOptimizer;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::window::WindowSortOptimizer;
+use crate::optimizer::ColumnSet;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -60,6 +65,10 @@ pub struct OptimizerContext {
     enable_distributed_optimization: bool,
     enable_join_reorder: bool,
     enable_dphyp: bool,
+    enable_eager_aggregation: bool,
+
+    // The output columns of the query used by the caller, `None` if they are unknown.
+    required_columns: Option<ColumnSet>,
 }
 
 impl OptimizerContext {
@@ -71,6 +80,9 @@ impl OptimizerContext {
             enable_distributed_optimization: false,
             enable_join_reorder: true,
             enable_dphyp: true,
+            enable_eager_aggregation: true,
+
+            required_columns: None,
         }
     }
 
@@ -88,6 +100,16 @@ impl OptimizerContext {
         self.enable_dphyp = enable;
         self
     }
+
+    pub fn with_enable_eager_aggregation(mut self, enable: bool) -> Self {
+        self.enable_eager_aggregation = enable;
+        self
+    }
+
+    pub fn with_required_columns(mut self, required_columns: ColumnSet) -> Self {
+        self.required_columns = Some(required_columns);
+        self
+    }
 }
 
 /// A recursive optimizer that will apply the given rules recursively.
@@ -131,6 +153,7 @@ impl<'a> RecursiveOptimizer<'a> {
                 *rule_id,
                 self.ctx.metadata.clone(),
                 self.after_join_reorder,
+                self.ctx.enable_eager_aggregation,
             )?;
             let mut state = TransformResult::new();
             if rule
@@ -168,14 +191,24 @@ pub fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
             rewrite_kind,
             formatted_ast,
             ignore_result,
-        } => Ok(Plan::Query {
-            s_expr: Box::new(optimize_query(opt_ctx, *s_expr)?),
-            bind_context,
-            metadata,
-            rewrite_kind,
-            formatted_ast,
-            ignore_result,
-        }),
+        } => {
+            let required_columns = bind_context
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .collect();
+            Ok(Plan::Query {
+                s_expr: Box::new(optimize_query(
+                    opt_ctx.with_required_columns(required_columns),
+                    *s_expr,
+                )?),
+                bind_context,
+                metadata,
+                rewrite_kind,
+                formatted_ast,
+                ignore_result,
+            })
+        }
         Plan::Explain { kind, config, plan } => match kind {
             ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
                 Ok(Plan::Explain { config, kind, plan })
@@ -210,13 +243,21 @@ pub fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
         Plan::ExplainAnalyze { plan } => Ok(Plan::ExplainAnalyze {
             plan: Box::new(optimize(opt_ctx, *plan)?),
         }),
-        Plan::CopyIntoLocation(CopyIntoLocationPlan { stage, path, from }) => {
-            Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
-                stage,
-                path,
-                from: Box::new(optimize(opt_ctx, *from)?),
-            }))
+        Plan::CreatePlanBaseline(mut plan) => {
+            plan.plan = Box::new(optimize(opt_ctx, *plan.plan)?);
+            Ok(Plan::CreatePlanBaseline(plan))
         }
+        Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage,
+            path,
+            from,
+            consume_streams,
+        }) => Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
+            stage,
+            path,
+            from: Box::new(optimize(opt_ctx, *from)?),
+            consume_streams,
+        })),
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
             plan.enable_distributed = opt_ctx.enable_distributed_optimization
                 && opt_ctx
@@ -252,6 +293,12 @@ pub fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<SE
     // Pull up and infer filter.
     s_expr = PullUpFilterOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
 
+    // Eliminate the joins whose one side is unused, before they are reordered.
+    if let Some(required_columns) = &opt_ctx.required_columns {
+        s_expr = JoinEliminationOptimizer::new(opt_ctx.metadata.clone())
+            .run(&s_expr, required_columns.clone())?;
+    }
+
     // Run default rewrite rules
     s_expr = RecursiveOptimizer::new(&DEFAULT_REWRITE_RULES, &opt_ctx).run(&s_expr)?;
 
@@ -276,6 +323,40 @@ pub fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<SE
     // Deduplicate join conditions.
     s_expr = DeduplicateJoinConditionOptimizer::new().run(&s_expr)?;
 
+    // Apply the join hints of query, the hinted join order and build sides are kept by cascades optimizer.
+    let join_hints = opt_ctx.metadata.read().join_hints().clone();
+    if !join_hints.is_empty() {
+        let (hinted, fixed_join_order) = JoinHintOptimizer::new(
+            opt_ctx.table_ctx.clone(),
+            opt_ctx.metadata.clone(),
+            join_hints,
+            enable_distributed_query,
+        )
+        .run(&s_expr)?;
+        s_expr = hinted;
+        dphyp_optimized |= fixed_join_order;
+    }
+
+    // Share the subtrees computed more than once, the materialized result is only readable on the local node.
+    if !enable_distributed_query
+        && opt_ctx
+            .table_ctx
+            .get_settings()
+            .get_enable_common_subexpression_reuse()?
+    {
+        s_expr = CommonSubexprOptimizer::new(opt_ctx.table_ctx.clone(), opt_ctx.metadata.clone())?
+            .run(&s_expr)?;
+    }
+
+    // Share the sorts of the windows whose partition and order keys are satisfied by the same order.
+    if opt_ctx
+        .table_ctx
+        .get_settings()
+        .get_enable_shared_window_sort()?
+    {
+        s_expr = WindowSortOptimizer::new(opt_ctx.table_ctx.clone())?.run(&s_expr)?;
+    }
+
     let mut cascades = CascadesOptimizer::new(
         opt_ctx.table_ctx.clone(),
         opt_ctx.metadata.clone(),
@@ -393,7 +474,7 @@ fn optimize_merge_into(opt_ctx: OptimizerContext, plan: Box<MergeInto>) -> Resul
     // 3. for full merge into, we use right outer join
     // for now, let's import the statistic info to determine left join or right join
     // we just do optimization for the top join (target and source),won't do recursive optimization.
-    let rule = RuleFactory::create_rule(RuleID::CommuteJoin, plan.meta_data.clone(), false)?;
+    let rule = RuleFactory::create_rule(RuleID::CommuteJoin, plan.meta_data.clone(), false, false)?;
     let mut state = TransformResult::new();
     // we will reorder the join order according to the cardinality of target and source.
     rule.apply(&join_sexpr, &mut state)?;
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use databend_common_exception::Result;
 use databend_common_expression::arithmetics_type::ResultTypeOfUnary;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 
@@ -33,18 +34,25 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// The histogram is constructed from the histogram collected by `ANALYZE TABLE`
+/// if there is one, otherwise from NDV(number of distinct values) and the total
+/// number of rows, which brings the assumption that the data is uniformly distributed.
+///
+/// The most common values of a collected histogram are also kept in their own
+/// buckets, so the buckets always cover all the values of the column.
 #[derive(Debug, Clone)]
 pub struct Histogram {
     pub buckets: Vec<HistogramBucket>,
+    /// The most common values and their estimated number of values, in ascending order.
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            buckets,
+            most_common_values: vec![],
+        }
     }
 
     /// Get number of buckets
@@ -71,6 +79,89 @@ impl Histogram {
     pub fn buckets_iter(&self) -> impl DoubleEndedIterator<Item = &HistogramBucket> {
         self.buckets.iter()
     }
+
+    /// Get estimated number of values equal to `datum` if it is one of the most common values.
+    pub fn most_common_value(&self, datum: &Datum) -> Option<f64> {
+        self.most_common_values
+            .iter()
+            .find(|(value, _)| {
+                value.type_comparable(datum) && value.compare(datum).ok() == Some(Ordering::Equal)
+            })
+            .map(|(_, num_values)| *num_values)
+    }
+
+    /// Get estimated number of values of all the most common values.
+    pub fn num_most_common_values(&self) -> f64 {
+        self.most_common_values
+            .iter()
+            .map(|(_, num_values)| num_values)
+            .sum()
+    }
+
+    pub fn update(&mut self, selectivity: f64) {
+        for bucket in self.buckets.iter_mut() {
+            bucket.update(selectivity);
+        }
+        for (_, num_values) in self.most_common_values.iter_mut() {
+            *num_values *= selectivity;
+        }
+    }
+}
+
+/// Construct a histogram from the histogram collected by `ANALYZE TABLE`, the numbers
+/// of values are scaled to `num_values`, the current number of non-null values of the column.
+///
+/// Returns `None` if the values of the column are not comparable in the optimizer.
+pub fn histogram_from_column_histogram(
+    column_histogram: &ColumnHistogram,
+    num_values: u64,
+) -> Option<Histogram> {
+    let collected_values = column_histogram.num_values();
+    if collected_values <= 0.0 {
+        return None;
+    }
+    // The table may be changed since it is analyzed.
+    let scale = num_values as f64 / collected_values;
+
+    let mut most_common_values = Vec::with_capacity(column_histogram.most_common_values.len());
+    for value in column_histogram.most_common_values.iter() {
+        let datum = Datum::from_scalar(value.value.clone())?;
+        most_common_values.push((datum, value.num_values * scale));
+    }
+
+    let mut buckets = Vec::with_capacity(
+        column_histogram.buckets.len() + column_histogram.most_common_values.len() + 1,
+    );
+    let mut lower_bound = None;
+    for bucket in column_histogram.buckets.iter() {
+        let upper_bound = Datum::from_scalar(bucket.upper_bound.clone())?;
+        if lower_bound.is_none() {
+            lower_bound = Datum::from_scalar(bucket.lower_bound.clone());
+        }
+        buckets.push(HistogramBucket::new(
+            upper_bound,
+            bucket.num_values * scale,
+            bucket.num_distinct,
+        ));
+    }
+    for (datum, num_values) in most_common_values.iter() {
+        buckets.push(HistogramBucket::new(datum.clone(), *num_values, 1.0));
+    }
+    // All the values are of the same type of the column, so they can be ordered directly.
+    buckets.sort_by(|a, b| a.upper_bound.cmp(&b.upper_bound));
+
+    // The first bucket is a dummy bucket which records the min value of the column.
+    let first = buckets.first()?.upper_bound().clone();
+    let min = match lower_bound {
+        Some(lower_bound) if lower_bound < first => lower_bound,
+        _ => first,
+    };
+    buckets.insert(0, HistogramBucket::new(min, 0.0, 0.0));
+
+    Some(Histogram {
+        buckets,
+        most_common_values,
+    })
 }
 
 /// Construct a histogram from NDV and total number of rows.
@@ -93,7 +184,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -148,7 +239,7 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
 }
 
 #[derive(Debug, Clone)]
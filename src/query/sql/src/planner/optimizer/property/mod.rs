@@ -23,6 +23,7 @@ mod histogram;
 #[allow(clippy::module_inception)]
 mod property;
 mod selectivity;
+mod unique_key;
 
 pub use builder::RelExpr;
 pub use column_stat::ColumnStat;
@@ -33,6 +34,7 @@ pub use constraint::ConstraintSet;
 pub use enforcer::require_property;
 pub use enforcer::DistributionEnforcer;
 pub use enforcer::Enforcer;
+pub use histogram::histogram_from_column_histogram;
 pub use histogram::histogram_from_ndv;
 pub use histogram::Histogram;
 pub use histogram::HistogramBucket;
@@ -50,3 +52,7 @@ pub use property::TableSet;
 pub use selectivity::SelectivityEstimator;
 pub use selectivity::DEFAULT_SELECTIVITY;
 pub use selectivity::MAX_SELECTIVITY;
+pub use unique_key::derive_unique_keys;
+pub use unique_key::is_unique;
+pub use unique_key::join_key_columns;
+pub use unique_key::matches_at_most_one_row;
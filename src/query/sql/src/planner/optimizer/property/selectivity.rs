@@ -191,9 +191,10 @@ impl<'a> SelectivityEstimator<'a> {
 
                 return match op {
                     ComparisonOp::Equal => {
-                        // For equal predicate, we just use cardinality of a single
-                        // value to estimate the selectivity. This assumes that
-                        // the column is in a uniform distribution.
+                        // For equal predicate, we use the frequency of the value if it
+                        // is one of the most common values, otherwise the cardinality of
+                        // a single value, which assumes the other values are in a uniform
+                        // distribution.
                         let selectivity = evaluate_equal(column_stat, constant);
                         if update {
                             update_statistic(
@@ -374,9 +375,7 @@ impl<'a> SelectivityEstimator<'a> {
                     if new_ndv as u64 <= 2 {
                         column_stat.histogram = None;
                     } else {
-                        for bucket in histogram.buckets.iter_mut() {
-                            bucket.update(selectivity);
-                        }
+                        histogram.update(selectivity);
                     }
                 }
             }
@@ -439,6 +438,27 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
         }
     }
 
+    if let (Some(constant_datum), Some(histogram)) = (datum, &column_stat.histogram) {
+        if !histogram.most_common_values.is_empty() {
+            let num_values = histogram.num_values();
+            if num_values <= 0.0 {
+                return 0.0;
+            }
+            if let Some(num_equal) = histogram.most_common_value(constant_datum) {
+                return (num_equal / num_values).min(1.0);
+            }
+            // The value is not one of the most common values, it shares the rest values
+            // with the other non-common values.
+            let num_others = (num_values - histogram.num_most_common_values()).max(0.0);
+            let ndv_others = column_stat.ndv - histogram.most_common_values.len() as f64;
+            return if ndv_others >= 1.0 {
+                num_others / num_values / ndv_others
+            } else {
+                0.0
+            };
+        }
+    }
+
     if column_stat.ndv == 0.0 {
         0.0
     } else {
@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableConstraint;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Derive the unique keys of `s_expr`, the rows produced by `s_expr` have distinct values on
+/// every key. An empty key means there is at most one row.
+///
+/// The keys are derived from the group items of aggregates and the declared `PRIMARY KEY`
+/// and `UNIQUE` constraints of tables. Like the constraints, the keys don't consider nulls:
+/// two rows may both have nulls in a key, which is fine for matching the rows by equality.
+pub fn derive_unique_keys(s_expr: &SExpr, metadata: &MetadataRef) -> Result<Vec<ColumnSet>> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => Ok(scan_unique_keys(scan, metadata)),
+        RelOperator::Aggregate(aggregate)
+            if aggregate.mode != AggregateMode::Partial && aggregate.grouping_sets.is_none() =>
+        {
+            Ok(vec![
+                aggregate
+                    .group_items
+                    .iter()
+                    .map(|item| item.index)
+                    .collect(),
+            ])
+        }
+        RelOperator::Filter(_)
+        | RelOperator::EvalScalar(_)
+        | RelOperator::Exchange(_)
+        | RelOperator::Sort(_)
+        | RelOperator::Limit(_) => derive_unique_keys(s_expr.child(0)?, metadata),
+        RelOperator::Join(join) => {
+            let left_keys = derive_unique_keys(s_expr.child(0)?, metadata)?;
+            let right_keys = derive_unique_keys(s_expr.child(1)?, metadata)?;
+            let mut keys = vec![];
+            match join.join_type {
+                JoinType::LeftSemi | JoinType::LeftAnti => keys = left_keys,
+                JoinType::RightSemi | JoinType::RightAnti => keys = right_keys,
+                JoinType::Inner
+                | JoinType::Left
+                | JoinType::LeftSingle
+                | JoinType::Right
+                | JoinType::RightSingle => {
+                    // Every row of a side matches at most one row of the other side,
+                    // so the keys of the side are still unique after the join.
+                    if !matches!(join.join_type, JoinType::Right | JoinType::RightSingle)
+                        && is_unique(&right_keys, &join_key_columns(&join.right_conditions))
+                    {
+                        keys.extend(left_keys.iter().cloned());
+                    }
+                    if !matches!(join.join_type, JoinType::Left | JoinType::LeftSingle)
+                        && is_unique(&left_keys, &join_key_columns(&join.left_conditions))
+                    {
+                        keys.extend(right_keys.iter().cloned());
+                    }
+                }
+                _ => {}
+            }
+            Ok(keys)
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+/// Check if `columns` contain one of the unique `keys`.
+pub fn is_unique(keys: &[ColumnSet], columns: &ColumnSet) -> bool {
+    keys.iter().any(|key| key.is_subset(columns))
+}
+
+/// Get the columns of the join keys that are plain column references.
+pub fn join_key_columns(conditions: &[ScalarExpr]) -> ColumnSet {
+    conditions
+        .iter()
+        .filter_map(|condition| match condition {
+            ScalarExpr::BoundColumnRef(column) => Some(column.column.index),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check if each row of the `side` child of `join` matches at most one row of the other child.
+pub fn matches_at_most_one_row(
+    s_expr: &SExpr,
+    join: &Join,
+    side: usize,
+    metadata: &MetadataRef,
+) -> Result<bool> {
+    let (other, conditions) = match side {
+        0 => (s_expr.child(1)?, &join.right_conditions),
+        _ => (s_expr.child(0)?, &join.left_conditions),
+    };
+    Ok(is_unique(
+        &derive_unique_keys(other, metadata)?,
+        &join_key_columns(conditions),
+    ))
+}
+
+fn scan_unique_keys(scan: &Scan, metadata: &MetadataRef) -> Vec<ColumnSet> {
+    // The change rows of a stream may have the same key.
+    if scan.change_type.is_some() {
+        return vec![];
+    }
+    let metadata = metadata.read();
+    let table = metadata.table(scan.table_index).table();
+    let table_info = table.get_table_info();
+    if table_info.meta.constraints.is_empty() {
+        return vec![];
+    }
+    let schema = table.schema();
+    let column_index = |column_id: u32| -> Option<IndexType> {
+        scan.columns
+            .iter()
+            .copied()
+            .find(|index| match metadata.column(*index) {
+                ColumnEntry::BaseTableColumn(column) => {
+                    column.path_indices.is_none()
+                        && schema.column_id_of(&column.column_name).ok() == Some(column_id)
+                }
+                _ => false,
+            })
+    };
+
+    table_info
+        .meta
+        .constraints
+        .values()
+        .filter_map(|constraint| match constraint {
+            TableConstraint::PrimaryKey { column_ids } | TableConstraint::Unique { column_ids } => {
+                column_ids
+                    .iter()
+                    .map(|column_id| column_index(*column_id))
+                    .collect::<Option<ColumnSet>>()
+            }
+            _ => None,
+        })
+        .collect()
+}
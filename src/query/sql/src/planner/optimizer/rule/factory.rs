@@ -18,6 +18,7 @@ use super::rewrite::RuleCommuteJoin;
 use super::rewrite::RuleEliminateEvalScalar;
 use super::rewrite::RuleFoldCountAggregate;
 use super::rewrite::RuleNormalizeScalarFilter;
+use super::rewrite::RulePushDownAggregateJoin;
 use super::rewrite::RulePushDownFilterAggregate;
 use super::rewrite::RulePushDownFilterEvalScalar;
 use super::rewrite::RulePushDownFilterJoin;
@@ -59,6 +60,7 @@ impl RuleFactory {
         id: RuleID,
         metadata: MetadataRef,
         after_join_reorder: bool,
+        enable_eager_aggregation: bool,
     ) -> Result<RulePtr> {
         match id {
             RuleID::EliminateEvalScalar => Ok(Box::new(RuleEliminateEvalScalar::new())),
@@ -83,6 +85,10 @@ impl RuleFactory {
             }
             RuleID::PushDownLimitAggregate => Ok(Box::new(RulePushDownLimitAggregate::new())),
             RuleID::PushDownFilterAggregate => Ok(Box::new(RulePushDownFilterAggregate::new())),
+            RuleID::PushDownAggregateJoin => Ok(Box::new(RulePushDownAggregateJoin::new(
+                metadata,
+                enable_eager_aggregation,
+            ))),
             RuleID::PushDownFilterWindow => Ok(Box::new(RulePushDownFilterWindow::new())),
             RuleID::EliminateFilter => Ok(Box::new(RuleEliminateFilter::new())),
             RuleID::MergeEvalScalar => Ok(Box::new(RuleMergeEvalScalar::new())),
@@ -23,6 +23,7 @@ mod rule_merge_eval_scalar;
 mod rule_merge_filter;
 mod rule_normalize_aggregate;
 mod rule_normalize_scalar;
+mod rule_push_down_aggregate_join;
 mod rule_push_down_filter_aggregate;
 mod rule_push_down_filter_eval_scalar;
 mod rule_push_down_filter_join;
@@ -53,6 +54,7 @@ pub use rule_merge_eval_scalar::RuleMergeEvalScalar;
 pub use rule_merge_filter::RuleMergeFilter;
 pub use rule_normalize_aggregate::RuleNormalizeAggregate;
 pub use rule_normalize_scalar::RuleNormalizeScalarFilter;
+pub use rule_push_down_aggregate_join::RulePushDownAggregateJoin;
 pub use rule_push_down_filter_aggregate::RulePushDownFilterAggregate;
 pub use rule_push_down_filter_eval_scalar::RulePushDownFilterEvalScalar;
 pub use rule_push_down_filter_join::try_push_down_filter_join;
@@ -57,6 +57,10 @@ impl Rule for RuleCommuteJoin {
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let mut join: Join = s_expr.plan().clone().try_into()?;
+        // The build side is decided by `BROADCAST` hint or plan baseline.
+        if join.hint.keep_sides {
+            return Ok(());
+        }
         let left_child = s_expr.child(0)?;
         let right_child = s_expr.child(1)?;
         let left_rel_expr = RelExpr::with_s_expr(left_child);
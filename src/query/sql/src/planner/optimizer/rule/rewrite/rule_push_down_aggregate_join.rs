@@ -0,0 +1,473 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+
+use crate::binder::wrap_cast;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::derive_unique_keys;
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::is_unique;
+use crate::optimizer::matches_at_most_one_row;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Push an aggregate down to one side of a join, if every row of the side matches at most
+/// one row of the other side and the aggregate groups by the join keys of the side. The groups
+/// are not changed by the join, and the group items from the other side are determined by
+/// the join keys, so they are produced by the join instead.
+///
+/// Input:  Aggregate: group by (l.k, r.x), SUM(l.y)
+///           \
+///          Join(l.k = r.k, r is unique on r.k)
+///          /  \
+///         l    r
+///
+/// Output: Join(l.k = r.k)
+///          /  \
+///   Aggregate  r
+///    group by (l.k), SUM(l.y)
+///        |
+///        l
+///
+/// Otherwise the aggregate is split into two (eager aggregation): the side is aggregated by its
+/// join keys and group items first, which only reduces the rows going into the join, and the
+/// partial results are aggregated by the group items after the join. It's only done if the
+/// aggregate has group items, its functions can be computed from the partial results, and
+/// the number of groups estimated by the NDVs of the group items is small enough compared
+/// to the rows of the side, see `EAGER_AGGREGATION_MAX_GROUP_RATIO`. It can be disabled by
+/// the setting `enable_eager_aggregation`.
+///
+/// Input:  Aggregate: group by (r.x), SUM(l.y), COUNT(*)
+///           \
+///          Join(l.k = r.k, r is unique on r.k)
+///          /  \
+///         l    r
+///
+/// Output: Aggregate: group by (r.x), SUM(s), SUM(c)
+///           \
+///          Join(l.k = r.k)
+///          /  \
+///   Aggregate  r
+///    group by (l.k), SUM(l.y) as s, COUNT(*) as c
+///        |
+///        l
+pub struct RulePushDownAggregateJoin {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+    metadata: MetadataRef,
+    enable_eager_aggregation: bool,
+}
+
+/// The side is only aggregated eagerly if the estimated number of groups is at most this
+/// ratio of its rows, otherwise the partial aggregate costs more than it saves in the join.
+const EAGER_AGGREGATION_MAX_GROUP_RATIO: f64 = 0.5;
+
+impl RulePushDownAggregateJoin {
+    pub fn new(metadata: MetadataRef, enable_eager_aggregation: bool) -> Self {
+        Self {
+            id: RuleID::PushDownAggregateJoin,
+            matchers: vec![
+                // Aggregate
+                //     |
+                // Expression
+                //     |
+                //    Join
+                //   /    \
+                //  *      *
+                Matcher::MatchOp {
+                    op_type: RelOp::Aggregate,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::EvalScalar,
+                        children: vec![Matcher::MatchOp {
+                            op_type: RelOp::Join,
+                            children: vec![Matcher::Leaf, Matcher::Leaf],
+                        }],
+                    }],
+                },
+                // Aggregate
+                //     |
+                //    Join
+                //   /    \
+                //  *      *
+                Matcher::MatchOp {
+                    op_type: RelOp::Aggregate,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Join,
+                        children: vec![Matcher::Leaf, Matcher::Leaf],
+                    }],
+                },
+            ],
+            metadata,
+            enable_eager_aggregation,
+        }
+    }
+
+    fn push_down(
+        &self,
+        aggregate: &Aggregate,
+        eval_scalar: &EvalScalar,
+        join_expr: &SExpr,
+        join: &Join,
+        side: usize,
+    ) -> Result<Option<SExpr>> {
+        // The rows of the preserved side of an outer join are grouped with nulls of the other side.
+        let outer = match (&join.join_type, side) {
+            (JoinType::Inner, _) => false,
+            (JoinType::Left, 0) | (JoinType::Right, 1) => true,
+            _ => return Ok(None),
+        };
+        if !matches_at_most_one_row(join_expr, join, side, &self.metadata)? {
+            return Ok(None);
+        }
+
+        let mut side_columns = RelExpr::with_s_expr(join_expr.child(side)?)
+            .derive_relational_prop()?
+            .output_columns
+            .clone();
+        let mut other_columns = RelExpr::with_s_expr(join_expr.child(1 - side)?)
+            .derive_relational_prop()?
+            .output_columns
+            .clone();
+
+        // Split the scalars evaluated before the aggregate by the sides.
+        let mut side_items = vec![];
+        let mut other_items = vec![];
+        for item in eval_scalar.items.iter() {
+            let used_columns = item.scalar.used_columns();
+            if used_columns.is_subset(&side_columns) {
+                side_items.push(item.clone());
+            } else if used_columns.is_subset(&other_columns) {
+                other_items.push(item.clone());
+            } else {
+                return Ok(None);
+            }
+        }
+        side_columns.extend(side_items.iter().map(|item| item.index));
+        other_columns.extend(other_items.iter().map(|item| item.index));
+
+        if aggregate
+            .aggregate_functions
+            .iter()
+            .any(|item| !item.scalar.used_columns().is_subset(&side_columns))
+        {
+            return Ok(None);
+        }
+
+        let mut group_items = Vec::with_capacity(aggregate.group_items.len());
+        let mut group_columns = ColumnSet::new();
+        for item in aggregate.group_items.iter() {
+            if side_columns.contains(&item.index) {
+                group_items.push(item.clone());
+            } else if !other_columns.contains(&item.index) {
+                return Ok(None);
+            }
+            group_columns.insert(item.index);
+        }
+
+        // Each group must have the same join keys. For inner joins, grouping by the keys of
+        // the other side is the same, otherwise the side is aggregated by the keys eagerly.
+        let (keys, other_keys) = match side {
+            0 => (&join.left_conditions, &join.right_conditions),
+            _ => (&join.right_conditions, &join.left_conditions),
+        };
+        let mut eager = false;
+        for (key, other_key) in keys.iter().zip(other_keys.iter()) {
+            let (ScalarExpr::BoundColumnRef(key_column), ScalarExpr::BoundColumnRef(other_column)) =
+                (key, other_key)
+            else {
+                return Ok(None);
+            };
+            let key_index = key_column.column.index;
+            if group_columns.contains(&key_index) {
+                continue;
+            }
+            if outer || !group_columns.contains(&other_column.column.index) {
+                eager = true;
+            }
+            group_columns.insert(key_index);
+            group_items.push(ScalarItem {
+                scalar: key.clone(),
+                index: key_index,
+            });
+        }
+
+        let mut side_expr = join_expr.child(side)?.clone();
+        if !side_items.is_empty() {
+            side_expr = SExpr::create_unary(
+                Arc::new(EvalScalar { items: side_items }.into()),
+                Arc::new(side_expr),
+            );
+        }
+        if eager {
+            return self.eager_aggregate(
+                aggregate,
+                join_expr,
+                side,
+                side_expr,
+                group_items,
+                other_items,
+            );
+        }
+        let aggregate_expr = SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    group_items,
+                    ..aggregate.clone()
+                }
+                .into(),
+            ),
+            Arc::new(side_expr),
+        );
+
+        let mut children = vec![
+            Arc::new(join_expr.child(0)?.clone()),
+            Arc::new(join_expr.child(1)?.clone()),
+        ];
+        children[side] = Arc::new(aggregate_expr);
+        let mut result = join_expr.replace_children(children);
+        if !other_items.is_empty() {
+            result = SExpr::create_unary(
+                Arc::new(EvalScalar { items: other_items }.into()),
+                Arc::new(result),
+            );
+        }
+        Ok(Some(result))
+    }
+
+    // Aggregates `side_expr` by `group_items` below the join, and aggregates the partial
+    // results by the group items of `aggregate` above the join.
+    fn eager_aggregate(
+        &self,
+        aggregate: &Aggregate,
+        join_expr: &SExpr,
+        side: usize,
+        side_expr: SExpr,
+        group_items: Vec<ScalarItem>,
+        other_items: Vec<ScalarItem>,
+    ) -> Result<Option<SExpr>> {
+        // The final aggregate of an aggregate without group items produces a row even if
+        // there is no partial result, which is not the same for COUNT.
+        if !self.enable_eager_aggregation || aggregate.group_items.is_empty() {
+            return Ok(None);
+        }
+        // The partial aggregate doesn't reduce the rows if the side is unique on the groups,
+        // which is also the case once the side has been aggregated eagerly.
+        let group_columns = group_items.iter().map(|item| item.index).collect();
+        if is_unique(
+            &derive_unique_keys(&side_expr, &self.metadata)?,
+            &group_columns,
+        ) {
+            return Ok(None);
+        }
+        // The groups are estimated by the NDVs of the group items, the same as the
+        // cardinality of an aggregate. The reduction is unknown without the NDVs.
+        let stat_info = RelExpr::with_s_expr(&side_expr).derive_cardinality()?;
+        let mut num_groups = 1.0;
+        for item in group_items.iter() {
+            match stat_info.statistics.column_stats.get(&item.index) {
+                Some(column_stat) => num_groups *= column_stat.ndv,
+                None => return Ok(None),
+            }
+        }
+        if num_groups > stat_info.cardinality * EAGER_AGGREGATION_MAX_GROUP_RATIO {
+            return Ok(None);
+        }
+
+        let function_factory = AggregateFunctionFactory::instance();
+        let mut partial_functions = Vec::with_capacity(aggregate.aggregate_functions.len());
+        let mut final_functions = Vec::with_capacity(aggregate.aggregate_functions.len());
+        let mut casts = vec![];
+        for item in aggregate.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(function) = &item.scalar else {
+                return Ok(None);
+            };
+            // The partial results of COUNT are summed up, the others are aggregated again.
+            let final_name = match function.func_name.as_str() {
+                "count" => "sum",
+                "sum" | "min" | "max" => function.func_name.as_str(),
+                _ => return Ok(None),
+            };
+            if function.distinct || !function.params.is_empty() {
+                return Ok(None);
+            }
+
+            let partial_type = *function.return_type.clone();
+            let partial_index = self.metadata.write().add_derived_column(
+                format!("_eager_{}", function.display_name),
+                partial_type.clone(),
+            );
+            partial_functions.push(ScalarItem {
+                scalar: item.scalar.clone(),
+                index: partial_index,
+            });
+
+            let final_type = function_factory
+                .get(final_name, vec![], vec![partial_type.clone()])?
+                .return_type()?;
+            let final_index = if final_type == partial_type {
+                item.index
+            } else {
+                let final_index = self.metadata.write().add_derived_column(
+                    format!("_eager_final_{}", function.display_name),
+                    final_type.clone(),
+                );
+                casts.push(ScalarItem {
+                    scalar: wrap_cast(
+                        &ScalarExpr::BoundColumnRef(BoundColumnRef {
+                            span: None,
+                            column: ColumnBindingBuilder::new(
+                                format!("_eager_final_{}", function.display_name),
+                                final_index,
+                                Box::new(final_type.clone()),
+                                Visibility::Visible,
+                            )
+                            .build(),
+                        }),
+                        &partial_type,
+                    ),
+                    index: item.index,
+                });
+                final_index
+            };
+            final_functions.push(ScalarItem {
+                scalar: ScalarExpr::AggregateFunction(AggregateFunction {
+                    func_name: final_name.to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: None,
+                        column: ColumnBindingBuilder::new(
+                            format!("_eager_{}", function.display_name),
+                            partial_index,
+                            Box::new(partial_type),
+                            Visibility::Visible,
+                        )
+                        .build(),
+                    })],
+                    return_type: Box::new(final_type),
+                    display_name: function.display_name.clone(),
+                }),
+                index: final_index,
+            });
+        }
+
+        let partial_expr = SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    group_items,
+                    aggregate_functions: partial_functions,
+                    ..aggregate.clone()
+                }
+                .into(),
+            ),
+            Arc::new(side_expr),
+        );
+        let mut children = vec![
+            Arc::new(join_expr.child(0)?.clone()),
+            Arc::new(join_expr.child(1)?.clone()),
+        ];
+        children[side] = Arc::new(partial_expr);
+        let mut result = join_expr.replace_children(children);
+        if !other_items.is_empty() {
+            result = SExpr::create_unary(
+                Arc::new(EvalScalar { items: other_items }.into()),
+                Arc::new(result),
+            );
+        }
+        result = SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    aggregate_functions: final_functions,
+                    ..aggregate.clone()
+                }
+                .into(),
+            ),
+            Arc::new(result),
+        );
+        if !casts.is_empty() {
+            result = SExpr::create_unary(
+                Arc::new(EvalScalar { items: casts }.into()),
+                Arc::new(result),
+            );
+        }
+        Ok(Some(result))
+    }
+}
+
+impl Rule for RulePushDownAggregateJoin {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let aggregate: Aggregate = s_expr.plan().clone().try_into()?;
+        if aggregate.mode != AggregateMode::Initial
+            || aggregate.grouping_sets.is_some()
+            || aggregate.limit.is_some()
+        {
+            return Ok(());
+        }
+
+        let (eval_scalar, join_expr) = match s_expr.child(0)?.plan() {
+            RelOperator::EvalScalar(eval_scalar) => {
+                (eval_scalar.clone(), s_expr.child(0)?.child(0)?)
+            }
+            _ => (EvalScalar { items: vec![] }, s_expr.child(0)?),
+        };
+        let join: Join = join_expr.plan().clone().try_into()?;
+        if join.left_conditions.is_empty()
+            || !join.non_equi_conditions.is_empty()
+            || join.marker_index.is_some()
+            || join.is_lateral
+            || join.need_hold_hash_table
+        {
+            return Ok(());
+        }
+
+        for side in [0, 1] {
+            if let Some(result) =
+                self.push_down(&aggregate, &eval_scalar, join_expr, &join, side)?
+            {
+                state.add_result(result);
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
@@ -48,6 +48,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownFilterProjectSet,
         RuleID::SemiToInnerJoin,
         RuleID::FoldCountAggregate,
+        RuleID::PushDownAggregateJoin,
         RuleID::TryApplyAggIndex,
         RuleID::SplitAggregate,
         RuleID::PushDownFilterScan,
@@ -77,6 +78,7 @@ pub enum RuleID {
     // Rewrite rules
     NormalizeAggregate,
     NormalizeScalarFilter,
+    PushDownAggregateJoin,
     PushDownFilterAggregate,
     PushDownFilterEvalScalar,
     PushDownFilterUnion,
@@ -126,6 +128,7 @@ impl Display for RuleID {
             RuleID::PushDownLimitSort => write!(f, "PushDownLimitSort"),
             RuleID::PushDownLimitAggregate => write!(f, "PushDownLimitAggregate"),
             RuleID::PushDownFilterAggregate => write!(f, "PushDownFilterAggregate"),
+            RuleID::PushDownAggregateJoin => write!(f, "PushDownAggregateJoin"),
             RuleID::PushDownLimitScan => write!(f, "PushDownLimitScan"),
             RuleID::PushDownSortScan => write!(f, "PushDownSortScan"),
             RuleID::PushDownLimitWindow => write!(f, "PushDownLimitWindow"),
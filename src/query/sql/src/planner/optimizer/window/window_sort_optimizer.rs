@@ -0,0 +1,286 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+
+use crate::optimizer::cost::DefaultCostModel;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::Window;
+use crate::IndexType;
+
+#[derive(Clone, Debug, PartialEq)]
+enum SortKey {
+    Column(IndexType),
+    // The derived columns of the windows are different, they are compared by the expressions.
+    Expr(ScalarExpr),
+}
+
+impl From<&ScalarItem> for SortKey {
+    fn from(item: &ScalarItem) -> Self {
+        match &item.scalar {
+            ScalarExpr::BoundColumnRef(column) => SortKey::Column(column.column.index),
+            scalar => SortKey::Expr(scalar.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct SortKeyItem {
+    key: SortKey,
+    asc: bool,
+    nulls_first: bool,
+}
+
+// The order of the input needed by a window: the rows of a partition are next to each
+// other, and they are sorted by the order keys in each partition.
+struct WindowOrdering {
+    partition: Vec<SortKey>,
+    order: Vec<SortKeyItem>,
+}
+
+impl WindowOrdering {
+    fn new(window: &Window) -> Self {
+        let mut partition = Vec::with_capacity(window.partition_by.len());
+        for item in window.partition_by.iter() {
+            let key = SortKey::from(item);
+            if !partition.contains(&key) {
+                partition.push(key);
+            }
+        }
+        // Keep the same defaults as the sort built for the window.
+        let order = window
+            .order_by
+            .iter()
+            .map(|item| SortKeyItem {
+                key: SortKey::from(&item.order_by_item),
+                asc: item.asc.unwrap_or(true),
+                nulls_first: item.nulls_first.unwrap_or(false),
+            })
+            .collect();
+        WindowOrdering { partition, order }
+    }
+
+    fn len(&self) -> usize {
+        self.partition.len() + self.order.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Whether the rows sorted by `items` are in the order of the window, the partition
+    // keys can be sorted in any order and direction.
+    fn is_satisfied_by(&self, items: &[SortKeyItem]) -> bool {
+        if items.len() < self.len() {
+            return false;
+        }
+        let (partition, order) = items[..self.len()].split_at(self.partition.len());
+        partition
+            .iter()
+            .all(|item| self.partition.contains(&item.key))
+            && self
+                .partition
+                .iter()
+                .all(|key| partition.iter().any(|item| &item.key == key))
+            && order == self.order.as_slice()
+    }
+
+    // The sort of the window, the partition keys are laid out in the order of `partition`.
+    fn sort_items(&self, partition: Vec<SortKey>) -> Vec<SortKeyItem> {
+        partition
+            .into_iter()
+            .map(|key| SortKeyItem {
+                key,
+                asc: true,
+                nulls_first: true,
+            })
+            .chain(self.order.iter().cloned())
+            .collect()
+    }
+}
+
+// A group of windows share the sort of the first window.
+struct WindowGroup {
+    windows: Vec<usize>,
+    // The partition keys of the first window, in the order of the shared sort.
+    partition: Vec<SortKey>,
+}
+
+// The WindowSortOptimizer lets the windows with compatible partition and order keys share a sort,
+// for example: select sum(c) over (partition by a), rank() over (partition by a order by b) from t
+//
+//    Window(rank)                      Window(sum, presorted)
+//        |                                     |
+//    Window(sum)           =>             Window(rank)
+//        |                                     |
+//      Scan                                  Scan
+//
+// The input is sorted by (a, b) for `rank`, the partitions of `sum` are already next to each
+// other in this order. The consecutive windows are grouped by the sort order that satisfies
+// most windows, the cost model decides the order when several orders satisfy the same windows.
+pub struct WindowSortOptimizer {
+    cost_model: DefaultCostModel,
+}
+
+impl WindowSortOptimizer {
+    pub fn new(ctx: Arc<dyn TableContext>) -> Result<Self> {
+        Ok(WindowSortOptimizer {
+            cost_model: DefaultCostModel::new(ctx)?,
+        })
+    }
+
+    pub fn run(&self, s_expr: &SExpr) -> Result<SExpr> {
+        if let RelOperator::Window(window) = s_expr.plan() {
+            if window.limit.is_none() {
+                return self.optimize_windows(s_expr);
+            }
+        }
+
+        let children = s_expr
+            .children()
+            .map(|child| Ok(Arc::new(self.run(child)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn optimize_windows(&self, s_expr: &SExpr) -> Result<SExpr> {
+        // The windows with a pushed down limit only sort the top rows, their sorts can't be shared.
+        let mut windows = vec![];
+        let mut input = s_expr;
+        while let RelOperator::Window(window) = input.plan() {
+            if window.limit.is_some() {
+                break;
+            }
+            windows.push(window.clone());
+            input = input.child(0)?;
+        }
+        // Bottom up, in the order of execution.
+        windows.reverse();
+        let input = self.run(input)?;
+
+        let groups = self.group_windows(&windows, &input)?;
+        if groups.iter().all(|group| group.windows.len() == 1) {
+            return Ok(self.build_windows(windows.into_iter(), input));
+        }
+
+        let mut grouped = vec![false; windows.len()];
+        let mut ordered_windows = Vec::with_capacity(windows.len());
+        for group in groups {
+            for (i, index) in group.windows.iter().enumerate() {
+                let mut window = windows[*index].clone();
+                window.presorted = i > 0;
+                if i == 0 {
+                    let position = |item: &ScalarItem| {
+                        let key = SortKey::from(item);
+                        group.partition.iter().position(|k| k == &key)
+                    };
+                    window.partition_by.sort_by_key(position);
+                }
+                grouped[*index] = true;
+                ordered_windows.push(window);
+            }
+        }
+        // The windows without partition and order keys don't need a sort.
+        for (window, grouped) in windows.iter().zip(grouped) {
+            if !grouped {
+                ordered_windows.push(window.clone());
+            }
+        }
+        Ok(self.build_windows(ordered_windows.into_iter(), input))
+    }
+
+    fn group_windows(&self, windows: &[Window], input: &SExpr) -> Result<Vec<WindowGroup>> {
+        let cardinality = RelExpr::with_s_expr(input)
+            .derive_cardinality()?
+            .cardinality;
+        let orderings = windows.iter().map(WindowOrdering::new).collect::<Vec<_>>();
+        let mut remaining = (0..windows.len())
+            .filter(|i| !orderings[*i].is_empty())
+            .collect::<Vec<_>>();
+
+        let mut groups = vec![];
+        while !remaining.is_empty() {
+            let mut best: Option<(f64, WindowGroup)> = None;
+            for head in remaining.iter() {
+                let partition = self.layout_partition(&orderings, &remaining, *head);
+                let items = orderings[*head].sort_items(partition.clone());
+                // The head is the first window of the group, it sorts the input for the group.
+                let mut members = vec![*head];
+                let mut cost = self.cost_model.compute_cost_sort(cardinality, items.len());
+                for i in remaining.iter().copied().filter(|i| i != head) {
+                    if orderings[i].is_satisfied_by(&items) {
+                        members.push(i);
+                    } else {
+                        // The window not in the group sorts the input by itself.
+                        cost += self
+                            .cost_model
+                            .compute_cost_sort(cardinality, orderings[i].len());
+                    }
+                }
+                if best
+                    .as_ref()
+                    .map_or(true, |(best_cost, _)| cost < *best_cost)
+                {
+                    best = Some((cost, WindowGroup {
+                        windows: members,
+                        partition,
+                    }));
+                }
+            }
+
+            let (_, group) = best.unwrap();
+            remaining.retain(|i| !group.windows.contains(i));
+            groups.push(group);
+        }
+        Ok(groups)
+    }
+
+    // Lay out the partition keys of `head`, the keys of the windows partitioned by fewer
+    // of them go first, so the windows find their partitions in the prefix of the sort.
+    fn layout_partition(
+        &self,
+        orderings: &[WindowOrdering],
+        remaining: &[usize],
+        head: usize,
+    ) -> Vec<SortKey> {
+        let head_partition = &orderings[head].partition;
+        let mut subsets = remaining
+            .iter()
+            .map(|i| &orderings[*i].partition)
+            .filter(|partition| partition.iter().all(|key| head_partition.contains(key)))
+            .collect::<Vec<_>>();
+        subsets.sort_by_key(|partition| partition.len());
+
+        let mut partition = Vec::with_capacity(head_partition.len());
+        for key in subsets.into_iter().flatten().chain(head_partition.iter()) {
+            if !partition.contains(key) {
+                partition.push(key.clone());
+            }
+        }
+        partition
+    }
+
+    fn build_windows(&self, windows: impl Iterator<Item = Window>, input: SExpr) -> SExpr {
+        windows.fold(input, |input, window| {
+            SExpr::create_unary(Arc::new(RelOperator::Window(window)), Arc::new(input))
+        })
+    }
+}
@@ -0,0 +1,492 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::JoinOutline;
+use databend_common_meta_app::principal::JoinOutlineDistribution;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::principal::PlanBaselineUsage;
+use databend_common_meta_types::NonEmptyString;
+use databend_common_users::UserApiProvider;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+use log::warn;
+use parking_lot::RwLock;
+
+use crate::optimizer::JoinHints;
+use crate::optimizer::JoinTree;
+use crate::optimizer::SExpr;
+use crate::plans::Exchange;
+use crate::plans::Join;
+use crate::plans::JoinDistribution;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::ParameterizedQuery;
+use crate::TableEntry;
+
+/// A query identified by the fingerprint of its plan baseline.
+#[derive(Clone, Debug)]
+pub struct PlanBaselineQuery {
+    pub fingerprint: String,
+    /// The normalized query, the literals are rendered as `?`.
+    pub query: String,
+    pub catalog: String,
+    pub database: String,
+}
+
+impl PlanBaselineQuery {
+    /// Normalizes the query in the same way as the plan cache. The query is
+    /// formatted and parsed again first, so the spaces and the cases of keywords
+    /// don't change the fingerprint. The join hints are removed, so the plan of a
+    /// hinted query can be pinned for the query without hints. Returns `None` if
+    /// the query can't have a baseline.
+    pub fn try_create(ctx: &dyn TableContext, query: &Query) -> Result<Option<Self>> {
+        let mut query = query.clone();
+        query.drive_mut(&mut JoinHintRemover);
+        let sql = query.to_string();
+        let tokens = tokenize_sql(&sql)?;
+        let (stmt, _) = parse_sql(&tokens, ctx.get_settings().get_sql_dialect()?)?;
+        let parameterized = match ParameterizedQuery::try_create(ctx, &tokens, &stmt)? {
+            Some(parameterized) => parameterized,
+            None => return Ok(None),
+        };
+
+        let catalog = ctx.get_current_catalog();
+        let database = ctx.get_current_database();
+        Ok(Some(PlanBaselineQuery {
+            fingerprint: PlanBaseline::fingerprint(&catalog, &database, parameterized.query()),
+            query: parameterized.query().to_string(),
+            catalog,
+            database,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_baseline(&self, ctx: &dyn TableContext) -> Result<Option<PlanBaseline>> {
+        UserApiProvider::instance()
+            .get_plan_baseline(&ctx.get_tenant(), &self.fingerprint)
+            .await
+    }
+}
+
+#[derive(VisitorMut)]
+#[visitor(SelectStmt(enter))]
+struct JoinHintRemover;
+
+impl JoinHintRemover {
+    fn enter_select_stmt(&mut self, stmt: &mut SelectStmt) {
+        if let Some(hints) = &mut stmt.hints {
+            hints.join_hints.clear();
+            if hints.hints_list.is_empty() {
+                stmt.hints = None;
+            }
+        }
+    }
+}
+
+/// Plans the bound query in the join order and distribution of its baseline,
+/// the baseline is ignored with a warning if its tables can't be found.
+pub fn apply_plan_baseline(
+    ctx: &dyn TableContext,
+    metadata: &MetadataRef,
+    baseline: &PlanBaseline,
+) {
+    let join_trees = {
+        let metadata = metadata.read();
+        baseline
+            .outline
+            .iter()
+            .map(|outline| resolve_join_outline(&metadata, outline))
+            .collect::<Result<Vec<_>>>()
+    };
+
+    match join_trees {
+        Ok(join_trees) => {
+            metadata.write().add_join_hints(JoinHints {
+                join_trees,
+                ..Default::default()
+            });
+            PlanBaselineStats::instance().record_use(&ctx.get_tenant(), &baseline.fingerprint);
+        }
+        Err(e) => {
+            let msg = format!(
+                "Plan baseline {} is ignored, because {}",
+                baseline.fingerprint,
+                e.message()
+            );
+            warn!("{}", msg);
+            ctx.push_warning(msg);
+        }
+    }
+}
+
+/// Captures the join trees of an optimized plan. Every group of inner and cross
+/// joins is a tree, the other subtrees are the relations of it. The tables are
+/// referred by their aliases or names, which must be unique in the query.
+///
+/// The outline keeps the shape of the join trees only, not the optimized `SExpr`.
+pub fn capture_join_outline(metadata: &Metadata, s_expr: &SExpr) -> Result<Vec<JoinOutline>> {
+    let mut collector = JoinOutlineCollector {
+        metadata,
+        outline: vec![],
+    };
+    collector.collect(s_expr)?;
+    Ok(collector.outline)
+}
+
+struct JoinOutlineCollector<'a> {
+    metadata: &'a Metadata,
+    outline: Vec<JoinOutline>,
+}
+
+impl JoinOutlineCollector<'_> {
+    // Collect the join trees in the expression, returns the tables of it.
+    fn collect(&mut self, s_expr: &SExpr) -> Result<Vec<String>> {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => Ok(vec![self.table_name(scan.table_index)?]),
+            RelOperator::Join(_) => {
+                let tree = self.join_tree(s_expr)?;
+                let tables = outline_tables(&tree);
+                // A tree with a relation of no table, e.g. `VALUES`, can't be resolved.
+                if !has_empty_relation(&tree) {
+                    self.outline.push(tree);
+                }
+                Ok(tables)
+            }
+            _ => {
+                let mut tables = vec![];
+                for child in s_expr.children() {
+                    tables.extend(self.collect(child)?);
+                }
+                Ok(tables)
+            }
+        }
+    }
+
+    fn join_tree(&mut self, s_expr: &SExpr) -> Result<JoinOutline> {
+        let join = Join::try_from(s_expr.plan().clone())?;
+        // Only the inner and cross joins are reordered together.
+        let reorderable = matches!(join.join_type, JoinType::Inner | JoinType::Cross);
+        let mut children = Vec::with_capacity(2);
+        for child in s_expr.children() {
+            children.push(if reorderable {
+                self.tree_node(child)?
+            } else {
+                JoinOutline::Relation {
+                    tables: self.collect(child)?,
+                }
+            });
+        }
+
+        let distribution = match (s_expr.child(0)?.plan(), s_expr.child(1)?.plan()) {
+            (_, RelOperator::Exchange(Exchange::Broadcast)) => {
+                Some(JoinOutlineDistribution::Broadcast)
+            }
+            (
+                RelOperator::Exchange(Exchange::Hash(_)),
+                RelOperator::Exchange(Exchange::Hash(_)),
+            ) => Some(JoinOutlineDistribution::Shuffle),
+            _ => None,
+        };
+        let build = children.pop().unwrap();
+        let probe = children.pop().unwrap();
+        Ok(JoinOutline::Join {
+            probe: Box::new(probe),
+            build: Box::new(build),
+            distribution,
+            hash_join: join.hint.hash_join,
+        })
+    }
+
+    fn tree_node(&mut self, s_expr: &SExpr) -> Result<JoinOutline> {
+        match s_expr.plan() {
+            RelOperator::Exchange(_) | RelOperator::Filter(_) => self.tree_node(s_expr.child(0)?),
+            RelOperator::Join(join)
+                if matches!(join.join_type, JoinType::Inner | JoinType::Cross) =>
+            {
+                self.join_tree(s_expr)
+            }
+            _ => Ok(JoinOutline::Relation {
+                tables: self.collect(s_expr)?,
+            }),
+        }
+    }
+
+    fn table_name(&self, index: IndexType) -> Result<String> {
+        let name = table_ref_name(self.metadata.table(index));
+        let references = self
+            .metadata
+            .tables()
+            .iter()
+            .filter(|table| table_ref_name(table) == name)
+            .count();
+        if references > 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table '{}' is referenced more than once in the query, give each reference a unique alias to create plan baseline",
+                name
+            )));
+        }
+        Ok(name)
+    }
+}
+
+fn table_ref_name(table: &TableEntry) -> String {
+    table
+        .alias_name()
+        .clone()
+        .unwrap_or_else(|| table.name().to_string())
+}
+
+fn outline_tables(outline: &JoinOutline) -> Vec<String> {
+    match outline {
+        JoinOutline::Relation { tables } => tables.clone(),
+        JoinOutline::Join { probe, build, .. } => {
+            let mut tables = outline_tables(probe);
+            tables.extend(outline_tables(build));
+            tables
+        }
+    }
+}
+
+fn has_empty_relation(outline: &JoinOutline) -> bool {
+    match outline {
+        JoinOutline::Relation { tables } => tables.is_empty(),
+        JoinOutline::Join { probe, build, .. } => {
+            has_empty_relation(probe) || has_empty_relation(build)
+        }
+    }
+}
+
+fn resolve_join_outline(metadata: &Metadata, outline: &JoinOutline) -> Result<JoinTree> {
+    match outline {
+        JoinOutline::Relation { tables } => {
+            let tables = tables
+                .iter()
+                .map(|name| {
+                    let mut indexes = metadata
+                        .tables()
+                        .iter()
+                        .filter(|table| table_ref_name(table) == *name)
+                        .map(|table| table.index());
+                    match (indexes.next(), indexes.next()) {
+                        (Some(index), None) => Ok(index),
+                        (None, _) => Err(ErrorCode::UnknownTable(format!(
+                            "table {} is not found",
+                            name
+                        ))),
+                        _ => Err(ErrorCode::SemanticError(format!(
+                            "table {} is referenced more than once",
+                            name
+                        ))),
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(JoinTree::Relation(tables))
+        }
+        JoinOutline::Join {
+            probe,
+            build,
+            distribution,
+            hash_join,
+        } => Ok(JoinTree::Join {
+            probe: Box::new(resolve_join_outline(metadata, probe)?),
+            build: Box::new(resolve_join_outline(metadata, build)?),
+            distribution: distribution.map(|distribution| match distribution {
+                JoinOutlineDistribution::Broadcast => JoinDistribution::Broadcast,
+                JoinOutlineDistribution::Shuffle => JoinDistribution::Shuffle,
+            }),
+            hash_join: *hash_join,
+        }),
+    }
+}
+
+/// How long the fingerprints of the plan baselines of a tenant are cached. The changes made on
+/// this node are seen at once, the ones made on the other nodes of the cluster after this.
+const PLAN_BASELINE_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// How often the usage of the plan baselines on this node is added to the meta.
+const PLAN_BASELINE_USAGE_FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The fingerprints of the plan baselines of a tenant.
+pub struct PlanBaselineFingerprints {
+    /// The seq of each baseline, which changes when the baseline is replaced.
+    fingerprints: HashMap<String, u64>,
+    /// Bumped on every change of the fingerprints seen by this node, the plans cached under
+    /// another generation are planned again.
+    pub generation: u64,
+}
+
+impl PlanBaselineFingerprints {
+    pub fn is_empty(&self) -> bool {
+        self.fingerprints.is_empty()
+    }
+
+    pub fn contains(&self, fingerprint: &str) -> bool {
+        self.fingerprints.contains_key(fingerprint)
+    }
+}
+
+struct CachedFingerprints {
+    fingerprints: Arc<PlanBaselineFingerprints>,
+    /// `None` if the fingerprints are invalidated.
+    cached_at: Option<Instant>,
+}
+
+/// A per-node cache of the fingerprints of the plan baselines, so that the queries without
+/// a baseline are planned without visiting the meta.
+pub struct PlanBaselineCache {
+    cache: RwLock<HashMap<NonEmptyString, CachedFingerprints>>,
+}
+
+impl PlanBaselineCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(PlanBaselineCache {
+            cache: RwLock::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PlanBaselineCache> {
+        GlobalInstance::get()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get(&self, tenant: &NonEmptyString) -> Result<Arc<PlanBaselineFingerprints>> {
+        let generation = match self.cache.read().get(tenant) {
+            Some(cached) => match cached.cached_at {
+                Some(cached_at) if cached_at.elapsed() < PLAN_BASELINE_CACHE_TTL => {
+                    return Ok(cached.fingerprints.clone());
+                }
+                _ => Some(cached.fingerprints.generation),
+            },
+            None => None,
+        };
+
+        let fingerprints = UserApiProvider::instance()
+            .get_plan_baseline_fingerprints(tenant)
+            .await?;
+        let mut cache = self.cache.write();
+        let fingerprints = match cache.get(tenant) {
+            // Reloaded by another query meanwhile.
+            Some(cached) if cached.fingerprints.generation != generation.unwrap_or_default() => {
+                return Ok(cached.fingerprints.clone());
+            }
+            Some(cached) if cached.fingerprints.fingerprints == fingerprints => {
+                cached.fingerprints.clone()
+            }
+            Some(cached) => Arc::new(PlanBaselineFingerprints {
+                fingerprints,
+                generation: cached.fingerprints.generation + 1,
+            }),
+            None => Arc::new(PlanBaselineFingerprints {
+                fingerprints,
+                generation: 0,
+            }),
+        };
+        cache.insert(tenant.clone(), CachedFingerprints {
+            fingerprints: fingerprints.clone(),
+            cached_at: Some(Instant::now()),
+        });
+        Ok(fingerprints)
+    }
+
+    /// Called after the plan baselines of the tenant are changed on this node.
+    pub fn invalidate(&self, tenant: &NonEmptyString) {
+        if let Some(cached) = self.cache.write().get_mut(tenant) {
+            cached.cached_at = None;
+        }
+    }
+}
+
+/// The usage of the plan baselines on this node, which is added to the meta in the
+/// background, the plans reused from plan cache are not counted.
+pub struct PlanBaselineStats {
+    pending: Arc<RwLock<HashMap<(NonEmptyString, String), PlanBaselineUsage>>>,
+}
+
+impl PlanBaselineStats {
+    pub fn init() -> Result<()> {
+        let pending = Arc::new(RwLock::new(HashMap::new()));
+        databend_common_base::runtime::spawn(Self::flush_periodically(pending.clone()));
+        GlobalInstance::set(Arc::new(PlanBaselineStats { pending }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PlanBaselineStats> {
+        GlobalInstance::get()
+    }
+
+    async fn flush_periodically(
+        pending: Arc<RwLock<HashMap<(NonEmptyString, String), PlanBaselineUsage>>>,
+    ) {
+        loop {
+            tokio::time::sleep(PLAN_BASELINE_USAGE_FLUSH_INTERVAL).await;
+            let usages = std::mem::take(&mut *pending.write());
+            for ((tenant, fingerprint), usage) in usages {
+                if let Err(e) = UserApiProvider::instance()
+                    .add_plan_baseline_usage(&tenant, &fingerprint, usage)
+                    .await
+                {
+                    warn!(
+                        "failed to add the usage of plan baseline {}: {}",
+                        fingerprint, e
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn record_use(&self, tenant: &NonEmptyString, fingerprint: &str) {
+        let mut pending = self.pending.write();
+        let usage = pending
+            .entry((tenant.clone(), fingerprint.to_string()))
+            .or_default();
+        usage.merge(&PlanBaselineUsage {
+            uses: 1,
+            last_used_on: Some(Utc::now()),
+        });
+    }
+
+    /// The usage on this node which is not added to the meta yet.
+    pub fn pending_usage(&self, tenant: &NonEmptyString, fingerprint: &str) -> PlanBaselineUsage {
+        self.pending
+            .read()
+            .get(&(tenant.clone(), fingerprint.to_string()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn remove(&self, tenant: &NonEmptyString, fingerprint: &str) {
+        self.pending
+            .write()
+            .remove(&(tenant.clone(), fingerprint.to_string()));
+    }
+}
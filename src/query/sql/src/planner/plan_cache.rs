@@ -0,0 +1,869 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::token::Token;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_base::base::GlobalInstance;
+use databend_common_cache::Cache;
+use databend_common_cache::LruCache;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr as EExpr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_api::deserialize_u64;
+use databend_common_meta_app::principal::UdfName;
+use databend_common_meta_app::schema::DBIdTableName;
+use databend_common_meta_app::schema::DatabaseId;
+use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_meta_app::schema::TableId;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::Key;
+use databend_common_metrics::cache::metrics_inc_cache_access_count;
+use databend_common_metrics::cache::metrics_inc_cache_hit_count;
+use databend_common_metrics::cache::metrics_inc_cache_miss_count;
+use databend_common_users::UserApiProvider;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::literal_to_scalar;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::Exchange;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::plans::VisitorMut;
+use crate::plans::WindowFuncType;
+use crate::NameResolutionContext;
+
+const PLAN_CACHE_NAME: &str = "plan_cache";
+/// The capacity before the first plan is cached, which resizes the cache to the
+/// `plan_cache_capacity` setting of the query.
+const DEFAULT_PLAN_CACHE_CAPACITY: u64 = 1024;
+
+/// A per-node cache of the optimized plans of queries.
+///
+/// Plans are keyed by the query text with the literals compared against
+/// expressions replaced by typed parameters, together with everything else
+/// the binder depends on: the tenant, the current catalog, database, user and
+/// role, the settings and the size of the cluster. So `a = 1` and `a = 2`
+/// share one entry, and the literals are substituted into the cached plan on
+/// a hit.
+///
+/// Every entry records the meta keys of the tables, databases and UDFs it
+/// depends on, with their sequence numbers. A DDL or a commit to any of them
+/// bumps a sequence number, which is checked with one read of the keys every
+/// time the entry is looked up.
+///
+/// The least recently used entries are evicted once the cache holds more
+/// than `plan_cache_capacity` entries.
+pub struct PlanCache {
+    entries: Mutex<LruCache<PlanCacheKey, Arc<PlanCacheEntry>>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct PlanCacheKey {
+    tenant: String,
+    /// The normalized query, parameters are rendered as `?::<type>`.
+    sql: String,
+    /// The fingerprint of the session state the plan depends on.
+    context: u64,
+}
+
+#[derive(Clone, Debug)]
+struct Parameter {
+    span: Span,
+    value: Scalar,
+    data_type: DataType,
+    text: String,
+}
+
+/// A query statement split into its cache key and the literals that act as parameters.
+#[derive(Clone, Debug)]
+pub struct ParameterizedQuery {
+    key: PlanCacheKey,
+    /// The normalized query, parameters are rendered as `?`.
+    query: String,
+    parameters: Vec<Parameter>,
+    /// The tables named in the query as `(catalog, database, table)`, views are
+    /// only visible here since the binder expands them.
+    table_names: Vec<(String, String, String)>,
+}
+
+#[derive(Clone, Debug)]
+struct TableVersion {
+    catalog: String,
+    database: String,
+    name: String,
+    table_id: u64,
+    seq: u64,
+}
+
+/// The value a meta key must have when a plan depending on it is cached.
+enum ExpectedValue {
+    /// The key maps a name to the id.
+    Id(u64),
+    /// The key has the sequence number.
+    Seq(u64),
+    /// The key has any value.
+    Any,
+}
+
+struct PlanCacheEntry {
+    query: String,
+    plan: Plan,
+    parameters: Vec<Parameter>,
+    /// Whether other literals can be substituted into the plan. If not, the plan
+    /// can only be reused by a query with exactly the same literals.
+    parameterized: bool,
+    /// The meta keys the plan depends on.
+    keys: Vec<String>,
+    /// The sequence numbers of `keys` when the plan was cached, `0` if a key didn't exist.
+    seqs: Vec<u64>,
+    /// The generation of the plan baselines the plan is planned under, see
+    /// [`crate::PlanBaselineFingerprints`]. `None` if the plan baselines are disabled.
+    plan_baseline_generation: Option<u64>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    created_on: i64,
+    last_used_on: AtomicI64,
+}
+
+/// A snapshot of a plan cache entry, used by `system.plan_cache`.
+#[derive(Clone, Debug)]
+pub struct PlanCacheItem {
+    pub query: String,
+    pub parameterized: bool,
+    pub hits: u64,
+    pub misses: u64,
+    /// Microseconds since the epoch.
+    pub created_on: i64,
+    /// Microseconds since the epoch.
+    pub last_used_on: i64,
+}
+
+impl PlanCache {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(PlanCache {
+            entries: Mutex::new(LruCache::new(DEFAULT_PLAN_CACHE_CAPACITY)),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PlanCache> {
+        GlobalInstance::get()
+    }
+
+    /// Returns a plan for `query`, or `None` if there is no reusable plan in the cache.
+    #[async_backtrace::framed]
+    pub async fn get(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        query: &ParameterizedQuery,
+        plan_baseline_generation: Option<u64>,
+    ) -> Result<Option<Plan>> {
+        metrics_inc_cache_access_count(1, PLAN_CACHE_NAME);
+        let entry = if has_session_state(ctx.as_ref()) {
+            None
+        } else {
+            self.entries
+                .lock()
+                .get(&query.key)
+                .filter(|entry| entry.plan_baseline_generation == plan_baseline_generation)
+                .cloned()
+        };
+        let plan = match &entry {
+            Some(entry) => entry.instantiate(ctx, query).await?,
+            None => None,
+        };
+
+        match (&plan, entry) {
+            (Some(_), Some(entry)) => {
+                metrics_inc_cache_hit_count(1, PLAN_CACHE_NAME);
+                entry.hits.fetch_add(1, Ordering::Relaxed);
+                entry
+                    .last_used_on
+                    .store(Utc::now().timestamp_micros(), Ordering::Relaxed);
+            }
+            _ => metrics_inc_cache_miss_count(1, PLAN_CACHE_NAME),
+        }
+        Ok(plan)
+    }
+
+    /// Caches the optimized plan of `query`, if the plan can be reused.
+    #[async_backtrace::framed]
+    pub async fn insert(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        query: ParameterizedQuery,
+        plan: &Plan,
+        plan_baseline_generation: Option<u64>,
+    ) -> Result<()> {
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            ..
+        } = plan
+        else {
+            return Ok(());
+        };
+        // The binder marks the query as not cacheable if the result depends on
+        // more than the query text, e.g. `now()` or `rand()`.
+        if !ctx.get_cacheable() {
+            return Ok(());
+        }
+        // The materialized ctes are registered in the context of the query that built the plan.
+        if !ctx.get_materialized_ctes().read().is_empty() {
+            return Ok(());
+        }
+        if has_session_state(ctx.as_ref()) {
+            return Ok(());
+        }
+        let capacity = ctx.get_settings().get_plan_cache_capacity()?;
+        if capacity == 0 {
+            return Ok(());
+        }
+
+        let mut tables = Vec::new();
+        {
+            let metadata = metadata.read();
+            if metadata.has_agg_indexes() {
+                return Ok(());
+            }
+            for entry in metadata.tables() {
+                if entry.is_source_of_stage() || entry.is_source_of_index() {
+                    return Ok(());
+                }
+                let table_info = entry.table().get_table_info().clone();
+                // Masking policies and row access policies are resolved against the current role.
+                if table_info
+                    .meta
+                    .column_mask_policy
+                    .as_ref()
+                    .map_or(false, |policies| !policies.is_empty())
+                    || table_info.meta.row_access_policy.is_some()
+                {
+                    return Ok(());
+                }
+                tables.push(TableVersion {
+                    catalog: entry.catalog().to_string(),
+                    database: entry.database().to_string(),
+                    name: entry.name().to_string(),
+                    table_id: table_info.ident.table_id,
+                    seq: table_info.ident.seq,
+                });
+            }
+        }
+
+        // Names that can't be resolved are CTEs.
+        for (catalog, database, name) in &query.table_names {
+            if tables.iter().any(|table| {
+                &table.catalog == catalog && &table.database == database && &table.name == name
+            }) {
+                continue;
+            }
+            if let Ok(table) = ctx.get_table(catalog, database, name).await {
+                let ident = &table.get_table_info().ident;
+                tables.push(TableVersion {
+                    catalog: catalog.clone(),
+                    database: database.clone(),
+                    name: name.clone(),
+                    table_id: ident.table_id,
+                    seq: ident.seq,
+                });
+            }
+        }
+        // The tables of other catalogs are not versioned by the meta service.
+        if tables.iter().any(|table| table.catalog != CATALOG_DEFAULT) {
+            return Ok(());
+        }
+
+        // A table depends on the key mapping its name to its id and on the key of its meta,
+        // so a rename, a drop or a commit changes one of them. The same goes for databases.
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let mut db_ids = HashMap::new();
+        let mut dependencies = Vec::new();
+        for table in &tables {
+            let db_id = match db_ids.get(&table.database) {
+                Some(db_id) => *db_id,
+                None => {
+                    let ident = catalog
+                        .get_database(tenant.as_str(), &table.database)
+                        .await?
+                        .get_db_info()
+                        .ident
+                        .clone();
+                    dependencies.push((
+                        DatabaseNameIdent::new(tenant.as_str(), &table.database).to_string_key(),
+                        ExpectedValue::Id(ident.db_id),
+                    ));
+                    dependencies.push((
+                        DatabaseId::new(ident.db_id).to_string_key(),
+                        ExpectedValue::Seq(ident.seq),
+                    ));
+                    db_ids.insert(table.database.clone(), ident.db_id);
+                    ident.db_id
+                }
+            };
+            dependencies.push((
+                DBIdTableName {
+                    db_id,
+                    table_name: table.name.clone(),
+                }
+                .to_string_key(),
+                ExpectedValue::Id(table.table_id),
+            ));
+            dependencies.push((
+                TableId::new(table.table_id).to_string_key(),
+                ExpectedValue::Seq(table.seq),
+            ));
+        }
+        // The UDFs are expanded by the binder, including the UDFs called by other UDFs and views.
+        for udf in metadata.read().udfs() {
+            dependencies.push((
+                UdfName::new(tenant.as_str(), udf).to_string_key(),
+                ExpectedValue::Any,
+            ));
+        }
+
+        // Table functions, time travel and stream tables are bound to table objects that are not
+        // the current version of a named table, and system tables are not stored in the meta
+        // service. Only cache the plan if every table is the one a new query would resolve.
+        let (keys, expected): (Vec<_>, Vec<_>) = dependencies.into_iter().unzip();
+        let values = UserApiProvider::instance()
+            .get_meta_store_client()
+            .mget_kv(&keys)
+            .await?;
+        let mut seqs = Vec::with_capacity(keys.len());
+        for (expected, value) in expected.iter().zip(values.iter()) {
+            let is_expected = match (expected, value) {
+                (ExpectedValue::Id(id), Some(value)) => {
+                    deserialize_u64(&value.data).map_or(false, |value| *value == *id)
+                }
+                (ExpectedValue::Seq(seq), Some(value)) => value.seq == *seq,
+                (ExpectedValue::Any, _) => true,
+                _ => false,
+            };
+            if !is_expected {
+                return Ok(());
+            }
+            seqs.push(value.as_ref().map_or(0, |value| value.seq));
+        }
+
+        let mut collector = ConstantSpanCollector::default();
+        rewrite_s_expr(&mut collector, s_expr)?;
+        let parameterized = query.parameters.iter().all(|parameter| {
+            collector.contains(parameter.span)
+                && !bind_context
+                    .columns
+                    .iter()
+                    .any(|column| column.column_name.contains(&parameter.text))
+        });
+
+        let now = Utc::now().timestamp_micros();
+        let mut entries = self.entries.lock();
+        if entries.capacity() != capacity as u64 {
+            entries.set_capacity(capacity as u64);
+        }
+        let (hits, misses) = match entries.peek(&query.key) {
+            Some(entry) => (
+                entry.hits.load(Ordering::Relaxed),
+                entry.misses.load(Ordering::Relaxed),
+            ),
+            None => (0, 0),
+        };
+
+        entries.put(
+            query.key,
+            Arc::new(PlanCacheEntry {
+                query: query.query,
+                plan: plan.clone(),
+                parameters: query.parameters,
+                parameterized,
+                keys,
+                seqs,
+                plan_baseline_generation,
+                hits: AtomicU64::new(hits),
+                misses: AtomicU64::new(misses + 1),
+                created_on: now,
+                last_used_on: AtomicI64::new(now),
+            }),
+        );
+        Ok(())
+    }
+
+    /// Returns the entries of the tenant.
+    pub fn items(&self, tenant: &str) -> Vec<PlanCacheItem> {
+        self.entries
+            .lock()
+            .iter()
+            .filter(|(key, _)| key.tenant == tenant)
+            .map(|(_, entry)| PlanCacheItem {
+                query: entry.query.clone(),
+                parameterized: entry.parameterized,
+                hits: entry.hits.load(Ordering::Relaxed),
+                misses: entry.misses.load(Ordering::Relaxed),
+                created_on: entry.created_on,
+                last_used_on: entry.last_used_on.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+}
+
+impl PlanCacheEntry {
+    async fn instantiate(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        query: &ParameterizedQuery,
+    ) -> Result<Option<Plan>> {
+        let Plan::Query {
+            s_expr,
+            metadata,
+            bind_context,
+            rewrite_kind,
+            ignore_result,
+            ..
+        } = &self.plan
+        else {
+            return Ok(None);
+        };
+
+        let same_parameters = self
+            .parameters
+            .iter()
+            .zip(query.parameters.iter())
+            .all(|(cached, parameter)| cached.value == parameter.value);
+        if !same_parameters && !self.parameterized {
+            return Ok(None);
+        }
+        if !self.is_valid().await? {
+            return Ok(None);
+        }
+
+        let s_expr = if same_parameters {
+            s_expr.clone()
+        } else {
+            let mut binder = ParameterBinder {
+                func_ctx: ctx.get_function_context()?,
+                parameters: self
+                    .parameters
+                    .iter()
+                    .zip(query.parameters.iter())
+                    .filter_map(|(cached, parameter)| Some((span_key(cached.span)?, parameter)))
+                    .collect(),
+            };
+            match rewrite_s_expr(&mut binder, s_expr) {
+                Ok(s_expr) => Box::new(s_expr),
+                // The new literals don't fit the types of the plan, plan the query again.
+                Err(_) => return Ok(None),
+            }
+        };
+
+        Ok(Some(Plan::Query {
+            s_expr,
+            metadata: Arc::new(RwLock::new(metadata.read().clone())),
+            bind_context: bind_context.clone(),
+            rewrite_kind: rewrite_kind.clone(),
+            formatted_ast: None,
+            ignore_result: *ignore_result,
+        }))
+    }
+
+    async fn is_valid(&self) -> Result<bool> {
+        let values = UserApiProvider::instance()
+            .get_meta_store_client()
+            .mget_kv(&self.keys)
+            .await?;
+        Ok(self
+            .seqs
+            .iter()
+            .zip(values.iter())
+            .all(|(seq, value)| value.as_ref().map_or(0, |value| value.seq) == *seq))
+    }
+}
+
+/// Temporary tables and the uncommitted changes of a transaction are only visible to the
+/// session, the cached plans are not resolved against them.
+fn has_session_state(ctx: &dyn TableContext) -> bool {
+    ctx.txn_mgr().lock().is_active() || !ctx.temp_tbl_mgr().lock().is_empty()
+}
+
+impl ParameterizedQuery {
+    /// The normalized query, parameters are rendered as `?`.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Splits a query statement into the cache key and the parameters.
+    /// Returns `None` if the statement is not a cacheable query.
+    pub fn try_create(
+        ctx: &dyn TableContext,
+        tokens: &[Token],
+        stmt: &Statement,
+    ) -> Result<Option<Self>> {
+        if !matches!(stmt, Statement::Query(_)) {
+            return Ok(None);
+        }
+        let mut collector = ParameterCollector {
+            name_resolution_ctx: NameResolutionContext::try_from(ctx.get_settings().as_ref())?,
+            current_catalog: ctx.get_current_catalog(),
+            current_database: ctx.get_current_database(),
+            literals: HashMap::new(),
+            table_names: Vec::new(),
+            time_travel: false,
+        };
+        stmt.drive(&mut collector);
+        if collector.time_travel {
+            return Ok(None);
+        }
+
+        let mut sql = String::new();
+        let mut query = String::new();
+        let mut parameters = Vec::new();
+        for token in tokens.iter().filter(|token| token.kind != TokenKind::EOI) {
+            if !sql.is_empty() {
+                sql.push(' ');
+                query.push(' ');
+            }
+            match collector.literals.get(&(token.span.start, token.span.end)) {
+                Some(literal) => {
+                    let (value, data_type) = literal_to_scalar(literal);
+                    write!(sql, "?::{data_type}").unwrap();
+                    query.push('?');
+                    parameters.push(Parameter {
+                        span: Some(token.span),
+                        value,
+                        data_type,
+                        text: token.text().to_string(),
+                    });
+                }
+                None => {
+                    sql.push_str(token.text());
+                    query.push_str(token.text());
+                }
+            }
+        }
+
+        Ok(Some(ParameterizedQuery {
+            key: PlanCacheKey {
+                tenant: ctx.get_tenant().to_string(),
+                sql,
+                context: context_fingerprint(ctx)?,
+            },
+            query,
+            parameters,
+            table_names: collector.table_names,
+        }))
+    }
+}
+
+fn context_fingerprint(ctx: &dyn TableContext) -> Result<u64> {
+    let mut hasher = DefaultHasher::new();
+    ctx.get_current_catalog().hash(&mut hasher);
+    ctx.get_current_database().hash(&mut hasher);
+    ctx.get_current_user()?
+        .identity()
+        .to_string()
+        .hash(&mut hasher);
+    ctx.get_current_role()
+        .map(|role| role.name)
+        .hash(&mut hasher);
+    ctx.get_cluster().nodes.len().hash(&mut hasher);
+    for setting in ctx.get_settings().as_ref() {
+        setting.name.hash(&mut hasher);
+        setting.user_value.as_string().hash(&mut hasher);
+    }
+    Ok(hasher.finish())
+}
+
+fn span_key(span: Span) -> Option<(u32, u32)> {
+    span.map(|span| (span.start, span.end))
+}
+
+/// Collects the literals that can be parameterized: the literals compared
+/// against other expressions, the bounds of `BETWEEN` and the items of `IN`.
+/// Other literals, such as `LIMIT` or the pattern of `LIKE`, shape the plan.
+#[derive(Visitor)]
+#[visitor(Expr(enter), TableReference(enter))]
+struct ParameterCollector {
+    name_resolution_ctx: NameResolutionContext,
+    current_catalog: String,
+    current_database: String,
+    literals: HashMap<(u32, u32), Literal>,
+    table_names: Vec<(String, String, String)>,
+    time_travel: bool,
+}
+
+impl ParameterCollector {
+    fn enter_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::BinaryOp {
+                op:
+                    BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::Gt
+                    | BinaryOperator::Gte
+                    | BinaryOperator::Lt
+                    | BinaryOperator::Lte,
+                left,
+                right,
+                ..
+            } => match (left.as_ref(), right.as_ref()) {
+                (Expr::Literal { .. }, Expr::Literal { .. }) => {}
+                (literal @ Expr::Literal { .. }, _) | (_, literal @ Expr::Literal { .. }) => {
+                    self.add(literal)
+                }
+                _ => {}
+            },
+            Expr::Between { low, high, .. } => {
+                self.add(low);
+                self.add(high);
+            }
+            Expr::InList { list, .. } => {
+                for item in list {
+                    self.add(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_table_reference(&mut self, table: &TableReference) {
+        if let TableReference::Table {
+            catalog,
+            database,
+            table,
+            travel_point,
+            since_point,
+            ..
+        } = table
+        {
+            if travel_point.is_some() || since_point.is_some() {
+                self.time_travel = true;
+            }
+            let normalize =
+                |ident: &Identifier| normalize_identifier(ident, &self.name_resolution_ctx).name;
+            let catalog = catalog
+                .as_ref()
+                .map_or_else(|| self.current_catalog.clone(), normalize);
+            let database = database
+                .as_ref()
+                .map_or_else(|| self.current_database.clone(), normalize);
+            self.table_names.push((catalog, database, normalize(table)));
+        }
+    }
+
+    fn add(&mut self, expr: &Expr) {
+        if let Expr::Literal {
+            span: Some(span),
+            lit,
+        } = expr
+        {
+            if !matches!(lit, Literal::Null) {
+                self.literals.insert((span.start, span.end), lit.clone());
+            }
+        }
+    }
+}
+
+/// Rebuilds `s_expr` with every scalar expression of the operators visited by `visitor`.
+fn rewrite_s_expr<V>(visitor: &mut V, s_expr: &SExpr) -> Result<SExpr>
+where V: for<'a> VisitorMut<'a> {
+    let children = s_expr
+        .children()
+        .map(|child| Ok(Arc::new(rewrite_s_expr(visitor, child)?)))
+        .collect::<Result<Vec<_>>>()?;
+    let mut plan = s_expr.plan().clone();
+    match &mut plan {
+        RelOperator::Scan(scan) => {
+            if let Some(predicates) = &mut scan.push_down_predicates {
+                for predicate in predicates {
+                    visitor.visit(predicate)?;
+                }
+            }
+            if let Some(prewhere) = &mut scan.prewhere {
+                for predicate in &mut prewhere.predicates {
+                    visitor.visit(predicate)?;
+                }
+            }
+        }
+        RelOperator::Join(join) => {
+            for condition in join
+                .left_conditions
+                .iter_mut()
+                .chain(join.right_conditions.iter_mut())
+                .chain(join.non_equi_conditions.iter_mut())
+            {
+                visitor.visit(condition)?;
+            }
+        }
+        RelOperator::EvalScalar(eval_scalar) => {
+            for item in &mut eval_scalar.items {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Filter(filter) => {
+            for predicate in &mut filter.predicates {
+                visitor.visit(predicate)?;
+            }
+        }
+        RelOperator::Aggregate(aggregate) => {
+            for item in aggregate
+                .group_items
+                .iter_mut()
+                .chain(aggregate.aggregate_functions.iter_mut())
+            {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Window(window) => {
+            for item in window
+                .arguments
+                .iter_mut()
+                .chain(window.partition_by.iter_mut())
+            {
+                visitor.visit(&mut item.scalar)?;
+            }
+            for order_by in &mut window.order_by {
+                visitor.visit(&mut order_by.order_by_item.scalar)?;
+            }
+            match &mut window.function {
+                WindowFuncType::Aggregate(func) => visitor.visit_aggregate_function(func)?,
+                WindowFuncType::NthValue(func) => visitor.visit(&mut func.arg)?,
+                WindowFuncType::LagLead(func) => {
+                    visitor.visit(&mut func.arg)?;
+                    if let Some(default) = func.default.as_mut() {
+                        visitor.visit(default)?
+                    }
+                }
+                _ => {}
+            }
+        }
+        RelOperator::ProjectSet(project_set) => {
+            for srf in &mut project_set.srfs {
+                visitor.visit(&mut srf.scalar)?;
+            }
+        }
+        RelOperator::Udf(udf) => {
+            for item in &mut udf.items {
+                visitor.visit(&mut item.scalar)?;
+            }
+        }
+        RelOperator::Exchange(Exchange::Hash(keys)) => {
+            for key in keys {
+                visitor.visit(key)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(s_expr
+        .replace_children(children)
+        .replace_plan(Arc::new(plan)))
+}
+
+#[derive(Default)]
+struct ConstantSpanCollector {
+    spans: HashSet<(u32, u32)>,
+}
+
+impl ConstantSpanCollector {
+    fn contains(&self, span: Span) -> bool {
+        span_key(span).map_or(false, |span| self.spans.contains(&span))
+    }
+}
+
+impl<'a> VisitorMut<'a> for ConstantSpanCollector {
+    fn visit_constant_expr(&mut self, constant: &'a mut ConstantExpr) -> Result<()> {
+        if let Some(span) = span_key(constant.span) {
+            self.spans.insert(span);
+        }
+        Ok(())
+    }
+}
+
+/// Replaces the constants that come from the parameters of the cached query
+/// with the literals of the new query.
+struct ParameterBinder<'b> {
+    func_ctx: FunctionContext,
+    parameters: HashMap<(u32, u32), &'b Parameter>,
+}
+
+impl<'a, 'b> VisitorMut<'a> for ParameterBinder<'b> {
+    fn visit_constant_expr(&mut self, constant: &'a mut ConstantExpr) -> Result<()> {
+        let Some(parameter) = span_key(constant.span).and_then(|span| self.parameters.get(&span))
+        else {
+            return Ok(());
+        };
+        let data_type = constant.value.as_ref().infer_data_type();
+        constant.value = if parameter.data_type == data_type {
+            parameter.value.clone()
+        } else {
+            let expr: EExpr = check_cast(
+                None,
+                false,
+                EExpr::Constant {
+                    span: None,
+                    scalar: parameter.value.clone(),
+                    data_type: parameter.data_type.clone(),
+                },
+                &data_type,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            match ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS) {
+                (EExpr::Constant { scalar, .. }, _) => scalar,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "cannot cast parameter {} to {data_type}",
+                        parameter.text
+                    )));
+                }
+            }
+        };
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::format_statement;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Statement;
@@ -29,10 +30,14 @@ use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use derive_visitor::DriveMut;
+use log::warn;
 use parking_lot::RwLock;
 
+use super::plan_cache::ParameterizedQuery;
+use super::plan_cache::PlanCache;
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
+use crate::apply_plan_baseline;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::Insert;
@@ -42,6 +47,8 @@ use crate::Binder;
 use crate::Metadata;
 use crate::MetadataRef;
 use crate::NameResolutionContext;
+use crate::PlanBaselineCache;
+use crate::PlanBaselineQuery;
 
 const PROBE_INSERT_INITIAL_TOKENS: usize = 128;
 const PROBE_INSERT_MAX_TOKENS: usize = 128 * 8;
@@ -108,8 +115,99 @@ impl Planner {
                         .attach_query_str(QueryKind::CopyIntoTable, String::new());
                 }
 
+                // The cache key is taken from the statement as written, before it is rewritten.
+                let plan_cache_query =
+                    if !is_insert_or_replace_stmt && settings.get_enable_plan_cache()? {
+                        ParameterizedQuery::try_create(self.ctx.as_ref(), &tokens, &stmt)?
+                    } else {
+                        None
+                    };
+                // The query is only fingerprinted if the tenant has any plan baseline, the
+                // baselines are an optimization, a failure to look them up is not an error.
+                let enable_plan_baseline =
+                    !is_insert_or_replace_stmt && settings.get_enable_plan_baseline()?;
+                let plan_baselines = if enable_plan_baseline {
+                    match PlanBaselineCache::instance()
+                        .get(&self.ctx.get_tenant())
+                        .await
+                    {
+                        Ok(plan_baselines) => Some(plan_baselines),
+                        Err(e) => {
+                            warn!("failed to load the plan baselines: {}", e);
+                            None
+                        }
+                    }
+                } else {
+                    None
+                };
+                let plan_baseline_query = match (&plan_baselines, &stmt) {
+                    (Some(plan_baselines), _) if plan_baselines.is_empty() => None,
+                    // The explained query is planned in the same way as it runs.
+                    (
+                        Some(plan_baselines),
+                        Statement::Query(query)
+                        | Statement::Explain {
+                            query: box Statement::Query(query),
+                            ..
+                        }
+                        | Statement::ExplainAnalyze {
+                            query: box Statement::Query(query),
+                        },
+                    ) => match PlanBaselineQuery::try_create(self.ctx.as_ref(), query) {
+                        Ok(query) => {
+                            query.filter(|query| plan_baselines.contains(&query.fingerprint))
+                        }
+                        Err(e) => {
+                            warn!("failed to fingerprint the query for plan baseline: {}", e);
+                            None
+                        }
+                    },
+                    _ => None,
+                };
+                // The cached plans are planned under the baselines of a generation.
+                let plan_baseline_generation = plan_baselines
+                    .as_ref()
+                    .map(|plan_baselines| plan_baselines.generation);
+                let plan_cache_query =
+                    plan_cache_query.filter(|_| !enable_plan_baseline || plan_baselines.is_some());
+
                 self.replace_stmt(&mut stmt, sql_dialect);
 
+                if let Some(query) = &plan_cache_query {
+                    if let Some(mut plan) = PlanCache::instance()
+                        .get(&self.ctx, query, plan_baseline_generation)
+                        .await?
+                    {
+                        if let Plan::Query {
+                            metadata,
+                            formatted_ast,
+                            ..
+                        } = &mut plan
+                        {
+                            if settings.get_enable_query_result_cache()? {
+                                *formatted_ast = Some(format_statement(stmt.clone())?);
+                            }
+                            let metadata = metadata.clone();
+                            return Ok((plan, PlanExtras {
+                                metadata,
+                                format,
+                                statement: stmt,
+                            }));
+                        }
+                    }
+                }
+
+                let plan_baseline = match &plan_baseline_query {
+                    Some(query) => match query.get_baseline(self.ctx.as_ref()).await {
+                        Ok(plan_baseline) => plan_baseline,
+                        Err(e) => {
+                            warn!("failed to get the plan baseline: {}", e);
+                            None
+                        }
+                    },
+                    None => None,
+                };
+
                 // Step 3: Bind AST with catalog, and generate a pure logical SExpr
                 let metadata = Arc::new(RwLock::new(Metadata::default()));
                 let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
@@ -120,6 +218,9 @@ impl Planner {
                     metadata.clone(),
                 );
                 let plan = binder.bind(&stmt).await?;
+                if let Some(baseline) = &plan_baseline {
+                    apply_plan_baseline(self.ctx.as_ref(), &metadata, baseline);
+                }
 
                 // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
                 let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
@@ -127,9 +228,20 @@ impl Planner {
                     .with_enable_join_reorder(unsafe {
                         !self.ctx.get_settings().get_disable_join_reorder()?
                     })
-                    .with_enable_dphyp(self.ctx.get_settings().get_enable_dphyp()?);
+                    .with_enable_dphyp(self.ctx.get_settings().get_enable_dphyp()?)
+                    .with_enable_eager_aggregation(
+                        self.ctx.get_settings().get_enable_eager_aggregation()?,
+                    );
 
                 let optimized_plan = optimize(opt_ctx, plan)?;
+                if let Some(query) = plan_cache_query {
+                    if let Err(e) = PlanCache::instance()
+                        .insert(&self.ctx, query, &optimized_plan, plan_baseline_generation)
+                        .await
+                    {
+                        warn!("failed to cache the plan: {}", e);
+                    }
+                }
                 Ok((optimized_plan, PlanExtras {
                     metadata,
                     format,
@@ -195,6 +307,14 @@ impl Planner {
     }
 
     fn replace_stmt(&self, stmt: &mut Statement, sql_dialect: Dialect) {
+        // The fingerprint of a plan baseline is taken from the query as written.
+        if matches!(
+            stmt,
+            Statement::CreatePlanBaseline(_) | Statement::DropPlanBaseline(_)
+        ) {
+            return;
+        }
+
         stmt.drive_mut(&mut DistinctToGroupBy::default());
         stmt.drive_mut(&mut AggregateRewriter { sql_dialect });
 
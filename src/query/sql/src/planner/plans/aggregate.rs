@@ -261,6 +261,8 @@ impl Operator for Aggregate {
                         for bucket in histogram.buckets.iter_mut() {
                             bucket.aggregate_values();
                         }
+                        // Every value is unique after aggregation.
+                        histogram.most_common_values.clear();
                     }
                 }
             }
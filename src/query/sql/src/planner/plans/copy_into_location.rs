@@ -29,6 +29,8 @@ pub struct CopyIntoLocationPlan {
     pub stage: Box<StageInfo>,
     pub path: String,
     pub from: Box<Plan>,
+    /// Whether the offsets of the streams read by the query advance when the copy commits.
+    pub consume_streams: bool,
 }
 
 impl CopyIntoLocationPlan {
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::DatabaseMeta;
@@ -29,6 +32,15 @@ pub struct CreateDatabasePlan {
     pub catalog: String,
     pub database: String,
     pub meta: DatabaseMeta,
+    /// The database whose tables are cloned into the new database.
+    pub clone_from: Option<CloneDatabaseSource>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloneDatabaseSource {
+    pub database: String,
+    /// Clone the tables as of the point, the latest version if `None`.
+    pub time_point: Option<DateTime<Utc>>,
 }
 
 impl From<CreateDatabasePlan> for CreateDatabaseReq {
@@ -105,6 +117,38 @@ pub struct RenameDatabaseEntity {
     pub new_database: String,
 }
 
+/// Revert.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevertDatabasePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub time_point: DateTime<Utc>,
+}
+
+/// Backup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BackupDatabasePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub stage: StageInfo,
+    /// The directory of the backups in the stage, empty or ends with '/'.
+    pub path: String,
+}
+
+/// Restore.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestoreDatabasePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub stage: StageInfo,
+    /// The directory of the backups in the stage, empty or ends with '/'.
+    pub path: String,
+}
+
 /// Undrop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UndropDatabasePlan {
@@ -19,6 +19,9 @@ mod database;
 mod file_format;
 mod index;
 mod notification;
+mod plan_baseline;
+mod row_access_policy;
+mod sequence;
 mod stage;
 mod stream;
 mod table;
@@ -34,6 +37,9 @@ pub use database::*;
 pub use file_format::*;
 pub use index::*;
 pub use notification::*;
+pub use plan_baseline::*;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use stage::*;
 pub use stream::*;
 pub use table::*;
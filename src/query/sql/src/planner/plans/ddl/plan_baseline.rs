@@ -0,0 +1,32 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::schema::CreateOption;
+
+use crate::plans::Plan;
+use crate::PlanBaselineQuery;
+
+#[derive(Clone, Debug)]
+pub struct CreatePlanBaselinePlan {
+    pub create_option: CreateOption,
+    pub query: PlanBaselineQuery,
+    /// The plan of the query, the join outline of the optimized plan is captured.
+    pub plan: Box<Plan>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DropPlanBaselinePlan {
+    pub if_exists: bool,
+    pub query: PlanBaselineQuery,
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
+use databend_common_meta_app::schema::DropSequenceReq;
+use databend_common_meta_app::schema::SequenceIdent;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateSequencePlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub sequence: String,
+    pub start: u64,
+    pub increment: u64,
+    pub comment: Option<String>,
+}
+
+impl CreateSequencePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+impl From<CreateSequencePlan> for CreateSequenceReq {
+    fn from(p: CreateSequencePlan) -> Self {
+        CreateSequenceReq {
+            create_option: p.create_option,
+            ident: SequenceIdent::new(p.tenant, p.sequence),
+            start: p.start,
+            step: p.increment,
+            comment: p.comment,
+            create_on: chrono::Utc::now(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropSequencePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub sequence: String,
+}
+
+impl DropSequencePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+impl From<DropSequencePlan> for DropSequenceReq {
+    fn from(p: DropSequencePlan) -> Self {
+        DropSequenceReq {
+            if_exists: p.if_exists,
+            ident: SequenceIdent::new(p.tenant, p.sequence),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescSequencePlan {
+    pub tenant: String,
+    pub sequence: String,
+}
+
+impl DescSequencePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("start", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("increment", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("current", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("comment", DataType::Nullable(Box::new(DataType::String))),
+            DataField::new("created_on", DataType::Timestamp),
+            DataField::new("updated_on", DataType::Timestamp),
+        ])
+    }
+}
@@ -19,6 +19,15 @@ pub enum StreamNavigation {
     AtStream { database: String, name: String },
 }
 
+/// The view that a stream is created on, the stream tracks the changes of the
+/// table that the view selects from and applies the query of the view to them.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StreamView {
+    pub database: String,
+    pub name: String,
+    pub query: String,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateStreamPlan {
     pub create_option: CreateOption,
@@ -29,6 +38,7 @@ pub struct CreateStreamPlan {
     pub table_database: String,
     pub table_name: String,
     pub navigation: Option<StreamNavigation>,
+    pub view: Option<StreamView>,
     pub append_only: bool,
     pub comment: Option<String>,
 }
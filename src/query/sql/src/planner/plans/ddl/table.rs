@@ -28,6 +28,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::storage::StorageParams;
@@ -55,6 +56,9 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    pub constraints: BTreeMap<String, TableConstraint>,
+    /// The table of `CREATE TABLE ... CLONE`, whose segments are shared with the new table.
+    pub clone_source: Option<CloneTableSource>,
 }
 
 impl CreateTablePlan {
@@ -63,6 +67,14 @@ impl CreateTablePlan {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub navigation: Option<NavigationPoint>,
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -324,6 +336,37 @@ impl DropTableColumnPlan {
     }
 }
 
+/// Add row access policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Drop row access policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // ModifyColumnAction after name resolved, used in ModifyTableColumnPlan
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::MaterializedViewRefreshMode;
+use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -43,3 +45,39 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateMaterializedViewPlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub schema: TableSchemaRef,
+    pub refresh_mode: MaterializedViewRefreshMode,
+    pub base_database: String,
+    pub base_table: String,
+    pub base_table_id: u64,
+    /// The stream on the base table which tracks the changes since the last refresh.
+    pub stream_name: String,
+    pub query: String,
+    /// The query reading the change set from the stream, it is none if the view
+    /// can only be fully refreshed.
+    pub incremental_query: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropMaterializedViewPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
@@ -94,6 +94,20 @@ impl JoinType {
     pub fn is_mark_join(&self) -> bool {
         matches!(self, JoinType::LeftMark | JoinType::RightMark)
     }
+
+    /// Whether the build side can be broadcast, it requires the join
+    /// doesn't need to output the unmatched rows of build side.
+    pub fn can_broadcast(&self) -> bool {
+        !matches!(
+            self,
+            JoinType::Right
+                | JoinType::Full
+                | JoinType::RightAnti
+                | JoinType::RightSemi
+                | JoinType::LeftMark
+                | JoinType::RightSingle
+        )
+    }
 }
 
 impl Display for JoinType {
@@ -142,6 +156,25 @@ impl Display for JoinType {
     }
 }
 
+/// The distribution of a join under cluster, forced by `BROADCAST` or `SHUFFLE` hint or plan baseline.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JoinDistribution {
+    /// Broadcast the build side to all the nodes.
+    Broadcast,
+    /// Shuffle both sides by the join keys.
+    Shuffle,
+}
+
+/// The join strategies forced by the optimizer hints of query.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct JoinHint {
+    pub distribution: Option<JoinDistribution>,
+    // Use hash join even if range join is applicable.
+    pub hash_join: bool,
+    // The build side is decided by hints, the join can't be commuted.
+    pub keep_sides: bool,
+}
+
 /// Join operator. We will choose hash join by default.
 /// In the case that using hash join, the right child
 /// is always the build side, and the left child is always
@@ -162,6 +195,7 @@ pub struct Join {
     // Original join type. Left/Right single join may be convert to inner join
     // Record the original join type and do some special processing during runtime.
     pub original_join_type: Option<JoinType>,
+    pub hint: JoinHint,
 }
 
 impl Default for Join {
@@ -176,6 +210,7 @@ impl Default for Join {
             need_hold_hash_table: false,
             is_lateral: false,
             original_join_type: None,
+            hint: Default::default(),
         }
     }
 }
@@ -529,6 +564,26 @@ impl Operator for Join {
             return Ok(required);
         }
 
+        match self.hint.distribution {
+            Some(JoinDistribution::Broadcast) => {
+                if child_index == 1 {
+                    required.distribution = Distribution::Broadcast;
+                } else {
+                    required.distribution = Distribution::Any;
+                }
+                return Ok(required);
+            }
+            Some(JoinDistribution::Shuffle) => {
+                if child_index == 0 {
+                    required.distribution = Distribution::Hash(self.left_conditions.clone());
+                } else {
+                    required.distribution = Distribution::Hash(self.right_conditions.clone());
+                }
+                return Ok(required);
+            }
+            None => {}
+        }
+
         // Try to use broadcast join
         if !matches!(
             self.join_type,
@@ -578,6 +633,32 @@ impl Operator for Join {
     ) -> Result<Vec<Vec<RequiredProperty>>> {
         let mut children_required = vec![];
 
+        match self.hint.distribution {
+            Some(JoinDistribution::Broadcast) => {
+                children_required.push(vec![
+                    RequiredProperty {
+                        distribution: Distribution::Any,
+                    },
+                    RequiredProperty {
+                        distribution: Distribution::Broadcast,
+                    },
+                ]);
+                return Ok(children_required);
+            }
+            Some(JoinDistribution::Shuffle) => {
+                children_required.push(vec![
+                    RequiredProperty {
+                        distribution: Distribution::Hash(self.left_conditions.clone()),
+                    },
+                    RequiredProperty {
+                        distribution: Distribution::Hash(self.right_conditions.clone()),
+                    },
+                ]);
+                return Ok(children_required);
+            }
+            None => {}
+        }
+
         if self.join_type != JoinType::Cross && !ctx.get_settings().get_enforce_broadcast_join()? {
             // (Hash, Hash)
             children_required.extend(
@@ -16,16 +16,19 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::TableSample;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::table::ChangeType;
 use itertools::Itertools;
 
 use super::ScalarItem;
+use crate::optimizer::histogram_from_column_histogram;
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
@@ -85,6 +88,8 @@ pub struct Statistics {
     pub statistics: Option<TableStatistics>,
     // statistics will be ignored in comparison and hashing
     pub col_stats: HashMap<IndexType, Option<BasicColumnStatistics>>,
+    // histograms collected by analyzing the table, will be ignored in comparison and hashing
+    pub histograms: HashMap<IndexType, ColumnHistogram>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -99,6 +104,8 @@ pub struct Scan {
     pub change_type: Option<ChangeType>,
     // Whether to update stream columns.
     pub update_stream_columns: bool,
+    // The sample of the table, `TABLESAMPLE` in SQL.
+    pub sample: Option<TableSample>,
 
     pub statistics: Statistics,
 }
@@ -112,6 +119,13 @@ impl Scan {
             .filter(|(col, _)| columns.contains(*col))
             .map(|(col, stat)| (*col, stat.clone()))
             .collect();
+        let histograms = self
+            .statistics
+            .histograms
+            .iter()
+            .filter(|(col, _)| columns.contains(*col))
+            .map(|(col, histogram)| (*col, histogram.clone()))
+            .collect();
 
         Scan {
             table_index: self.table_index,
@@ -122,11 +136,13 @@ impl Scan {
             statistics: Statistics {
                 statistics: self.statistics.statistics,
                 col_stats,
+                histograms,
             },
             prewhere,
             agg_index: self.agg_index.clone(),
             change_type: self.change_type.clone(),
             update_stream_columns: self.update_stream_columns,
+            sample: self.sample.clone(),
         }
     }
 
@@ -158,6 +174,7 @@ impl PartialEq for Scan {
         self.table_index == other.table_index
             && self.columns == other.columns
             && self.push_down_predicates == other.push_down_predicates
+            && self.sample == other.sample
     }
 }
 
@@ -218,13 +235,23 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
-                .ok();
+                let histogram = self
+                    .statistics
+                    .histograms
+                    .get(k)
+                    .and_then(|histogram| {
+                        let num_values = num_rows.saturating_sub(col_stat.null_count);
+                        histogram_from_column_histogram(histogram, num_values)
+                    })
+                    .or_else(|| {
+                        histogram_from_ndv(
+                            ndv,
+                            num_rows,
+                            Some((min.clone(), max.clone())),
+                            DEFAULT_HISTOGRAM_BUCKETS,
+                        )
+                        .ok()
+                    });
                 let column_stat = ColumnStat {
                     min,
                     max,
@@ -264,8 +291,14 @@ impl Operator for Scan {
             (_, _) => 0.0,
         };
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        // Only the sampled part of the table is read.
+        let cardinality = match &self.sample {
+            Some(sample) => cardinality * sample.probability.0,
+            None => cardinality,
+        };
+
+        // If prewhere is not none or the table is sampled, we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.sample.is_none() {
             precise_cardinality
         } else {
             None
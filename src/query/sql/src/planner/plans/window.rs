@@ -66,6 +66,8 @@ pub struct Window {
     pub frame: WindowFuncFrame,
     // limit for potentially possible push-down
     pub limit: Option<usize>,
+    // the input is already sorted by the window below, which shares its sort with this window
+    pub presorted: bool,
 }
 
 impl Window {
@@ -0,0 +1,333 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Indirection;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::OrderByExpr;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::NameResolutionContext;
+
+/// Checks the query of a materialized view, it collects the referenced tables and
+/// whether the query can be maintained incrementally from the change set of the base
+/// table. Only the queries of filters and projections on a single table are incremental,
+/// the others are refreshed by recomputing the whole query.
+#[derive(Debug, Clone, Default, Visitor)]
+#[visitor(
+    Query(enter),
+    SelectStmt(enter),
+    TableReference(enter),
+    Expr(enter),
+    FunctionCall(enter)
+)]
+pub struct MaterializedViewChecker {
+    /// The tables referenced by the query, in the form of `(catalog, database, table)`.
+    pub tables: Vec<(Option<Identifier>, Option<Identifier>, Identifier)>,
+    /// The first non-deterministic function in the query.
+    pub non_deterministic_function: Option<String>,
+    /// Table functions or stage locations, whose changes can't be tracked.
+    pub unsupported_source: Option<String>,
+    /// The common table expressions are referenced like tables.
+    pub has_cte: bool,
+    not_incremental: bool,
+    query_count: usize,
+}
+
+impl MaterializedViewChecker {
+    pub fn is_incremental(&self) -> bool {
+        !self.not_incremental && self.query_count == 1 && self.tables.len() == 1
+    }
+
+    fn enter_query(&mut self, query: &Query) {
+        self.query_count += 1;
+        self.has_cte |= query.with.is_some();
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+            || !matches!(&query.body, SetExpr::Select(_))
+        {
+            self.not_incremental = true;
+        }
+    }
+
+    fn enter_select_stmt(&mut self, stmt: &SelectStmt) {
+        if stmt.distinct
+            || stmt.group_by.is_some()
+            || stmt.having.is_some()
+            || stmt.window_list.is_some()
+            || stmt.qualify.is_some()
+        {
+            self.not_incremental = true;
+        }
+        // The stream has the additional change columns, so the star can't be expanded
+        // to the same columns as the base table.
+        if stmt
+            .select_list
+            .iter()
+            .any(|target| target.is_star() || target.has_window())
+        {
+            self.not_incremental = true;
+        }
+    }
+
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        match table_ref {
+            TableReference::Table {
+                catalog,
+                database,
+                table,
+                travel_point,
+                since_point,
+                pivot,
+                unpivot,
+                sample,
+                ..
+            } => {
+                if travel_point.is_some()
+                    || since_point.is_some()
+                    || pivot.is_some()
+                    || unpivot.is_some()
+                    || sample.is_some()
+                {
+                    self.not_incremental = true;
+                }
+                self.tables
+                    .push((catalog.clone(), database.clone(), table.clone()));
+            }
+            TableReference::TableFunction { name, .. } => {
+                self.unsupported_source
+                    .get_or_insert_with(|| format!("table function {}", name));
+            }
+            TableReference::Location { location, .. } => {
+                self.unsupported_source
+                    .get_or_insert_with(|| format!("location {}", location));
+            }
+            TableReference::Join { .. } => {
+                self.not_incremental = true;
+            }
+            TableReference::Subquery { .. } => {}
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::CountAll { .. } => self.not_incremental = true,
+            // The base table is referenced by alias in the incremental query.
+            Expr::ColumnRef {
+                column: ColumnRef {
+                    database: Some(_), ..
+                },
+                ..
+            } => self.not_incremental = true,
+            _ => {}
+        }
+    }
+
+    fn enter_function_call(&mut self, func: &FunctionCall) {
+        let name = &func.name.name;
+        if func.window.is_some() || AggregateFunctionFactory::instance().contains(name) {
+            self.not_incremental = true;
+        }
+        if self.non_deterministic_function.is_none()
+            && BUILTIN_FUNCTIONS
+                .get_property(name)
+                .map(|p| p.non_deterministic)
+                .unwrap_or(false)
+        {
+            self.non_deterministic_function = Some(name.clone());
+        }
+    }
+}
+
+/// Replaces the base table with the stream on it, the stream is aliased as the table
+/// so that the qualified column references are still valid.
+#[derive(Debug, Clone, VisitorMut)]
+#[visitor(TableReference(enter))]
+pub struct MaterializedViewStreamRewriter {
+    pub stream_database: String,
+    pub stream_name: String,
+}
+
+impl MaterializedViewStreamRewriter {
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table {
+            span,
+            catalog,
+            table,
+            alias,
+            ..
+        } = table_ref
+        {
+            let alias = alias.clone().or_else(|| {
+                Some(TableAlias {
+                    name: table.clone(),
+                    columns: vec![],
+                })
+            });
+            *table_ref = TableReference::Table {
+                span: *span,
+                catalog: catalog.clone(),
+                database: Some(Identifier::from_name_with_quoted(
+                    self.stream_database.clone(),
+                    Some('`'),
+                )),
+                table: Identifier::from_name_with_quoted(self.stream_name.clone(), Some('`')),
+                alias,
+                travel_point: None,
+                since_point: None,
+                pivot: None,
+                unpivot: None,
+                sample: None,
+            };
+        }
+    }
+}
+
+/// A fresh materialized view which a query can be rewritten to.
+#[derive(Debug, Clone)]
+pub struct MaterializedViewTarget {
+    pub database: String,
+    pub view: String,
+    /// The columns of the view.
+    pub columns: Vec<String>,
+}
+
+/// Replaces the queries which are the same as the query of a fresh materialized view
+/// with the scan on the view. The queries are compared after the table names are
+/// qualified, so the formatting of the statements doesn't matter. A query whose body
+/// is the query of the view is rewritten as well, if its `ORDER BY` only refers to the
+/// columns of the view, the `ORDER BY`, `LIMIT` and `OFFSET` are kept on the scan.
+#[derive(Debug, Clone, VisitorMut)]
+#[visitor(Query(enter))]
+pub struct MaterializedViewRewriter {
+    /// The query of the view to the view.
+    pub views: HashMap<String, MaterializedViewTarget>,
+    pub name_resolution_ctx: NameResolutionContext,
+    pub rewritten: bool,
+}
+
+impl MaterializedViewRewriter {
+    fn enter_query(&mut self, query: &mut Query) {
+        if let Some(target) = self.views.get(&query.to_string()) {
+            *query = Self::scan_view(query, target, vec![], vec![], None);
+            self.rewritten = true;
+            return;
+        }
+
+        if query.with.is_some()
+            || (query.order_by.is_empty() && query.limit.is_empty() && query.offset.is_none())
+        {
+            return;
+        }
+        let Some(target) = self.views.get(&query.body.to_string()) else {
+            return;
+        };
+        let resolvable = query.order_by.iter().all(|order_by| match &order_by.expr {
+            Expr::ColumnRef {
+                column:
+                    ColumnRef {
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(ident),
+                    },
+                ..
+            } => {
+                let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                target.columns.contains(&name)
+            }
+            Expr::Literal {
+                lit: Literal::UInt64(pos),
+                ..
+            } => *pos >= 1 && *pos as usize <= target.columns.len(),
+            _ => false,
+        });
+        if !resolvable {
+            return;
+        }
+        *query = Self::scan_view(
+            query,
+            target,
+            query.order_by.clone(),
+            query.limit.clone(),
+            query.offset.clone(),
+        );
+        self.rewritten = true;
+    }
+
+    fn scan_view(
+        query: &Query,
+        target: &MaterializedViewTarget,
+        order_by: Vec<OrderByExpr>,
+        limit: Vec<Expr>,
+        offset: Option<Expr>,
+    ) -> Query {
+        let star = SelectTarget::StarColumns {
+            qualified: vec![Indirection::Star(None)],
+            column_filter: None,
+        };
+        let from = TableReference::Table {
+            span: None,
+            catalog: None,
+            database: Some(Identifier::from_name_with_quoted(
+                target.database.clone(),
+                Some('`'),
+            )),
+            table: Identifier::from_name_with_quoted(target.view.clone(), Some('`')),
+            alias: None,
+            travel_point: None,
+            since_point: None,
+            pivot: None,
+            unpivot: None,
+            sample: None,
+        };
+        Query {
+            span: query.span,
+            with: None,
+            body: SetExpr::Select(Box::new(SelectStmt {
+                span: query.span,
+                hints: None,
+                distinct: false,
+                select_list: vec![star],
+                from: vec![from],
+                selection: None,
+                group_by: None,
+                having: None,
+                window_list: None,
+                qualify: None,
+            })),
+            order_by,
+            limit,
+            offset,
+            ignore_result: query.ignore_result,
+        }
+    }
+}
@@ -17,6 +17,7 @@ mod aggregating_index_visitor;
 mod distinct_to_groupby;
 mod grouping_check;
 mod lowering;
+mod materialized_view_visitor;
 mod name_resolution;
 mod type_check;
 mod udf_rewriter;
@@ -31,10 +32,15 @@ pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
+pub use materialized_view_visitor::MaterializedViewChecker;
+pub(crate) use materialized_view_visitor::MaterializedViewRewriter;
+pub use materialized_view_visitor::MaterializedViewStreamRewriter;
+pub(crate) use materialized_view_visitor::MaterializedViewTarget;
 pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
+pub use type_check::literal_to_scalar;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
@@ -44,5 +50,12 @@ pub use view_rewriter::ViewRewriter;
 pub(crate) use virtual_column_rewriter::VirtualColumnRewriter;
 pub use window_check::WindowChecker;
 
-pub(crate) const SUPPORTED_AGGREGATING_INDEX_FUNCTIONS: [&str; 6] =
-    ["sum", "min", "max", "avg", "count", "approx_count_distinct"];
+pub(crate) const SUPPORTED_AGGREGATING_INDEX_FUNCTIONS: [&str; 7] = [
+    "sum",
+    "min",
+    "max",
+    "avg",
+    "count",
+    "approx_count_distinct",
+    "approx_top_k",
+];
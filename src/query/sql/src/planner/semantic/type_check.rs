@@ -1735,6 +1735,30 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Convert the k of approx_top_k to params
+        // It also applies to `approx_top_k_state` which is used by the aggregating index.
+        let params = if (func_name.eq_ignore_ascii_case("approx_top_k")
+            || func_name.eq_ignore_ascii_case("approx_top_k_state"))
+            && arguments.len() == 2
+            && params.is_empty()
+        {
+            let k = ConstantExpr::try_from(arguments[1].clone());
+
+            let is_positive_integer = match &k {
+                Ok(v) => v.value.is_positive(),
+                Err(_) => false,
+            } && arg_types[1].is_integer();
+            if !is_positive_integer {
+                return Err(ErrorCode::SemanticError(
+                    "The k of `approx_top_k` must be a constant positive int",
+                ));
+            }
+
+            vec![k.unwrap().value]
+        } else {
+            params
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
@@ -2853,24 +2877,7 @@ impl<'a> TypeChecker<'a> {
         &self,
         literal: &databend_common_ast::ast::Literal,
     ) -> Result<Box<(Scalar, DataType)>> {
-        let value = match literal {
-            Literal::UInt64(value) => Scalar::Number(NumberScalar::UInt64(*value)),
-            Literal::Decimal256 {
-                value,
-                precision,
-                scale,
-            } => Scalar::Decimal(DecimalScalar::Decimal256(*value, DecimalSize {
-                precision: *precision,
-                scale: *scale,
-            })),
-            Literal::Float64(float) => Scalar::Number(NumberScalar::Float64((*float).into())),
-            Literal::String(string) => Scalar::String(string.clone()),
-            Literal::Boolean(boolean) => Scalar::Boolean(*boolean),
-            Literal::Null => Scalar::Null,
-        };
-        let value = shrink_scalar(value);
-        let data_type = value.as_ref().infer_data_type();
-        Ok(Box::new((value, data_type)))
+        Ok(Box::new(literal_to_scalar(literal)))
     }
 
     // TODO(leiysky): use an array builder function instead, since we should allow declaring
@@ -2991,6 +2998,7 @@ impl<'a> TypeChecker<'a> {
         let Some(udf) = udf else {
             return Ok(None);
         };
+        self.metadata.write().add_udf(udf_name.to_string());
 
         let name = udf.name;
 
@@ -3897,6 +3905,29 @@ impl<'a> TypeChecker<'a> {
     }
 }
 
+/// Converts a literal to the scalar value and its data type, the value is shrunk to the
+/// smallest type that fits.
+pub fn literal_to_scalar(literal: &Literal) -> (Scalar, DataType) {
+    let value = match literal {
+        Literal::UInt64(value) => Scalar::Number(NumberScalar::UInt64(*value)),
+        Literal::Decimal256 {
+            value,
+            precision,
+            scale,
+        } => Scalar::Decimal(DecimalScalar::Decimal256(*value, DecimalSize {
+            precision: *precision,
+            scale: *scale,
+        })),
+        Literal::Float64(float) => Scalar::Number(NumberScalar::Float64((*float).into())),
+        Literal::String(string) => Scalar::String(string.clone()),
+        Literal::Boolean(boolean) => Scalar::Boolean(*boolean),
+        Literal::Null => Scalar::Null,
+    };
+    let value = shrink_scalar(value);
+    let data_type = value.as_ref().infer_data_type();
+    (value, data_type)
+}
+
 pub fn resolve_type_name_by_str(name: &str, not_null: bool) -> Result<TableDataType> {
     let sql_tokens = databend_common_ast::parser::tokenize_sql(name)?;
     let ast = databend_common_ast::parser::run_parser(
@@ -34,6 +34,7 @@ impl ViewRewriter {
             since_point,
             pivot,
             unpivot,
+            sample,
         } = table_ref
         {
             // Must rewrite view query when table_ref::database is none. If not:
@@ -53,6 +54,7 @@ impl ViewRewriter {
                     since_point: since_point.clone(),
                     pivot: pivot.clone(),
                     unpivot: unpivot.clone(),
+                    sample: sample.clone(),
                 }
             }
         }
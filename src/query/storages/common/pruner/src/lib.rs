@@ -19,6 +19,7 @@ mod internal_column_pruner;
 mod limiter_pruner;
 mod page_pruner;
 mod range_pruner;
+mod sample_pruner;
 mod topn_pruner;
 
 pub use block_meta::BlockMetaIndex;
@@ -30,4 +31,6 @@ pub use page_pruner::PagePruner;
 pub use page_pruner::PagePrunerCreator;
 pub use range_pruner::RangePruner;
 pub use range_pruner::RangePrunerCreator;
+pub use sample_pruner::sample_point;
+pub use sample_pruner::SamplePruner;
 pub use topn_pruner::TopNPrunner;
@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use siphasher::sip::SipHasher13;
+
+/// Maps the keys to a point in `[0, 1)` with a hash that is fixed across builds and nodes,
+/// so the samples taken with the same seed are repeatable.
+pub fn sample_point(seed: u64, keys: &[&[u8]]) -> f64 {
+    let mut hasher = SipHasher13::new_with_keys(seed, 0);
+    for key in keys {
+        hasher.write(key);
+    }
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Samples blocks by their locations, for `TABLESAMPLE BLOCK`.
+///
+/// A block is kept if the hash of its location and the seed falls below the probability,
+/// so the same blocks are sampled on every node and by every run with the same seed.
+pub struct SamplePruner {
+    probability: f64,
+    seed: u64,
+}
+
+impl SamplePruner {
+    pub fn create(probability: f64, seed: Option<u64>) -> Arc<Self> {
+        let seed = seed.unwrap_or_else(|| RandomState::new().build_hasher().finish());
+        Arc::new(SamplePruner { probability, seed })
+    }
+
+    pub fn should_keep(&self, location: &str) -> bool {
+        if self.probability >= 1.0 {
+            return true;
+        }
+        sample_point(self.seed, &[location.as_bytes()]) < self.probability
+    }
+}
@@ -19,7 +19,10 @@ pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
 pub use v2::MetaHLL;
 pub use v2::Statistics;
-pub use v2::TableSnapshotStatistics;
+pub use v3::ColumnHistogram;
+pub use v3::HistogramBucket;
+pub use v3::MostCommonValue;
+pub use v3::TableSnapshotStatistics;
 pub use v4::CompactSegmentInfo;
 pub use v4::IndexInfo;
 pub use v4::IndexSegmentInfo;
@@ -29,4 +32,5 @@ pub use v4::TableSnapshotLite;
 
 use super::v0;
 use super::v2;
+use super::v3;
 use super::v4;
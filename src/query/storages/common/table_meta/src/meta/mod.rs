@@ -54,6 +54,7 @@ pub mod testing {
     pub use super::v1::TableSnapshot as TableSnapshotV1;
     pub use super::v2::SegmentInfo as SegmentInfoV2;
     pub use super::v2::TableSnapshot as TableSnapshotV2;
+    pub use super::v2::TableSnapshotStatistics as TableSnapshotStatisticsV2;
     pub use super::v3::SegmentInfo as SegmentInfoV3;
     pub use super::v3::TableSnapshot as TableSnapshotV3;
 }
@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::v2;
+use crate::meta::FormatVersion;
+use crate::meta::MetaHLL;
+use crate::meta::SnapshotId;
+use crate::meta::Versioned;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshotStatistics {
+    /// format version of snapshot
+    pub format_version: FormatVersion,
+
+    /// id of snapshot
+    pub snapshot_id: SnapshotId,
+    pub hll: HashMap<ColumnId, MetaHLL>,
+    /// histograms of the columns, built from the sampled blocks of the snapshot.
+    pub histograms: HashMap<ColumnId, ColumnHistogram>,
+}
+
+/// The distribution of the non-null values of a column.
+///
+/// The most common values are kept out of the buckets, so a skewed value does not
+/// inflate the estimation of the other values in its bucket.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ColumnHistogram {
+    /// Equi-height buckets in ascending order, which do not overlap.
+    pub buckets: Vec<HistogramBucket>,
+    pub most_common_values: Vec<MostCommonValue>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    pub lower_bound: Scalar,
+    pub upper_bound: Scalar,
+    /// Estimated number of values of the table in the bucket.
+    pub num_values: f64,
+    /// Estimated number of distinct values of the table in the bucket.
+    pub num_distinct: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MostCommonValue {
+    pub value: Scalar,
+    /// Estimated number of values of the table equal to `value`.
+    pub num_values: f64,
+}
+
+impl ColumnHistogram {
+    /// Estimated number of non-null values of the table.
+    pub fn num_values(&self) -> f64 {
+        let buckets = self.buckets.iter().map(|b| b.num_values).sum::<f64>();
+        let mcv = self
+            .most_common_values
+            .iter()
+            .map(|v| v.num_values)
+            .sum::<f64>();
+        buckets + mcv
+    }
+}
+
+impl TableSnapshotStatistics {
+    pub fn new(
+        hll: HashMap<ColumnId, MetaHLL>,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
+        snapshot_id: SnapshotId,
+    ) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id,
+            hll,
+            histograms,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    pub fn column_distinct_values(&self) -> HashMap<ColumnId, u64> {
+        self.hll
+            .iter()
+            .map(|hll| (*hll.0, hll.1.count() as u64))
+            .collect()
+    }
+}
+
+impl From<v2::TableSnapshotStatistics> for TableSnapshotStatistics {
+    fn from(value: v2::TableSnapshotStatistics) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: value.snapshot_id,
+            hll: value.hll,
+            histograms: HashMap::new(),
+        }
+    }
+}
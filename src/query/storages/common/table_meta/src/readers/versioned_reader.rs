@@ -20,6 +20,7 @@ use crate::meta::load_json;
 use crate::meta::IndexInfo;
 use crate::meta::IndexInfoVersion;
 use crate::meta::TableSnapshotStatistics;
+use crate::meta::TableSnapshotStatisticsV2;
 use crate::meta::TableSnapshotStatisticsVersion;
 
 #[async_trait::async_trait]
@@ -39,10 +40,14 @@ impl VersionedReader<TableSnapshotStatistics> for TableSnapshotStatisticsVersion
         reader.read_to_end(&mut buffer).await?;
         let r = match self {
             TableSnapshotStatisticsVersion::V0(v) => {
+                let ts = load_json(&buffer, v).await?;
+                TableSnapshotStatistics::from(TableSnapshotStatisticsV2::from(ts))
+            }
+            TableSnapshotStatisticsVersion::V2(v) => {
                 let ts = load_json(&buffer, v).await?;
                 TableSnapshotStatistics::from(ts)
             }
-            TableSnapshotStatisticsVersion::V2(v) => load_json(&buffer, v).await?,
+            TableSnapshotStatisticsVersion::V3(v) => load_json(&buffer, v).await?,
         };
         Ok(r)
     }
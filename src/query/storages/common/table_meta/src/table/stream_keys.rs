@@ -18,6 +18,12 @@ pub const OPT_KEY_DATABASE_NAME: &str = "table_database";
 pub const OPT_KEY_TABLE_ID: &str = "table_id";
 pub const OPT_KEY_TABLE_VER: &str = "table_version";
 pub const OPT_KEY_MODE: &str = "mode";
+// The version of an external source table, e.g. the snapshot id of an Iceberg table.
+pub const OPT_KEY_SOURCE_VERSION: &str = "source_version";
+// The view that a stream is created on, the stream tracks the table of the view.
+pub const OPT_KEY_VIEW_DATABASE: &str = "view_database";
+pub const OPT_KEY_VIEW_NAME: &str = "view_name";
+pub const OPT_KEY_VIEW_QUERY: &str = "view_query";
 
 pub const MODE_APPEND_ONLY: &str = "append_only";
 pub const MODE_STANDARD: &str = "standard";
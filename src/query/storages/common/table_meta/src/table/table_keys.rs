@@ -12,8 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::LazyLock;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
@@ -36,6 +43,31 @@ pub const OPT_KEY_CONNECTION_NAME: &str = "connection_name";
 // e.g. the partition columns of a Delta table
 pub const OPT_KEY_ENGINE_META: &str = "engine_meta";
 
+// Materialized view options, the view is a fuse table maintained from the change set of
+// a stream on its base table.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+// The query reading the stream instead of the base table, only exists if the view can be
+// refreshed incrementally.
+pub const OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL_QUERY: &str = "materialized_view_incremental_query";
+pub const OPT_KEY_MATERIALIZED_VIEW_STREAM: &str = "materialized_view_stream";
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE: &str = "materialized_view_refresh_mode";
+pub const OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID: &str = "materialized_view_base_table_id";
+// The ids of the materialized views defined on the table, kept on the base table so that
+// the views can be found without listing the tables of the databases.
+pub const OPT_KEY_MATERIALIZED_VIEW_IDS: &str = "materialized_view_ids";
+
+// Temporary table options, the table is only visible to the session which creates it, and
+// the data of the table is stored under the session-private prefix.
+pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
+
+// Zero-copy clone options, a clone shares the segments and blocks of its source table.
+// The ids of the clones of a table are kept under their own meta key, see `TableClonesKey`.
+pub const OPT_KEY_CLONE_SOURCE_TABLE_ID: &str = "clone_source_table_id";
+
+// The former names of the table, a rename is appended each time the table is renamed, so
+// that the name the table had at a point in time can be restored by `REVERT DATABASE`.
+pub const OPT_KEY_RENAME_HISTORY: &str = "rename_history";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -53,6 +85,15 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_STREAM);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_IDS);
+    r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_RENAME_HISTORY);
     r
 });
 
@@ -62,6 +103,15 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_INCREMENTAL_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_STREAM);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_BASE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_IDS);
+    r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_RENAME_HISTORY);
     r
 });
 
@@ -72,3 +122,30 @@ pub fn is_reserved_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
 pub fn is_internal_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     INTERNAL_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
+
+/// Parses the comma separated table ids under the option `key`, such as
+/// [`OPT_KEY_MATERIALIZED_VIEW_IDS`].
+pub fn parse_table_ids(options: &BTreeMap<String, String>, key: &str) -> Vec<u64> {
+    options
+        .get(key)
+        .map(|ids| ids.split(',').filter_map(|id| id.parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// A rename of a table, recorded under [`OPT_KEY_RENAME_HISTORY`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableRename {
+    pub renamed_on: DateTime<Utc>,
+    /// The database the table was in before the rename.
+    pub db_id: u64,
+    /// The name the table had before the rename.
+    pub name: String,
+}
+
+/// Parses the renames recorded under [`OPT_KEY_RENAME_HISTORY`], in the order they happened.
+pub fn parse_rename_history(options: &BTreeMap<String, String>) -> Vec<TableRename> {
+    options
+        .get(OPT_KEY_RENAME_HISTORY)
+        .and_then(|history| serde_json::from_str(history).ok())
+        .unwrap_or_default()
+}
@@ -23,3 +23,24 @@ pub fn table_storage_prefix(database_id: impl Display, table_id: impl Display) -
 pub fn database_storage_prefix(database_id: impl Display) -> String {
     format!("{}", database_id)
 }
+
+/// Constructs the prefix path which covers all the data of the temporary tables of a given tenant
+pub fn temp_tables_storage_prefix(tenant: impl Display) -> String {
+    format!("_tmp_tbl/{}", tenant)
+}
+
+/// Constructs the prefix path which covers all the data of the temporary tables of a given session,
+/// the id of the query node which holds the session is a part of the path, so that the data left
+/// by a node which is gone can be told apart.
+pub fn temp_table_storage_prefix(
+    tenant: impl Display,
+    node_id: impl Display,
+    session_id: impl Display,
+) -> String {
+    format!(
+        "{}/{}/{}",
+        temp_tables_storage_prefix(tenant),
+        node_id,
+        session_id
+    )
+}
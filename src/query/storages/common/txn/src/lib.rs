@@ -13,6 +13,9 @@
 // limitations under the License.
 
 mod manager;
+mod temp_table;
 pub use manager::TxnManager;
 pub use manager::TxnManagerRef;
 pub use manager::TxnState;
+pub use temp_table::TempTblMgr;
+pub use temp_table::TempTblMgrRef;
@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeqExt;
+use parking_lot::Mutex;
+
+/// Ids of temporary tables are allocated from a reserved range in the query node,
+/// so that they never collide with the table ids allocated by the meta service.
+const TEMP_TBL_ID_BEGIN: u64 = 0xFFFF_FFFF_0000_0000;
+
+static NEXT_TEMP_TBL_ID: AtomicU64 = AtomicU64::new(TEMP_TBL_ID_BEGIN);
+
+/// Temporary tables of a session.
+///
+/// The tables are never written to the meta service, they only live in the session
+/// which creates them.
+#[derive(Debug, Clone, Default)]
+pub struct TempTblMgr {
+    /// `(db_name, table_name) -> table_id`
+    name_to_id: BTreeMap<(String, String), u64>,
+    id_to_table: HashMap<u64, TableInfo>,
+    /// Tables which are dropped or replaced, but whose data is not purged yet.
+    dropped_tables: Vec<TableInfo>,
+}
+
+pub type TempTblMgrRef = Arc<Mutex<TempTblMgr>>;
+
+impl TempTblMgr {
+    pub fn init() -> TempTblMgrRef {
+        Arc::new(Mutex::new(TempTblMgr::default()))
+    }
+
+    pub fn is_temp_table_id(table_id: u64) -> bool {
+        table_id >= TEMP_TBL_ID_BEGIN
+    }
+
+    pub fn next_table_id() -> u64 {
+        NEXT_TEMP_TBL_ID.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.id_to_table.is_empty() && self.dropped_tables.is_empty()
+    }
+
+    /// Adds the table, the table with the same name is replaced.
+    pub fn add_table(&mut self, db_name: &str, table_info: TableInfo) {
+        let key = (db_name.to_string(), table_info.name.clone());
+        let table_id = table_info.ident.table_id;
+        if let Some(old_id) = self.name_to_id.insert(key, table_id) {
+            self.dropped_tables.extend(self.id_to_table.remove(&old_id));
+        }
+        self.id_to_table.insert(table_id, table_info);
+    }
+
+    pub fn get_table(&self, db_name: &str, table_name: &str) -> Option<TableInfo> {
+        self.name_to_id
+            .get(&(db_name.to_string(), table_name.to_string()))
+            .and_then(|id| self.id_to_table.get(id))
+            .cloned()
+    }
+
+    pub fn get_table_by_id(&self, table_id: u64) -> Option<TableInfo> {
+        self.id_to_table.get(&table_id).cloned()
+    }
+
+    pub fn list_all_tables(&self) -> Vec<TableInfo> {
+        self.id_to_table.values().cloned().collect()
+    }
+
+    pub fn list_tables(&self, db_name: &str) -> Vec<TableInfo> {
+        self.name_to_id
+            .iter()
+            .filter(|((db, _), _)| db == db_name)
+            .filter_map(|(_, id)| self.id_to_table.get(id))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns false if the table does not exist or the seq of the table is mismatched.
+    pub fn check_table_meta(&self, req: &UpdateTableMetaReq) -> bool {
+        self.id_to_table
+            .get(&req.table_id)
+            .map_or(false, |t| req.seq.match_seq(t.ident.seq).is_ok())
+    }
+
+    /// Returns false if the table does not exist or the seq of the table is mismatched.
+    pub fn update_table_meta(&mut self, req: &UpdateTableMetaReq) -> bool {
+        if !self.check_table_meta(req) {
+            return false;
+        }
+        let Some(table_info) = self.id_to_table.get_mut(&req.table_id) else {
+            return false;
+        };
+        table_info.ident.seq += 1;
+        table_info.meta = req.new_table_meta.clone();
+        true
+    }
+
+    /// Returns false if the table does not exist or the new name is taken.
+    pub fn rename_table(
+        &mut self,
+        db_name: &str,
+        table_name: &str,
+        new_db_name: &str,
+        new_table_name: &str,
+    ) -> bool {
+        let new_key = (new_db_name.to_string(), new_table_name.to_string());
+        if self.name_to_id.contains_key(&new_key) {
+            return false;
+        }
+        let key = (db_name.to_string(), table_name.to_string());
+        let Some(table_id) = self.name_to_id.remove(&key) else {
+            return false;
+        };
+        if let Some(table_info) = self.id_to_table.get_mut(&table_id) {
+            table_info.name = new_table_name.to_string();
+            table_info.desc = format!("'{}'.'{}'", new_db_name, new_table_name);
+        }
+        self.name_to_id.insert(new_key, table_id);
+        true
+    }
+
+    pub fn drop_table_by_id(&mut self, table_id: u64) -> Option<TableInfo> {
+        let table_info = self.id_to_table.remove(&table_id)?;
+        self.name_to_id.retain(|_, id| *id != table_id);
+        self.dropped_tables.push(table_info.clone());
+        Some(table_info)
+    }
+
+    /// Takes the tables whose data needs to be purged.
+    pub fn take_dropped_tables(&mut self) -> Vec<TableInfo> {
+        std::mem::take(&mut self.dropped_tables)
+    }
+
+    /// Drops all the tables, it is called when the session ends.
+    pub fn drop_all_tables(&mut self) -> Vec<TableInfo> {
+        self.name_to_id.clear();
+        let mut tables = self.take_dropped_tables();
+        tables.extend(self.id_to_table.drain().map(|(_, table_info)| table_info));
+        tables
+    }
+}
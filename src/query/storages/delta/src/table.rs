@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -42,6 +44,7 @@ use databend_common_storages_parquet::ParquetFilesPart;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
+use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use deltalake::kernel::Add;
 use deltalake::DeltaTableBuilder;
@@ -153,24 +156,42 @@ impl DeltaTable {
         Ok((schema, meta))
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    fn build(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op).with_metakey(Metakey::Version));
 
-        let mut table = DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
+        DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
             .with_storage_backend(opendal_store, Url::from_directory_path("/").unwrap())
             .build()
             .map_err(|err| {
                 ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
-            })?;
+            })
+    }
 
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
         Ok(table)
     }
 
+    /// Loads the table at the given version, the log of the version must not be cleaned up.
+    #[async_backtrace::framed]
+    async fn load_version(
+        sp: &StorageParams,
+        version: i64,
+    ) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
+        table.load_version(version).await.map_err(|err| {
+            ErrorCode::ReadTableDataError(format!(
+                "Delta table load version {version} failed: {err:?}"
+            ))
+        })?;
+        Ok(table)
+    }
+
     #[async_backtrace::framed]
     async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
         self.table
@@ -275,17 +296,22 @@ impl DeltaTable {
         _push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
+        let adds = Self::file_actions(table)?;
+        self.adds_to_partitions(&adds)
+    }
+
+    fn file_actions(table: &deltalake::table::DeltaTable) -> Result<Vec<Add>> {
+        table
+            .snapshot()
+            .and_then(|f| f.file_actions())
+            .map_err(|e| ErrorCode::ReadTableDataError(format!("Cannot read file_actions: {e:?}")))
+    }
 
+    fn adds_to_partitions(&self, adds: &[Add]) -> Result<(PartStatistics, Partitions)> {
         let mut read_rows = 0;
         let mut read_bytes = 0;
 
         let partition_fields = self.get_partition_fields()?;
-        let adds = table
-            .snapshot()
-            .and_then(|f| f.file_actions())
-            .map_err(|e| {
-                ErrorCode::ReadTableDataError(format!("Cannot read file_actions: {e:?}"))
-            })?;
         let total_files = adds.len();
 
         #[derive(serde::Deserialize)]
@@ -381,4 +407,55 @@ impl Table for DeltaTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    #[async_backtrace::framed]
+    async fn changes_version(&self) -> Result<String> {
+        let table = self.table().await?;
+        Ok(table.version().to_string())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_changes_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        base_version: &str,
+        change_type: ChangeType,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let table = self.table().await?;
+        let base_version = base_version.parse::<i64>()?;
+        let base_table = Self::load_version(self.get_storage_params()?, base_version).await?;
+
+        let base_adds = Self::file_actions(&base_table)?;
+        let latest_adds = Self::file_actions(table)?;
+
+        // Deletion vectors mark deleted rows of a data file without rewriting it,
+        // they cannot be expressed by the difference of the data files.
+        let base_dvs: HashMap<&str, _> = base_adds
+            .iter()
+            .map(|add| (add.path.as_str(), &add.deletion_vector))
+            .collect();
+        if latest_adds.iter().any(|add| {
+            add.deletion_vector.is_some()
+                && base_dvs.get(add.path.as_str()) != Some(&&add.deletion_vector)
+        }) {
+            return Err(ErrorCode::IllegalStream(format!(
+                "Stream on delta table {} does not support deletion vectors",
+                self.info.name
+            )));
+        }
+
+        // Data files are immutable, the changes are the files that only exist on one side.
+        let (adds, others) = match change_type {
+            ChangeType::Append | ChangeType::Insert => (latest_adds, base_adds),
+            ChangeType::Delete => (base_adds, latest_adds),
+        };
+        let others: HashSet<String> = others.into_iter().map(|add| add.path).collect();
+        let adds = adds
+            .into_iter()
+            .filter(|add| !others.contains(&add.path))
+            .collect::<Vec<_>>();
+
+        self.adds_to_partitions(&adds)
+    }
 }
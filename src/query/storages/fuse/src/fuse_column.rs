@@ -18,18 +18,21 @@ use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::ColumnStatisticsProvider;
 use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 
 /// A column statistics provider for fuse table.
 #[derive(Default)]
 pub struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, Option<BasicColumnStatistics>>,
+    histograms: HashMap<ColumnId, ColumnHistogram>,
 }
 
 impl FuseTableColumnStatisticsProvider {
     pub fn new(
         column_stats: HashMap<ColumnId, FuseColumnStatistics>,
         column_distinct_values: Option<HashMap<ColumnId, u64>>,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
         row_count: u64,
     ) -> Self {
         let column_stats = column_stats
@@ -47,7 +50,10 @@ impl FuseTableColumnStatisticsProvider {
                 (column_id, stat.get_useful_stat(row_count))
             })
             .collect();
-        Self { column_stats }
+        Self {
+            column_stats,
+            histograms,
+        }
     }
 }
 
@@ -59,4 +65,8 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn num_rows(&self) -> Option<u64> {
         None
     }
+
+    fn histogram(&self, column_id: ColumnId) -> Option<&ColumnHistogram> {
+        self.histograms.get(&column_id)
+    }
 }
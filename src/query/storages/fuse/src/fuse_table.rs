@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -77,6 +78,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 use log::warn;
 use opendal::Operator;
@@ -285,9 +287,14 @@ impl FuseTable {
             return Ok(prefix.clone());
         }
 
-        // otherwise, use database id and table id as storage prefix
-
         let table_id = table_info.ident.table_id;
+
+        // temporary tables are stored under the prefix private to the session
+        if let Some(temp_prefix) = table_info.options().get(OPT_KEY_TEMP_PREFIX) {
+            return Ok(table_storage_prefix(temp_prefix, table_id));
+        }
+
+        // otherwise, use database id and table id as storage prefix
         let db_id = table_info
             .options()
             .get(OPT_KEY_DATABASE_ID)
@@ -805,12 +812,14 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     Some(table_statistics.column_distinct_values()),
+                    table_statistics.histograms.clone(),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     None,
+                    HashMap::new(),
                     snapshot.summary.row_count,
                 )
             }
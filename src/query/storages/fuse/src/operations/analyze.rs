@@ -19,19 +19,24 @@ use std::time::Instant;
 use async_trait::async_trait;
 use async_trait::unboxed_simple;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
+use databend_common_expression::ScalarRef;
 use databend_common_io::prelude::borsh_deserialize_from_slice;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sinks::AsyncSink;
 use databend_common_pipeline_sinks::AsyncSinker;
+use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
@@ -39,7 +44,10 @@ use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::operations::read_block;
+use crate::statistics::build_column_histogram;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
 use crate::FuseTable;
@@ -112,7 +120,12 @@ impl SinkAnalyzeState {
         // always use the latest table
         let tenant = self.ctx.get_tenant();
         let catalog = CatalogManager::instance()
-            .get_catalog(tenant.as_str(), &self.catalog, self.ctx.txn_mgr())
+            .get_catalog(
+                tenant.as_str(),
+                &self.catalog,
+                self.ctx.txn_mgr(),
+                self.ctx.temp_tbl_mgr(),
+            )
             .await?;
         let table = catalog
             .get_table(tenant.as_str(), &self.database, &self.table)
@@ -164,7 +177,13 @@ impl SinkAnalyzeState {
 
         let snapshot = snapshot.unwrap();
         // 3. Generate new table statistics
-        let table_statistics = TableSnapshotStatistics::new(ndv_states, self.snapshot_id);
+        let ndvs = ndv_states
+            .iter()
+            .map(|(column_id, hll)| (*column_id, hll.count() as u64))
+            .collect();
+        let histograms = collect_histograms(table, snapshot.as_ref(), &self.ctx, &ndvs).await?;
+        let table_statistics =
+            TableSnapshotStatistics::new(ndv_states, histograms, self.snapshot_id);
         let table_statistics_location = table
             .meta_location_generator
             .snapshot_statistics_location_from_uuid(
@@ -273,3 +292,101 @@ pub async fn regenerate_statistics(
 
     Ok((col_stats, cluster_stats))
 }
+
+/// Number of blocks sampled to build the histograms of a table.
+const HISTOGRAM_SAMPLE_BLOCKS: usize = 64;
+
+/// Picks at most `n` items evenly from `items`.
+fn sample_evenly<T: Clone>(items: &[T], n: usize) -> Vec<T> {
+    if items.len() <= n {
+        return items.to_vec();
+    }
+    let step = items.len() as f64 / n as f64;
+    (0..n)
+        .map(|i| items[(i as f64 * step) as usize].clone())
+        .collect()
+}
+
+/// Builds the histograms of the columns in `ndvs` from the blocks sampled evenly from
+/// the snapshot.
+pub async fn collect_histograms(
+    table: &FuseTable,
+    snapshot: &TableSnapshot,
+    ctx: &Arc<dyn TableContext>,
+    ndvs: &HashMap<ColumnId, u64>,
+) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+    let schema = table.schema();
+    let (field_indices, column_ids): (Vec<usize>, Vec<ColumnId>) = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| ndvs.contains_key(&f.column_id()))
+        .map(|(i, f)| (i, f.column_id()))
+        .unzip();
+    if field_indices.is_empty() || snapshot.summary.row_count == 0 {
+        return Ok(HashMap::new());
+    }
+
+    // The blocks are sampled from the sampled segments, all the segments need not be read.
+    let segments = sample_evenly(&snapshot.segments, HISTOGRAM_SAMPLE_BLOCKS);
+    let segments_io = SegmentsIO::create(ctx.clone(), table.operator.clone(), schema);
+    let mut blocks: Vec<Arc<BlockMeta>> = Vec::new();
+    for segment in segments_io
+        .read_segments::<SegmentInfo>(&segments, true)
+        .await?
+    {
+        blocks.extend(segment?.blocks);
+    }
+    let blocks = sample_evenly(&blocks, HISTOGRAM_SAMPLE_BLOCKS);
+
+    let reader = table.create_block_reader(
+        ctx.clone(),
+        Projection::Columns(field_indices),
+        false,
+        false,
+        false,
+    )?;
+    let read_settings = ReadSettings::from_ctx(ctx)?;
+    let mut values = vec![Vec::new(); column_ids.len()];
+    let mut sampled_rows = 0;
+    for (i, block_meta) in blocks.iter().enumerate() {
+        let block = read_block(
+            table.storage_format,
+            &reader,
+            block_meta,
+            &read_settings,
+            ctx.get_id(),
+        )
+        .await?;
+        sampled_rows += block.num_rows();
+        for (entry, values) in block.columns().iter().zip(values.iter_mut()) {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+            values.extend(
+                column
+                    .iter()
+                    .filter(|v| !matches!(v, ScalarRef::Null))
+                    .map(|v| v.to_owned()),
+            );
+        }
+        ctx.set_status_info(&format!(
+            "analyze: sampled blocks for histograms:{}/{}",
+            i + 1,
+            blocks.len()
+        ));
+    }
+    if sampled_rows == 0 {
+        return Ok(HashMap::new());
+    }
+
+    let scale = snapshot.summary.row_count as f64 / sampled_rows as f64;
+    Ok(column_ids
+        .into_iter()
+        .zip(values)
+        .map(|(column_id, values)| {
+            let ndv = ndvs.get(&column_id).copied();
+            (column_id, build_column_histogram(values, scale, ndv))
+        })
+        .collect())
+}
@@ -0,0 +1,316 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use log::info;
+use opendal::Operator;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_TBL_BLOCK_PREFIX;
+use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
+
+impl FuseTable {
+    /// Copies the current snapshot of the table, with all the segments, blocks and bloom
+    /// indexes it references, to `target` under `root`, keeping their locations.
+    ///
+    /// `base` is the id and the location of the snapshot of the table in the previous backup
+    /// under `root`. Fuse files are never modified once written, so only the files which are
+    /// not referenced by the base snapshot are copied, and nothing is copied if the table is
+    /// still at the base snapshot.
+    ///
+    /// Returns the id and the location of the snapshot in the backup, `None` if the table
+    /// is empty.
+    #[async_backtrace::framed]
+    pub async fn backup_to(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        target: &Operator,
+        root: &str,
+        base: Option<(Uuid, Location)>,
+    ) -> Result<Option<(Uuid, Location)>> {
+        let Some(snapshot_location) = self.snapshot_loc().await? else {
+            return Ok(None);
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(None);
+        };
+        if base
+            .as_ref()
+            .is_some_and(|(id, _)| *id == snapshot.snapshot_id)
+        {
+            return Ok(base);
+        }
+
+        let base_files = match &base {
+            Some((_, base_location)) => {
+                backup_files(ctx.clone(), target, root, base_location).await?
+            }
+            None => HashSet::new(),
+        };
+        let segments = snapshot
+            .segments
+            .iter()
+            .filter(|(loc, _)| !base_files.contains(loc))
+            .cloned()
+            .collect::<Vec<_>>();
+        let locations = self
+            .get_block_locations(ctx.clone(), &segments, false, false)
+            .await?;
+        let mut files = Vec::with_capacity(
+            segments.len() + locations.block_location.len() + locations.bloom_location.len(),
+        );
+        files.extend(segments.into_iter().map(|(loc, _)| loc));
+        files.extend(locations.block_location);
+        files.extend(locations.bloom_location);
+        let files = files
+            .into_iter()
+            .filter(|loc| !base_files.contains(loc))
+            .map(|loc| (loc.clone(), format!("{root}{loc}")))
+            .collect::<Vec<_>>();
+        info!(
+            "backup of table {} copies {} files, {} files are shared with the previous backup",
+            self.table_info.desc,
+            files.len(),
+            base_files.len()
+        );
+        copy_files(ctx.as_ref(), &self.operator, target, files).await?;
+
+        // the snapshot goes last, a backup never references a snapshot with missing files.
+        let target_location = format!("{root}{snapshot_location}");
+        copy_files(ctx.as_ref(), &self.operator, target, vec![(
+            snapshot_location.clone(),
+            target_location,
+        )])
+        .await?;
+
+        let ver = TableMetaLocationGenerator::snapshot_version(&snapshot_location);
+        info!(
+            "backup of table {} is at snapshot {}",
+            self.table_info.desc, snapshot_location
+        );
+        Ok(Some((snapshot.snapshot_id, (snapshot_location, ver))))
+    }
+
+    /// Restores the table from the snapshot at `snapshot_location`, backed up by
+    /// [`FuseTable::backup_to`] to `source` under `root`.
+    ///
+    /// The blocks and bloom indexes are copied under the prefix of the table, the segments
+    /// are written again to reference them, and a new snapshot is committed.
+    #[async_backtrace::framed]
+    pub async fn restore_from(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &Operator,
+        root: &str,
+        snapshot_location: &Location,
+    ) -> Result<()> {
+        let reader = MetaReaders::table_snapshot_reader(source.clone());
+        let backup_snapshot = reader
+            .read(&LoadParams {
+                location: format!("{root}{}", snapshot_location.0),
+                len_hint: None,
+                ver: snapshot_location.1,
+                put_cache: false,
+            })
+            .await?;
+
+        let segments_io = SegmentsIO::create(
+            ctx.clone(),
+            source.clone(),
+            Arc::new(backup_snapshot.schema.clone()),
+        );
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+        let prefix = self.meta_location_generator.prefix();
+        // the cluster key is numbered again in the restored table.
+        let backup_cluster_key_id = backup_snapshot.cluster_key_meta.as_ref().map(|(id, _)| *id);
+        let cluster_key_id = self.cluster_key_meta().map(|(id, _)| id);
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+        let mut segments = Vec::with_capacity(backup_snapshot.segments.len());
+        for chunk in backup_snapshot.segments.chunks(chunk_size) {
+            let chunk = chunk
+                .iter()
+                .map(|(loc, ver)| (format!("{root}{loc}"), *ver))
+                .collect::<Vec<_>>();
+            for segment in segments_io
+                .read_segments::<SegmentInfo>(&chunk, false)
+                .await?
+            {
+                let segment = segment?;
+                let mut files = Vec::new();
+                let blocks = segment
+                    .blocks
+                    .iter()
+                    .map(|block| {
+                        let mut block = BlockMeta::clone(block);
+                        block.cluster_stats = match (block.cluster_stats.take(), cluster_key_id) {
+                            (Some(mut stats), Some(id))
+                                if Some(stats.cluster_key_id) == backup_cluster_key_id =>
+                            {
+                                stats.cluster_key_id = id;
+                                Some(stats)
+                            }
+                            _ => None,
+                        };
+                        let location = relocate(prefix, &block.location.0)?;
+                        files.push((format!("{root}{}", block.location.0), location.clone()));
+                        block.location.0 = location;
+                        if let Some(bloom) = &mut block.bloom_filter_index_location {
+                            let location = relocate(prefix, &bloom.0)?;
+                            files.push((format!("{root}{}", bloom.0), location.clone()));
+                            bloom.0 = location;
+                        }
+                        Ok(Arc::new(block))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                copy_files(ctx.as_ref(), source, &self.operator, files).await?;
+                let segment = SegmentInfo::new(blocks, segment.summary.clone());
+                segments.push(segment_writer.write_segment_no_cache(&segment).await?);
+            }
+        }
+
+        let snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            self.table_info.schema().as_ref().clone(),
+            backup_snapshot.summary.clone(),
+            segments,
+            self.cluster_key_meta(),
+            // the table statistics are collected again by analyzing the restored table.
+            None,
+            None,
+        );
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.table_info,
+            &self.meta_location_generator,
+            snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+}
+
+/// The segments, blocks and bloom indexes referenced by the snapshot at `location` of the
+/// backup under `root`, by their locations in the backed up table.
+async fn backup_files(
+    ctx: Arc<dyn TableContext>,
+    op: &Operator,
+    root: &str,
+    location: &Location,
+) -> Result<HashSet<String>> {
+    let reader = MetaReaders::table_snapshot_reader(op.clone());
+    let snapshot = reader
+        .read(&LoadParams {
+            location: format!("{root}{}", location.0),
+            len_hint: None,
+            ver: location.1,
+            put_cache: false,
+        })
+        .await?;
+
+    let segments_io =
+        SegmentsIO::create(ctx.clone(), op.clone(), Arc::new(snapshot.schema.clone()));
+    let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
+    let mut files = HashSet::new();
+    for chunk in snapshot.segments.chunks(chunk_size) {
+        let chunk = chunk
+            .iter()
+            .map(|(loc, ver)| (format!("{root}{loc}"), *ver))
+            .collect::<Vec<_>>();
+        for segment in segments_io
+            .read_segments::<SegmentInfo>(&chunk, false)
+            .await?
+        {
+            for block in segment?.blocks.iter() {
+                files.insert(block.location.0.clone());
+                if let Some(bloom) = &block.bloom_filter_index_location {
+                    files.insert(bloom.0.clone());
+                }
+            }
+        }
+    }
+    files.extend(snapshot.segments.iter().map(|(loc, _)| loc.clone()));
+    Ok(files)
+}
+
+/// Moves a block or index file under `prefix`, keeping its directory and file name,
+/// e.g. `1/2/_b/xxx.parquet` is moved to `{prefix}/_b/xxx.parquet`.
+///
+/// The locations come from the backup, only the block and bloom index directories are
+/// accepted, so that a restored file never lands outside of the table.
+fn relocate(prefix: &str, location: &str) -> Result<String> {
+    let mut parts = location.rsplitn(3, '/');
+    let file = parts.next().unwrap_or_default();
+    let dir = parts.next().unwrap_or_default();
+    let valid_dir = dir == FUSE_TBL_BLOCK_PREFIX || dir == FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
+    if !valid_dir || file.is_empty() || file.starts_with('.') || file.contains('\\') {
+        return Err(ErrorCode::StorageOther(format!(
+            "Invalid file location {location} in the backup"
+        )));
+    }
+    Ok(format!("{prefix}/{dir}/{file}"))
+}
+
+/// Copies the files `(from, to)` from `source` to `target`.
+async fn copy_files(
+    ctx: &dyn TableContext,
+    source: &Operator,
+    target: &Operator,
+    files: Vec<(String, String)>,
+) -> Result<()> {
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    let tasks = files.into_iter().map(|(from, to)| {
+        let source = source.clone();
+        let target = target.clone();
+        async move {
+            let data = source.read(&from).await?;
+            target.write(&to, data).await?;
+            Ok::<_, ErrorCode>(())
+        }
+    });
+
+    let threads_nums = ctx.get_settings().get_max_threads()? as usize;
+    execute_futures_in_parallel(
+        tasks,
+        threads_nums,
+        threads_nums * 2,
+        "fuse-backup-copy-worker".to_owned(),
+    )
+    .await?
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+    Ok(())
+}
@@ -0,0 +1,368 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::get_pb_value;
+use databend_common_meta_api::send_txn;
+use databend_common_meta_api::serialize_struct;
+use databend_common_meta_api::txn_cond_seq;
+use databend_common_meta_api::txn_op_del;
+use databend_common_meta_api::txn_op_put;
+use databend_common_meta_app::schema::TableClonesKey;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::ConditionResult::Eq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::TxnRequest;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::parse_table_ids;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use itertools::Itertools;
+use log::info;
+use log::warn;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+const MAX_UPDATE_TABLE_IDS_RETRIES: usize = 10;
+
+/// The files which must not be purged by the GC of a table.
+#[derive(Default)]
+pub struct SharedLocations {
+    /// The storage prefix of the table, ends with '/'.
+    own_prefix: String,
+    /// A clone does not own the files outside its storage prefix, they are shared from the
+    /// source table.
+    is_clone: bool,
+    /// The files under the storage prefix of the table which are still referenced by its clones.
+    pub segments: HashSet<String>,
+    pub blocks: HashSet<String>,
+    pub blooms: HashSet<String>,
+}
+
+impl SharedLocations {
+    pub fn is_owned(&self, location: &str) -> bool {
+        !self.is_clone || location.starts_with(&self.own_prefix)
+    }
+
+    pub fn is_purgeable(&self, location: &str) -> bool {
+        self.is_owned(location)
+            && !self.segments.contains(location)
+            && !self.blocks.contains(location)
+            && !self.blooms.contains(location)
+    }
+
+    /// Removes the files which can not be purged from `locations`.
+    pub fn retain_purgeable(&self, locations: &mut HashSet<String>) {
+        locations.retain(|loc| self.is_purgeable(loc));
+    }
+
+    /// Removes the files which are not owned by the table from `locations`.
+    pub fn retain_owned(&self, locations: &mut HashSet<String>) {
+        locations.retain(|loc| self.is_owned(loc));
+    }
+}
+
+/// Reads the ids of the clones of the table `table_id`, including the clones of its clones.
+#[async_backtrace::framed]
+pub async fn get_clone_table_ids(table_id: u64) -> Result<Vec<u64>> {
+    let meta_api = UserApiProvider::instance().get_meta_store_client();
+    let (_, table_ids) = get_pb_value(meta_api.as_ref(), &TableClonesKey::new(table_id)).await?;
+    Ok(table_ids.map(|ids| ids.id_list).unwrap_or_default())
+}
+
+/// Rewrites the ids of the clones of the table `table_id` with `f`, and retries if they are
+/// updated concurrently.
+///
+/// The ids are kept under their own key instead of the options of the table, registering a
+/// clone does not change the version of its source.
+#[async_backtrace::framed]
+pub async fn update_clone_table_ids<F>(table_id: u64, f: F) -> Result<()>
+where F: Fn(&mut Vec<u64>) {
+    let meta_api = UserApiProvider::instance().get_meta_store_client();
+    let key = TableClonesKey::new(table_id);
+    for _ in 0..MAX_UPDATE_TABLE_IDS_RETRIES {
+        let (seq, table_ids) = get_pb_value(meta_api.as_ref(), &key).await?;
+        let mut table_ids = table_ids.unwrap_or_default();
+        f(&mut table_ids.id_list);
+        let op = if table_ids.id_list.is_empty() {
+            txn_op_del(&key)
+        } else {
+            txn_op_put(&key, serialize_struct(&table_ids).map_err(MetaError::from)?)
+        };
+        let txn_req = TxnRequest {
+            condition: vec![txn_cond_seq(&key, Eq, seq)],
+            if_then: vec![op],
+            else_then: vec![],
+        };
+        let (succ, _) = send_txn(meta_api.as_ref(), txn_req).await?;
+        if succ {
+            return Ok(());
+        }
+    }
+    Err(ErrorCode::TableVersionMismatched(format!(
+        "Can not update the clones of table {}, they are modified concurrently, please retry later",
+        table_id
+    )))
+}
+
+/// Rewrites the table ids under the option `key` of the table `table_id` with `f`, and
+/// retries if the table is modified concurrently. Returns the meta the ids were updated on,
+/// or `None` if the table has been vacuumed.
+#[async_backtrace::framed]
+pub async fn update_table_ids<F>(
+    catalog: &dyn Catalog,
+    tenant: &str,
+    table_id: u64,
+    key: &str,
+    f: F,
+) -> Result<Option<Arc<TableMeta>>>
+where
+    F: Fn(&mut Vec<u64>),
+{
+    for _ in 0..MAX_UPDATE_TABLE_IDS_RETRIES {
+        let (ident, meta) = match catalog.get_table_meta_by_id(table_id).await {
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => return Ok(None),
+            Err(e) => return Err(e),
+            Ok(v) => v,
+        };
+        let mut table_ids = parse_table_ids(&meta.options, key);
+        f(&mut table_ids);
+        let db_id = meta
+            .options
+            .get(OPT_KEY_DATABASE_ID)
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!("Database id of table {} is missing", table_id))
+            })?
+            .parse::<u64>()?;
+        let database = catalog.get_db_name_by_id(db_id).await?;
+        let value = if table_ids.is_empty() {
+            None
+        } else {
+            Some(table_ids.iter().join(","))
+        };
+        let req = UpsertTableOptionReq {
+            table_id,
+            seq: MatchSeq::Exact(ident.seq),
+            options: HashMap::from([(key.to_string(), value)]),
+        };
+        match catalog.upsert_table_option(tenant, &database, req).await {
+            Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => continue,
+            Err(e) => return Err(e),
+            Ok(_) => return Ok(Some(meta)),
+        }
+    }
+    Err(ErrorCode::TableVersionMismatched(format!(
+        "Can not update the option {} of table {}, it is modified concurrently, please retry later",
+        key, table_id
+    )))
+}
+
+impl FuseTable {
+    pub fn is_clone(&self) -> bool {
+        self.table_info
+            .options()
+            .contains_key(OPT_KEY_CLONE_SOURCE_TABLE_ID)
+    }
+
+    /// The ids of the clones of the table, including the clones of its clones.
+    #[async_backtrace::framed]
+    pub async fn clone_table_ids(&self) -> Result<Vec<u64>> {
+        get_clone_table_ids(self.get_id()).await
+    }
+
+    /// Whether any of the clones of the table is not vacuumed yet.
+    #[async_backtrace::framed]
+    pub async fn has_clones(&self, catalog: &dyn Catalog) -> Result<bool> {
+        for table_id in self.clone_table_ids().await? {
+            match catalog.get_table_meta_by_id(table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                Err(e) => return Err(e),
+                Ok(_) => return Ok(true),
+            }
+        }
+        Ok(false)
+    }
+
+    /// Commits the first snapshot of a newly created clone, which references the segments
+    /// of `source_snapshot` instead of copying them.
+    ///
+    /// The clone must have been registered on its sources before `source_snapshot` is read.
+    /// A GC of the source which started before the registration may still purge the snapshot,
+    /// so it is checked again once the clone is committed.
+    #[async_backtrace::framed]
+    pub async fn commit_clone_snapshot(
+        &self,
+        ctx: &dyn TableContext,
+        source_snapshot_location: &str,
+        source_snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        let snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            self.table_info.schema().as_ref().clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            self.cluster_key_meta(),
+            // the table statistics belong to the source, they are collected again by analyzing the clone.
+            None,
+            None,
+        );
+        FuseTable::commit_to_meta_server(
+            ctx,
+            &self.table_info,
+            &self.meta_location_generator,
+            snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await?;
+
+        if !self.operator.is_exist(source_snapshot_location).await? {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "The snapshot {} to be cloned has been purged",
+                source_snapshot_location
+            )));
+        }
+        Ok(())
+    }
+
+    /// Collects the files of the table which are still referenced by any snapshot of its
+    /// clones, dropped clones are taken into account until they are vacuumed.
+    ///
+    /// The blocks of `referenced_segments`, which are kept by the caller anyway, are not read
+    /// again. The ids of the vacuumed clones are pruned from the clones of the table.
+    #[async_backtrace::framed]
+    pub async fn get_shared_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        referenced_segments: &HashSet<String>,
+    ) -> Result<SharedLocations> {
+        let mut shared = SharedLocations {
+            own_prefix: format!("{}/", self.meta_location_generator.prefix()),
+            is_clone: self.is_clone(),
+            ..Default::default()
+        };
+
+        let clone_table_ids = self.clone_table_ids().await?;
+        if clone_table_ids.is_empty() {
+            return Ok(shared);
+        }
+
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let mut vacuumed = HashSet::new();
+        let mut segments = HashSet::new();
+        for table_id in clone_table_ids {
+            let (ident, meta) = match catalog.get_table_meta_by_id(table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => {
+                    info!(
+                        "clone {} of table {} has been vacuumed, ignore it",
+                        table_id, self.table_info.desc
+                    );
+                    vacuumed.insert(table_id);
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            let table_info = TableInfo {
+                ident,
+                desc: format!("clone {} of {}", table_id, self.table_info.desc),
+                meta: meta.as_ref().clone(),
+                ..Default::default()
+            };
+            let table = catalog.get_table_by_info(&table_info)?;
+            let clone = FuseTable::try_from_table(table.as_ref())?;
+            // Snapshots are immutable, they are read through the cache so that the following
+            // GCs of the table do not read them again.
+            let reader = MetaReaders::table_snapshot_reader(clone.get_operator());
+            for location in clone.list_snapshot_files().await? {
+                let params = LoadParams {
+                    ver: TableMetaLocationGenerator::snapshot_version(&location),
+                    location,
+                    len_hint: None,
+                    put_cache: true,
+                };
+                match reader.read(&params).await {
+                    // concurrent gc of the clone, the snapshot is no longer referenced.
+                    Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => continue,
+                    Err(e) => return Err(e),
+                    Ok(snapshot) => segments.extend(snapshot.segments.iter().cloned()),
+                }
+            }
+        }
+
+        if !vacuumed.is_empty() {
+            if let Err(e) =
+                update_clone_table_ids(self.get_id(), |ids| ids.retain(|id| !vacuumed.contains(id)))
+                    .await
+            {
+                warn!(
+                    "failed to prune the vacuumed clones of table {}: {}",
+                    self.table_info.desc, e
+                );
+            }
+        }
+
+        // The segments written by the clone may still reference the blocks of the table,
+        // e.g. after the segments are compacted, so all of them are read except the ones
+        // whose blocks are referenced by the table itself.
+        let own_prefix = shared.own_prefix.clone();
+        let is_own = |loc: &String| loc.starts_with(&own_prefix);
+        let segments = Vec::from_iter(segments);
+        let unreferenced_segments = segments
+            .iter()
+            .filter(|(loc, _)| !referenced_segments.contains(loc))
+            .cloned()
+            .collect::<Vec<_>>();
+        let locations = self
+            .get_block_locations(ctx.clone(), &unreferenced_segments, true, true)
+            .await?;
+        // Only the files under the prefix of the table are kept, the others are shared from
+        // the sources of the table or written by the clones themselves.
+        shared.segments = segments
+            .into_iter()
+            .map(|(loc, _)| loc)
+            .filter(is_own)
+            .collect();
+        shared.blocks = locations
+            .block_location
+            .into_iter()
+            .filter(is_own)
+            .collect();
+        shared.blooms = locations
+            .bloom_location
+            .into_iter()
+            .filter(is_own)
+            .collect();
+        Ok(shared)
+    }
+}
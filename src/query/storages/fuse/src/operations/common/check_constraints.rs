@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::TableSchema;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::OnErrorMode;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_sql::executor::physical_plans::MutationKind;
+use databend_common_sql::parse_exprs;
+
+use crate::FuseTable;
+
+/// Evaluates the CHECK constraints of a table on the blocks written to it.
+///
+/// A row satisfies a constraint if the expression is true or NULL. The rows violating a
+/// constraint fail the statement, unless `ON_ERROR` of the insertion says otherwise:
+///
+/// - `CONTINUE` skips the violating rows.
+/// - `ABORT_N` skips the violating rows, until `N` rows have been violating.
+/// - `SKIP_FILE` fails, like the parse errors do. The blocks written to the table may mix
+///   the rows of several files, there is no file to skip.
+///
+/// The skipped rows are counted per constraint in the copy status of the query.
+pub struct CheckConstraints {
+    ctx: Arc<dyn TableContext>,
+    func_ctx: FunctionContext,
+    table_name: String,
+    // (constraint name, expression on the written block)
+    checks: Vec<(String, Expr)>,
+    // The number of violating rows to abort at, `None` if the violating rows are always
+    // skipped.
+    abort_num: Option<u64>,
+}
+
+impl CheckConstraints {
+    /// Returns `None` if there is nothing to check for the mutation.
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        table: &FuseTable,
+        block_schema: &TableSchema,
+        kind: MutationKind,
+    ) -> Result<Option<Self>> {
+        if !matches!(
+            kind,
+            MutationKind::Insert
+                | MutationKind::Replace
+                | MutationKind::Update
+                | MutationKind::MergeInto
+        ) {
+            return Ok(None);
+        }
+
+        let (names, exprs): (Vec<_>, Vec<_>) = table
+            .get_table_info()
+            .meta
+            .constraints
+            .iter()
+            .filter_map(|(name, constraint)| match constraint {
+                TableConstraint::Check { expr } => Some((name.clone(), expr.clone())),
+                _ => None,
+            })
+            .unzip();
+        if names.is_empty() {
+            return Ok(None);
+        }
+
+        let sql = exprs
+            .iter()
+            .map(|expr| format!("coalesce(({expr}), true)"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let table_schema = table.schema();
+        let exprs = parse_exprs(ctx.clone(), Arc::new(table.clone()), &sql)?;
+        let checks = names
+            .into_iter()
+            .zip(exprs)
+            .map(|(name, expr)| {
+                // e.g. a virtual computed column, which is rejected by CREATE TABLE.
+                let missing = expr
+                    .column_refs()
+                    .into_keys()
+                    .map(|index| table_schema.field(index).name())
+                    .find(|column| block_schema.index_of(column).is_err());
+                if let Some(column) = missing {
+                    return Err(ErrorCode::Internal(format!(
+                        "check constraint `{}` of table `{}` references column `{}` which is not written",
+                        name,
+                        table.name(),
+                        column
+                    )));
+                }
+                // The expressions are bound to the table schema, map them to the written block.
+                let expr = expr.project_column_ref(|index| {
+                    block_schema
+                        .index_of(table_schema.field(*index).name())
+                        .unwrap()
+                });
+                Ok((name, expr))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // only the insertions can skip rows, an update or a merge always fails.
+        let on_error_mode = match kind {
+            MutationKind::Insert => ctx.get_on_error_mode(),
+            _ => None,
+        };
+        let abort_num = match on_error_mode {
+            Some(OnErrorMode::Continue) => None,
+            Some(OnErrorMode::AbortNum(n)) => Some(n),
+            Some(OnErrorMode::SkipFileNum(_)) | None => Some(1),
+        };
+
+        Ok(Some(CheckConstraints {
+            func_ctx: ctx.get_function_context()?,
+            table_name: table.name().to_string(),
+            checks,
+            abort_num,
+            ctx,
+        }))
+    }
+
+    pub fn check(&self, block: DataBlock) -> Result<DataBlock> {
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let mut filter: Option<Bitmap> = None;
+        for (name, expr) in &self.checks {
+            let value = evaluator.run(expr)?;
+            let passed = match value.try_downcast::<BooleanType>().unwrap() {
+                Value::Scalar(true) => continue,
+                Value::Scalar(false) => Bitmap::new_zeroed(block.num_rows()),
+                Value::Column(passed) if passed.unset_bits() == 0 => continue,
+                Value::Column(passed) => passed,
+            };
+            let violated = match self.abort_num {
+                Some(1) => 1,
+                _ => self
+                    .ctx
+                    .get_copy_status()
+                    .add_check_violations(name, passed.unset_bits()),
+            };
+            if self.abort_num.is_some_and(|n| violated as u64 >= n) {
+                return Err(ErrorCode::CheckConstraintViolated(format!(
+                    "new row for table `{}` violates check constraint `{}`",
+                    self.table_name, name
+                )));
+            }
+            filter = Some(match filter {
+                Some(filter) => &filter & &passed,
+                None => passed,
+            });
+        }
+
+        match filter {
+            Some(filter) => block.filter_with_bitmap(&filter),
+            None => Ok(block),
+        }
+    }
+}
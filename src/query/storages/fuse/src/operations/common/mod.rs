@@ -13,11 +13,13 @@
 // limitations under the License.
 
 mod abort_operation;
+mod check_constraints;
 mod meta;
 mod processors;
 mod snapshot_generator;
 
 pub use abort_operation::AbortOperation;
+pub use check_constraints::CheckConstraints;
 pub use meta::*;
 pub use processors::*;
 pub use snapshot_generator::*;
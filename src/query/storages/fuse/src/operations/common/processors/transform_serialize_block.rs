@@ -40,6 +40,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::operations::common::BlockMetaIndex;
+use crate::operations::common::CheckConstraints;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
@@ -69,6 +70,7 @@ pub struct TransformSerializeBlock {
 
     block_builder: BlockBuilder,
     dal: Operator,
+    check_constraints: Option<CheckConstraints>,
 }
 
 impl TransformSerializeBlock {
@@ -99,6 +101,9 @@ impl TransformSerializeBlock {
             ..table.schema().as_ref().clone()
         });
 
+        let check_constraints =
+            CheckConstraints::try_create(ctx.clone(), table, &source_schema, kind)?;
+
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
@@ -117,6 +122,7 @@ impl TransformSerializeBlock {
             output_data: None,
             block_builder,
             dal: table.get_operator(),
+            check_constraints,
         })
     }
 
@@ -251,6 +257,16 @@ impl Processor for TransformSerializeBlock {
                 // Check if the datablock is valid, this is needed to ensure data is correct
                 block.check_valid()?;
 
+                let block = match &self.check_constraints {
+                    Some(check_constraints) => check_constraints.check(block)?,
+                    None => block,
+                };
+                if block.is_empty() {
+                    // all the rows violate the check constraints and are skipped
+                    self.output_data = Some(Self::mutation_logs(MutationLogEntry::DoNothing));
+                    return Ok(());
+                }
+
                 let serialized =
                     self.block_builder
                         .build(block, |block, generator| match &stats_type {
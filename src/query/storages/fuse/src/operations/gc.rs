@@ -40,6 +40,7 @@ use crate::io::SegmentsIO;
 use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::SharedLocations;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
@@ -80,6 +81,21 @@ impl FuseTable {
         let mut dry_run_purge_files = vec![];
         let mut purged_snapshot_count = 0;
 
+        // The files shared with the clones of the table are never purged, they are collected
+        // once there is something to purge. The blocks of the root snapshot are kept anyway,
+        // unless the root snapshot is purged too.
+        let mut shared_locations = None;
+        let referenced_segments = if keep_last_snapshot {
+            root_snapshot_info
+                .snapshot_lite
+                .segments
+                .iter()
+                .map(|(loc, _)| loc.clone())
+                .collect()
+        } else {
+            HashSet::new()
+        };
+
         let catalog = ctx.get_catalog(&ctx.get_current_catalog()).await?;
         let table_agg_index_ids = catalog
             .list_index_ids_by_table_id(ListIndexesByIdReq {
@@ -160,12 +176,16 @@ impl FuseTable {
             }
 
             if !snapshots_to_be_purged.is_empty() {
+                let shared_locations = self
+                    .load_shared_locations(ctx, &mut shared_locations, &referenced_segments)
+                    .await?;
                 if dry_run {
                     debug_assert!(limit.is_some());
                     self.dry_run_purge(
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        shared_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -181,6 +201,7 @@ impl FuseTable {
                         ctx,
                         &mut counter,
                         &root_snapshot_info.referenced_locations,
+                        shared_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -216,11 +237,15 @@ impl FuseTable {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
             }
+            let shared_locations = self
+                .load_shared_locations(ctx, &mut shared_locations, &referenced_segments)
+                .await?;
             if dry_run {
                 self.dry_run_purge(
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    shared_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -232,6 +257,7 @@ impl FuseTable {
                     ctx,
                     &mut counter,
                     &root_snapshot_info.referenced_locations,
+                    shared_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -247,12 +273,16 @@ impl FuseTable {
 
         // 3. purge root snapshots.
         if !keep_last_snapshot {
+            let shared_locations = self
+                .load_shared_locations(ctx, &mut shared_locations, &referenced_segments)
+                .await?;
             self.purge_root_snapshot(
                 ctx,
                 &mut counter,
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                shared_locations,
                 &table_agg_index_ids,
             )
             .await?;
@@ -260,6 +290,19 @@ impl FuseTable {
         Ok(None)
     }
 
+    #[async_backtrace::framed]
+    async fn load_shared_locations<'a>(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        shared_locations: &'a mut Option<SharedLocations>,
+        referenced_segments: &HashSet<String>,
+    ) -> Result<&'a SharedLocations> {
+        if shared_locations.is_none() {
+            *shared_locations = Some(self.get_shared_locations(ctx, referenced_segments).await?);
+        }
+        Ok(shared_locations.as_ref().unwrap())
+    }
+
     async fn read_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -316,6 +359,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        shared_locations: &SharedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -331,7 +375,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || !shared_locations.is_purgeable(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -345,15 +391,27 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || !shared_locations.is_purgeable(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| shared_locations.is_purgeable(&loc.0))
+                    .map(|loc| loc.0.clone()),
+            );
         }
-        purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.extend(
+            ts_to_be_purged
+                .iter()
+                .filter(|loc| shared_locations.is_owned(loc))
+                .map(|loc| loc.to_string()),
+        );
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
 
         Ok(())
@@ -365,6 +423,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        shared_locations: &SharedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -383,7 +442,9 @@ impl FuseTable {
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || !shared_locations.is_purgeable(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
@@ -398,18 +459,21 @@ impl FuseTable {
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || !shared_locations.is_purgeable(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
-            let segment_locations_to_be_purged = HashSet::from_iter(
+            let mut segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
                     .map(|loc| loc.0.clone())
                     .collect::<Vec<String>>(),
             );
+            shared_locations.retain_purgeable(&mut segment_locations_to_be_purged);
 
             // Refresh status.
             {
@@ -434,6 +498,8 @@ impl FuseTable {
             .await?;
         }
 
+        let mut ts_to_be_purged = ts_to_be_purged;
+        shared_locations.retain_owned(&mut ts_to_be_purged);
         self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
             .await
     }
@@ -445,15 +511,20 @@ impl FuseTable {
         root_snapshot: Arc<SnapshotLiteExtended>,
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        shared_locations: &SharedLocations,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
-        let segment_locations_to_be_purged = HashSet::from_iter(
+        let mut root_location_tuple = root_location_tuple;
+        shared_locations.retain_purgeable(&mut root_location_tuple.block_location);
+        shared_locations.retain_purgeable(&mut root_location_tuple.bloom_location);
+        let mut segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        shared_locations.retain_purgeable(&mut segment_locations_to_be_purged);
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         for index_id in table_agg_index_ids {
@@ -478,6 +549,7 @@ impl FuseTable {
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
+        shared_locations.retain_owned(&mut ts_to_be_purged);
         self.purge_ts_snapshots(
             ctx,
             counter,
@@ -15,6 +15,8 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod backup;
+mod clone;
 mod commit;
 mod common;
 mod compact;
@@ -36,6 +38,10 @@ mod update;
 mod util;
 
 pub use agg_index_sink::AggIndexSink;
+pub use clone::get_clone_table_ids;
+pub use clone::update_clone_table_ids;
+pub use clone::update_table_ids;
+pub use clone::SharedLocations;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
@@ -195,6 +195,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
+                            create_on: None,
                             offsets: None,
                             base_block_ids: None,
                             inner: None,
@@ -75,6 +75,46 @@ impl FuseTable {
         }
     }
 
+    /// Reads the snapshot of the table as of `time_point` along with its location, `None` if
+    /// the table had no data at that point, which is told apart from purged history by
+    /// reaching the first snapshot.
+    #[async_backtrace::framed]
+    pub async fn read_table_snapshot_as_of(
+        &self,
+        time_point: DateTime<Utc>,
+    ) -> Result<Option<(Arc<TableSnapshot>, String)>> {
+        let Some(location) = self.snapshot_loc().await? else {
+            return Ok(None);
+        };
+        let snapshot_version = TableMetaLocationGenerator::snapshot_version(location.as_str());
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut snapshot_stream = reader.snapshot_history(
+            location,
+            snapshot_version,
+            self.meta_location_generator().clone(),
+        );
+
+        let mut reached_first = false;
+        while let Some((snapshot, format_version)) = snapshot_stream.try_next().await? {
+            if snapshot.timestamp.is_some_and(|ts| ts <= time_point) {
+                let location = self
+                    .meta_location_generator
+                    .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?;
+                return Ok(Some((snapshot, location)));
+            }
+            reached_first = snapshot.prev_snapshot_id.is_none();
+        }
+
+        if reached_first {
+            Ok(None)
+        } else {
+            Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point for table {}, the history has been purged",
+                self.table_info.desc
+            )))
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn navigate_to_snapshot(
         &self,
@@ -73,6 +73,7 @@ pub(crate) fn add_data_block_meta(
             block_location: block_meta.block_location.clone(),
             segment_location: block_meta.segment_location.clone(),
             snapshot_location: block_meta.snapshot_location.clone(),
+            create_on: fuse_part.create_on,
             offsets,
             base_block_ids,
             inner: meta,
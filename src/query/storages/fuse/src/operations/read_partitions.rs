@@ -211,16 +211,16 @@ impl FuseTable {
 
         type CacheItem = (PartStatistics, Partitions);
 
-        let derterministic_cache_key =
-            push_downs
-                .as_ref()
-                .filter(|p| p.is_deterministic)
-                .map(|push_downs| {
-                    format!(
-                        "{:x}",
-                        Sha256::digest(format!("{:?}_{:?}", segments_location, push_downs))
-                    )
-                });
+        let derterministic_cache_key = push_downs
+            .as_ref()
+            // A sample without seed picks different blocks on every read.
+            .filter(|p| p.is_deterministic && p.sample.as_ref().map_or(true, |s| s.seed.is_some()))
+            .map(|push_downs| {
+                format!(
+                    "{:x}",
+                    Sha256::digest(format!("{:?}_{:?}", segments_location, push_downs))
+                )
+            });
 
         if let Some(cache_key) = derterministic_cache_key.as_ref() {
             if let Some(cache) = CacheItem::cache() {
@@ -400,9 +400,9 @@ impl FuseTable {
     }
 
     fn is_exact(push_downs: &Option<PushDownInfo>) -> bool {
-        push_downs
-            .as_ref()
-            .map_or(true, |extra| extra.filters.is_none())
+        push_downs.as_ref().map_or(true, |extra| {
+            extra.filters.is_none() && extra.sample.is_none()
+        })
     }
 
     fn all_columns_partitions(
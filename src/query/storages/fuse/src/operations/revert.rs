@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use databend_common_catalog::table::NavigationDescriptor;
-use databend_common_catalog::table::Table;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -29,48 +29,64 @@ impl FuseTable {
         ctx: &dyn TableContext,
         navigation_descriptor: NavigationDescriptor,
     ) -> Result<()> {
+        let Some((req, snapshot_location)) =
+            self.prepare_revert_to(&navigation_descriptor.point).await?
+        else {
+            return Ok(());
+        };
+
+        // let's roll
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        catalog.update_table_meta(&self.table_info, req).await?;
+
+        // try keep the snapshot hit
+        Self::write_last_snapshot_hint(
+            &self.operator,
+            &self.meta_location_generator,
+            snapshot_location,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// Prepares the request which reverts the table to `point`, along with the location of the
+    /// snapshot reverted to. Returns `None` if the table is already at the point.
+    ///
+    /// The request is not committed, so that the reverts of several tables can be committed
+    /// together, see [`FuseTable::write_last_snapshot_hint`] for the hint to write afterwards.
+    #[async_backtrace::framed]
+    pub async fn prepare_revert_to(
+        &self,
+        point: &NavigationPoint,
+    ) -> Result<Option<(UpdateTableMetaReq, String)>> {
         // 1. try navigate to the point
-        let table = self.navigate_to(&navigation_descriptor.point).await?;
+        let table = self.navigate_to(point).await?;
         let table_reverting_to = FuseTable::try_from_table(table.as_ref())?;
-        let table_info = table_reverting_to.get_table_info();
 
-        // shortcut. if reverting to the same point, just return ok
-        if self.snapshot_loc().await? == table_reverting_to.snapshot_loc().await? {
-            return Ok(());
+        // shortcut. if reverting to the same point, there is nothing to commit
+        let snapshot_location = table_reverting_to.snapshot_loc().await?;
+        if self.snapshot_loc().await? == snapshot_location {
+            return Ok(None);
         }
+        let snapshot_location = snapshot_location.ok_or_else(|| {
+            ErrorCode::Internal(
+                "internal error, fuse table which navigated to given point has no snapshot location",
+            )
+        })?;
 
         // 2. prepare table meta which being reverted to
         let table_meta_to_be_committed = table_reverting_to.table_info.meta.clone();
 
         // 3. prepare the request
         //  using the CURRENT version as the base table version
-        let base_version = self.table_info.ident.seq;
-        let catalog = ctx.get_catalog(&table_info.meta.catalog).await?;
-        let table_id = table_info.ident.table_id;
         let req = UpdateTableMetaReq {
-            table_id,
-            seq: MatchSeq::Exact(base_version),
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
             new_table_meta: table_meta_to_be_committed,
             copied_files: None,
             deduplicated_label: None,
             update_stream_meta: vec![],
         };
-
-        // 4. let's roll
-        let reply = catalog.update_table_meta(&self.table_info, req).await;
-        if reply.is_ok() {
-            // try keep the snapshot hit
-            let snapshot_location = table_reverting_to.snapshot_loc().await?.ok_or_else(|| {
-                    ErrorCode::Internal("internal error, fuse table which navigated to given point has no snapshot location")
-                })?;
-            Self::write_last_snapshot_hint(
-                &table_reverting_to.operator,
-                &table_reverting_to.meta_location_generator,
-                snapshot_location,
-            )
-            .await;
-        };
-
-        reply.map(|_| ())
+        Ok(Some((req, snapshot_location)))
     }
 }
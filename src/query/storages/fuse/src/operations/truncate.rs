@@ -31,58 +31,14 @@ impl FuseTable {
     #[inline]
     #[async_backtrace::framed]
     pub async fn do_truncate(&self, ctx: Arc<dyn TableContext>, purge: bool) -> Result<()> {
-        if let Some(prev_snapshot) = self.read_table_snapshot().await? {
-            // 1. prepare new snapshot
-            let prev_id = prev_snapshot.snapshot_id;
-            let prev_format_version = self.snapshot_format_version(None).await?;
-            let new_snapshot = TableSnapshot::new(
-                Uuid::new_v4(),
-                &prev_snapshot.timestamp,
-                Some((prev_id, prev_format_version)),
-                prev_snapshot.schema.clone(),
-                Default::default(),
-                vec![],
-                self.cluster_key_meta.clone(),
-                // truncate MUST reset ts location
-                None,
-                None,
-            );
-
-            // 2. write down new snapshot
-            let loc = self.meta_location_generator();
-            let new_snapshot_loc =
-                loc.snapshot_location_from_uuid(&new_snapshot.snapshot_id, TableSnapshot::VERSION)?;
-            let bytes = new_snapshot.to_bytes()?;
-            self.operator.write(&new_snapshot_loc, bytes).await?;
-
-            // 3. commit new meta to meta server
-            let mut new_table_meta = self.table_info.meta.clone();
-
-            // update snapshot location
-            new_table_meta.options.insert(
-                OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
-                new_snapshot_loc.clone(),
-            );
-            // reset table statistics
-            new_table_meta.statistics = TableStatistics::default();
-
+        if let Some((req, new_snapshot_loc)) = self.prepare_truncate().await? {
             let table_id = self.table_info.ident.table_id;
-            let table_version = self.table_info.ident.seq;
             let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
 
             // commit table meta to meta server.
             // `truncate_table` is not supposed to be retry-able, thus we use
             // `update_data_table_meta` directly.
-            catalog
-                .update_table_meta(&self.table_info, UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                    copied_files: None,
-                    deduplicated_label: None,
-                    update_stream_meta: vec![],
-                })
-                .await?;
+            catalog.update_table_meta(&self.table_info, req).await?;
 
             catalog
                 .truncate_table(&self.table_info, TruncateTableReq {
@@ -119,4 +75,58 @@ impl FuseTable {
 
         Ok(())
     }
+
+    /// Writes down an empty snapshot following the current one, and prepares the request
+    /// which commits it, along with the location of the new snapshot. Returns `None` if the
+    /// table has no snapshot yet.
+    #[async_backtrace::framed]
+    pub async fn prepare_truncate(&self) -> Result<Option<(UpdateTableMetaReq, String)>> {
+        let Some(prev_snapshot) = self.read_table_snapshot().await? else {
+            return Ok(None);
+        };
+
+        // 1. prepare new snapshot
+        let prev_id = prev_snapshot.snapshot_id;
+        let prev_format_version = self.snapshot_format_version(None).await?;
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev_snapshot.timestamp,
+            Some((prev_id, prev_format_version)),
+            prev_snapshot.schema.clone(),
+            Default::default(),
+            vec![],
+            self.cluster_key_meta.clone(),
+            // truncate MUST reset ts location
+            None,
+            None,
+        );
+
+        // 2. write down new snapshot
+        let loc = self.meta_location_generator();
+        let new_snapshot_loc =
+            loc.snapshot_location_from_uuid(&new_snapshot.snapshot_id, TableSnapshot::VERSION)?;
+        let bytes = new_snapshot.to_bytes()?;
+        self.operator.write(&new_snapshot_loc, bytes).await?;
+
+        // 3. prepare the new meta
+        let mut new_table_meta = self.table_info.meta.clone();
+
+        // update snapshot location
+        new_table_meta.options.insert(
+            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+            new_snapshot_loc.clone(),
+        );
+        // reset table statistics
+        new_table_meta.statistics = TableStatistics::default();
+
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+            update_stream_meta: vec![],
+        };
+        Ok(Some((req, new_snapshot_loc)))
+    }
 }
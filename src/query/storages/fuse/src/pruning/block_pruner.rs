@@ -72,18 +72,19 @@ impl BlockPruner {
         let range_pruner = self.pruning_ctx.range_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
 
-        let blocks = if let Some(internal_column_pruner) = &self.pruning_ctx.internal_column_pruner
-        {
-            block_metas
-                .iter()
-                .enumerate()
-                .filter(|(_, block)| {
-                    internal_column_pruner.should_keep(BLOCK_NAME_COL_NAME, &block.location.0)
-                })
-                .collect::<Vec<_>>()
-        } else {
-            block_metas.iter().enumerate().collect()
-        };
+        let internal_column_pruner = &self.pruning_ctx.internal_column_pruner;
+        let sample_pruner = &self.pruning_ctx.sample_pruner;
+        let blocks = block_metas
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| {
+                internal_column_pruner.as_ref().map_or(true, |pruner| {
+                    pruner.should_keep(BLOCK_NAME_COL_NAME, &block.location.0)
+                }) && sample_pruner
+                    .as_ref()
+                    .map_or(true, |pruner| pruner.should_keep(&block.location.0))
+            })
+            .collect::<Vec<_>>();
 
         let mut blocks = blocks.into_iter();
         let pruning_tasks = std::iter::from_fn(|| {
@@ -229,18 +230,19 @@ impl BlockPruner {
 
         let start = Instant::now();
 
-        let blocks = if let Some(internal_column_pruner) = &self.pruning_ctx.internal_column_pruner
-        {
-            block_metas
-                .iter()
-                .enumerate()
-                .filter(|(_, block)| {
-                    internal_column_pruner.should_keep(BLOCK_NAME_COL_NAME, &block.location.0)
-                })
-                .collect::<Vec<_>>()
-        } else {
-            block_metas.iter().enumerate().collect::<Vec<_>>()
-        };
+        let internal_column_pruner = &self.pruning_ctx.internal_column_pruner;
+        let sample_pruner = &self.pruning_ctx.sample_pruner;
+        let blocks = block_metas
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| {
+                internal_column_pruner.as_ref().map_or(true, |pruner| {
+                    pruner.should_keep(BLOCK_NAME_COL_NAME, &block.location.0)
+                }) && sample_pruner
+                    .as_ref()
+                    .map_or(true, |pruner| pruner.should_keep(&block.location.0))
+            })
+            .collect::<Vec<_>>();
         let mut result = Vec::with_capacity(blocks.len());
         let block_num = block_metas.len();
         for (block_idx, block_meta) in blocks {
@@ -18,6 +18,7 @@ use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::TableSample;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -36,6 +37,7 @@ use databend_storages_common_pruner::PagePruner;
 use databend_storages_common_pruner::PagePrunerCreator;
 use databend_storages_common_pruner::RangePruner;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_pruner::SamplePruner;
 use databend_storages_common_pruner::TopNPrunner;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterKey;
@@ -63,6 +65,7 @@ pub struct PruningContext {
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
+    pub sample_pruner: Option<Arc<SamplePruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
 }
@@ -145,6 +148,10 @@ impl PruningContext {
         let internal_column_pruner =
             InternalColumnPruner::try_create(func_ctx, filter_expr.as_ref());
 
+        // Sample pruner, for `TABLESAMPLE BLOCK`, the blocks not sampled are pruned.
+        let sample_pruner = TableSample::block_sample(push_down)
+            .map(|sample| SamplePruner::create(sample.probability.0, sample.seed));
+
         // Constraint the degree of parallelism
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
 
@@ -166,6 +173,7 @@ impl PruningContext {
             bloom_pruner,
             page_pruner,
             internal_column_pruner,
+            sample_pruner,
             pruning_stats,
         });
         Ok(pruning_ctx)
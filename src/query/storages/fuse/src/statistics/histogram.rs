@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
+use databend_storages_common_table_meta::meta::HistogramBucket;
+use databend_storages_common_table_meta::meta::MostCommonValue;
+
+pub const HISTOGRAM_NUM_BUCKETS: usize = 100;
+pub const HISTOGRAM_NUM_MOST_COMMON_VALUES: usize = 16;
+/// The values longer than this many bytes are left out of the histogram.
+pub const HISTOGRAM_MAX_VALUE_SIZE: usize = 256;
+
+/// A value is one of the most common values if it is at least this many times as frequent
+/// as the average value of the sample.
+const MOST_COMMON_VALUE_FACTOR: f64 = 1.25;
+
+/// Builds the histogram of a column from a sample of its non-null values.
+///
+/// The values which can't be kept in the statistics are skipped, see [`is_histogram_value`].
+///
+/// # Arguments
+///  * `values` - the sampled non-null values
+///  * `scale` - number of rows of the table per sampled row
+///  * `ndv` - estimated number of distinct values of the table, if any
+pub fn build_column_histogram(
+    mut values: Vec<Scalar>,
+    scale: f64,
+    ndv: Option<u64>,
+) -> ColumnHistogram {
+    values.retain(is_histogram_value);
+    if values.is_empty() {
+        return ColumnHistogram::default();
+    }
+
+    values.sort();
+    let mut runs: Vec<(Scalar, usize)> = Vec::new();
+    for value in values {
+        match runs.last_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => runs.push((value, 1)),
+        }
+    }
+    let num_sampled = runs.iter().map(|(_, count)| count).sum::<usize>();
+    let average = num_sampled as f64 / runs.len() as f64;
+
+    let mut most_common = (0..runs.len())
+        .filter(|i| runs[*i].1 > 1 && runs[*i].1 as f64 > average * MOST_COMMON_VALUE_FACTOR)
+        .collect::<Vec<_>>();
+    most_common.sort_by(|a, b| runs[*b].1.cmp(&runs[*a].1));
+    most_common.truncate(HISTOGRAM_NUM_MOST_COMMON_VALUES);
+    let most_common = most_common.into_iter().collect::<HashSet<_>>();
+
+    let mut most_common_values = Vec::with_capacity(most_common.len());
+    let mut rest = Vec::with_capacity(runs.len() - most_common.len());
+    for (i, (value, count)) in runs.into_iter().enumerate() {
+        if most_common.contains(&i) {
+            most_common_values.push(MostCommonValue {
+                value,
+                num_values: count as f64 * scale,
+            });
+        } else {
+            rest.push((value, count));
+        }
+    }
+
+    // The sample misses the rare values, the distinct values of the buckets are scaled up to
+    // the distinct values of the table.
+    let sampled_distinct = rest.len() as f64;
+    let table_distinct = ndv.map_or(sampled_distinct, |ndv| {
+        (ndv as f64 - most_common_values.len() as f64).max(sampled_distinct)
+    });
+    let distinct_scale = if sampled_distinct > 0.0 {
+        table_distinct / sampled_distinct
+    } else {
+        1.0
+    };
+
+    let rest_rows = rest.iter().map(|(_, count)| count).sum::<usize>();
+    let bucket_rows = rest_rows.div_ceil(HISTOGRAM_NUM_BUCKETS).max(1);
+    let mut buckets = Vec::with_capacity(HISTOGRAM_NUM_BUCKETS);
+    let mut bucket: Option<(Scalar, usize, usize)> = None;
+    let num_rest = rest.len();
+    for (i, (value, count)) in rest.into_iter().enumerate() {
+        let (lower_bound, num_values, num_distinct) =
+            bucket.get_or_insert_with(|| (value.clone(), 0, 0));
+        *num_values += count;
+        *num_distinct += 1;
+        // a value never spans two buckets.
+        if *num_values >= bucket_rows || i + 1 == num_rest {
+            buckets.push(HistogramBucket {
+                lower_bound: lower_bound.clone(),
+                upper_bound: value,
+                num_values: *num_values as f64 * scale,
+                num_distinct: *num_distinct as f64 * distinct_scale,
+            });
+            bucket = None;
+        }
+    }
+
+    ColumnHistogram {
+        buckets,
+        most_common_values,
+    }
+}
+
+/// The statistics are written as json, which has no NaN or infinity, and the long values
+/// would bloat the statistics, so the non-finite floats and the values longer than
+/// [`HISTOGRAM_MAX_VALUE_SIZE`] are not kept. The nested values are not kept either, the
+/// estimation never compares them.
+fn is_histogram_value(value: &Scalar) -> bool {
+    match value {
+        Scalar::Number(NumberScalar::Float32(v)) => v.is_finite(),
+        Scalar::Number(NumberScalar::Float64(v)) => v.is_finite(),
+        Scalar::String(v) => v.len() <= HISTOGRAM_MAX_VALUE_SIZE,
+        Scalar::Binary(v) | Scalar::Bitmap(v) | Scalar::Variant(v) | Scalar::Geometry(v) => {
+            v.len() <= HISTOGRAM_MAX_VALUE_SIZE
+        }
+        Scalar::Array(_) | Scalar::Map(_) | Scalar::Tuple(_) => false,
+        _ => true,
+    }
+}
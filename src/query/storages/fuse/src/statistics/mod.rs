@@ -16,6 +16,7 @@ pub mod accumulator;
 mod block_statistics;
 mod cluster_statistics;
 mod column_statistic;
+mod histogram;
 pub mod reducers;
 
 pub use accumulator::StatisticsAccumulator;
@@ -30,6 +31,10 @@ pub use column_statistic::traverse;
 pub use column_statistic::Trim;
 pub use column_statistic::STATS_REPLACEMENT_CHAR;
 pub use column_statistic::STATS_STRING_PREFIX_LEN;
+pub use histogram::build_column_histogram;
+pub use histogram::HISTOGRAM_MAX_VALUE_SIZE;
+pub use histogram::HISTOGRAM_NUM_BUCKETS;
+pub use histogram::HISTOGRAM_NUM_MOST_COMMON_VALUES;
 pub use reducers::merge_statistics;
 pub use reducers::reduce_block_metas;
 pub use reducers::reduce_block_statistics;
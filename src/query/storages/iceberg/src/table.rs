@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -48,6 +49,7 @@ use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::table::ChangeType;
 use icelake::catalog::Catalog;
 use opendal::Operator;
 use tokio::sync::OnceCell;
@@ -260,6 +262,16 @@ impl IcebergTable {
             ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
         })?;
 
+        self.prune_data_files(ctx, table, data_files, push_downs)
+    }
+
+    fn prune_data_files(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        table: &icelake::Table,
+        data_files: Vec<icelake::types::DataFile>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
         let filter = push_downs.as_ref().and_then(|extra| {
             extra
                 .filters
@@ -316,6 +328,66 @@ impl IcebergTable {
             Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
         ))
     }
+
+    /// Reads the live files of the given snapshot from its manifests,
+    /// the snapshot must not be expired.
+    ///
+    /// Returns the data files and the (v2) delete files separately.
+    async fn snapshot_files(
+        &self,
+        table: &icelake::Table,
+        snapshot_id: &str,
+    ) -> Result<(Vec<icelake::types::DataFile>, Vec<icelake::types::DataFile>)> {
+        // The table had no snapshot when the stream was created.
+        if snapshot_id.is_empty() {
+            return Ok((vec![], vec![]));
+        }
+        let snapshot_id = snapshot_id.parse::<i64>()?;
+        let snapshot = table
+            .current_table_metadata()
+            .snapshots
+            .iter()
+            .flatten()
+            .find(|snapshot| snapshot.snapshot_id == snapshot_id)
+            .ok_or_else(|| {
+                ErrorCode::IllegalStream(format!(
+                    "The snapshot {snapshot_id} of iceberg table {} is expired",
+                    self.info.name
+                ))
+            })?;
+
+        let op = init_operator(self.get_storage_params()?)?;
+        let content = read_table_file(&op, table, &snapshot.manifest_list).await?;
+        let manifest_list = icelake::types::parse_manifest_list(&content).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse manifest list: {e:?}"))
+        })?;
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+        for entry in manifest_list.entries {
+            let content = read_table_file(&op, table, &entry.manifest_path).await?;
+            let manifest = icelake::types::parse_manifest_file(&content).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Cannot parse manifest file: {e:?}"))
+            })?;
+            let files = manifest
+                .entries
+                .into_iter()
+                .filter(|entry| entry.status != icelake::types::ManifestStatus::Deleted)
+                .map(|entry| entry.data_file);
+            // Delete manifests only track position/equality delete files, check the
+            // content of the files as well in case a writer mixed them up.
+            if entry.content == icelake::types::ManifestContentType::Deletes {
+                delete_files.extend(files);
+            } else {
+                for file in files {
+                    match file.content {
+                        icelake::types::DataContentType::Data => data_files.push(file),
+                        _ => delete_files.push(file),
+                    }
+                }
+            }
+        }
+        Ok((data_files, delete_files))
+    }
 }
 
 #[async_trait]
@@ -368,6 +440,69 @@ impl Table for IcebergTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    #[async_backtrace::framed]
+    async fn changes_version(&self) -> Result<String> {
+        let table = self.table().await?;
+        Ok(table
+            .current_table_metadata()
+            .current_snapshot_id
+            .map(|id| id.to_string())
+            .unwrap_or_default())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_changes_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base_version: &str,
+        change_type: ChangeType,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let table = self.table().await?;
+
+        let latest_version = self.changes_version().await?;
+        let (base_files, base_deletes) = self.snapshot_files(table, base_version).await?;
+        let (latest_files, latest_deletes) = self.snapshot_files(table, &latest_version).await?;
+
+        // Row level deletes (merge-on-read) keep the data files and only add delete files,
+        // they cannot be expressed by the difference of the data files.
+        let base_deletes: HashSet<String> =
+            base_deletes.into_iter().map(|df| df.file_path).collect();
+        if latest_deletes
+            .iter()
+            .any(|df| !base_deletes.contains(&df.file_path))
+        {
+            return Err(ErrorCode::IllegalStream(format!(
+                "Stream on iceberg table {} does not support row level deletes, \
+                 rewrite the data files of the table instead (copy-on-write)",
+                self.info.name
+            )));
+        }
+
+        // Data files are immutable, the changes are the files that only exist on one side.
+        let (files, others) = match change_type {
+            ChangeType::Append | ChangeType::Insert => (latest_files, base_files),
+            ChangeType::Delete => (base_files, latest_files),
+        };
+        let others: HashSet<String> = others.into_iter().map(|df| df.file_path).collect();
+        let files = files
+            .into_iter()
+            .filter(|df| !others.contains(&df.file_path))
+            .collect();
+
+        self.prune_data_files(ctx, table, files, push_downs)
+    }
+}
+
+async fn read_table_file(op: &Operator, table: &icelake::Table, path: &str) -> Result<Vec<u8>> {
+    let path = table
+        .rel_path(path)
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("Invalid iceberg file path: {e:?}")))?;
+    let content = op.read(&path).await.map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("Cannot read iceberg file {path}: {e:?}"))
+    })?;
+    Ok(content.to_vec())
 }
 
 struct OperatorCreatorWrapper(DataOperator);
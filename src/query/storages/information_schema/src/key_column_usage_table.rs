@@ -27,18 +27,20 @@ pub struct KeyColumnUsageTable {}
 impl KeyColumnUsageTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT \
-        NULL as constraint_catalog, \
-        NULL as constraint_schema, \
-        NULL as constraint_name, \
-        NULL as table_catalog, \
-        NULL as table_schema, \
-        NULL as table_name, \
-        NULL as column_name, \
-        NULL as ordinal_position, \
-        NULL as position_in_unique_constraint, \
-        NULL as referenced_table_schema, \
-        NULL as referenced_table_name, \
-        NULL as referenced_column_name"
+        'default' as constraint_catalog, \
+        database as constraint_schema, \
+        name as constraint_name, \
+        'default' as table_catalog, \
+        database as table_schema, \
+        table as table_name, \
+        column_name as column_name, \
+        ordinal_position as ordinal_position, \
+        if(type = 'FOREIGN KEY', ordinal_position, NULL) as position_in_unique_constraint, \
+        referenced_database as referenced_table_schema, \
+        referenced_table as referenced_table_name, \
+        referenced_column as referenced_column_name \
+        FROM system.constraints \
+        WHERE type <> 'CHECK'"
             .to_string();
 
         let mut options = BTreeMap::new();
@@ -17,6 +17,7 @@ mod key_column_usage_table;
 mod keywords_table;
 mod schemata_table;
 mod statistics_table;
+mod table_constraints_table;
 mod tables_table;
 mod views_table;
 
@@ -25,5 +26,6 @@ pub use key_column_usage_table::KeyColumnUsageTable;
 pub use keywords_table::KeywordsTable;
 pub use schemata_table::SchemataTable;
 pub use statistics_table::StatisticsTable;
+pub use table_constraints_table::TableConstraintsTable;
 pub use tables_table::TablesTable;
 pub use views_table::ViewsTable;
@@ -93,6 +93,12 @@ impl MemoryTable {
         Ok(Box::new(table))
     }
 
+    /// Removes the data of the table from the shared store, it is only used to purge
+    /// the dropped temporary tables.
+    pub fn purge(table_id: u64) {
+        IN_MEMORY_DATA.write().remove(&table_id);
+    }
+
     pub fn description() -> StorageDescription {
         StorageDescription {
             engine_name: "MEMORY".to_string(),
@@ -52,6 +52,10 @@ impl StageTable {
         let op = StageTable::get_op(&self.table_info.stage_info)?;
         let fmt = self.table_info.stage_info.file_format_params.clone();
         let uuid = uuid::Uuid::new_v4().to_string();
+        let uuid = match &self.unload_prefix {
+            Some(prefix) => format!("{prefix}_{uuid}"),
+            None => uuid,
+        };
         let group_id = AtomicUsize::new(0);
         match fmt {
             FileFormatParams::Parquet(_) => append_data_to_parquet_files(
@@ -19,3 +19,4 @@ mod path;
 mod row_based_file;
 
 pub use output::UnloadOutput;
+pub use path::unload_path_prefix;
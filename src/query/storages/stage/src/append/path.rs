@@ -32,24 +32,28 @@ pub fn unload_path(
         .map(|c| format!(".{}", c.extension()))
         .unwrap_or_default();
 
-    let path = &stage_table_info.files_info.path;
+    format!(
+        "{}{:0>4}_{:0>8}.{}{}",
+        unload_path_prefix(&stage_table_info.files_info.path, uuid),
+        group_id,
+        batch_id,
+        format_name,
+        suffix
+    )
+}
 
+/// The common prefix of the paths of the files unloaded to `path` whose names start with `uuid`.
+pub fn unload_path_prefix(path: &str, uuid: &str) -> String {
     if path.ends_with("data_") {
-        format!(
-            "{}{}_{:0>4}_{:0>8}.{}{}",
-            path, uuid, group_id, batch_id, format_name, suffix
-        )
+        format!("{}{}_", path, uuid)
     } else {
         let (path, sep) = if path == "/" {
             ("", "")
         } else if path.ends_with('/') {
-            (path.as_str(), "")
+            (path, "")
         } else {
-            (path.as_str(), "/")
+            (path, "/")
         };
-        format!(
-            "{}{}data_{}_{:0>4}_{:0>8}.{}{}",
-            path, sep, uuid, group_id, batch_id, format_name, suffix
-        )
+        format!("{}{}data_{}_", path, sep, uuid)
     }
 }
@@ -22,4 +22,5 @@ mod one_file_partition;
 mod read;
 mod stage_table;
 
+pub use append::unload_path_prefix;
 pub use stage_table::StageTable;
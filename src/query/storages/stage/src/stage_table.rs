@@ -51,10 +51,21 @@ pub struct StageTable {
     // fn get_table_info(&self) -> &TableInfo).
     table_info_placeholder: TableInfo,
     block_compact_threshold: Mutex<Option<BlockThresholds>>,
+    // The prefix of the names of the unloaded files, before the uuid.
+    unload_prefix: Option<String>,
 }
 
 impl StageTable {
     pub fn try_create(table_info: StageTableInfo) -> Result<Arc<dyn Table>> {
+        Self::try_create_with_unload_prefix(table_info, None)
+    }
+
+    /// Create a stage table whose unloaded files are named by `unload_prefix` followed by a uuid,
+    /// so that the files unloaded by a query can be found by [`crate::unload_path_prefix`].
+    pub fn try_create_with_unload_prefix(
+        table_info: StageTableInfo,
+        unload_prefix: Option<String>,
+    ) -> Result<Arc<dyn Table>> {
         let table_info_placeholder = TableInfo {
             ident: Default::default(),
             desc: "".to_string(),
@@ -70,6 +81,7 @@ impl StageTable {
             table_info,
             table_info_placeholder,
             block_compact_threshold: Default::default(),
+            unload_prefix,
         }))
     }
 
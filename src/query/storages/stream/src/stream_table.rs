@@ -58,6 +58,7 @@ use databend_storages_common_table_meta::table::StreamMode;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_VERSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
@@ -78,6 +79,8 @@ pub struct StreamTable {
     table_version: u64,
     mode: StreamMode,
     snapshot_location: Option<String>,
+    /// The version of an external source table, which is not a fuse table.
+    source_version: Option<String>,
 }
 
 impl StreamTable {
@@ -104,6 +107,7 @@ impl StreamTable {
             .and_then(|s| s.parse::<StreamMode>().ok())
             .unwrap_or(StreamMode::AppendOnly);
         let snapshot_location = options.get(OPT_KEY_SNAPSHOT_LOCATION).cloned();
+        let source_version = options.get(OPT_KEY_SOURCE_VERSION).cloned();
         Ok(Box::new(StreamTable {
             stream_info: table_info,
             table_name,
@@ -112,6 +116,7 @@ impl StreamTable {
             table_version,
             mode,
             snapshot_location,
+            source_version,
         }))
     }
 
@@ -149,7 +154,8 @@ impl StreamTable {
             )));
         }
 
-        if !table.change_tracking_enabled() {
+        // The changes of an external table are tracked by its own versions.
+        if self.source_version.is_none() && !table.change_tracking_enabled() {
             return Err(ErrorCode::IllegalStream(format!(
                 "Change tracking is not enabled for table '{}.{}'",
                 self.table_database, self.table_name
@@ -171,6 +177,10 @@ impl StreamTable {
         self.snapshot_location.clone()
     }
 
+    pub fn source_version(&self) -> Option<String> {
+        self.source_version.clone()
+    }
+
     pub fn source_table_name(&self) -> &str {
         &self.table_name
     }
@@ -191,6 +201,15 @@ impl StreamTable {
     ) -> Result<(PartStatistics, Partitions)> {
         let start = Instant::now();
         let table = self.source_table(ctx.clone()).await?;
+        if let Some(source_version) = &self.source_version {
+            let change_type = push_downs
+                .as_ref()
+                .and_then(|v| v.change_type.clone())
+                .unwrap_or(ChangeType::Append);
+            return table
+                .read_changes_partitions(ctx, source_version, change_type, push_downs)
+                .await;
+        }
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
 
         let fuse_segment_io =
@@ -287,6 +306,14 @@ impl StreamTable {
     #[minitrace::trace]
     pub async fn check_stream_status(&self, ctx: Arc<dyn TableContext>) -> Result<StreamStatus> {
         let base_table = self.source_table(ctx).await?;
+        if let Some(source_version) = &self.source_version {
+            let status = if &base_table.changes_version().await? == source_version {
+                StreamStatus::NoData
+            } else {
+                StreamStatus::MayHaveData
+            };
+            return Ok(status);
+        }
         let status = if base_table.get_table_info().ident.seq == self.table_version {
             StreamStatus::NoData
         } else {
@@ -337,6 +364,10 @@ impl Table for StreamTable {
         match self.mode {
             StreamMode::AppendOnly => Ok(StreamMode::AppendOnly),
             StreamMode::Standard => {
+                // The removed data files of an external table are read as deletions.
+                if self.source_version.is_some() {
+                    return Ok(StreamMode::Standard);
+                }
                 if self.snapshot_location.is_none() {
                     return Ok(StreamMode::AppendOnly);
                 }
@@ -402,6 +433,9 @@ impl Table for StreamTable {
         ctx: Arc<dyn TableContext>,
         change_type: Option<ChangeType>,
     ) -> Result<Option<TableStatistics>> {
+        if self.source_version.is_some() {
+            return Ok(None);
+        }
         let table = self.source_table(ctx.clone()).await?;
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let change_type = change_type.unwrap_or(ChangeType::Append);
@@ -53,7 +53,7 @@ impl AsyncSystemTable for CatalogsTable {
         let mgr = CatalogManager::instance();
 
         let catalog_names = mgr
-            .list_catalogs(ctx.get_tenant().as_str(), ctx.txn_mgr())
+            .list_catalogs(ctx.get_tenant().as_str(), ctx.txn_mgr(), ctx.temp_tbl_mgr())
             .await?
             .into_iter()
             .map(|v| v.name())
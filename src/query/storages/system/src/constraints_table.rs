@@ -0,0 +1,231 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::UInt64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+
+/// Lists the constraints of the tables, one row for each column of a key constraint.
+pub struct ConstraintsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ConstraintsTable {
+    const NAME: &'static str = "system.constraints";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let visibility_checker = ctx.get_visibility_checker().await?;
+
+        let mut db_names: Vec<String> = Vec::new();
+        if let Some(filter) = push_downs
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| &f.filter)
+        {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if col_name == "database" {
+                    if let Scalar::String(database) = scalar {
+                        if !db_names.contains(database) {
+                            db_names.push(database.clone());
+                        }
+                    }
+                }
+            });
+        }
+        let dbs = if db_names.is_empty() {
+            catalog.list_databases(tenant.as_str()).await?
+        } else {
+            let mut dbs = Vec::with_capacity(db_names.len());
+            for db_name in db_names {
+                if let Ok(db) = catalog.get_database(tenant.as_str(), &db_name).await {
+                    dbs.push(db);
+                }
+            }
+            dbs
+        };
+
+        let mut databases = vec![];
+        let mut tables = vec![];
+        let mut names = vec![];
+        let mut types = vec![];
+        let mut column_names = vec![];
+        let mut ordinal_positions = vec![];
+        let mut ref_databases = vec![];
+        let mut ref_tables = vec![];
+        let mut ref_columns = vec![];
+        let mut expressions = vec![];
+
+        for db in dbs {
+            let db_id = db.get_db_info().ident.db_id;
+            if !visibility_checker.check_database_visibility(CATALOG_DEFAULT, db.name(), db_id) {
+                continue;
+            }
+            for table in catalog.list_tables(tenant.as_str(), db.name()).await? {
+                let meta = &table.get_table_info().meta;
+                if meta.constraints.is_empty()
+                    || !visibility_checker.check_table_visibility(
+                        CATALOG_DEFAULT,
+                        db.name(),
+                        table.name(),
+                        db_id,
+                        table.get_id(),
+                    )
+                {
+                    continue;
+                }
+
+                for (name, constraint) in &meta.constraints {
+                    let mut push_row =
+                        |column: Option<String>,
+                         position: Option<u64>,
+                         reference: Option<(String, String, String)>,
+                         expression: Option<String>| {
+                            databases.push(db.name().to_string());
+                            tables.push(table.name().to_string());
+                            names.push(name.clone());
+                            types.push(constraint.constraint_type().to_string());
+                            column_names.push(column);
+                            ordinal_positions.push(position);
+                            let (ref_database, ref_table, ref_column) = match reference {
+                                Some((d, t, c)) => (Some(d), Some(t), Some(c)),
+                                None => (None, None, None),
+                            };
+                            ref_databases.push(ref_database);
+                            ref_tables.push(ref_table);
+                            ref_columns.push(ref_column);
+                            expressions.push(expression);
+                        };
+
+                    if let TableConstraint::Check { expr } = constraint {
+                        push_row(None, None, None, Some(expr.clone()));
+                        continue;
+                    }
+                    for (i, column_id) in constraint.column_ids().iter().enumerate() {
+                        // Columns dropped after the constraint was created are skipped.
+                        let Some(field) = meta
+                            .schema
+                            .fields()
+                            .iter()
+                            .find(|f| f.column_id() == *column_id)
+                        else {
+                            continue;
+                        };
+                        let reference = match constraint {
+                            TableConstraint::ForeignKey {
+                                ref_database,
+                                ref_table,
+                                ref_columns,
+                                ..
+                            } => Some((
+                                ref_database.clone(),
+                                ref_table.clone(),
+                                ref_columns[i].clone(),
+                            )),
+                            _ => None,
+                        };
+                        push_row(
+                            Some(field.name().clone()),
+                            Some(i as u64 + 1),
+                            reference,
+                            None,
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(names),
+            StringType::from_data(types),
+            StringType::from_opt_data(column_names),
+            UInt64Type::from_opt_data(ordinal_positions),
+            StringType::from_opt_data(ref_databases),
+            StringType::from_opt_data(ref_tables),
+            StringType::from_opt_data(ref_columns),
+            StringType::from_opt_data(expressions),
+        ]))
+    }
+}
+
+impl ConstraintsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let nullable_string = TableDataType::Nullable(Box::new(TableDataType::String));
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("type", TableDataType::String),
+            TableField::new("column_name", nullable_string.clone()),
+            TableField::new(
+                "ordinal_position",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new("referenced_database", nullable_string.clone()),
+            TableField::new("referenced_table", nullable_string.clone()),
+            TableField::new("referenced_column", nullable_string.clone()),
+            TableField::new("expression", nullable_string),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'constraints'".to_string(),
+            name: "constraints".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemConstraints".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
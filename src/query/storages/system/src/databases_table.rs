@@ -58,7 +58,7 @@ impl AsyncSystemTable for DatabasesTable {
         let tenant = ctx.get_tenant();
         let catalogs = CatalogManager::instance();
         let catalogs: Vec<(String, Arc<dyn Catalog>)> = catalogs
-            .list_catalogs(tenant.as_str(), ctx.txn_mgr())
+            .list_catalogs(tenant.as_str(), ctx.txn_mgr(), ctx.temp_tbl_mgr())
             .await?
             .iter()
             .map(|e| (e.name(), e.clone()))
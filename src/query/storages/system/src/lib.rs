@@ -30,6 +30,7 @@ mod clustering_history_table;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
+mod constraints_table;
 mod contributors_table;
 mod credits_table;
 mod databases_table;
@@ -45,6 +46,8 @@ mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod plan_baselines_table;
+mod plan_cache_table;
 mod processes_table;
 mod processor_profile_table;
 mod queries_queue;
@@ -78,6 +81,7 @@ pub use clustering_history_table::ClusteringHistoryTable;
 pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
+pub use constraints_table::ConstraintsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
@@ -96,6 +100,8 @@ pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use plan_baselines_table::PlanBaselinesTable;
+pub use plan_cache_table::PlanCacheTable;
 pub use processes_table::ProcessesTable;
 pub use processor_profile_table::ProcessorProfileTable;
 pub use queries_queue::QueriesQueueTable;
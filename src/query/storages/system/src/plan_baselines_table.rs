@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::PlanBaselineStats;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct PlanBaselinesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PlanBaselinesTable {
+    const NAME: &'static str = "system.plan_baselines";
+
+    // The usage of the baselines is summed over the nodes in the meta.
+    const IS_LOCAL: bool = true;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let user_api = UserApiProvider::instance();
+        let baselines = user_api.get_plan_baselines(&tenant).await?;
+        let mut usages = user_api.get_plan_baseline_usages(&tenant).await?;
+        let stats = PlanBaselineStats::instance();
+
+        let mut fingerprints = Vec::with_capacity(baselines.len());
+        let mut queries = Vec::with_capacity(baselines.len());
+        let mut catalogs = Vec::with_capacity(baselines.len());
+        let mut databases = Vec::with_capacity(baselines.len());
+        let mut outlines = Vec::with_capacity(baselines.len());
+        let mut created_on = Vec::with_capacity(baselines.len());
+        let mut uses = Vec::with_capacity(baselines.len());
+        let mut last_used_on = Vec::with_capacity(baselines.len());
+        for baseline in baselines {
+            // The usage on this node which is not added to the meta yet is counted as well.
+            let mut usage = usages.remove(&baseline.fingerprint).unwrap_or_default();
+            usage.merge(&stats.pending_usage(&tenant, &baseline.fingerprint));
+            let outline = baseline
+                .outline
+                .iter()
+                .map(|outline| outline.to_string())
+                .collect::<Vec<_>>();
+
+            fingerprints.push(baseline.fingerprint);
+            queries.push(baseline.query);
+            catalogs.push(baseline.catalog);
+            databases.push(baseline.database);
+            outlines.push(outline.join(", "));
+            created_on.push(baseline.created_on.timestamp_micros());
+            uses.push(usage.uses);
+            last_used_on.push(usage.last_used_on.map(|ts| ts.timestamp_micros()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(fingerprints),
+            StringType::from_data(queries),
+            StringType::from_data(catalogs),
+            StringType::from_data(databases),
+            StringType::from_data(outlines),
+            TimestampType::from_data(created_on),
+            UInt64Type::from_data(uses),
+            TimestampType::from_opt_data(last_used_on),
+        ]))
+    }
+}
+
+impl PlanBaselinesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("fingerprint", TableDataType::String),
+            TableField::new("query", TableDataType::String),
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("outline", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("uses", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "last_used_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'plan_baselines'".to_string(),
+            name: "plan_baselines".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPlanBaselines".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PlanBaselinesTable { table_info })
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::PlanCache;
+use databend_common_storages_fuse::TableContext;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+pub struct PlanCacheTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for PlanCacheTable {
+    const NAME: &'static str = "system.plan_cache";
+
+    // Allow distributed query.
+    const IS_LOCAL: bool = false;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let local_node = ctx.get_cluster().local_id.clone();
+        let items = PlanCache::instance().items(ctx.get_tenant().as_str());
+
+        let mut nodes = Vec::with_capacity(items.len());
+        let mut queries = Vec::with_capacity(items.len());
+        let mut parameterized = Vec::with_capacity(items.len());
+        let mut hits = Vec::with_capacity(items.len());
+        let mut misses = Vec::with_capacity(items.len());
+        let mut created_on = Vec::with_capacity(items.len());
+        let mut last_used_on = Vec::with_capacity(items.len());
+        for item in items {
+            nodes.push(local_node.clone());
+            queries.push(item.query);
+            parameterized.push(item.parameterized);
+            hits.push(item.hits);
+            misses.push(item.misses);
+            created_on.push(item.created_on);
+            last_used_on.push(item.last_used_on);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(nodes),
+            StringType::from_data(queries),
+            BooleanType::from_data(parameterized),
+            UInt64Type::from_data(hits),
+            UInt64Type::from_data(misses),
+            TimestampType::from_data(created_on),
+            TimestampType::from_data(last_used_on),
+        ]))
+    }
+}
+
+impl PlanCacheTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new("query", TableDataType::String),
+            TableField::new("parameterized", TableDataType::Boolean),
+            TableField::new("hits", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("misses", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("last_used_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'plan_cache'".to_string(),
+            name: "plan_cache".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPlanCache".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -106,7 +106,7 @@ where TablesTable<T>: HistoryAware
         let tenant = ctx.get_tenant();
         let catalog_mgr = CatalogManager::instance();
         let catalogs = catalog_mgr
-            .list_catalogs(tenant.as_str(), ctx.txn_mgr())
+            .list_catalogs(tenant.as_str(), ctx.txn_mgr(), ctx.temp_tbl_mgr())
             .await?;
         let visibility_checker = ctx.get_visibility_checker().await?;
 
@@ -20,6 +20,7 @@ extern crate core;
 mod jwt;
 mod network_policy;
 mod password_policy;
+mod plan_baseline;
 mod role_mgr;
 mod user;
 mod user_api;
@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::principal::PlanBaselineUsage;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_types::NonEmptyString;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a plan baseline.
+    #[async_backtrace::framed]
+    pub async fn add_plan_baseline(
+        &self,
+        tenant: &NonEmptyString,
+        baseline: PlanBaseline,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let client = self.plan_baseline_api(tenant);
+        client.add(baseline, create_option).await
+    }
+
+    // Get the plan baseline of a statement fingerprint.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baseline(
+        &self,
+        tenant: &NonEmptyString,
+        fingerprint: &str,
+    ) -> Result<Option<PlanBaseline>> {
+        let client = self.plan_baseline_api(tenant);
+        client.get(fingerprint).await
+    }
+
+    // Get all plan baselines of tenant.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baselines(&self, tenant: &NonEmptyString) -> Result<Vec<PlanBaseline>> {
+        let client = self.plan_baseline_api(tenant);
+        client.list().await
+    }
+
+    // Get the fingerprints of all plan baselines of tenant, along with their seqs.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baseline_fingerprints(
+        &self,
+        tenant: &NonEmptyString,
+    ) -> Result<HashMap<String, u64>> {
+        let client = self.plan_baseline_api(tenant);
+        client.list_fingerprints().await
+    }
+
+    // Add to the usage of the plan baseline of a statement fingerprint.
+    #[async_backtrace::framed]
+    pub async fn add_plan_baseline_usage(
+        &self,
+        tenant: &NonEmptyString,
+        fingerprint: &str,
+        usage: PlanBaselineUsage,
+    ) -> Result<()> {
+        let client = self.plan_baseline_api(tenant);
+        client.add_usage(fingerprint, usage).await
+    }
+
+    // Get the usages of all plan baselines of tenant.
+    #[async_backtrace::framed]
+    pub async fn get_plan_baseline_usages(
+        &self,
+        tenant: &NonEmptyString,
+    ) -> Result<HashMap<String, PlanBaselineUsage>> {
+        let client = self.plan_baseline_api(tenant);
+        client.list_usages().await
+    }
+
+    // Drop the plan baseline of a statement fingerprint.
+    #[async_backtrace::framed]
+    pub async fn drop_plan_baseline(
+        &self,
+        tenant: &NonEmptyString,
+        fingerprint: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.plan_baseline_api(tenant);
+        match client.remove(fingerprint).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PLAN_BASELINE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop plan baseline)"))
+                }
+            }
+        }
+    }
+}
@@ -27,6 +27,8 @@ use databend_common_management::NetworkPolicyApi;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyApi;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PlanBaselineApi;
+use databend_common_management::PlanBaselineMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
 use databend_common_management::RoleApi;
@@ -165,6 +167,10 @@ impl UserApiProvider {
         Arc::new(PasswordPolicyMgr::create(self.client.clone(), tenant))
     }
 
+    pub fn plan_baseline_api(&self, tenant: &NonEmptyString) -> Arc<dyn PlanBaselineApi> {
+        Arc::new(PlanBaselineMgr::create(self.client.clone(), tenant))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
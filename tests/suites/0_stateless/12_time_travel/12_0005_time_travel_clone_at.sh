@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t12_0005_clone" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists t12_0005_clone_at" | $BENDSQL_CLIENT_CONNECT
+echo "create table t12_0005_clone(a int, b int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005_clone values(1, 10),(2, 20)" | $BENDSQL_CLIENT_CONNECT
+
+SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default','t12_0005_clone') limit 1" | $BENDSQL_CLIENT_CONNECT)
+
+# alter table add a column will create a new snapshot
+echo "alter table t12_0005_clone add column c int default 3" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005_clone values(3, 30, 33)" | $BENDSQL_CLIENT_CONNECT
+
+echo "the clone takes the schema of the snapshot it is cloned at"
+echo "create table t12_0005_clone_at clone t12_0005_clone at (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+echo "select name from system.columns where database = 'default' and table = 't12_0005_clone_at' order by name" | $BENDSQL_CLIENT_CONNECT
+echo "select * from t12_0005_clone_at order by a" | $BENDSQL_CLIENT_CONNECT
+
+echo "columns added to the clone later do not mix with the ones of the source"
+echo "alter table t12_0005_clone_at add column d string default 'd'" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005_clone_at values(4, 40, 'x')" | $BENDSQL_CLIENT_CONNECT
+echo "select * from t12_0005_clone_at order by a" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop table t12_0005_clone_at" | $BENDSQL_CLIENT_CONNECT
+echo "drop table t12_0005_clone" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,93 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+echo "drop database if exists db16" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db16_clone" | $BENDSQL_CLIENT_CONNECT
+echo "create database db16" | $BENDSQL_CLIENT_CONNECT
+
+echo "create table db16.t1(c int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "create table db16.t2(c int not null)" | $BENDSQL_CLIENT_CONNECT
+# t3 has no data at the time point
+echo "create table db16.t3(c int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db16.t1 values(1),(2)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db16.t2 values(1)" | $BENDSQL_CLIENT_CONNECT
+# t5, t6 and t8 are renamed after the time point
+echo "create table db16.t5(c int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "create table db16.t6(c int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "create table db16.t8(c int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db16.t5 values(5)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db16.t6 values(6)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db16.t8 values(8),(8)" | $BENDSQL_CLIENT_CONNECT
+
+sleep 1
+TIMEPOINT=$(echo "select now()" | $BENDSQL_CLIENT_CONNECT)
+sleep 1
+
+# the changes after the time point
+echo "insert into db16.t1 values(3)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db16.t3 values(1)" | $BENDSQL_CLIENT_CONNECT
+echo "drop table db16.t2" | $BENDSQL_CLIENT_CONNECT
+echo "create table db16.t2(c int not null, d int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db16.t2 values(1, 1),(2, 2)" | $BENDSQL_CLIENT_CONNECT
+echo "create table db16.t4(c int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "rename table db16.t5 to db16.t7" | $BENDSQL_CLIENT_CONNECT
+echo "create table db16.t5(c int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db16.t5 values(50)" | $BENDSQL_CLIENT_CONNECT
+# swap the names of t6 and t8
+echo "rename table db16.t6 to db16.t9" | $BENDSQL_CLIENT_CONNECT
+echo "rename table db16.t8 to db16.t6" | $BENDSQL_CLIENT_CONNECT
+echo "rename table db16.t9 to db16.t8" | $BENDSQL_CLIENT_CONNECT
+
+echo "create database db16_clone clone db16 at (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT
+echo "checking that the clone has the data of t1 at the time point"
+echo "select count(*)=2 from db16_clone.t1" | $BENDSQL_CLIENT_CONNECT
+echo "checking that the clone has the dropped t2 at the time point"
+echo "select count(*)=1 from db16_clone.t2" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=1 from system.columns where database='db16_clone' and table='t2'" | $BENDSQL_CLIENT_CONNECT
+echo "checking that the clone has no data of t3"
+echo "select count(*)=0 from db16_clone.t3" | $BENDSQL_CLIENT_CONNECT
+echo "checking that the clone has no t4"
+echo "select count(*)=0 from system.tables where database='db16_clone' and name='t4'" | $BENDSQL_CLIENT_CONNECT
+echo "checking that the clone has the renamed tables under their names at the time point"
+echo "select sum(c)=5 from db16_clone.t5" | $BENDSQL_CLIENT_CONNECT
+echo "select sum(c)=6 from db16_clone.t6" | $BENDSQL_CLIENT_CONNECT
+echo "select sum(c)=16 from db16_clone.t8" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=0 from system.tables where database='db16_clone' and name in ('t7', 't9')" | $BENDSQL_CLIENT_CONNECT
+
+echo "alter database db16 flashback to (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT
+echo "checking that after flashback, t1 has 2 rows"
+echo "select count(*)=2 from db16.t1" | $BENDSQL_CLIENT_CONNECT
+echo "checking that after flashback, the dropped t2 is restored"
+echo "select count(*)=1 from db16.t2" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=1 from system.columns where database='db16' and table='t2'" | $BENDSQL_CLIENT_CONNECT
+echo "checking that after flashback, t3 is empty"
+echo "select count(*)=0 from db16.t3" | $BENDSQL_CLIENT_CONNECT
+echo "checking that after flashback, t4 is dropped and can be undropped"
+echo "select count(*)=0 from system.tables where database='db16' and name='t4'" | $BENDSQL_CLIENT_CONNECT
+echo "undrop table db16.t4" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=0 from db16.t4" | $BENDSQL_CLIENT_CONNECT
+echo "checking that after flashback, the renamed tables have their names at the time point"
+echo "select sum(c)=5 from db16.t5" | $BENDSQL_CLIENT_CONNECT
+echo "select sum(c)=6 from db16.t6" | $BENDSQL_CLIENT_CONNECT
+echo "select sum(c)=16 from db16.t8" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=0 from system.tables where database='db16' and name in ('t7', 't9')" | $BENDSQL_CLIENT_CONNECT
+echo "checking that flashback to the same time point again changes nothing"
+echo "alter database db16 flashback to (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $BENDSQL_CLIENT_CONNECT
+echo "select sum(c)=5 from db16.t5" | $BENDSQL_CLIENT_CONNECT
+echo "select sum(c)=6 from db16.t6" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=2 from db16.t1" | $BENDSQL_CLIENT_CONNECT
+
+echo "checking that the clone is independent of the database"
+echo "insert into db16_clone.t1 values(4)" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=2 from db16.t1" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=3 from db16_clone.t1" | $BENDSQL_CLIENT_CONNECT
+
+echo "flash back a database to a snapshot should report error 1065"
+echo "alter database db16 flashback to (snapshot => 'NOT_EXIST')" | $BENDSQL_CLIENT_CONNECT
+
+## Drop database.
+echo "drop database db16_clone" | $BENDSQL_CLIENT_CONNECT
+echo "drop database db16" | $BENDSQL_CLIENT_CONNECT
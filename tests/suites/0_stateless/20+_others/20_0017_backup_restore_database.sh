@@ -0,0 +1,49 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+echo "drop database if exists db20_17" | $BENDSQL_CLIENT_CONNECT
+echo "drop database if exists db20_17_restored" | $BENDSQL_CLIENT_CONNECT
+echo "drop stage if exists s20_17" | $BENDSQL_CLIENT_CONNECT
+echo "create stage s20_17" | $BENDSQL_CLIENT_CONNECT
+echo "create database db20_17" | $BENDSQL_CLIENT_CONNECT
+
+echo "create table db20_17.t1(c int not null) cluster by (c)" | $BENDSQL_CLIENT_CONNECT
+echo "create table db20_17.t2(c int not null, s string)" | $BENDSQL_CLIENT_CONNECT
+echo "create view db20_17.v1 as select * from db20_17.t1 where c > 1" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db20_17.t1 values(1),(2)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into db20_17.t2 values(1, 'a')" | $BENDSQL_CLIENT_CONNECT
+
+echo "backup database db20_17 to @s20_17/backups/" | $BENDSQL_CLIENT_CONNECT
+
+echo "checking that an incremental backup only records the new data"
+echo "insert into db20_17.t1 values(3)" | $BENDSQL_CLIENT_CONNECT
+echo "backup database db20_17 to @s20_17/backups/" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=2 from list_stage(location => '@s20_17/backups/backup_manifests/')" | $BENDSQL_CLIENT_CONNECT
+
+echo "checking that the latest backup is restored"
+echo "restore database db20_17_restored from @s20_17/backups/" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=3 from db20_17_restored.t1" | $BENDSQL_CLIENT_CONNECT
+echo "select * from db20_17_restored.t2" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=2 from db20_17_restored.v1" | $BENDSQL_CLIENT_CONNECT
+echo "select cluster_by from system.tables where database='db20_17_restored' and name='t1'" | $BENDSQL_CLIENT_CONNECT
+
+echo "checking that the restored database is independent of the database"
+echo "insert into db20_17_restored.t1 values(4)" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=3 from db20_17.t1" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*)=4 from db20_17_restored.t1" | $BENDSQL_CLIENT_CONNECT
+
+echo "restore to an existing database should report error 2301"
+echo "restore database db20_17 from @s20_17/backups/" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "ResponseError with 2301"
+
+echo "checking that a failed restore leaves no database behind"
+echo "remove @s20_17/backups/data/" | $BENDSQL_CLIENT_CONNECT
+echo "restore database db20_17_broken from @s20_17/backups/" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -c "ResponseError"
+echo "select count(*) from system.databases where name = 'db20_17_broken'" | $BENDSQL_CLIENT_CONNECT
+
+## Drop database and stage.
+echo "drop database db20_17_restored" | $BENDSQL_CLIENT_CONNECT
+echo "drop database db20_17" | $BENDSQL_CLIENT_CONNECT
+echo "drop stage s20_17" | $BENDSQL_CLIENT_CONNECT
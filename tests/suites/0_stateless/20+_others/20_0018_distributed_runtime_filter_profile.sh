@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t20_0018_probe" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists t20_0018_build" | $BENDSQL_CLIENT_CONNECT
+echo "create table t20_0018_probe(a int not null)" | $BENDSQL_CLIENT_CONNECT
+echo "create table t20_0018_build(a int not null)" | $BENDSQL_CLIENT_CONNECT
+
+# Every insert writes one block, only the first block of the probe table has the keys of the build table.
+for i in $(seq 0 59); do
+	echo "insert into t20_0018_probe select number + $((i * 1000)) from numbers(1000)" | $BENDSQL_CLIENT_CONNECT
+done
+echo "insert into t20_0018_build values(3), (500)" | $BENDSQL_CLIENT_CONNECT
+
+NODES=$(echo "select count(*) from system.clusters" | $BENDSQL_CLIENT_CONNECT)
+QUERY="explain analyze select count(*) from t20_0018_probe join t20_0018_build on t20_0018_probe.a = t20_0018_build.a"
+
+# The profiles of the probe scans of all nodes are merged in the plan, the timings can't be matched,
+# so only the number of parts pruned by the runtime filters is checked.
+PROFILE=$(echo "set prefer_broadcast_join = 0; set max_threads = 1; $QUERY" | $BENDSQL_CLIENT_CONNECT)
+
+echo "expects the probe scan to prune parts by the runtime filters"
+echo "$PROFILE" | grep -c "parts pruned by runtime filter"
+
+# Under cluster, each node reads about 60 / nodes parts, pruning more than that needs the merged
+# filters to reach the probe scans of other nodes.
+echo "expects more parts to be pruned than a single node reads"
+echo "$PROFILE" | awk -F': ' -v nodes="$NODES" '/parts pruned by runtime filter/ { print ($2 > 60 / nodes) }'
+
+# Without the distributed runtime filters, the shuffle join under cluster doesn't prune any parts.
+echo "expects no parts to be pruned under cluster without the distributed runtime filters"
+PROFILE=$(echo "set prefer_broadcast_join = 0; set max_threads = 1; set enable_distributed_runtime_filter = 0; $QUERY" | $BENDSQL_CLIENT_CONNECT)
+echo "$PROFILE" | grep -c "parts pruned by runtime filter"
+
+echo "drop table t20_0018_probe" | $BENDSQL_CLIENT_CONNECT
+echo "drop table t20_0018_build" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop user if exists u_temp_tbl;" | $BENDSQL_CLIENT_CONNECT
+echo "create user u_temp_tbl identified by 'password';" | $BENDSQL_CLIENT_CONNECT
+
+query() {
+	curl -s -u "$1" -XPOST "http://localhost:8000/v1/query" --header 'Content-Type: application/json' -d "$2"
+}
+
+# the temporary table is handed over to the next query of root
+session=$(query root: '{"sql": "create temporary table t_handover(a int)", "pagination": {"wait_time_secs": 5}}' | jq -c ".session")
+session=$(query root: '{"sql": "insert into t_handover values (1)", "pagination": {"wait_time_secs": 5}, "session": '"$session"'}' | jq -c ".session")
+
+# another user can not take the temporary table with the session of root
+other_session=$(echo "$session" | jq -c 'del(.role)')
+query u_temp_tbl:password '{"sql": "select * from t_handover", "pagination": {"wait_time_secs": 5}, "session": '"$other_session"'}' | jq ".error.code"
+
+query root: '{"sql": "select * from t_handover", "pagination": {"wait_time_secs": 5}, "session": '"$session"'}' | jq -c ".data"
+
+echo "drop user if exists u_temp_tbl;" | $BENDSQL_CLIENT_CONNECT
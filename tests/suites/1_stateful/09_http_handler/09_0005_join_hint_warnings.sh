@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t_hint1;" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists t_hint2;" | $BENDSQL_CLIENT_CONNECT
+echo "create table t_hint1(a int);" | $BENDSQL_CLIENT_CONNECT
+echo "create table t_hint2(a int);" | $BENDSQL_CLIENT_CONNECT
+
+function query_warnings() {
+	curl -s --header 'Content-Type: application/json' --request POST '127.0.0.1:8000/v1/query/' --data-raw "{\"sql\": \"$1\", \"pagination\": { \"wait_time_secs\": 5}}" -u root: | jq -c ".warnings"
+}
+
+# unknown table
+query_warnings "select /*+ LEADING(t_hint3 t_hint1) */ count(*) from t_hint1, t_hint2 where t_hint1.a = t_hint2.a"
+# the table is in the outer query block
+query_warnings "select count(*) from t_hint1 where a in (select /*+ HASH_JOIN(t_hint1) */ x.a from t_hint2 x, t_hint2 y where x.a = y.a)"
+# no join with the table
+query_warnings "select /*+ HASH_JOIN(t_hint1) */ count(*) from t_hint1"
+# applied hint
+query_warnings "select /*+ HASH_JOIN(t_hint1) */ count(*) from t_hint1, t_hint2 where t_hint1.a = t_hint2.a"
+
+echo "drop table if exists t_hint1;" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists t_hint2;" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,53 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# The tables are copied to a temp dir, so that new versions can be committed to them.
+WORKDIR=$(mktemp -d)
+ICEBERG_ROOT=${WORKDIR}/iceberg_tbl
+DELTA_ROOT=${WORKDIR}/delta_tbl
+cp -r "$TESTS_DATA_DIR"/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl "$ICEBERG_ROOT"
+cp -r "$TESTS_DATA_DIR"/delta/partitioned "$DELTA_ROOT"
+
+stmt "drop database if exists db_stream_external;"
+stmt "create database db_stream_external;"
+
+comment "stream on iceberg table"
+# Hide the second snapshot of the iceberg table.
+mv "$ICEBERG_ROOT"/metadata/00002-*.metadata.json "$WORKDIR"/
+echo ">>>> create table db_stream_external.t_iceberg engine = iceberg location = 'fs://\${ICEBERG_ROOT}/';"
+echo "create table db_stream_external.t_iceberg engine = iceberg location = 'fs://${ICEBERG_ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+stmt "create stream db_stream_external.s_iceberg on table db_stream_external.t_iceberg append_only = false;"
+query "select id, data, change\$action, change\$is_update from db_stream_external.s_iceberg order by id;"
+
+# Commit the second snapshot.
+mv "$WORKDIR"/00002-*.metadata.json "$ICEBERG_ROOT"/metadata/
+query "select id, data, change\$action, change\$is_update from db_stream_external.s_iceberg order by id;"
+
+stmt "create table db_stream_external.sink_iceberg(id int, data string);"
+stmt "insert into db_stream_external.sink_iceberg select id, data from db_stream_external.s_iceberg;"
+query "select * from db_stream_external.sink_iceberg order by id;"
+query "select count(*) from db_stream_external.s_iceberg;"
+
+comment "stream on delta table"
+# Hide the last version of the delta table.
+mv "$DELTA_ROOT"/_delta_log/00000000000000000005.json "$WORKDIR"/
+echo ">>>> create table db_stream_external.t_delta engine = delta location = 'fs://\${DELTA_ROOT}/';"
+echo "create table db_stream_external.t_delta engine = delta location = 'fs://${DELTA_ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+stmt "create stream db_stream_external.s_delta on table db_stream_external.t_delta append_only = false;"
+query "select c1, change\$action from db_stream_external.s_delta order by c1;"
+
+# Commit version 5, which appends a file.
+mv "$WORKDIR"/00000000000000000005.json "$DELTA_ROOT"/_delta_log/
+query "select c1, change\$action from db_stream_external.s_delta order by c1;"
+
+# Commit version 6, which removes the file of partition p0=10/p2=32/p4=34.
+cat > "$DELTA_ROOT"/_delta_log/00000000000000000006.json <<'LOG'
+{"commitInfo":{"timestamp":1701923000000,"operation":"DELETE","operationParameters":{"predicate":"[\"(c1 = 31)\"]"},"isolationLevel":"Serializable","isBlindAppend":false}}
+{"remove":{"path":"p0=10/p2=32/p4=34/part-00000-059b1fce-f443-4514-ba9a-1844251fc345.c000.snappy.parquet","deletionTimestamp":1701923000000,"dataChange":true,"extendedFileMetadata":true,"partitionValues":{"p0":"10","p2":"32","p4":"34"},"size":889}}
+LOG
+query "select c1, change\$action, change\$is_update from db_stream_external.s_delta order by c1;"
+
+stmt "drop database db_stream_external;"
+rm -rf "$WORKDIR"